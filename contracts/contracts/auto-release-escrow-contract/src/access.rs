@@ -0,0 +1,112 @@
+use paystar_common::AccessControl;
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::{
+    error::ContractError,
+    governance,
+    storage::{self, Role},
+};
+
+/// Binds the shared admin, role and pause logic to this contract's storage keys.
+pub struct EscrowAccess;
+
+impl AccessControl for EscrowAccess {
+    const CONTRACT: Symbol = symbol_short!("escrow");
+    const ROLES: &'static [Role] = &[Role::Arbiter, Role::Pauser, Role::FeeManager];
+
+    fn load_admin(env: &Env) -> Option<Address> {
+        storage::get_admin(env)
+    }
+
+    fn store_admin(env: &Env, admin: &Address) {
+        storage::set_admin(env, admin);
+    }
+
+    fn load_pending_admin(env: &Env) -> Option<Address> {
+        storage::get_pending_admin(env)
+    }
+
+    fn store_pending_admin(env: &Env, pending_admin: Option<&Address>) {
+        match pending_admin {
+            Some(pending_admin) => storage::set_pending_admin(env, pending_admin),
+            None => storage::remove_pending_admin(env),
+        }
+    }
+
+    fn has_granted_role(env: &Env, role: Role, account: &Address) -> bool {
+        storage::has_granted_role(env, role, account)
+    }
+
+    fn store_role(env: &Env, role: Role, account: &Address, granted: bool) {
+        if granted {
+            storage::grant_role(env, role, account);
+        } else {
+            storage::revoke_role(env, role, account);
+        }
+    }
+
+    fn load_paused(env: &Env) -> bool {
+        storage::is_paused(env)
+    }
+
+    fn store_paused(env: &Env, paused: bool) {
+        storage::set_paused(env, paused);
+    }
+}
+
+/// Checks the caller's authorization and that it is the admin.
+pub fn require_admin(env: &Env, caller: &Address) -> Result<(), ContractError> {
+    Ok(EscrowAccess::require_admin(env, caller)?)
+}
+
+/// Checks the caller's authorization and that it holds `role` (the admin holds every role).
+pub fn require_role(env: &Env, role: Role, caller: &Address) -> Result<(), ContractError> {
+    Ok(EscrowAccess::require_role(env, role, caller)?)
+}
+
+/// Allows the admin to propose a new admin. The handover only happens once the
+/// proposed admin accepts, so a mistyped address cannot take control.
+pub fn propose_admin(env: &Env, admin: Address, new_admin: Address) -> Result<(), ContractError> {
+    Ok(EscrowAccess::propose_admin(env, &admin, &new_admin)?)
+}
+
+/// Allows the proposed admin to accept the handover.
+pub fn accept_admin(env: &Env, new_admin: Address) -> Result<(), ContractError> {
+    Ok(EscrowAccess::accept_admin(env, &new_admin)?)
+}
+
+/// Allows the admin to grant a non-admin role to an account.
+pub fn grant_role(
+    env: &Env,
+    admin: Address,
+    role: Role,
+    account: Address,
+) -> Result<(), ContractError> {
+    // The arbiter registry is governed by the signers once governance is enabled
+    if role == Role::Arbiter {
+        governance::require_disabled(env)?;
+    }
+    Ok(EscrowAccess::set_role(env, &admin, role, &account, true)?)
+}
+
+/// Allows the admin to revoke a non-admin role from an account.
+pub fn revoke_role(
+    env: &Env,
+    admin: Address,
+    role: Role,
+    account: Address,
+) -> Result<(), ContractError> {
+    if role == Role::Arbiter {
+        governance::require_disabled(env)?;
+    }
+    Ok(EscrowAccess::set_role(env, &admin, role, &account, false)?)
+}
+
+/// Allows a pauser to pause or unpause the contract.
+pub fn set_paused(env: &Env, pauser: Address, paused: bool) -> Result<(), ContractError> {
+    Ok(EscrowAccess::set_paused(env, &pauser, paused)?)
+}
+
+pub fn require_not_paused(env: &Env) -> Result<(), ContractError> {
+    Ok(EscrowAccess::require_not_paused(env)?)
+}
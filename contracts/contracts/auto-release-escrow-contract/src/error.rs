@@ -1,3 +1,4 @@
+use paystar_common::CommonError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -6,6 +7,8 @@ use soroban_sdk::contracterror;
 pub enum ContractError {
     // State Errors
     AlreadyInitialized = 1,
+    NotInitialized = 35,
+    ContractPaused = 22,
     ReleaseTimeNotPassed = 2,
     EscrowNotActive = 3,
     EscrowAlreadyDisputed = 4,
@@ -14,9 +17,54 @@ pub enum ContractError {
     // Authorization Errors
     NotAdmin = 6,
     NotBuyer = 7,
+    MissingRole = 23,
+    NotPendingAdmin = 24,
+    NotSeller = 13,
+    NotParticipant = 15,
+    NotPayer = 20,
+
+    // Upgrade Errors
+    AlreadyMigrated = 21,
+    InvalidRole = 25,
 
     // Data Errors
     EscrowNotFound = 8,
     InvalidAmount = 9,
     InvalidReleaseTime = 10,
+    InvalidFee = 11,
+    InsufficientFees = 12,
+    EscrowAlreadyAcknowledged = 14,
+    NoPendingExtension = 16,
+    ExtensionLimitReached = 17,
+    CannotAcceptOwnExtension = 18,
+    InvalidKeeperReward = 19,
+    AmountOverflow = 36,
+    InsufficientBalance = 37,
+
+    // Governance Errors
+    GovernanceRequired = 26,
+    GovernanceNotEnabled = 27,
+    NotSigner = 28,
+    ProposalNotFound = 29,
+    ProposalExpired = 30,
+    ProposalAlreadyApproved = 31,
+    ProposalAlreadyExecuted = 32,
+    ThresholdNotMet = 33,
+    InvalidGovernanceConfig = 34,
+}
+
+impl From<CommonError> for ContractError {
+    fn from(error: CommonError) -> Self {
+        match error {
+            CommonError::NotInitialized => ContractError::NotInitialized,
+            CommonError::NotAdmin => ContractError::NotAdmin,
+            CommonError::MissingRole => ContractError::MissingRole,
+            CommonError::NotPendingAdmin => ContractError::NotPendingAdmin,
+            CommonError::InvalidRole => ContractError::InvalidRole,
+            CommonError::ContractPaused => ContractError::ContractPaused,
+            CommonError::InvalidAmount => ContractError::InvalidAmount,
+            CommonError::AmountOverflow => ContractError::AmountOverflow,
+            CommonError::InsufficientBalance => ContractError::InsufficientBalance,
+        }
+    }
 }
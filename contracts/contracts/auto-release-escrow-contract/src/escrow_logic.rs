@@ -1,30 +1,32 @@
-use soroban_sdk::{token, Address, Env, String};
+use paystar_common::{amount, token, AccessControl};
+use soroban_sdk::{Address, Env, String, Vec};
 
 use crate::{
+    access::{self, EscrowAccess},
     error::ContractError,
-    event,
-    storage::{self, Escrow, EscrowStatus},
+    event, governance,
+    storage::{self, Escrow, EscrowStatus, ExtensionProposal, FeeConfig, ReleaseExtension, Role},
+    upgrade,
 };
 
+/// Upper bound on the keeper reward, as a share of the platform fee (10%).
+pub const MAX_KEEPER_REWARD_BPS: u32 = 1_000;
+/// Upper bound on the platform fee (10%).
+pub const MAX_FEE_BPS: u32 = 1_000;
+/// Maximum number of times an escrow's release time can be extended.
+pub const MAX_EXTENSIONS: u32 = 3;
+/// Release buckets `release_matured` moves past in one call, empty days included.
+pub const MAX_RELEASE_DAYS_PER_CALL: u32 = 30;
+
 /// Initializes the contract with an admin. Can only be called once.
 pub fn initialize(env: &Env, admin: Address) -> Result<(), ContractError> {
     if storage::has_admin(env) {
         return Err(ContractError::AlreadyInitialized);
     }
     admin.require_auth();
-    storage::set_admin(env, &admin);
-    Ok(())
-}
-
-/// Allows the current admin to set a new admin.
-pub fn set_admin(env: &Env, admin: Address, new_admin: Address) -> Result<(), ContractError> {
-    admin.require_auth();
-    if !storage::is_admin(env, &admin) {
-        return Err(ContractError::NotAdmin);
-    }
-
-    storage::set_admin(env, &new_admin);
-    event::admin_changed(env, &admin, &new_admin);
+    EscrowAccess::initialize_admin(env, &admin);
+    // Fresh deployments start on the current layout and need no migration.
+    storage::set_schema_version(env, upgrade::CURRENT_SCHEMA_VERSION);
     Ok(())
 }
 
@@ -37,8 +39,32 @@ pub fn create_escrow(
     payment_token: Address,
     release_timestamp: u64,
 ) -> Result<u64, ContractError> {
-    buyer.require_auth();
+    create_funded_escrow(
+        env,
+        buyer.clone(),
+        buyer,
+        seller,
+        amount,
+        payment_token,
+        release_timestamp,
+    )
+}
+
+/// Creates a new escrow paid for by `payer` on behalf of `buyer`.
+/// The payer's funds are locked and any refund is routed back to the payer,
+/// while the buyer keeps the right to confirm receipt or dispute.
+pub fn create_funded_escrow(
+    env: &Env,
+    payer: Address,
+    buyer: Address,
+    seller: Address,
+    amount: i128,
+    payment_token: Address,
+    release_timestamp: u64,
+) -> Result<u64, ContractError> {
+    payer.require_auth();
 
+    access::require_not_paused(env)?;
     if amount <= 0 {
         return Err(ContractError::InvalidAmount);
     }
@@ -46,13 +72,13 @@ pub fn create_escrow(
         return Err(ContractError::InvalidReleaseTime);
     }
 
-    // Lock the buyer's funds in the contract.
-    let token_client = token::Client::new(env, &payment_token);
-    token_client.transfer(&buyer, &env.current_contract_address(), &amount);
+    // Lock the payer's funds in the contract.
+    token::deposit(env, &payment_token, &payer, amount)?;
 
     let escrow_id = storage::get_next_escrow_id(env);
     let escrow = Escrow {
         id: escrow_id,
+        payer: payer.clone(),
         buyer: buyer.clone(),
         seller: seller.clone(),
         amount,
@@ -61,10 +87,13 @@ pub fn create_escrow(
         status: EscrowStatus::Active,
         dispute_reason: None,
         buyer_confirmed: false,
+        seller_acknowledged: false,
+        extensions: Vec::new(env),
     };
 
     storage::set_escrow(env, &escrow);
-    event::escrow_created(env, escrow_id, &buyer, &seller, amount);
+    enqueue_escrow(env, escrow_id, release_timestamp);
+    event::escrow_created(env, &payer, &escrow);
 
     Ok(escrow_id)
 }
@@ -84,7 +113,127 @@ pub fn confirm_receipt(env: &Env, buyer: Address, escrow_id: u64) -> Result<(),
 
     escrow.buyer_confirmed = true;
     storage::set_escrow(env, &escrow);
-    event::receipt_confirmed(env, escrow_id, &buyer);
+    event::receipt_confirmed(env, &buyer, &escrow);
+
+    Ok(())
+}
+
+/// Allows the seller to acknowledge the escrow, confirming the order was accepted or shipped.
+/// Once acknowledged, the buyer can no longer cancel.
+pub fn acknowledge_escrow(env: &Env, seller: Address, escrow_id: u64) -> Result<(), ContractError> {
+    seller.require_auth();
+
+    let mut escrow = storage::get_escrow(env, escrow_id)?;
+
+    if escrow.seller != seller {
+        return Err(ContractError::NotSeller);
+    }
+    if escrow.status != EscrowStatus::Active {
+        return Err(ContractError::EscrowNotActive);
+    }
+    if escrow.seller_acknowledged {
+        return Err(ContractError::EscrowAlreadyAcknowledged);
+    }
+
+    escrow.seller_acknowledged = true;
+    storage::set_escrow(env, &escrow);
+    event::escrow_acknowledged(env, &seller, &escrow);
+
+    Ok(())
+}
+
+/// Allows the payer to cancel for a full refund while the seller has not yet acknowledged.
+pub fn cancel_escrow(env: &Env, payer: Address, escrow_id: u64) -> Result<(), ContractError> {
+    payer.require_auth();
+
+    let mut escrow = storage::get_escrow(env, escrow_id)?;
+
+    if escrow.payer != payer {
+        return Err(ContractError::NotPayer);
+    }
+    if escrow.status != EscrowStatus::Active {
+        return Err(ContractError::EscrowNotActive);
+    }
+    if escrow.seller_acknowledged {
+        return Err(ContractError::EscrowAlreadyAcknowledged);
+    }
+
+    token::payout(env, &escrow.payment_token, &escrow.payer, escrow.amount)?;
+
+    escrow.status = EscrowStatus::Cancelled;
+    storage::set_escrow(env, &escrow);
+    dequeue_escrow(env, escrow_id, escrow.release_timestamp);
+    event::escrow_cancelled(env, &payer, &escrow);
+
+    Ok(())
+}
+
+/// Allows the buyer or seller to propose a later release timestamp.
+/// A new proposal replaces any pending one.
+pub fn propose_extension(
+    env: &Env,
+    proposer: Address,
+    escrow_id: u64,
+    new_release_timestamp: u64,
+) -> Result<(), ContractError> {
+    proposer.require_auth();
+
+    let escrow = storage::get_escrow(env, escrow_id)?;
+
+    if proposer != escrow.buyer && proposer != escrow.seller {
+        return Err(ContractError::NotParticipant);
+    }
+    if escrow.status != EscrowStatus::Active {
+        return Err(ContractError::EscrowNotActive);
+    }
+    if escrow.extensions.len() >= MAX_EXTENSIONS {
+        return Err(ContractError::ExtensionLimitReached);
+    }
+    if new_release_timestamp <= escrow.release_timestamp {
+        return Err(ContractError::InvalidReleaseTime);
+    }
+
+    let proposal = ExtensionProposal {
+        proposer: proposer.clone(),
+        new_release_timestamp,
+    };
+    storage::set_extension_proposal(env, escrow_id, &proposal);
+    event::extension_proposed(env, escrow_id, &proposal);
+
+    Ok(())
+}
+
+/// Allows the counterparty of a pending proposal to accept it, moving the release timestamp.
+pub fn accept_extension(env: &Env, accepter: Address, escrow_id: u64) -> Result<(), ContractError> {
+    accepter.require_auth();
+
+    let mut escrow = storage::get_escrow(env, escrow_id)?;
+
+    if accepter != escrow.buyer && accepter != escrow.seller {
+        return Err(ContractError::NotParticipant);
+    }
+    if escrow.status != EscrowStatus::Active {
+        return Err(ContractError::EscrowNotActive);
+    }
+    let proposal =
+        storage::get_extension_proposal(env, escrow_id).ok_or(ContractError::NoPendingExtension)?;
+    if proposal.proposer == accepter {
+        return Err(ContractError::CannotAcceptOwnExtension);
+    }
+
+    let previous_release_timestamp = escrow.release_timestamp;
+    escrow.extensions.push_back(ReleaseExtension {
+        proposer: proposal.proposer,
+        previous_release_timestamp,
+        new_release_timestamp: proposal.new_release_timestamp,
+        accepted_at: env.ledger().timestamp(),
+    });
+    escrow.release_timestamp = proposal.new_release_timestamp;
+    storage::set_escrow(env, &escrow);
+    dequeue_escrow(env, escrow_id, previous_release_timestamp);
+    enqueue_escrow(env, escrow_id, escrow.release_timestamp);
+    storage::remove_extension_proposal(env, escrow_id);
+    event::extension_accepted(env, &accepter, &escrow);
 
     Ok(())
 }
@@ -103,20 +252,67 @@ pub fn release_funds(env: &Env, escrow_id: u64) -> Result<(), ContractError> {
         return Err(ContractError::ReleaseTimeNotPassed);
     }
 
-    let token_client = token::Client::new(env, &escrow.payment_token);
-    token_client.transfer(
-        &env.current_contract_address(),
-        &escrow.seller,
-        &escrow.amount,
-    );
-
-    escrow.status = EscrowStatus::Released;
-    storage::set_escrow(env, &escrow);
-    event::funds_released(env, escrow_id, &escrow.seller, escrow.amount);
+    let fee = settle_release(env, &mut escrow)?;
+    let collected_fees = accrue_fee(env, &escrow.payment_token, fee)?;
+    dequeue_escrow(env, escrow_id, escrow.release_timestamp);
+    // Anyone can trigger a release, so it is attributed to the contract itself
+    let actor = env.current_contract_address();
+    event::funds_released(env, &actor, &escrow, fee, 0, collected_fees);
 
     Ok(())
 }
 
+/// Releases matured escrows in release-time order, up to `limit` escrows.
+/// Each call walks at most `MAX_RELEASE_DAYS_PER_CALL` release buckets from the
+/// release cursor, so a keeper catching up on a long backlog calls it repeatedly.
+/// The keeper is paid a share of each platform fee, if a keeper reward is configured.
+/// Returns the number of escrows released.
+pub fn release_matured(env: &Env, keeper: Address, limit: u32) -> Result<u32, ContractError> {
+    let now = env.ledger().timestamp();
+    let Some(mut day) = storage::get_release_cursor(env) else {
+        return Ok(0);
+    };
+    let reward_bps = storage::get_keeper_reward_bps(env);
+    let mut released = 0;
+    let mut days = 0;
+
+    while released < limit && days < MAX_RELEASE_DAYS_PER_CALL && day <= storage::release_day(now) {
+        let mut bucket = storage::get_release_bucket(env, day);
+        while released < limit {
+            let Some((release_timestamp, escrow_id)) = bucket.first() else {
+                break;
+            };
+            if release_timestamp > now {
+                break;
+            }
+            bucket.pop_front();
+
+            let mut escrow = storage::get_escrow(env, escrow_id)?;
+            if escrow.status != EscrowStatus::Active {
+                continue;
+            }
+
+            let fee = settle_release(env, &mut escrow)?;
+            let reward = amount::bps_share(fee, reward_bps)?;
+            token::payout(env, &escrow.payment_token, &keeper, reward)?;
+            let collected_fees = accrue_fee(env, &escrow.payment_token, fee - reward)?;
+            event::funds_released(env, &keeper, &escrow, fee, reward, collected_fees);
+            released += 1;
+        }
+        storage::set_release_bucket(env, day, &bucket);
+
+        // A bucket still holding escrows has reached the limit or the present
+        if !bucket.is_empty() {
+            break;
+        }
+        day += 1;
+        days += 1;
+    }
+
+    storage::set_release_cursor(env, day);
+    Ok(released)
+}
+
 /// Allows the buyer to raise a dispute before the release time.
 pub fn dispute_escrow(
     env: &Env,
@@ -139,40 +335,215 @@ pub fn dispute_escrow(
     }
 
     escrow.status = EscrowStatus::Disputed;
-    escrow.dispute_reason = Some(reason.clone());
+    escrow.dispute_reason = Some(reason);
     storage::set_escrow(env, &escrow);
-    event::escrow_disputed(env, escrow_id, &buyer, reason);
+    dequeue_escrow(env, escrow_id, escrow.release_timestamp);
+    event::escrow_disputed(env, &buyer, &escrow);
 
     Ok(())
 }
 
-/// Allows a designated admin to resolve a dispute by refunding the buyer.
+/// Allows an arbiter to resolve a dispute by refunding the payer.
 pub fn resolve_dispute_and_refund(
     env: &Env,
-    admin: Address,
+    arbiter: Address,
     escrow_id: u64,
 ) -> Result<(), ContractError> {
-    admin.require_auth();
-    if !storage::is_admin(env, &admin) {
-        return Err(ContractError::NotAdmin);
+    access::require_role(env, Role::Arbiter, &arbiter)?;
+
+    let escrow = storage::get_escrow(env, escrow_id)?;
+    // Large rulings need the signers' approval once governance is enabled
+    if let Some(config) = storage::get_governance(env)
+        && escrow.amount > config.ruling_threshold
+    {
+        return Err(ContractError::GovernanceRequired);
     }
 
+    refund_disputed_escrow(env, &arbiter, escrow_id)
+}
+
+/// Refunds the payer of a disputed escrow. Callers check who may rule.
+pub(crate) fn refund_disputed_escrow(
+    env: &Env,
+    initiator: &Address,
+    escrow_id: u64,
+) -> Result<(), ContractError> {
     let mut escrow = storage::get_escrow(env, escrow_id)?;
 
     if escrow.status != EscrowStatus::Disputed {
         return Err(ContractError::EscrowNotDisputed);
     }
 
-    let token_client = token::Client::new(env, &escrow.payment_token);
-    token_client.transfer(
-        &env.current_contract_address(),
-        &escrow.buyer,
-        &escrow.amount,
-    );
+    token::payout(env, &escrow.payment_token, &escrow.payer, escrow.amount)?;
 
     escrow.status = EscrowStatus::Refunded;
     storage::set_escrow(env, &escrow);
-    event::funds_refunded(env, escrow_id, &escrow.buyer, escrow.amount);
+    event::funds_refunded(env, initiator, &escrow);
+
+    Ok(())
+}
+
+/// Extends the TTL of an escrow, its pending extension proposal, its release
+/// bucket and the contract instance so long-running escrows are not archived.
+/// Anyone can call this, e.g. a keeper or the frontend on behalf of a user.
+pub fn bump(env: &Env, escrow_id: u64) -> Result<(), ContractError> {
+    storage::extend_escrow(env, escrow_id)
+}
+
+/// Extends the storage TTL of a granted role, so an idle arbiter or fee manager
+/// is not archived between disputes.
+pub fn bump_role(env: &Env, role: Role, account: Address) -> Result<(), ContractError> {
+    if !storage::has_granted_role(env, role, &account) {
+        return Err(ContractError::MissingRole);
+    }
+    Ok(())
+}
+
+/// Allows a fee manager to set the platform fee (in basis points) and the treasury address.
+pub fn set_fee(
+    env: &Env,
+    fee_manager: Address,
+    fee_bps: u32,
+    treasury: Address,
+) -> Result<(), ContractError> {
+    access::require_role(env, Role::FeeManager, &fee_manager)?;
+    governance::require_disabled(env)?;
+
+    apply_fee(env, &fee_manager, fee_bps, treasury)
+}
+
+/// Stores a new fee configuration. Callers check who may change it.
+pub(crate) fn apply_fee(
+    env: &Env,
+    initiator: &Address,
+    fee_bps: u32,
+    treasury: Address,
+) -> Result<(), ContractError> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(ContractError::InvalidFee);
+    }
+
+    let fee_config = FeeConfig { fee_bps, treasury };
+    storage::set_fee_config(env, &fee_config);
+    event::fee_updated(env, initiator, &fee_config);
+    Ok(())
+}
+
+/// Allows a fee manager to withdraw accrued fees for a given token to the treasury.
+pub fn withdraw_fees(
+    env: &Env,
+    fee_manager: Address,
+    token: Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    access::require_role(env, Role::FeeManager, &fee_manager)?;
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    let collected = storage::get_collected_fees(env, &token);
+    if amount > collected {
+        return Err(ContractError::InsufficientFees);
+    }
+    let fee_config = storage::get_fee_config(env).ok_or(ContractError::InvalidFee)?;
+
+    storage::set_collected_fees(env, &token, collected - amount);
+    token::payout(env, &token, &fee_config.treasury, amount)?;
+
+    event::fees_withdrawn(
+        env,
+        &fee_manager,
+        &token,
+        &fee_config.treasury,
+        amount,
+        collected - amount,
+    );
+    Ok(())
+}
+
+/// Allows a fee manager to set the share of the platform fee (in basis points of the fee)
+/// paid to keepers that settle matured escrows through `release_matured`.
+pub fn set_keeper_reward(
+    env: &Env,
+    fee_manager: Address,
+    reward_bps: u32,
+) -> Result<(), ContractError> {
+    access::require_role(env, Role::FeeManager, &fee_manager)?;
+    governance::require_disabled(env)?;
+
+    apply_keeper_reward(env, &fee_manager, reward_bps)
+}
+
+/// Stores a new keeper reward. Callers check who may change it.
+pub(crate) fn apply_keeper_reward(
+    env: &Env,
+    initiator: &Address,
+    reward_bps: u32,
+) -> Result<(), ContractError> {
+    if reward_bps > MAX_KEEPER_REWARD_BPS {
+        return Err(ContractError::InvalidKeeperReward);
+    }
 
+    storage::set_keeper_reward_bps(env, reward_bps);
+    event::keeper_reward_updated(env, initiator, reward_bps);
     Ok(())
 }
+
+/// Pays the seller net of the platform fee and marks the escrow released.
+/// Returns the fee, which the caller is responsible for accruing and announcing.
+fn settle_release(env: &Env, escrow: &mut Escrow) -> Result<i128, ContractError> {
+    // Deduct the platform fee; it stays in the contract until withdrawn.
+    let fee = calculate_fee(env, escrow.amount)?;
+    let seller_amount = escrow.amount - fee;
+
+    token::payout(env, &escrow.payment_token, &escrow.seller, seller_amount)?;
+
+    escrow.status = EscrowStatus::Released;
+    storage::set_escrow(env, escrow);
+
+    Ok(fee)
+}
+
+/// Inserts an escrow into the bucket of its release day, keeping it ordered by release time.
+pub(crate) fn enqueue_escrow(env: &Env, escrow_id: u64, release_timestamp: u64) {
+    let day = storage::release_day(release_timestamp);
+    let mut bucket = storage::get_release_bucket(env, day);
+    let entry = (release_timestamp, escrow_id);
+    if let Err(index) = bucket.binary_search(entry) {
+        bucket.insert(index, entry);
+        storage::set_release_bucket(env, day, &bucket);
+    }
+    // Migrated escrows can be due before the cursor
+    if storage::get_release_cursor(env).is_none_or(|cursor| day < cursor) {
+        storage::set_release_cursor(env, day);
+    }
+}
+
+/// Removes an escrow from the bucket of its release day, if present.
+fn dequeue_escrow(env: &Env, escrow_id: u64, release_timestamp: u64) {
+    let day = storage::release_day(release_timestamp);
+    let mut bucket = storage::get_release_bucket(env, day);
+    if let Ok(index) = bucket.binary_search((release_timestamp, escrow_id)) {
+        bucket.remove(index);
+        storage::set_release_bucket(env, day, &bucket);
+    }
+}
+
+/// Computes the platform fee owed on a settlement amount.
+fn calculate_fee(env: &Env, amount: i128) -> Result<i128, ContractError> {
+    match storage::get_fee_config(env) {
+        Some(fee_config) => Ok(amount::bps_share(amount, fee_config.fee_bps)?),
+        None => Ok(0),
+    }
+}
+
+/// Adds a fee to the per-token accrued balance and returns the new balance.
+fn accrue_fee(env: &Env, token: &Address, fee: i128) -> Result<i128, ContractError> {
+    let collected = storage::get_collected_fees(env, token);
+    if fee == 0 {
+        return Ok(collected);
+    }
+    let collected = amount::add(collected, fee)?;
+    storage::set_collected_fees(env, token, collected);
+    Ok(collected)
+}
@@ -1,41 +1,320 @@
-use soroban_sdk::{symbol_short, Address, Env, String};
+use paystar_common::{
+    events::{self, MigrationEvent, UpgradeEvent, CONTRACT_WIDE, EVENT_VERSION},
+    AccessControl,
+};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, IntoVal, Symbol, Val};
 
-/// Emits an event when a new escrow is created and funded.
-pub fn escrow_created(env: &Env, escrow_id: u64, buyer: &Address, seller: &Address, amount: i128) {
-    let topics = (symbol_short!("created"), buyer.clone(), seller.clone());
-    let data = (escrow_id, amount);
-    env.events().publish(topics, data);
+use crate::{
+    access::EscrowAccess,
+    storage::{Escrow, ExtensionProposal, FeeConfig, GovernanceConfig, GovernanceProposal},
+};
+
+/// Payload of every escrow lifecycle event: the escrow as stored after the change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowEvent {
+    pub version: u32,
+    pub escrow: Escrow,
+}
+
+/// Payload of `ext_prop`. A new proposal replaces any pending one; accepting
+/// it (`ext_acc`) clears it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtensionEvent {
+    pub version: u32,
+    pub escrow_id: u64,
+    pub proposal: ExtensionProposal,
+}
+
+/// Payload of `released`: the settled escrow and where its funds went.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseEvent {
+    pub version: u32,
+    pub escrow: Escrow,
+    pub seller_amount: i128,
+    pub fee: i128,
+    pub keeper_reward: i128, // Paid out of the fee to the keeper that settled the escrow
+    pub collected_fees: i128, // Accrued fees in the payment token after the release
+}
+
+/// Payload of `fee_set`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeEvent {
+    pub version: u32,
+    pub fee_config: FeeConfig,
+}
+
+/// Payload of `fee_wdraw`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeWithdrawalEvent {
+    pub version: u32,
+    pub token: Address,
+    pub treasury: Address,
+    pub amount: i128,
+    pub collected_fees: i128, // Accrued fees left in the token after the withdrawal
+}
+
+/// Payload of `keep_set`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperRewardEvent {
+    pub version: u32,
+    pub reward_bps: u32,
+}
+
+/// Payload of `gov_set`: the signer set now in charge.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceEvent {
+    pub version: u32,
+    pub config: GovernanceConfig,
+}
+
+/// Payload of `gov_off`: governance was disabled and the admin is back in charge.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceDisabledEvent {
+    pub version: u32,
+}
+
+/// Payload of `gov_prop`, `gov_appr` and `gov_exec`: the proposal after the change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalEvent {
+    pub version: u32,
+    pub proposal: GovernanceProposal,
+}
+
+/// Emits an event when a new escrow is created and funded by the payer.
+pub fn escrow_created(env: &Env, payer: &Address, escrow: &Escrow) {
+    publish_escrow(env, symbol_short!("created"), payer, escrow);
 }
 
 /// Emits an event when a buyer confirms receipt.
-pub fn receipt_confirmed(env: &Env, escrow_id: u64, buyer: &Address) {
-    let topics = (symbol_short!("confirmed"), buyer.clone());
-    env.events().publish(topics, escrow_id);
+pub fn receipt_confirmed(env: &Env, buyer: &Address, escrow: &Escrow) {
+    publish_escrow(env, symbol_short!("confirmed"), buyer, escrow);
+}
+
+/// Emits an event when the seller acknowledges an escrow (order accepted or shipped).
+pub fn escrow_acknowledged(env: &Env, seller: &Address, escrow: &Escrow) {
+    publish_escrow(env, symbol_short!("acked"), seller, escrow);
+}
+
+/// Emits an event when the payer cancels an unacknowledged escrow and is refunded.
+pub fn escrow_cancelled(env: &Env, payer: &Address, escrow: &Escrow) {
+    publish_escrow(env, symbol_short!("cancelled"), payer, escrow);
+}
+
+/// Emits an event when a party proposes a new release timestamp.
+pub fn extension_proposed(env: &Env, escrow_id: u64, proposal: &ExtensionProposal) {
+    let event = ExtensionEvent {
+        version: EVENT_VERSION,
+        escrow_id,
+        proposal: proposal.clone(),
+    };
+    publish(
+        env,
+        symbol_short!("ext_prop"),
+        escrow_id.into(),
+        &proposal.proposer,
+        event,
+    );
+}
+
+/// Emits an event when the other party accepts a release-time extension.
+pub fn extension_accepted(env: &Env, accepter: &Address, escrow: &Escrow) {
+    publish_escrow(env, symbol_short!("ext_acc"), accepter, escrow);
 }
 
-/// Emits an event when funds are released to the seller.
-pub fn funds_released(env: &Env, escrow_id: u64, seller: &Address, amount: i128) {
-    let topics = (symbol_short!("released"), seller.clone());
-    let data = (escrow_id, amount);
-    env.events().publish(topics, data);
+/// Emits an event when funds are released to the seller, net of the platform fee.
+pub fn funds_released(
+    env: &Env,
+    actor: &Address,
+    escrow: &Escrow,
+    fee: i128,
+    keeper_reward: i128,
+    collected_fees: i128,
+) {
+    let event = ReleaseEvent {
+        version: EVENT_VERSION,
+        escrow: escrow.clone(),
+        seller_amount: escrow.amount - fee,
+        fee,
+        keeper_reward,
+        collected_fees,
+    };
+    publish(
+        env,
+        symbol_short!("released"),
+        escrow.id.into(),
+        actor,
+        event,
+    );
 }
 
 /// Emits an event when an escrow is disputed by the buyer.
-pub fn escrow_disputed(env: &Env, escrow_id: u64, buyer: &Address, reason: String) {
-    let topics = (symbol_short!("disputed"), buyer.clone());
-    let data = (escrow_id, reason);
-    env.events().publish(topics, data);
+pub fn escrow_disputed(env: &Env, buyer: &Address, escrow: &Escrow) {
+    publish_escrow(env, symbol_short!("disputed"), buyer, escrow);
+}
+
+/// Emits an event when a dispute is resolved and funds are refunded to the payer.
+pub fn funds_refunded(env: &Env, arbiter: &Address, escrow: &Escrow) {
+    publish_escrow(env, symbol_short!("refunded"), arbiter, escrow);
+}
+
+/// Emits an event when the platform fee configuration is updated.
+pub fn fee_updated(env: &Env, initiator: &Address, fee_config: &FeeConfig) {
+    let event = FeeEvent {
+        version: EVENT_VERSION,
+        fee_config: fee_config.clone(),
+    };
+    publish(
+        env,
+        symbol_short!("fee_set"),
+        CONTRACT_WIDE,
+        initiator,
+        event,
+    );
+}
+
+/// Emits an event when accrued fees are withdrawn to the treasury.
+pub fn fees_withdrawn(
+    env: &Env,
+    fee_manager: &Address,
+    token: &Address,
+    treasury: &Address,
+    amount: i128,
+    collected_fees: i128,
+) {
+    let event = FeeWithdrawalEvent {
+        version: EVENT_VERSION,
+        token: token.clone(),
+        treasury: treasury.clone(),
+        amount,
+        collected_fees,
+    };
+    publish(
+        env,
+        symbol_short!("fee_wdraw"),
+        CONTRACT_WIDE,
+        fee_manager,
+        event,
+    );
+}
+
+/// Emits an event when the keeper reward share is updated.
+pub fn keeper_reward_updated(env: &Env, fee_manager: &Address, reward_bps: u32) {
+    let event = KeeperRewardEvent {
+        version: EVENT_VERSION,
+        reward_bps,
+    };
+    publish(
+        env,
+        symbol_short!("keep_set"),
+        CONTRACT_WIDE,
+        fee_manager,
+        event,
+    );
+}
+
+/// Emits an event when the contract WASM is replaced.
+pub fn contract_upgraded(env: &Env, initiator: &Address, new_wasm_hash: &BytesN<32>) {
+    let event = UpgradeEvent {
+        version: EVENT_VERSION,
+        wasm_hash: new_wasm_hash.clone(),
+    };
+    publish(
+        env,
+        symbol_short!("upgraded"),
+        CONTRACT_WIDE,
+        initiator,
+        event,
+    );
+}
+
+/// Emits an event for every escrow rewritten into the current layout.
+pub fn escrow_migrated(env: &Env, admin: &Address, escrow: &Escrow) {
+    publish_escrow(env, symbol_short!("mig_rec"), admin, escrow);
+}
+
+/// Emits an event when a migration batch runs.
+pub fn records_migrated(env: &Env, admin: &Address, migrated: u32, schema_version: u32) {
+    let event = MigrationEvent {
+        version: EVENT_VERSION,
+        migrated,
+        schema_version,
+    };
+    publish(env, symbol_short!("migrated"), CONTRACT_WIDE, admin, event);
+}
+
+/// Emits an event when multisig governance is enabled or reconfigured.
+pub fn governance_updated(env: &Env, initiator: &Address, config: &GovernanceConfig) {
+    let event = GovernanceEvent {
+        version: EVENT_VERSION,
+        config: config.clone(),
+    };
+    publish(
+        env,
+        symbol_short!("gov_set"),
+        CONTRACT_WIDE,
+        initiator,
+        event,
+    );
+}
+
+/// Emits an event when multisig governance is disabled.
+pub fn governance_disabled(env: &Env, initiator: &Address) {
+    let event = GovernanceDisabledEvent {
+        version: EVENT_VERSION,
+    };
+    publish(
+        env,
+        symbol_short!("gov_off"),
+        CONTRACT_WIDE,
+        initiator,
+        event,
+    );
+}
+
+/// Emits an event when a signer proposes a governance action.
+pub fn action_proposed(env: &Env, proposal: &GovernanceProposal) {
+    publish_proposal(env, symbol_short!("gov_prop"), &proposal.proposer, proposal);
+}
+
+/// Emits an event when a signer approves a governance action.
+pub fn action_approved(env: &Env, signer: &Address, proposal: &GovernanceProposal) {
+    publish_proposal(env, symbol_short!("gov_appr"), signer, proposal);
+}
+
+/// Emits an event when an approved governance action runs.
+pub fn action_executed(env: &Env, proposal: &GovernanceProposal) {
+    publish_proposal(env, symbol_short!("gov_exec"), &proposal.proposer, proposal);
+}
+
+fn publish_escrow(env: &Env, action: Symbol, actor: &Address, escrow: &Escrow) {
+    let event = EscrowEvent {
+        version: EVENT_VERSION,
+        escrow: escrow.clone(),
+    };
+    publish(env, action, escrow.id.into(), actor, event);
 }
 
-/// Emits an event when a dispute is resolved and funds are refunded.
-pub fn funds_refunded(env: &Env, escrow_id: u64, buyer: &Address, amount: i128) {
-    let topics = (symbol_short!("refunded"), buyer.clone());
-    let data = (escrow_id, amount);
-    env.events().publish(topics, data);
+fn publish_proposal(env: &Env, action: Symbol, actor: &Address, proposal: &GovernanceProposal) {
+    let event = ProposalEvent {
+        version: EVENT_VERSION,
+        proposal: proposal.clone(),
+    };
+    publish(env, action, proposal.id.into(), actor, event);
 }
 
-/// Emits an event when the admin is changed.
-pub fn admin_changed(env: &Env, old_admin: &Address, new_admin: &Address) {
-    let topics = (symbol_short!("set_admin"), old_admin.clone());
-    env.events().publish(topics, new_admin.clone());
+/// Publishes under the `(contract, action, id, actor)` topics shared by every PayStar contract.
+fn publish<D>(env: &Env, action: Symbol, id: u128, actor: &Address, data: D)
+where
+    D: IntoVal<Env, Val>,
+{
+    events::publish(env, EscrowAccess::CONTRACT, action, id, actor, data);
 }
@@ -0,0 +1,174 @@
+use paystar_common::AccessControl;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::{
+    access::{self, EscrowAccess},
+    error::ContractError,
+    escrow_logic, event,
+    storage::{self, GovernanceAction, GovernanceConfig, GovernanceProposal, Role},
+    upgrade,
+};
+
+/// Longest a proposal can stay open, in seconds: 30 days, as long as an untouched
+/// proposal record is kept alive.
+pub const MAX_PROPOSAL_TTL: u64 = 30 * 86_400;
+
+/// Allows the admin to hand sensitive actions over to an M-of-N signer set.
+/// Once enabled, fee and keeper reward changes, upgrades, arbiter registry edits and rulings on
+/// disputes above `ruling_threshold` only run through approved proposals.
+pub fn enable_governance(
+    env: &Env,
+    admin: Address,
+    config: GovernanceConfig,
+) -> Result<(), ContractError> {
+    access::require_admin(env, &admin)?;
+    require_disabled(env)?;
+    validate_config(&config)?;
+
+    storage::set_governance(env, &config);
+    event::governance_updated(env, &admin, &config);
+    Ok(())
+}
+
+/// Allows a signer to propose a governance action. The proposer's approval is counted.
+pub fn propose_action(
+    env: &Env,
+    signer: Address,
+    action: GovernanceAction,
+) -> Result<u64, ContractError> {
+    let config = require_signer(env, &signer)?;
+    if let GovernanceAction::UpdateGovernance(new_config) = &action {
+        validate_config(new_config)?;
+    }
+
+    let proposal = GovernanceProposal {
+        id: storage::get_next_proposal_id(env),
+        proposer: signer.clone(),
+        action: action.clone(),
+        approvals: vec![env, signer.clone()],
+        expires_at: env.ledger().timestamp().saturating_add(config.proposal_ttl),
+        executed: false,
+    };
+    storage::set_proposal(env, &proposal);
+    event::action_proposed(env, &proposal);
+
+    Ok(proposal.id)
+}
+
+/// Allows a signer to approve an open proposal.
+pub fn approve_action(env: &Env, signer: Address, proposal_id: u64) -> Result<(), ContractError> {
+    require_signer(env, &signer)?;
+
+    let mut proposal = get_open_proposal(env, proposal_id)?;
+    if proposal.approvals.contains(&signer) {
+        return Err(ContractError::ProposalAlreadyApproved);
+    }
+
+    proposal.approvals.push_back(signer.clone());
+    storage::set_proposal(env, &proposal);
+    event::action_approved(env, &signer, &proposal);
+    Ok(())
+}
+
+/// Runs a proposal once enough current signers approved it. Anyone can call this.
+pub fn execute_action(env: &Env, proposal_id: u64) -> Result<(), ContractError> {
+    let config = storage::get_governance(env).ok_or(ContractError::GovernanceNotEnabled)?;
+    let mut proposal = get_open_proposal(env, proposal_id)?;
+
+    // Approvals from signers removed since the proposal was made no longer count
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approver| config.signers.contains(approver))
+        .count() as u32;
+    if approvals < config.threshold {
+        return Err(ContractError::ThresholdNotMet);
+    }
+
+    proposal.executed = true;
+    storage::set_proposal(env, &proposal);
+
+    match proposal.action.clone() {
+        GovernanceAction::SetFee(fee_bps, treasury) => {
+            escrow_logic::apply_fee(env, &proposal.proposer, fee_bps, treasury)?
+        }
+        GovernanceAction::SetKeeperReward(reward_bps) => {
+            escrow_logic::apply_keeper_reward(env, &proposal.proposer, reward_bps)?
+        }
+        GovernanceAction::Upgrade(new_wasm_hash) => {
+            upgrade::apply_upgrade(env, &proposal.proposer, new_wasm_hash)
+        }
+        GovernanceAction::GrantArbiter(account) => {
+            storage::grant_role(env, Role::Arbiter, &account);
+            EscrowAccess::publish_role(env, &proposal.proposer, Role::Arbiter, &account, true);
+        }
+        GovernanceAction::RevokeArbiter(account) => {
+            storage::revoke_role(env, Role::Arbiter, &account);
+            EscrowAccess::publish_role(env, &proposal.proposer, Role::Arbiter, &account, false);
+        }
+        GovernanceAction::ResolveDispute(escrow_id) => {
+            escrow_logic::refund_disputed_escrow(env, &proposal.proposer, escrow_id)?
+        }
+        GovernanceAction::UpdateGovernance(new_config) => {
+            storage::set_governance(env, &new_config);
+            event::governance_updated(env, &proposal.proposer, &new_config);
+        }
+        GovernanceAction::DisableGovernance => {
+            storage::remove_governance(env);
+            event::governance_disabled(env, &proposal.proposer);
+        }
+    }
+
+    event::action_executed(env, &proposal);
+    Ok(())
+}
+
+/// Rejects direct calls to actions that governance has taken over.
+pub(crate) fn require_disabled(env: &Env) -> Result<(), ContractError> {
+    if storage::get_governance(env).is_some() {
+        return Err(ContractError::GovernanceRequired);
+    }
+    Ok(())
+}
+
+fn require_signer(env: &Env, signer: &Address) -> Result<GovernanceConfig, ContractError> {
+    signer.require_auth();
+    let config = storage::get_governance(env).ok_or(ContractError::GovernanceNotEnabled)?;
+    if !config.signers.contains(signer) {
+        return Err(ContractError::NotSigner);
+    }
+    Ok(config)
+}
+
+fn get_open_proposal(env: &Env, proposal_id: u64) -> Result<GovernanceProposal, ContractError> {
+    let proposal = storage::get_proposal(env, proposal_id)?;
+    if proposal.executed {
+        return Err(ContractError::ProposalAlreadyExecuted);
+    }
+    if env.ledger().timestamp() > proposal.expires_at {
+        return Err(ContractError::ProposalExpired);
+    }
+    Ok(proposal)
+}
+
+fn validate_config(config: &GovernanceConfig) -> Result<(), ContractError> {
+    if config.threshold == 0
+        || config.threshold > config.signers.len()
+        || config.ruling_threshold < 0
+        || config.proposal_ttl == 0
+        || config.proposal_ttl > MAX_PROPOSAL_TTL
+        || has_duplicates(&config.signers)
+    {
+        return Err(ContractError::InvalidGovernanceConfig);
+    }
+    Ok(())
+}
+
+fn has_duplicates(signers: &Vec<Address>) -> bool {
+    for (i, signer) in signers.iter().enumerate() {
+        if signers.first_index_of(&signer) != Some(i as u32) {
+            return true;
+        }
+    }
+    false
+}
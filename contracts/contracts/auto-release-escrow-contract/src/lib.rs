@@ -1,15 +1,27 @@
 #![no_std]
 
+mod access;
 mod error;
 mod escrow_logic;
 mod event;
+mod governance;
 mod storage;
 #[cfg(test)]
 mod test;
+mod upgrade;
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use paystar_common::AccessControl;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
-use crate::{error::ContractError, storage::Escrow};
+use crate::access::EscrowAccess;
+
+pub use crate::{
+    error::ContractError,
+    storage::{
+        Escrow, EscrowStatus, ExtensionProposal, FeeConfig, GovernanceAction, GovernanceConfig,
+        GovernanceProposal, ReleaseExtension, Role, RELEASE_BUCKET_SECONDS,
+    },
+};
 
 #[contract]
 pub struct AutoReleaseEscrowContract;
@@ -21,9 +33,39 @@ impl AutoReleaseEscrowContract {
         escrow_logic::initialize(&env, admin)
     }
 
-    /// Allows the current admin to transfer admin rights to a new address.
-    pub fn set_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), ContractError> {
-        escrow_logic::set_admin(&env, admin, new_admin)
+    /// Allows the current admin to propose a new admin. Admin rights only move
+    /// once the proposed address calls `accept_admin`.
+    pub fn propose_admin(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+    ) -> Result<(), ContractError> {
+        access::propose_admin(&env, admin, new_admin)
+    }
+
+    /// Allows the proposed admin to accept admin rights.
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        access::accept_admin(&env, new_admin)
+    }
+
+    /// Allows the admin to grant the Arbiter, Pauser or FeeManager role to an address.
+    pub fn grant_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), ContractError> {
+        access::grant_role(&env, admin, role, account)
+    }
+
+    /// Allows the admin to revoke a previously granted role.
+    pub fn revoke_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), ContractError> {
+        access::revoke_role(&env, admin, role, account)
     }
 
     /// Creates a new escrow agreement and locks the buyer's funds.
@@ -45,18 +87,81 @@ impl AutoReleaseEscrowContract {
         )
     }
 
+    /// Creates an escrow funded by a third-party `payer` on behalf of `buyer`,
+    /// e.g. a gift or an employer purchase. Refunds go back to the payer.
+    pub fn create_funded_escrow(
+        env: Env,
+        payer: Address,
+        buyer: Address,
+        seller: Address,
+        amount: i128,
+        payment_token: Address,
+        release_timestamp: u64,
+    ) -> Result<u64, ContractError> {
+        escrow_logic::create_funded_escrow(
+            &env,
+            payer,
+            buyer,
+            seller,
+            amount,
+            payment_token,
+            release_timestamp,
+        )
+    }
+
+    /// Allows the seller to acknowledge the escrow (order accepted or shipped).
+    /// After this the buyer can no longer cancel.
+    pub fn acknowledge_escrow(
+        env: Env,
+        seller: Address,
+        escrow_id: u64,
+    ) -> Result<(), ContractError> {
+        escrow_logic::acknowledge_escrow(&env, seller, escrow_id)
+    }
+
+    /// Allows the payer to cancel and get a full refund before the seller acknowledges.
+    pub fn cancel_escrow(env: Env, payer: Address, escrow_id: u64) -> Result<(), ContractError> {
+        escrow_logic::cancel_escrow(&env, payer, escrow_id)
+    }
+
     /// Allows the buyer to confirm they have received the goods/service,
     /// enabling an early release of funds.
     pub fn confirm_receipt(env: Env, buyer: Address, escrow_id: u64) -> Result<(), ContractError> {
         escrow_logic::confirm_receipt(&env, buyer, escrow_id)
     }
 
+    /// Allows the buyer or seller to propose a later release timestamp.
+    pub fn propose_extension(
+        env: Env,
+        proposer: Address,
+        escrow_id: u64,
+        new_release_timestamp: u64,
+    ) -> Result<(), ContractError> {
+        escrow_logic::propose_extension(&env, proposer, escrow_id, new_release_timestamp)
+    }
+
+    /// Allows the other party to accept a pending release-time extension.
+    pub fn accept_extension(
+        env: Env,
+        accepter: Address,
+        escrow_id: u64,
+    ) -> Result<(), ContractError> {
+        escrow_logic::accept_extension(&env, accepter, escrow_id)
+    }
+
     /// Releases the funds to the seller if the auto-release time has passed
     /// OR if the buyer has confirmed receipt.
     pub fn release_funds(env: Env, escrow_id: u64) -> Result<(), ContractError> {
         escrow_logic::release_funds(&env, escrow_id)
     }
 
+    /// Releases matured escrows in release-time order, up to `limit` escrows and
+    /// `MAX_RELEASE_DAYS_PER_CALL` days of release buckets. Anyone can call this; the keeper is paid a share of the platform fee if configured.
+    /// Returns the number of escrows released.
+    pub fn release_matured(env: Env, keeper: Address, limit: u32) -> Result<u32, ContractError> {
+        escrow_logic::release_matured(&env, keeper, limit)
+    }
+
     /// Allows the buyer to request a refund if there is a dispute.
     pub fn dispute_escrow(
         env: Env,
@@ -67,19 +172,183 @@ impl AutoReleaseEscrowContract {
         escrow_logic::dispute_escrow(&env, buyer, escrow_id, reason)
     }
 
-    /// Allows an admin/arbiter to resolve a dispute, refunding the buyer.
+    /// Extends the storage TTL of an escrow and the contract instance. Permissionless.
+    pub fn bump(env: Env, escrow_id: u64) -> Result<(), ContractError> {
+        escrow_logic::bump(&env, escrow_id)
+    }
+
+    /// Extends the storage TTL of a granted role and the contract instance. Permissionless.
+    pub fn bump_role(env: Env, role: Role, account: Address) -> Result<(), ContractError> {
+        escrow_logic::bump_role(&env, role, account)
+    }
+
+    /// Allows an arbiter to resolve a dispute, refunding the payer.
     pub fn resolve_dispute_and_refund(
         env: Env,
-        admin: Address,
+        arbiter: Address,
         escrow_id: u64,
     ) -> Result<(), ContractError> {
-        escrow_logic::resolve_dispute_and_refund(&env, admin, escrow_id)
+        escrow_logic::resolve_dispute_and_refund(&env, arbiter, escrow_id)
+    }
+
+    /// Allows a pauser to pause the contract during an incident.
+    /// New escrows are blocked; releases, refunds and cancellations stay open.
+    pub fn pause(env: Env, pauser: Address) -> Result<(), ContractError> {
+        access::set_paused(&env, pauser, true)
+    }
+
+    /// Allows a pauser to lift a pause.
+    pub fn unpause(env: Env, pauser: Address) -> Result<(), ContractError> {
+        access::set_paused(&env, pauser, false)
+    }
+
+    /// Allows a fee manager to configure the platform fee (in basis points) and treasury.
+    pub fn set_fee(
+        env: Env,
+        fee_manager: Address,
+        fee_bps: u32,
+        treasury: Address,
+    ) -> Result<(), ContractError> {
+        escrow_logic::set_fee(&env, fee_manager, fee_bps, treasury)
+    }
+
+    /// Allows a fee manager to withdraw accrued platform fees of a token to the treasury.
+    pub fn withdraw_fees(
+        env: Env,
+        fee_manager: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        escrow_logic::withdraw_fees(&env, fee_manager, token, amount)
+    }
+
+    /// Allows a fee manager to set the keeper reward as a share (in bps) of the platform fee.
+    pub fn set_keeper_reward(
+        env: Env,
+        fee_manager: Address,
+        reward_bps: u32,
+    ) -> Result<(), ContractError> {
+        escrow_logic::set_keeper_reward(&env, fee_manager, reward_bps)
+    }
+
+    /// Allows the admin to replace the contract WASM, keeping all stored data.
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        upgrade::upgrade(&env, admin, new_wasm_hash)
+    }
+
+    /// Allows the admin to hand fee changes, upgrades, arbiter registry edits and
+    /// large dispute rulings over to an M-of-N signer set.
+    pub fn enable_governance(
+        env: Env,
+        admin: Address,
+        config: GovernanceConfig,
+    ) -> Result<(), ContractError> {
+        governance::enable_governance(&env, admin, config)
+    }
+
+    /// Allows a signer to propose a governance action, counting their approval.
+    pub fn propose_action(
+        env: Env,
+        signer: Address,
+        action: GovernanceAction,
+    ) -> Result<u64, ContractError> {
+        governance::propose_action(&env, signer, action)
+    }
+
+    /// Allows a signer to approve an open governance proposal.
+    pub fn approve_action(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        governance::approve_action(&env, signer, proposal_id)
+    }
+
+    /// Runs a governance proposal once it has enough approvals and has not expired.
+    pub fn execute_action(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+        governance::execute_action(&env, proposal_id)
+    }
+
+    /// Allows the admin to rewrite up to `limit` stored escrows into the current
+    /// layout after an upgrade. Call repeatedly until `get_schema_version` is current.
+    pub fn migrate(env: Env, admin: Address, limit: u32) -> Result<u32, ContractError> {
+        upgrade::migrate(&env, admin, limit)
     }
 
     // --- Read-Only Functions ---
 
+    /// Retrieves the current admin.
+    pub fn get_admin(env: Env) -> Result<Address, ContractError> {
+        Ok(EscrowAccess::admin(&env)?)
+    }
+
+    /// Retrieves the admin proposed through `propose_admin`, if any.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        storage::get_pending_admin(&env)
+    }
+
+    /// Retrieves the multisig governance configuration, if governance is enabled.
+    pub fn get_governance(env: Env) -> Option<GovernanceConfig> {
+        storage::get_governance(&env)
+    }
+
+    /// Retrieves a governance proposal.
+    pub fn get_governance_proposal(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<GovernanceProposal, ContractError> {
+        storage::get_proposal(&env, proposal_id)
+    }
+
+    /// Returns whether an address holds a role (the admin holds every role).
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        EscrowAccess::has_role(&env, role, &account)
+    }
+
     /// Retrieves the details of a specific escrow.
     pub fn get_escrow(env: Env, escrow_id: u64) -> Result<Escrow, ContractError> {
         storage::get_escrow(&env, escrow_id)
     }
+
+    /// Retrieves the pending release-time extension proposal of an escrow, if any.
+    pub fn get_extension_proposal(env: Env, escrow_id: u64) -> Option<ExtensionProposal> {
+        storage::get_extension_proposal(&env, escrow_id)
+    }
+
+    /// Retrieves the active escrows releasing on `day` (the release timestamp divided by
+    /// `RELEASE_BUCKET_SECONDS`) as `(release_timestamp, escrow_id)`, ordered by release time.
+    /// Keepers use this to decide when to call `release_matured`.
+    pub fn get_release_bucket(env: Env, day: u64) -> Vec<(u64, u64)> {
+        storage::get_release_bucket(&env, day)
+    }
+
+    /// Retrieves the first day whose release bucket may still hold escrows, if any escrow
+    /// was ever queued.
+    pub fn get_release_cursor(env: Env) -> Option<u64> {
+        storage::get_release_cursor(&env)
+    }
+
+    /// Returns whether the contract is currently paused.
+    pub fn is_paused(env: Env) -> bool {
+        storage::is_paused(&env)
+    }
+
+    /// Retrieves the layout version of the stored records.
+    pub fn get_schema_version(env: Env) -> u32 {
+        storage::get_schema_version(&env)
+    }
+
+    /// Retrieves the current platform fee configuration, if any.
+    pub fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        storage::get_fee_config(&env)
+    }
+
+    /// Retrieves the platform fees accrued (and not yet withdrawn) for a token.
+    pub fn get_collected_fees(env: Env, token: Address) -> i128 {
+        storage::get_collected_fees(&env, &token)
+    }
 }
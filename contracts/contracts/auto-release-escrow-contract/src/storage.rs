@@ -1,19 +1,69 @@
-use soroban_sdk::{contracttype, Address, Env, String};
+use soroban_sdk::{
+    contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol, Val, Vec,
+};
 
 use crate::error::ContractError;
 
+pub use paystar_common::Role;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum EscrowStatus {
-    Active,   // Funds are locked, release time is pending
-    Released, // Funds have been sent to the seller
-    Refunded, // Funds have been returned to the buyer
-    Disputed, // Awaiting admin resolution
+    Active,    // Funds are locked, release time is pending
+    Released,  // Funds have been sent to the seller
+    Refunded,  // Funds have been returned to the buyer
+    Disputed,  // Awaiting admin resolution
+    Cancelled, // Buyer cancelled before the seller acknowledged
+}
+
+/// A pending request to move the release timestamp, awaiting the other party.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtensionProposal {
+    pub proposer: Address,
+    pub new_release_timestamp: u64,
+}
+
+/// A release-time extension both parties agreed on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseExtension {
+    pub proposer: Address,
+    pub previous_release_timestamp: u64,
+    pub new_release_timestamp: u64,
+    pub accepted_at: u64,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Escrow {
+    pub id: u64,
+    pub payer: Address, // Funds the escrow and receives any refund
+    pub buyer: Address, // Beneficiary who confirms receipt or disputes
+    pub seller: Address,
+    pub amount: i128,
+    pub payment_token: Address,
+    pub release_timestamp: u64,
+    pub status: EscrowStatus,
+    pub dispute_reason: Option<String>,
+    pub buyer_confirmed: bool,
+    pub seller_acknowledged: bool,
+    pub extensions: Vec<ReleaseExtension>,
+}
+
+/// Platform fee charged on every settled escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub fee_bps: u32, // Fee in basis points (1 bps = 0.01%)
+    pub treasury: Address,
+}
+
+/// Escrow layout written by schema version 0, before payers,
+/// seller acknowledgement and release-time extensions existed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowV0 {
     pub id: u64,
     pub buyer: Address,
     pub seller: Address,
@@ -25,12 +75,107 @@ pub struct Escrow {
     pub buyer_confirmed: bool,
 }
 
+/// M-of-N signer set that takes over sensitive admin actions once enabled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,         // Approvals needed before an action can run
+    pub ruling_threshold: i128, // Disputes above this amount need a proposal
+    pub proposal_ttl: u64,      // Seconds a proposal stays open
+}
+
+/// Sensitive actions that run through governance once it is enabled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GovernanceAction {
+    SetFee(u32, Address),
+    SetKeeperReward(u32),
+    Upgrade(BytesN<32>),
+    GrantArbiter(Address),
+    RevokeArbiter(Address),
+    ResolveDispute(u64),
+    UpdateGovernance(GovernanceConfig),
+    DisableGovernance,
+}
+
+/// A governance action gathering signer approvals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceProposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: GovernanceAction,
+    pub approvals: Vec<Address>,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
     Admin,
     EscrowCounter,
     Escrow(u64),
+    FeeConfig,
+    CollectedFees(Address), // Accrued fees per payment token
+    ExtensionProposal(u64), // Pending release-time extension per escrow
+    ReleaseBucket(u64), // Active escrows releasing on a day, as (release_timestamp, escrow_id), ascending
+    ReleaseCursor,      // First day whose release bucket may still hold escrows
+    KeeperRewardBps,    // Share of the platform fee paid to keepers, in bps
+    SchemaVersion,      // Layout version of the stored records
+    MigrationCursor,    // Last escrow id rewritten by an in-progress migration
+    Paused,             // Circuit breaker for new escrows
+    PendingAdmin,       // Proposed admin awaiting acceptance
+    Role(Role, Address), // Granted non-admin roles
+    Governance,         // Multisig configuration, absent when disabled
+    ProposalCounter,    // Last governance proposal id
+    Proposal(u64),      // Governance proposals
+}
+
+// --- TTL Management ---
+
+/// Span of a release bucket: escrows are queued per day of their release time.
+pub const RELEASE_BUCKET_SECONDS: u64 = 86_400;
+
+/// Ledgers closed per day, at roughly five seconds per ledger.
+const DAY_IN_LEDGERS: u32 = 17_280;
+/// Every access keeps a record alive for at least 30 more days.
+pub const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+/// TTLs are only extended once they drop below this, so repeated accesses stay cheap.
+pub const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Extends the contract instance (admin, config, counters) and its code.
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+fn extend_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+/// Extends an escrow along with the records it depends on: its pending
+/// extension proposal, its release bucket and the contract instance.
+pub fn extend_escrow(env: &Env, escrow_id: u64) -> Result<(), ContractError> {
+    let escrow = get_escrow(env, escrow_id)?;
+
+    let proposal_key = DataKey::ExtensionProposal(escrow_id);
+    if env.storage().persistent().has(&proposal_key) {
+        extend_persistent(env, &proposal_key);
+    }
+    let bucket_key = DataKey::ReleaseBucket(release_day(escrow.release_timestamp));
+    if env.storage().persistent().has(&bucket_key) {
+        extend_persistent(env, &bucket_key);
+    }
+    let fees_key = DataKey::CollectedFees(escrow.payment_token);
+    if env.storage().persistent().has(&fees_key) {
+        extend_persistent(env, &fees_key);
+    }
+    Ok(())
 }
 
 // --- Storage Helper Functions ---
@@ -43,12 +188,45 @@ pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&DataKey::Admin, admin);
 }
 
-pub fn get_admin(env: &Env) -> Address {
-    env.storage().instance().get(&DataKey::Admin).unwrap()
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
 }
 
-pub fn is_admin(env: &Env, user: &Address) -> bool {
-    get_admin(env) == *user
+pub fn set_pending_admin(env: &Env, pending_admin: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingAdmin, pending_admin);
+}
+
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
+pub fn has_granted_role(env: &Env, role: Role, user: &Address) -> bool {
+    let key = DataKey::Role(role, user.clone());
+    let granted = env.storage().persistent().has(&key);
+    if granted {
+        extend_persistent(env, &key);
+        extend_instance(env);
+    }
+    granted
+}
+
+pub fn grant_role(env: &Env, role: Role, user: &Address) {
+    let key = DataKey::Role(role, user.clone());
+    env.storage().persistent().set(&key, &true);
+    extend_persistent(env, &key);
+    extend_instance(env);
+}
+
+pub fn revoke_role(env: &Env, role: Role, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Role(role, user.clone()));
 }
 
 pub fn get_next_escrow_id(env: &Env) -> u64 {
@@ -65,14 +243,220 @@ pub fn get_next_escrow_id(env: &Env) -> u64 {
 }
 
 pub fn get_escrow(env: &Env, escrow_id: u64) -> Result<Escrow, ContractError> {
-    env.storage()
+    let key = DataKey::Escrow(escrow_id);
+    let escrow = env
+        .storage()
         .persistent()
-        .get(&DataKey::Escrow(escrow_id))
-        .ok_or(ContractError::EscrowNotFound)
+        .get(&key)
+        .ok_or(ContractError::EscrowNotFound)?;
+    extend_persistent(env, &key);
+    extend_instance(env);
+    Ok(escrow)
 }
 
 pub fn set_escrow(env: &Env, escrow: &Escrow) {
+    let key = DataKey::Escrow(escrow.id);
+    env.storage().persistent().set(&key, escrow);
+    extend_persistent(env, &key);
+    extend_instance(env);
+}
+
+pub fn get_fee_config(env: &Env) -> Option<FeeConfig> {
+    env.storage().instance().get(&DataKey::FeeConfig)
+}
+
+pub fn set_fee_config(env: &Env, fee_config: &FeeConfig) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeConfig, fee_config);
+}
+
+pub fn get_collected_fees(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::CollectedFees(token.clone());
+    match env.storage().persistent().get(&key) {
+        Some(collected) => {
+            extend_persistent(env, &key);
+            collected
+        }
+        None => 0,
+    }
+}
+
+pub fn set_collected_fees(env: &Env, token: &Address, amount: i128) {
+    let key = DataKey::CollectedFees(token.clone());
+    env.storage().persistent().set(&key, &amount);
+    extend_persistent(env, &key);
+}
+
+pub fn get_extension_proposal(env: &Env, escrow_id: u64) -> Option<ExtensionProposal> {
+    let key = DataKey::ExtensionProposal(escrow_id);
+    let proposal = env.storage().persistent().get(&key);
+    if proposal.is_some() {
+        extend_persistent(env, &key);
+    }
+    proposal
+}
+
+pub fn set_extension_proposal(env: &Env, escrow_id: u64, proposal: &ExtensionProposal) {
+    let key = DataKey::ExtensionProposal(escrow_id);
+    env.storage().persistent().set(&key, proposal);
+    extend_persistent(env, &key);
+}
+
+pub fn remove_extension_proposal(env: &Env, escrow_id: u64) {
     env.storage()
         .persistent()
-        .set(&DataKey::Escrow(escrow.id), escrow);
+        .remove(&DataKey::ExtensionProposal(escrow_id));
+}
+
+/// The release bucket an escrow maturing at `release_timestamp` is queued in.
+pub fn release_day(release_timestamp: u64) -> u64 {
+    release_timestamp / RELEASE_BUCKET_SECONDS
+}
+
+pub fn get_release_bucket(env: &Env, day: u64) -> Vec<(u64, u64)> {
+    let key = DataKey::ReleaseBucket(day);
+    match env.storage().persistent().get(&key) {
+        Some(bucket) => {
+            extend_persistent(env, &key);
+            bucket
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Stores a release bucket, dropping it once it is empty.
+pub fn set_release_bucket(env: &Env, day: u64, bucket: &Vec<(u64, u64)>) {
+    let key = DataKey::ReleaseBucket(day);
+    if bucket.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, bucket);
+    extend_persistent(env, &key);
+}
+
+pub fn get_release_cursor(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::ReleaseCursor)
+}
+
+pub fn set_release_cursor(env: &Env, day: u64) {
+    env.storage().instance().set(&DataKey::ReleaseCursor, &day);
+}
+
+pub fn get_keeper_reward_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::KeeperRewardBps)
+        .unwrap_or(0)
+}
+
+pub fn set_keeper_reward_bps(env: &Env, reward_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::KeeperRewardBps, &reward_bps);
+}
+
+/// Deployments that predate schema versioning are treated as version 0.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+}
+
+pub fn get_escrow_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::EscrowCounter)
+        .unwrap_or(0)
+}
+
+/// Reads an escrow still stored in the version 0 layout. Escrows already in
+/// the current layout, e.g. created between an upgrade and its migration,
+/// read as `None`.
+pub fn get_escrow_v0(env: &Env, escrow_id: u64) -> Option<EscrowV0> {
+    let key = DataKey::Escrow(escrow_id);
+    let fields: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+    if fields.contains_key(symbol_short!("payer")) {
+        return None;
+    }
+    env.storage().persistent().get(&key)
+}
+
+pub fn get_migration_cursor(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or(0)
+}
+
+pub fn set_migration_cursor(env: &Env, escrow_id: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationCursor, &escrow_id);
+}
+
+pub fn remove_migration_cursor(env: &Env) {
+    env.storage().instance().remove(&DataKey::MigrationCursor);
+}
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&DataKey::Paused, &paused);
+}
+
+pub fn get_governance(env: &Env) -> Option<GovernanceConfig> {
+    env.storage().instance().get(&DataKey::Governance)
+}
+
+pub fn set_governance(env: &Env, config: &GovernanceConfig) {
+    env.storage().instance().set(&DataKey::Governance, config);
+}
+
+pub fn remove_governance(env: &Env) {
+    env.storage().instance().remove(&DataKey::Governance);
+}
+
+pub fn get_next_proposal_id(env: &Env) -> u64 {
+    let current_id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::ProposalCounter)
+        .unwrap_or(0);
+    let next_id = current_id + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::ProposalCounter, &next_id);
+    next_id
+}
+
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Result<GovernanceProposal, ContractError> {
+    let key = DataKey::Proposal(proposal_id);
+    let proposal = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(ContractError::ProposalNotFound)?;
+    extend_persistent(env, &key);
+    extend_instance(env);
+    Ok(proposal)
+}
+
+pub fn set_proposal(env: &Env, proposal: &GovernanceProposal) {
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    extend_persistent(env, &key);
 }
@@ -1,13 +1,29 @@
 #![cfg(test)]
 
 use super::*;
-use crate::{error::ContractError, storage::EscrowStatus};
+use crate::{
+    error::ContractError,
+    event::{EscrowEvent, ReleaseEvent},
+    storage::{
+        DataKey, EscrowStatus, GovernanceAction, GovernanceConfig, Role, BUMP_AMOUNT,
+        LIFETIME_THRESHOLD,
+    },
+};
+use paystar_common::events::EVENT_VERSION;
 use soroban_sdk::{
-    testutils::{Address as _, Ledger as _},
-    token, Address, Env, IntoVal, String,
+    symbol_short,
+    testutils::{
+        storage::{Instance as _, Persistent as _},
+        Address as _, Events as _, Ledger as _,
+    },
+    token, vec, Address, BytesN, Env, IntoVal, String, Symbol, Val,
 };
 use token::StellarAssetClient as TokenAdminClient;
 
+mod escrow_v0 {
+    soroban_sdk::contractimport!(file = "testdata/auto_release_escrow_contract_v0.wasm");
+}
+
 fn create_token_contract<'a>(
     env: &Env,
     admin: &Address,
@@ -31,6 +47,48 @@ struct EscrowTest<'a> {
 }
 
 impl<'a> EscrowTest<'a> {
+    /// Enables 2-of-3 governance; rulings on disputes above 500 need approval.
+    fn enable_governance(&self) -> (Address, Address, Address) {
+        let signers = (
+            Address::generate(&self.env),
+            Address::generate(&self.env),
+            Address::generate(&self.env),
+        );
+        let config = GovernanceConfig {
+            signers: vec![
+                &self.env,
+                signers.0.clone(),
+                signers.1.clone(),
+                signers.2.clone(),
+            ],
+            threshold: 2,
+            ruling_threshold: 500,
+            proposal_ttl: 3600,
+        };
+        self.contract.enable_governance(&self.admin, &config);
+        signers
+    }
+
+    /// Remaining TTLs, in ledgers, of an escrow record and the contract instance.
+    fn ttls(&self, escrow_id: u64) -> (u32, u32) {
+        self.env.as_contract(&self.contract.address, || {
+            (
+                self.env
+                    .storage()
+                    .persistent()
+                    .get_ttl(&DataKey::Escrow(escrow_id)),
+                self.env.storage().instance().get_ttl(),
+            )
+        })
+    }
+
+    /// Topics and data of the last event published by the contract.
+    fn last_event(&self) -> (soroban_sdk::Vec<Val>, Val) {
+        let (contract, topics, data) = self.env.events().all().last().unwrap();
+        assert_eq!(contract, self.contract.address);
+        (topics, data)
+    }
+
     fn setup() -> Self {
         let env = Env::default();
         env.mock_all_auths();
@@ -75,12 +133,18 @@ fn test_initialize() {
 }
 
 #[test]
-fn test_set_admin() {
+fn test_two_step_admin_transfer() {
     let test = EscrowTest::setup();
     let new_admin = Address::generate(&test.env);
 
-    // Current admin sets a new admin
-    test.contract.set_admin(&test.admin, &new_admin);
+    // Proposing alone does not hand over admin rights
+    test.contract.propose_admin(&test.admin, &new_admin);
+    assert_eq!(test.contract.get_admin(), test.admin);
+    assert_eq!(test.contract.get_pending_admin(), Some(new_admin.clone()));
+
+    test.contract.accept_admin(&new_admin);
+    assert_eq!(test.contract.get_admin(), new_admin);
+    assert_eq!(test.contract.get_pending_admin(), None);
 
     // Verify the new admin can perform admin actions, like resolving a dispute
     let escrow_id = test.contract.create_escrow(
@@ -98,19 +162,111 @@ fn test_set_admin() {
         .resolve_dispute_and_refund(&new_admin, &escrow_id);
     let escrow = test.contract.get_escrow(&escrow_id);
     assert_eq!(escrow.status, EscrowStatus::Refunded);
+
+    // The old admin has lost its rights
+    let result = test.contract.try_propose_admin(&test.admin, &test.admin);
+    assert_eq!(result, Err(Ok(ContractError::NotAdmin)));
+}
+
+#[test]
+fn test_propose_admin_unauthorized() {
+    let test = EscrowTest::setup();
+    let new_admin = Address::generate(&test.env);
+
+    // A non-admin (the seller) tries to propose a new admin
+    let result = test.contract.try_propose_admin(&test.seller, &new_admin);
+    assert_eq!(result, Err(Ok(ContractError::NotAdmin)));
 }
 
 #[test]
-fn test_set_admin_unauthorized() {
+fn test_accept_admin_fails_if_not_proposed() {
     let test = EscrowTest::setup();
     let new_admin = Address::generate(&test.env);
 
-    // A non-admin (the seller) tries to set a new admin
-    let result = test.contract.try_set_admin(&test.seller, &new_admin);
+    let result = test.contract.try_accept_admin(&new_admin);
+    assert_eq!(result, Err(Ok(ContractError::NotPendingAdmin)));
+
+    test.contract.propose_admin(&test.admin, &new_admin);
+    let result = test.contract.try_accept_admin(&test.seller);
+    assert_eq!(result, Err(Ok(ContractError::NotPendingAdmin)));
+}
+
+#[test]
+fn test_granted_roles_and_revocation() {
+    let test = EscrowTest::setup();
+    let arbiter = Address::generate(&test.env);
+    let pauser = Address::generate(&test.env);
+
+    test.contract
+        .grant_role(&test.admin, &Role::Arbiter, &arbiter);
+    test.contract
+        .grant_role(&test.admin, &Role::Pauser, &pauser);
+    assert!(test.contract.has_role(&Role::Arbiter, &arbiter));
+    assert!(!test.contract.has_role(&Role::Pauser, &arbiter));
+    assert!(test.contract.has_role(&Role::FeeManager, &test.admin));
+
+    // A pauser can pause but not manage fees
+    test.contract.pause(&pauser);
+    assert!(test.contract.is_paused());
+    let result = test.contract.try_set_fee(&pauser, &100, &pauser);
+    assert_eq!(result, Err(Ok(ContractError::MissingRole)));
+    test.contract.unpause(&pauser);
+
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 100),
+    );
+    test.contract
+        .dispute_escrow(&test.buyer, &escrow_id, &"reason".into_val(&test.env));
+
+    // Once revoked, the arbiter can no longer resolve disputes
+    test.contract
+        .revoke_role(&test.admin, &Role::Arbiter, &arbiter);
+    assert!(!test.contract.has_role(&Role::Arbiter, &arbiter));
+    let result = test
+        .contract
+        .try_resolve_dispute_and_refund(&arbiter, &escrow_id);
+    assert_eq!(result, Err(Ok(ContractError::MissingRole)));
+
+    test.contract
+        .grant_role(&test.admin, &Role::Arbiter, &arbiter);
+    test.contract
+        .resolve_dispute_and_refund(&arbiter, &escrow_id);
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+}
+
+#[test]
+fn test_admin_role_cannot_be_granted() {
+    let test = EscrowTest::setup();
+    let result = test
+        .contract
+        .try_grant_role(&test.admin, &Role::Admin, &test.seller);
+    assert_eq!(result, Err(Ok(ContractError::InvalidRole)));
+
+    let result = test
+        .contract
+        .try_grant_role(&test.seller, &Role::Arbiter, &test.seller);
     assert_eq!(result, Err(Ok(ContractError::NotAdmin)));
 }
 
 #[test]
+fn test_marketplace_roles_cannot_be_granted() {
+    let test = EscrowTest::setup();
+    let result = test
+        .contract
+        .try_grant_role(&test.admin, &Role::Attester, &test.seller);
+    assert_eq!(result, Err(Ok(ContractError::InvalidRole)));
+    let result = test
+        .contract
+        .try_revoke_role(&test.admin, &Role::Attester, &test.seller);
+    assert_eq!(result, Err(Ok(ContractError::InvalidRole)));
+}
+
+#[test]
+#[allow(clippy::bool_assert_comparison)] // Kept as originally written
 fn test_create_escrow_and_fund_locking() {
     let test = EscrowTest::setup();
     let release_timestamp = test.env.ledger().timestamp() + 3600;
@@ -137,6 +293,22 @@ fn test_create_escrow_and_fund_locking() {
     assert_eq!(test.token.balance(&test.contract.address), 1000);
 }
 
+#[test]
+fn test_create_escrow_fails_with_insufficient_balance() {
+    let test = EscrowTest::setup();
+    let release_timestamp = test.env.ledger().timestamp() + 3600;
+
+    let result = test.contract.try_create_escrow(
+        &test.buyer,
+        &test.seller,
+        &10_001,
+        &test.token.address,
+        &release_timestamp,
+    );
+    assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+    assert_eq!(test.token.balance(&test.buyer), 10000);
+}
+
 #[test]
 fn test_release_funds_after_time_elapses() {
     let test = EscrowTest::setup();
@@ -163,6 +335,7 @@ fn test_release_funds_after_time_elapses() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)] // Kept as originally written
 fn test_confirm_receipt_and_early_release() {
     let test = EscrowTest::setup();
     let release_timestamp = test.env.ledger().timestamp() + 3600; // 1 hour
@@ -294,7 +467,7 @@ fn test_dispute_fails_if_already_disputed() {
 }
 
 #[test]
-fn test_resolve_dispute_fails_if_not_admin() {
+fn test_resolve_dispute_fails_without_arbiter_role() {
     let test = EscrowTest::setup();
     let release_timestamp = test.env.ledger().timestamp() + 3600;
     let escrow_id = test.contract.create_escrow(
@@ -311,5 +484,974 @@ fn test_resolve_dispute_fails_if_not_admin() {
     let result = test
         .contract
         .try_resolve_dispute_and_refund(&test.seller, &escrow_id);
-    assert_eq!(result, Err(Ok(ContractError::NotAdmin)));
+    assert_eq!(result, Err(Ok(ContractError::MissingRole)));
+}
+
+#[test]
+fn test_release_deducts_platform_fee() {
+    let test = EscrowTest::setup();
+    let treasury = Address::generate(&test.env);
+    test.contract.set_fee(&test.admin, &250, &treasury); // 2.5%
+
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 10),
+    );
+    test.env.ledger().with_mut(|l| l.timestamp += 20);
+    test.contract.release_funds(&escrow_id);
+
+    // Seller receives the amount net of the fee; the fee stays in the contract.
+    assert_eq!(test.token.balance(&test.seller), 975);
+    assert_eq!(test.token.balance(&test.contract.address), 25);
+    assert_eq!(test.contract.get_collected_fees(&test.token.address), 25);
+
+    // Admin withdraws the accrued fees to the treasury.
+    test.contract
+        .withdraw_fees(&test.admin, &test.token.address, &25);
+    assert_eq!(test.token.balance(&treasury), 25);
+    assert_eq!(test.contract.get_collected_fees(&test.token.address), 0);
+}
+
+#[test]
+fn test_events_carry_full_escrow_state() {
+    let test = EscrowTest::setup();
+    let treasury = Address::generate(&test.env);
+    test.contract.set_fee(&test.admin, &250, &treasury); // 2.5%
+
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 10),
+    );
+    let (topics, data) = test.last_event();
+    let expected_topics: soroban_sdk::Vec<Val> = (
+        symbol_short!("escrow"),
+        symbol_short!("created"),
+        escrow_id as u128,
+        test.buyer.clone(),
+    )
+        .into_val(&test.env);
+    assert_eq!(topics, expected_topics);
+    let event: EscrowEvent = data.into_val(&test.env);
+    assert_eq!(event.version, EVENT_VERSION);
+    assert_eq!(event.escrow, test.contract.get_escrow(&escrow_id));
+
+    test.env.ledger().with_mut(|l| l.timestamp += 20);
+    test.contract.release_funds(&escrow_id);
+
+    // The release event alone tells an indexer where every token went.
+    let (topics, data) = test.last_event();
+    let action: Symbol = topics.get(1).unwrap().into_val(&test.env);
+    assert_eq!(action, symbol_short!("released"));
+    let event: ReleaseEvent = data.into_val(&test.env);
+    assert_eq!(event.escrow, test.contract.get_escrow(&escrow_id));
+    assert_eq!(event.escrow.status, EscrowStatus::Released);
+    assert_eq!(event.seller_amount, 975);
+    assert_eq!(event.fee, 25);
+    assert_eq!(event.keeper_reward, 0);
+    assert_eq!(event.collected_fees, 25);
+}
+
+#[test]
+fn test_set_fee_fails_above_max() {
+    let test = EscrowTest::setup();
+    let treasury = Address::generate(&test.env);
+
+    let result = test.contract.try_set_fee(&test.admin, &1001, &treasury);
+    assert_eq!(result, Err(Ok(ContractError::InvalidFee)));
+}
+
+#[test]
+fn test_set_fee_fails_without_fee_manager_role() {
+    let test = EscrowTest::setup();
+    let treasury = Address::generate(&test.env);
+
+    let result = test.contract.try_set_fee(&test.seller, &100, &treasury);
+    assert_eq!(result, Err(Ok(ContractError::MissingRole)));
+}
+
+#[test]
+fn test_withdraw_fees_fails_above_collected() {
+    let test = EscrowTest::setup();
+    let treasury = Address::generate(&test.env);
+    test.contract.set_fee(&test.admin, &100, &treasury);
+
+    let result = test
+        .contract
+        .try_withdraw_fees(&test.admin, &test.token.address, &1);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientFees)));
+}
+
+#[test]
+fn test_cancel_before_acknowledgement_refunds_buyer() {
+    let test = EscrowTest::setup();
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 3600),
+    );
+
+    test.contract.cancel_escrow(&test.buyer, &escrow_id);
+
+    let escrow = test.contract.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Cancelled);
+    assert_eq!(test.token.balance(&test.buyer), 10000);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+
+    // A cancelled escrow can no longer be released.
+    let result = test.contract.try_release_funds(&escrow_id);
+    assert_eq!(result, Err(Ok(ContractError::EscrowNotActive)));
+}
+
+#[test]
+fn test_cancel_fails_after_acknowledgement() {
+    let test = EscrowTest::setup();
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 3600),
+    );
+
+    test.contract.acknowledge_escrow(&test.seller, &escrow_id);
+    assert!(test.contract.get_escrow(&escrow_id).seller_acknowledged);
+
+    let result = test.contract.try_cancel_escrow(&test.buyer, &escrow_id);
+    assert_eq!(result, Err(Ok(ContractError::EscrowAlreadyAcknowledged)));
+}
+
+#[test]
+fn test_acknowledge_fails_if_not_seller() {
+    let test = EscrowTest::setup();
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 3600),
+    );
+
+    let result = test
+        .contract
+        .try_acknowledge_escrow(&test.buyer, &escrow_id);
+    assert_eq!(result, Err(Ok(ContractError::NotSeller)));
+}
+
+#[test]
+fn test_mutually_agreed_extension() {
+    let test = EscrowTest::setup();
+    let release_timestamp = test.env.ledger().timestamp() + 100;
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &release_timestamp,
+    );
+
+    // Seller asks for more time, buyer agrees.
+    test.contract
+        .propose_extension(&test.seller, &escrow_id, &(release_timestamp + 500));
+    test.contract.accept_extension(&test.buyer, &escrow_id);
+
+    let escrow = test.contract.get_escrow(&escrow_id);
+    assert_eq!(escrow.release_timestamp, release_timestamp + 500);
+    assert_eq!(test.contract.get_extension_proposal(&escrow_id), None);
+    assert_eq!(escrow.extensions.len(), 1);
+    let extension = escrow.extensions.get(0).unwrap();
+    assert_eq!(extension.proposer, test.seller);
+    assert_eq!(extension.previous_release_timestamp, release_timestamp);
+
+    // The original release time no longer allows release.
+    test.env.ledger().with_mut(|l| l.timestamp += 200);
+    let result = test.contract.try_release_funds(&escrow_id);
+    assert_eq!(result, Err(Ok(ContractError::ReleaseTimeNotPassed)));
+}
+
+#[test]
+fn test_extension_cannot_be_self_accepted() {
+    let test = EscrowTest::setup();
+    let release_timestamp = test.env.ledger().timestamp() + 100;
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &release_timestamp,
+    );
+
+    test.contract
+        .propose_extension(&test.buyer, &escrow_id, &(release_timestamp + 50));
+    let result = test.contract.try_accept_extension(&test.buyer, &escrow_id);
+    assert_eq!(result, Err(Ok(ContractError::CannotAcceptOwnExtension)));
+
+    let result = test.contract.try_accept_extension(&test.admin, &escrow_id);
+    assert_eq!(result, Err(Ok(ContractError::NotParticipant)));
+}
+
+#[test]
+fn test_extension_limit() {
+    let test = EscrowTest::setup();
+    let mut release_timestamp = test.env.ledger().timestamp() + 100;
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &release_timestamp,
+    );
+
+    for _ in 0..escrow_logic::MAX_EXTENSIONS {
+        release_timestamp += 100;
+        test.contract
+            .propose_extension(&test.seller, &escrow_id, &release_timestamp);
+        test.contract.accept_extension(&test.buyer, &escrow_id);
+    }
+
+    let result =
+        test.contract
+            .try_propose_extension(&test.seller, &escrow_id, &(release_timestamp + 100));
+    assert_eq!(result, Err(Ok(ContractError::ExtensionLimitReached)));
+}
+
+#[test]
+fn test_release_matured_in_time_order() {
+    let test = EscrowTest::setup();
+    let now = test.env.ledger().timestamp();
+    let late = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(now + 300),
+    );
+    let early = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(now + 100),
+    );
+    let middle = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(now + 200),
+    );
+
+    let bucket = test.contract.get_release_bucket(&0);
+    assert_eq!(bucket.get(0).unwrap(), (now + 100, early));
+    assert_eq!(bucket.get(2).unwrap(), (now + 300, late));
+
+    // Only the first two have matured.
+    test.env.ledger().with_mut(|l| l.timestamp += 250);
+    let keeper = Address::generate(&test.env);
+    let released = test.contract.release_matured(&keeper, &10);
+
+    assert_eq!(released, 2);
+    assert_eq!(
+        test.contract.get_escrow(&early).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(
+        test.contract.get_escrow(&middle).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(test.contract.get_escrow(&late).status, EscrowStatus::Active);
+    assert_eq!(test.contract.get_release_bucket(&0).len(), 1);
+}
+
+#[test]
+fn test_release_matured_respects_limit_and_skips_settled() {
+    let test = EscrowTest::setup();
+    let now = test.env.ledger().timestamp();
+    let first = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(now + 100),
+    );
+    test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(now + 100),
+    );
+    test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(now + 100),
+    );
+
+    // A disputed escrow leaves the queue.
+    test.contract
+        .dispute_escrow(&test.buyer, &first, &"reason".into_val(&test.env));
+    assert_eq!(test.contract.get_release_bucket(&0).len(), 2);
+
+    test.env.ledger().with_mut(|l| l.timestamp += 150);
+    let keeper = Address::generate(&test.env);
+    assert_eq!(test.contract.release_matured(&keeper, &1), 1);
+    assert_eq!(test.contract.release_matured(&keeper, &1), 1);
+    assert_eq!(test.contract.release_matured(&keeper, &1), 0);
+    assert_eq!(test.token.balance(&test.seller), 200);
+}
+
+#[test]
+fn test_release_matured_walks_day_buckets() {
+    let test = EscrowTest::setup();
+    let now = test.env.ledger().timestamp();
+    let days = escrow_logic::MAX_RELEASE_DAYS_PER_CALL as u64;
+    let soon = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(now + 100),
+    );
+    let later = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(now + (days + 5) * RELEASE_BUCKET_SECONDS),
+    );
+
+    // Escrows are queued in the bucket of their release day.
+    assert_eq!(test.contract.get_release_bucket(&0).len(), 1);
+    assert_eq!(
+        test.contract
+            .get_release_bucket(&(days + 5))
+            .get(0)
+            .unwrap(),
+        (now + (days + 5) * RELEASE_BUCKET_SECONDS, later)
+    );
+    assert_eq!(test.contract.get_release_cursor(), Some(0));
+
+    // One call moves past at most MAX_RELEASE_DAYS_PER_CALL empty days.
+    test.env
+        .ledger()
+        .with_mut(|l| l.timestamp += (days + 10) * RELEASE_BUCKET_SECONDS);
+    let keeper = Address::generate(&test.env);
+    assert_eq!(test.contract.release_matured(&keeper, &10), 1);
+    assert_eq!(
+        test.contract.get_escrow(&soon).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(test.contract.get_release_cursor(), Some(days));
+    assert!(test.contract.get_release_bucket(&0).is_empty());
+
+    assert_eq!(test.contract.release_matured(&keeper, &10), 1);
+    assert_eq!(
+        test.contract.get_escrow(&later).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(test.contract.release_matured(&keeper, &10), 0);
+}
+
+#[test]
+fn test_keeper_reward_is_paid_from_fee() {
+    let test = EscrowTest::setup();
+    let treasury = Address::generate(&test.env);
+    let keeper = Address::generate(&test.env);
+    test.contract.set_fee(&test.admin, &200, &treasury); // 2%
+    test.contract.set_keeper_reward(&test.admin, &1_000); // 10% of the fee
+
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 10),
+    );
+    test.env.ledger().with_mut(|l| l.timestamp += 20);
+    test.contract.release_matured(&keeper, &5);
+
+    assert_eq!(
+        test.contract.get_escrow(&escrow_id).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(test.token.balance(&test.seller), 980);
+    assert_eq!(test.token.balance(&keeper), 2);
+    assert_eq!(test.contract.get_collected_fees(&test.token.address), 18);
+}
+
+#[test]
+fn test_set_keeper_reward_fails_above_max() {
+    let test = EscrowTest::setup();
+
+    let result = test.contract.try_set_keeper_reward(&test.admin, &1_001);
+    assert_eq!(result, Err(Ok(ContractError::InvalidKeeperReward)));
+}
+
+#[test]
+fn test_extension_moves_escrow_in_release_queue() {
+    let test = EscrowTest::setup();
+    let now = test.env.ledger().timestamp();
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(now + 100),
+    );
+
+    test.contract
+        .propose_extension(&test.buyer, &escrow_id, &(now + 1000));
+    test.contract.accept_extension(&test.seller, &escrow_id);
+
+    test.env.ledger().with_mut(|l| l.timestamp += 200);
+    let keeper = Address::generate(&test.env);
+    assert_eq!(test.contract.release_matured(&keeper, &10), 0);
+    assert_eq!(
+        test.contract.get_release_bucket(&0).get(0).unwrap(),
+        (now + 1000, escrow_id)
+    );
+}
+
+#[test]
+fn test_third_party_funded_escrow() {
+    let test = EscrowTest::setup();
+    let payer = Address::generate(&test.env);
+    TokenAdminClient::new(&test.env, &test.token.address).mint(&payer, &1000);
+
+    let escrow_id = test.contract.create_funded_escrow(
+        &payer,
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 3600),
+    );
+
+    let escrow = test.contract.get_escrow(&escrow_id);
+    assert_eq!(escrow.payer, payer);
+    assert_eq!(escrow.buyer, test.buyer);
+    assert_eq!(test.token.balance(&payer), 0);
+    assert_eq!(test.token.balance(&test.buyer), 10000);
+
+    // The beneficiary buyer, not the payer, confirms receipt.
+    let result = test.contract.try_confirm_receipt(&payer, &escrow_id);
+    assert_eq!(result, Err(Ok(ContractError::NotBuyer)));
+    test.contract.confirm_receipt(&test.buyer, &escrow_id);
+    test.contract.release_funds(&escrow_id);
+    assert_eq!(test.token.balance(&test.seller), 1000);
+}
+
+#[test]
+fn test_refunds_follow_the_payer() {
+    let test = EscrowTest::setup();
+    let payer = Address::generate(&test.env);
+    TokenAdminClient::new(&test.env, &test.token.address).mint(&payer, &2000);
+
+    let release_timestamp = test.env.ledger().timestamp() + 3600;
+    let cancelled = test.contract.create_funded_escrow(
+        &payer,
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &release_timestamp,
+    );
+    let disputed = test.contract.create_funded_escrow(
+        &payer,
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &release_timestamp,
+    );
+
+    // Only the payer may cancel, and the refund goes to them.
+    let result = test.contract.try_cancel_escrow(&test.buyer, &cancelled);
+    assert_eq!(result, Err(Ok(ContractError::NotPayer)));
+    test.contract.cancel_escrow(&payer, &cancelled);
+    assert_eq!(test.token.balance(&payer), 1000);
+
+    // The buyer disputes; the admin refund also goes to the payer.
+    test.contract
+        .dispute_escrow(&test.buyer, &disputed, &"never arrived".into_val(&test.env));
+    test.contract
+        .resolve_dispute_and_refund(&test.admin, &disputed);
+    assert_eq!(test.token.balance(&payer), 2000);
+    assert_eq!(test.token.balance(&test.buyer), 10000);
+}
+
+#[test]
+fn test_fresh_deployment_is_on_current_schema() {
+    let test = EscrowTest::setup();
+    assert_eq!(
+        test.contract.get_schema_version(),
+        upgrade::CURRENT_SCHEMA_VERSION
+    );
+
+    let result = test.contract.try_migrate(&test.admin, &10);
+    assert_eq!(result, Err(Ok(ContractError::AlreadyMigrated)));
+}
+
+#[test]
+fn test_upgrade_fails_if_not_admin() {
+    let test = EscrowTest::setup();
+    let wasm_hash = test.env.deployer().upload_contract_wasm(escrow_v0::WASM);
+
+    let result = test.contract.try_upgrade(&test.seller, &wasm_hash);
+    assert_eq!(result, Err(Ok(ContractError::NotAdmin)));
+}
+
+#[test]
+fn test_upgrade_replaces_wasm() {
+    let test = EscrowTest::setup();
+    let wasm_hash: BytesN<32> = test.env.deployer().upload_contract_wasm(escrow_v0::WASM);
+
+    test.contract.upgrade(&test.admin, &wasm_hash);
+
+    // The contract now runs the version 0 code, which has no schema version entry point.
+    assert!(test.contract.try_get_schema_version().is_err());
+    let v0 = escrow_v0::Client::new(&test.env, &test.contract.address);
+    let escrow_id = v0.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 100),
+    );
+    assert_eq!(v0.get_escrow(&escrow_id).amount, 100);
+}
+
+#[test]
+fn test_migrate_v0_escrows_after_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&buyer, &10000);
+
+    // Deploy version 0 and create escrows in the old layout.
+    let contract_id = env.register(escrow_v0::WASM, ());
+    let v0 = escrow_v0::Client::new(&env, &contract_id);
+    v0.initialize(&admin);
+    let release_timestamp = env.ledger().timestamp() + 100;
+    let active = v0.create_escrow(&buyer, &seller, &1000, &token.address, &release_timestamp);
+    let disputed = v0.create_escrow(&buyer, &seller, &500, &token.address, &release_timestamp);
+    v0.dispute_escrow(&buyer, &disputed, &"reason".into_val(&env));
+
+    // Swap in the current code at the same address, keeping storage.
+    env.register_at(&contract_id, AutoReleaseEscrowContract, ());
+    let contract = AutoReleaseEscrowContractClient::new(&env, &contract_id);
+    assert_eq!(contract.get_schema_version(), 0);
+
+    // Migrate in batches of one.
+    assert_eq!(contract.migrate(&admin, &1), 1);
+    assert_eq!(contract.get_schema_version(), 0);
+    assert_eq!(contract.migrate(&admin, &1), 1);
+    assert_eq!(
+        contract.get_schema_version(),
+        upgrade::CURRENT_SCHEMA_VERSION
+    );
+
+    let escrow = contract.get_escrow(&active);
+    assert_eq!(escrow.payer, buyer);
+    assert!(escrow.seller_acknowledged);
+    assert_eq!(escrow.extensions.len(), 0);
+    assert_eq!(
+        contract.get_escrow(&disputed).status,
+        EscrowStatus::Disputed
+    );
+
+    // Only the active escrow joins the release queue and settles normally.
+    assert_eq!(contract.get_release_bucket(&0).len(), 1);
+    env.ledger().with_mut(|l| l.timestamp += 200);
+    assert_eq!(contract.release_matured(&admin, &10), 1);
+    assert_eq!(token.balance(&seller), 1000);
+}
+
+#[test]
+fn test_migrate_skips_escrows_created_after_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    token_admin.mint(&buyer, &10000);
+
+    let contract_id = env.register(escrow_v0::WASM, ());
+    let v0 = escrow_v0::Client::new(&env, &contract_id);
+    v0.initialize(&admin);
+    let release_timestamp = env.ledger().timestamp() + 100;
+    let legacy = v0.create_escrow(&buyer, &seller, &1000, &token.address, &release_timestamp);
+
+    // An escrow created on the new code before the migration has run.
+    env.register_at(&contract_id, AutoReleaseEscrowContract, ());
+    let contract = AutoReleaseEscrowContractClient::new(&env, &contract_id);
+    let created = contract.create_escrow(&buyer, &seller, &400, &token.address, &release_timestamp);
+
+    // Only the legacy escrow is rewritten; the new one is left as it was.
+    assert_eq!(contract.migrate(&admin, &10), 1);
+    assert_eq!(
+        contract.get_schema_version(),
+        upgrade::CURRENT_SCHEMA_VERSION
+    );
+    assert_eq!(contract.get_escrow(&legacy).payer, buyer);
+    let escrow = contract.get_escrow(&created);
+    assert_eq!(escrow.amount, 400);
+    assert!(!escrow.seller_acknowledged);
+
+    env.ledger().with_mut(|l| l.timestamp += 200);
+    assert_eq!(contract.release_matured(&admin, &10), 2);
+    assert_eq!(token.balance(&seller), 1400);
+}
+
+#[test]
+fn test_pause_blocks_new_escrows_but_not_refunds() {
+    let test = EscrowTest::setup();
+    let release_timestamp = test.env.ledger().timestamp() + 3600;
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &release_timestamp,
+    );
+
+    test.contract.pause(&test.admin);
+    assert!(test.contract.is_paused());
+
+    let result = test.contract.try_create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &release_timestamp,
+    );
+    assert_eq!(result, Err(Ok(ContractError::ContractPaused)));
+
+    // Cancellations still return funds while paused.
+    test.contract.cancel_escrow(&test.buyer, &escrow_id);
+    assert_eq!(test.token.balance(&test.buyer), 10000);
+
+    test.contract.unpause(&test.admin);
+    assert!(!test.contract.is_paused());
+    test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &release_timestamp,
+    );
+}
+
+#[test]
+fn test_pause_fails_without_pauser_role() {
+    let test = EscrowTest::setup();
+    let result = test.contract.try_pause(&test.seller);
+    assert_eq!(result, Err(Ok(ContractError::MissingRole)));
+}
+
+#[test]
+fn test_governance_fee_change_needs_threshold() {
+    let test = EscrowTest::setup();
+    let (alice, bob, _) = test.enable_governance();
+    let treasury = Address::generate(&test.env);
+
+    // Direct fee changes are no longer allowed
+    let result = test.contract.try_set_fee(&test.admin, &250, &treasury);
+    assert_eq!(result, Err(Ok(ContractError::GovernanceRequired)));
+
+    let proposal_id = test
+        .contract
+        .propose_action(&alice, &GovernanceAction::SetFee(250, treasury.clone()));
+    let result = test.contract.try_execute_action(&proposal_id);
+    assert_eq!(result, Err(Ok(ContractError::ThresholdNotMet)));
+
+    let result = test.contract.try_approve_action(&alice, &proposal_id);
+    assert_eq!(result, Err(Ok(ContractError::ProposalAlreadyApproved)));
+
+    test.contract.approve_action(&bob, &proposal_id);
+    test.contract.execute_action(&proposal_id);
+    assert_eq!(test.contract.get_fee_config().unwrap().fee_bps, 250);
+    assert!(test.contract.get_governance_proposal(&proposal_id).executed);
+
+    let result = test.contract.try_execute_action(&proposal_id);
+    assert_eq!(result, Err(Ok(ContractError::ProposalAlreadyExecuted)));
+}
+
+#[test]
+fn test_governance_keeper_reward_change_needs_threshold() {
+    let test = EscrowTest::setup();
+    let (alice, bob, _) = test.enable_governance();
+
+    // A fee manager can no longer pick their own reward
+    let result = test.contract.try_set_keeper_reward(&test.admin, &500);
+    assert_eq!(result, Err(Ok(ContractError::GovernanceRequired)));
+
+    let proposal_id = test
+        .contract
+        .propose_action(&alice, &GovernanceAction::SetKeeperReward(1_001));
+    test.contract.approve_action(&bob, &proposal_id);
+    let result = test.contract.try_execute_action(&proposal_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidKeeperReward)));
+
+    let proposal_id = test
+        .contract
+        .propose_action(&alice, &GovernanceAction::SetKeeperReward(500));
+    test.contract.approve_action(&bob, &proposal_id);
+    test.contract.execute_action(&proposal_id);
+    assert!(test.contract.get_governance_proposal(&proposal_id).executed);
+}
+
+#[test]
+fn test_governance_proposal_expires() {
+    let test = EscrowTest::setup();
+    let (alice, bob, _) = test.enable_governance();
+    let treasury = Address::generate(&test.env);
+
+    let proposal_id = test
+        .contract
+        .propose_action(&alice, &GovernanceAction::SetFee(250, treasury));
+    test.env.ledger().with_mut(|l| l.timestamp += 3601);
+
+    let result = test.contract.try_approve_action(&bob, &proposal_id);
+    assert_eq!(result, Err(Ok(ContractError::ProposalExpired)));
+    assert_eq!(test.contract.get_fee_config(), None);
+}
+
+#[test]
+fn test_governance_large_rulings_need_approval() {
+    let test = EscrowTest::setup();
+    let (alice, _, carol) = test.enable_governance();
+    let release_timestamp = test.env.ledger().timestamp() + 100;
+    let reason: String = "not delivered".into_val(&test.env);
+
+    let small = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &release_timestamp,
+    );
+    let large = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &1000,
+        &test.token.address,
+        &release_timestamp,
+    );
+    test.contract.dispute_escrow(&test.buyer, &small, &reason);
+    test.contract.dispute_escrow(&test.buyer, &large, &reason);
+
+    // Small rulings stay with the arbiter
+    test.contract
+        .resolve_dispute_and_refund(&test.admin, &small);
+    let result = test
+        .contract
+        .try_resolve_dispute_and_refund(&test.admin, &large);
+    assert_eq!(result, Err(Ok(ContractError::GovernanceRequired)));
+
+    let proposal_id = test
+        .contract
+        .propose_action(&alice, &GovernanceAction::ResolveDispute(large));
+    test.contract.approve_action(&carol, &proposal_id);
+    test.contract.execute_action(&proposal_id);
+
+    assert_eq!(
+        test.contract.get_escrow(&large).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+}
+
+#[test]
+fn test_governance_controls_arbiter_registry() {
+    let test = EscrowTest::setup();
+    let (alice, bob, _) = test.enable_governance();
+    let arbiter = Address::generate(&test.env);
+
+    let result = test
+        .contract
+        .try_grant_role(&test.admin, &Role::Arbiter, &arbiter);
+    assert_eq!(result, Err(Ok(ContractError::GovernanceRequired)));
+    // Other roles stay with the admin
+    test.contract
+        .grant_role(&test.admin, &Role::Pauser, &arbiter);
+
+    let result = test
+        .contract
+        .try_propose_action(&arbiter, &GovernanceAction::GrantArbiter(arbiter.clone()));
+    assert_eq!(result, Err(Ok(ContractError::NotSigner)));
+
+    let proposal_id = test
+        .contract
+        .propose_action(&alice, &GovernanceAction::GrantArbiter(arbiter.clone()));
+    test.contract.approve_action(&bob, &proposal_id);
+    test.contract.execute_action(&proposal_id);
+    assert!(test.contract.has_role(&Role::Arbiter, &arbiter));
+
+    // Disabling governance hands the actions back to the admin
+    let proposal_id = test
+        .contract
+        .propose_action(&alice, &GovernanceAction::DisableGovernance);
+    test.contract.approve_action(&bob, &proposal_id);
+    test.contract.execute_action(&proposal_id);
+    assert_eq!(test.contract.get_governance(), None);
+    test.contract
+        .revoke_role(&test.admin, &Role::Arbiter, &arbiter);
+}
+
+#[test]
+fn test_enable_governance_rejects_invalid_config() {
+    let test = EscrowTest::setup();
+    let signer = Address::generate(&test.env);
+    let mut config = GovernanceConfig {
+        signers: vec![&test.env, signer.clone(), signer.clone()],
+        threshold: 2,
+        ruling_threshold: 0,
+        proposal_ttl: 3600,
+    };
+
+    // Duplicate signers would let one key meet the threshold
+    let result = test.contract.try_enable_governance(&test.admin, &config);
+    assert_eq!(result, Err(Ok(ContractError::InvalidGovernanceConfig)));
+
+    config.signers = vec![&test.env, signer];
+    let result = test.contract.try_enable_governance(&test.admin, &config);
+    assert_eq!(result, Err(Ok(ContractError::InvalidGovernanceConfig)));
+
+    // A proposal may not outlive its record, nor its expiry overflow
+    config.threshold = 1;
+    config.proposal_ttl = u64::MAX;
+    let result = test.contract.try_enable_governance(&test.admin, &config);
+    assert_eq!(result, Err(Ok(ContractError::InvalidGovernanceConfig)));
+
+    let result = test.contract.try_enable_governance(&test.seller, &config);
+    assert_eq!(result, Err(Ok(ContractError::NotAdmin)));
+
+    config.proposal_ttl = governance::MAX_PROPOSAL_TTL;
+    test.contract.enable_governance(&test.admin, &config);
+}
+
+#[test]
+fn test_governance_upgrade() {
+    let test = EscrowTest::setup();
+    let (_, bob, carol) = test.enable_governance();
+    let wasm_hash: BytesN<32> = test.env.deployer().upload_contract_wasm(escrow_v0::WASM);
+
+    let result = test.contract.try_upgrade(&test.admin, &wasm_hash);
+    assert_eq!(result, Err(Ok(ContractError::GovernanceRequired)));
+
+    let proposal_id = test
+        .contract
+        .propose_action(&bob, &GovernanceAction::Upgrade(wasm_hash));
+    test.contract.approve_action(&carol, &proposal_id);
+    test.contract.execute_action(&proposal_id);
+
+    // The contract now runs the version 0 code
+    assert!(test.contract.try_get_schema_version().is_err());
+}
+
+#[test]
+fn test_escrow_outlives_default_ttl() {
+    let test = EscrowTest::setup();
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 100),
+    );
+    assert_eq!(test.ttls(escrow_id), (BUMP_AMOUNT, BUMP_AMOUNT));
+
+    // Far beyond the network's minimum persistent TTL
+    test.env.ledger().with_mut(|l| l.sequence_number += 100_000);
+    assert_eq!(test.contract.get_escrow(&escrow_id).amount, 100);
+}
+
+#[test]
+fn test_bump_keeps_escrow_alive() {
+    let test = EscrowTest::setup();
+    let escrow_id = test.contract.create_escrow(
+        &test.buyer,
+        &test.seller,
+        &100,
+        &test.token.address,
+        &(test.env.ledger().timestamp() + 100),
+    );
+
+    // Accesses within the threshold leave the TTL alone
+    let elapsed = BUMP_AMOUNT - LIFETIME_THRESHOLD - 1;
+    test.env.ledger().with_mut(|l| l.sequence_number += elapsed);
+    test.contract.bump(&escrow_id);
+    assert_eq!(
+        test.ttls(escrow_id),
+        (LIFETIME_THRESHOLD + 1, LIFETIME_THRESHOLD + 1)
+    );
+
+    // One ledger before the escrow would be archived
+    test.env
+        .ledger()
+        .with_mut(|l| l.sequence_number += LIFETIME_THRESHOLD);
+    test.contract.bump(&escrow_id);
+    assert_eq!(test.ttls(escrow_id), (BUMP_AMOUNT, BUMP_AMOUNT));
+
+    // Without the bump the escrow would have been archived by now
+    test.env
+        .ledger()
+        .with_mut(|l| l.sequence_number += BUMP_AMOUNT - 100);
+    assert_eq!(
+        test.contract.get_escrow(&escrow_id).status,
+        EscrowStatus::Active
+    );
+
+    let result = test.contract.try_bump(&(escrow_id + 1));
+    assert_eq!(result, Err(Ok(ContractError::EscrowNotFound)));
+}
+
+#[test]
+fn test_roles_stay_alive_while_idle() {
+    let test = EscrowTest::setup();
+    let arbiter = Address::generate(&test.env);
+    test.contract
+        .grant_role(&test.admin, &Role::Arbiter, &arbiter);
+    let role_ttl = || {
+        test.env.as_contract(&test.contract.address, || {
+            test.env
+                .storage()
+                .persistent()
+                .get_ttl(&DataKey::Role(Role::Arbiter, arbiter.clone()))
+        })
+    };
+    assert_eq!(role_ttl(), BUMP_AMOUNT);
+
+    // A role check extends the entry once it is within the threshold
+    test.env
+        .ledger()
+        .with_mut(|l| l.sequence_number += BUMP_AMOUNT - LIFETIME_THRESHOLD + 1);
+    assert!(test.contract.has_role(&Role::Arbiter, &arbiter));
+    assert_eq!(role_ttl(), BUMP_AMOUNT);
+
+    // A keeper can extend an arbiter nobody has checked
+    test.env
+        .ledger()
+        .with_mut(|l| l.sequence_number += BUMP_AMOUNT - LIFETIME_THRESHOLD + 1);
+    test.contract.bump_role(&Role::Arbiter, &arbiter);
+    assert_eq!(role_ttl(), BUMP_AMOUNT);
+
+    let result = test.contract.try_bump_role(&Role::Pauser, &arbiter);
+    assert_eq!(result, Err(Ok(ContractError::MissingRole)));
 }
@@ -0,0 +1,84 @@
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+use crate::{
+    access,
+    error::ContractError,
+    escrow_logic, event, governance,
+    storage::{self, Escrow, EscrowStatus},
+};
+
+/// Layout version of the records written by this build.
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// Replaces the contract WASM. Stored data is kept; call `migrate` afterwards
+/// if the new build bumps the schema version.
+pub fn upgrade(env: &Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), ContractError> {
+    access::require_admin(env, &admin)?;
+    governance::require_disabled(env)?;
+
+    apply_upgrade(env, &admin, new_wasm_hash);
+    Ok(())
+}
+
+/// Swaps in the new WASM. Callers check who may upgrade.
+pub(crate) fn apply_upgrade(env: &Env, initiator: &Address, new_wasm_hash: BytesN<32>) {
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+    event::contract_upgraded(env, initiator, &new_wasm_hash);
+}
+
+/// Rewrites up to `limit` stored escrows into the current layout, resuming
+/// where the previous batch stopped. The schema version is bumped once every
+/// escrow has been rewritten. Returns the number of escrows migrated.
+pub fn migrate(env: &Env, admin: Address, limit: u32) -> Result<u32, ContractError> {
+    access::require_admin(env, &admin)?;
+    if storage::get_schema_version(env) >= CURRENT_SCHEMA_VERSION {
+        return Err(ContractError::AlreadyMigrated);
+    }
+
+    let last_id = storage::get_escrow_counter(env);
+    let mut cursor = storage::get_migration_cursor(env);
+    let mut migrated = 0;
+
+    while migrated < limit && cursor < last_id {
+        cursor += 1;
+        if let Some(legacy) = storage::get_escrow_v0(env, cursor) {
+            let escrow = migrate_escrow_v0(env, legacy);
+            storage::set_escrow(env, &escrow);
+            event::escrow_migrated(env, &admin, &escrow);
+            if escrow.status == EscrowStatus::Active {
+                escrow_logic::enqueue_escrow(env, escrow.id, escrow.release_timestamp);
+            }
+            migrated += 1;
+        }
+    }
+
+    if cursor >= last_id {
+        storage::set_schema_version(env, CURRENT_SCHEMA_VERSION);
+        storage::remove_migration_cursor(env);
+    } else {
+        storage::set_migration_cursor(env, cursor);
+    }
+
+    event::records_migrated(env, &admin, migrated, storage::get_schema_version(env));
+    Ok(migrated)
+}
+
+/// Version 0 escrows were always funded by the buyer and had no cancellation
+/// window, so they are treated as already acknowledged by the seller.
+fn migrate_escrow_v0(env: &Env, legacy: storage::EscrowV0) -> Escrow {
+    Escrow {
+        id: legacy.id,
+        payer: legacy.buyer.clone(),
+        buyer: legacy.buyer,
+        seller: legacy.seller,
+        amount: legacy.amount,
+        payment_token: legacy.payment_token,
+        release_timestamp: legacy.release_timestamp,
+        status: legacy.status,
+        dispute_reason: legacy.dispute_reason,
+        buyer_confirmed: legacy.buyer_confirmed,
+        seller_acknowledged: true,
+        extensions: Vec::new(env),
+    }
+}
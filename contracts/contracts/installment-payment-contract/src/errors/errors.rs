@@ -1,3 +1,4 @@
+use paystar_common::CommonError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -11,4 +12,38 @@ pub enum ContractError {
     InvalidAgreementId = 6,
     NotAuthorized = 7,
     AgreementNotFOund = 8,
+    InvalidFee = 9,
+    InsufficientFees = 10,
+    NotInitialized = 11,
+    AlreadyMigrated = 12,
+    ContractPaused = 13,
+    NotPendingAdmin = 14,
+    InvalidRole = 15,
+    GovernanceRequired = 16,
+    GovernanceNotEnabled = 17,
+    NotSigner = 18,
+    ProposalNotFound = 19,
+    ProposalExpired = 20,
+    ProposalAlreadyApproved = 21,
+    ProposalAlreadyExecuted = 22,
+    ThresholdNotMet = 23,
+    InvalidGovernanceConfig = 24,
+    AmountOverflow = 25,
+    InsufficientBalance = 26,
+}
+
+// the shared access control has finer grained errors than this contract reports
+impl From<CommonError> for ContractError {
+    fn from(error: CommonError) -> Self {
+        match error {
+            CommonError::NotInitialized => ContractError::NotInitialized,
+            CommonError::NotAdmin | CommonError::MissingRole => ContractError::NotAuthorized,
+            CommonError::NotPendingAdmin => ContractError::NotPendingAdmin,
+            CommonError::InvalidRole => ContractError::InvalidRole,
+            CommonError::ContractPaused => ContractError::ContractPaused,
+            CommonError::InvalidAmount => ContractError::InvalidAmount,
+            CommonError::AmountOverflow => ContractError::AmountOverflow,
+            CommonError::InsufficientBalance => ContractError::InsufficientBalance,
+        }
+    }
 }
@@ -0,0 +1,69 @@
+// payloads of the events published by the installment contract
+// topics follow the (contract, action, id, actor) layout shared by the paystar contracts,
+// every payload carries EVENT_VERSION and the state after the change so an indexer never has to read storage
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::contracts::{
+    FeeConfig, GovernanceConfig, GovernanceProposal, InstallmentAgreement,
+};
+
+// created, accepted, paid, cancelled and mig_rec: the agreement as stored after the change
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AgreementEvent {
+    pub version: u32,
+    pub agreement: InstallmentAgreement,
+}
+
+// finalized: the settled agreement and where its funds went
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AgreementSettledEvent {
+    pub version: u32,
+    pub agreement: InstallmentAgreement,
+    pub seller_amount: u128,
+    pub fee: u128,
+    pub collected_fees: u128, // accrued fees in the agreement token after the settlement
+}
+
+// fee_set
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeEvent {
+    pub version: u32,
+    pub fee_config: FeeConfig,
+}
+
+// fee_wdraw
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeWithdrawalEvent {
+    pub version: u32,
+    pub token: Address,
+    pub treasury: Address,
+    pub amount: u128,
+    pub collected_fees: u128, // accrued fees left in the token after the withdrawal
+}
+
+// gov_set: the signer set now in charge
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GovernanceEvent {
+    pub version: u32,
+    pub config: GovernanceConfig,
+}
+
+// gov_off: governance was disabled and the admin is back in charge
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GovernanceDisabledEvent {
+    pub version: u32,
+}
+
+// gov_prop, gov_appr and gov_exec: the proposal after the change
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposalEvent {
+    pub version: u32,
+    pub proposal: GovernanceProposal,
+}
@@ -1,13 +1,21 @@
+use paystar_common::{
+    amount,
+    events::{self, MigrationEvent, UpgradeEvent, CONTRACT_WIDE, EVENT_VERSION},
+    token, AccessControl,
+};
 use soroban_sdk::{
-    contract, contractimpl, symbol_short,
-    token::{self, TokenClient},
-    Address, Env, String, Symbol,
+    contract, contractimpl, symbol_short, vec, Address, BytesN, Env, IntoVal, String, Symbol, Val,
+    Vec,
 };
 
 use crate::errors::errors::*;
+use crate::events::events::*;
 use crate::storage::{contracts::*, storage::*};
 
 const ADMIN: Symbol = symbol_short!("i_p_admin"); // length cannot be more than 9, hence, i = installment, p = payment,
+pub const MAX_FEE_BPS: u32 = 1_000; // the platform fee can never exceed 10%
+pub const MAX_PROPOSAL_TTL: u64 = 30 * 86_400; // proposals stay open at most 30 days, as long as an untouched record lives
+pub const CURRENT_SCHEMA_VERSION: u32 = 1; // layout version of the records written by this build
 
 #[contract]
 pub struct InstallmentPayment;
@@ -27,12 +35,15 @@ impl InstallmentPayment {
         }
         // check auth
         admin.require_auth();
-        env.storage().persistent().set(&ADMIN, &admin);
+        InstallmentAccess::initialize_admin(&env, &admin);
+        // a fresh deployment starts on the current layout, nothing to migrate
+        save_schema_version(&env, CURRENT_SCHEMA_VERSION);
         Ok(admin)
     }
 
     // since it is the buyer that want to pay on installment , it best we allow buyer to create the agreement then choose list pf Arbitrator provided by the platform
     // if the seller is satisfied with the agreement , the seller accepts/ agree to the agreement which then buyer can start making deposits
+    #[allow(clippy::too_many_arguments)]
     pub fn create_installment_agreement(
         env: Env,
         seller: Address,
@@ -51,11 +62,18 @@ impl InstallmentPayment {
         }
 
         // ensure buyer is not the seller
-        if &buyer == &seller {
+        if buyer == seller {
             return Err(ContractError::DuplicateUsers);
         }
 
-        if &buyer == &arbitrator || &seller == &arbitrator {
+        if buyer == arbitrator || seller == arbitrator {
+            return Err(ContractError::ArbitratorNotAllowed);
+        }
+
+        // once the platform is initialized, the arbitrator must be one of its arbiters
+        if env.storage().persistent().has(&ADMIN)
+            && !InstallmentAccess::has_role(&env, Role::Arbiter, &arbitrator)
+        {
             return Err(ContractError::ArbitratorNotAllowed);
         }
 
@@ -71,21 +89,21 @@ impl InstallmentPayment {
         let install_agreement: InstallmentAgreement = InstallmentAgreement::new(
             &env,
             new_agreement_id,
-            buyer,
+            buyer.clone(),
             seller,
             amount,
             deadline,
             arbitrator,
             description,
             token,
+            current_fee_bps(&env),
         );
 
         //save the agreement
         save_new_agreement_id(&env, new_agreement_id);
-        save_installment_agreement(&env, new_agreement_id, install_agreement);
+        save_installment_agreement(&env, new_agreement_id, install_agreement.clone());
 
-        env.events()
-            .publish(("installment_agreement_created",), new_agreement_id);
+        publish_agreement(&env, symbol_short!("created"), &buyer, install_agreement);
 
         Ok(true)
     }
@@ -98,10 +116,13 @@ impl InstallmentPayment {
     ) -> Result<bool, ContractError> {
         buyer_address.require_auth();
 
+        // no new money comes in while the contract is paused
+        InstallmentAccess::require_not_paused(&env)?;
+
         let installment_agreement_optional: Option<InstallmentAgreement> =
             get_installment_agreement(&env, agreement_id);
 
-        if &installment_agreement_optional.is_none() == &true {
+        if installment_agreement_optional.is_none() {
             return Err(ContractError::AgreementNotFOund);
         }
 
@@ -121,37 +142,31 @@ impl InstallmentPayment {
             "agreement has been finalized"
         );
         assert!(
-            &installment_agreement.deadline > &env.ledger().timestamp(),
+            installment_agreement.deadline > env.ledger().timestamp(),
             "agreement past stipulated deadline"
         );
 
         assert!(installment_amount > 0, "amount cannot be zero");
 
-        let token_address: &Address = &installment_agreement.token;
-
-        // create the token client
-        let token_contract: TokenClient = token::TokenClient::new(&env, &token_address);
-        let user_balance: i128 = token_contract.balance(&buyer_address);
-
-        assert!(
-            user_balance >= installment_amount as i128,
-            "insufficient balance"
-        );
-
-        token_contract.transfer(
+        // the buyer's balance is checked before anything moves
+        token::transfer_u128(
+            &env,
+            &installment_agreement.token,
             &buyer_address,
             &env.current_contract_address(),
-            &(installment_amount as i128),
-        );
+            installment_amount,
+        )?;
 
         installment_agreement
             .update_installment_agreement_payment_and_history(&env, installment_amount);
 
-        save_installment_agreement(&env, agreement_id, installment_agreement);
+        save_installment_agreement(&env, agreement_id, installment_agreement.clone());
 
-        env.events().publish(
-            ("installment_payment_made",),
-            (&agreement_id, &buyer_address, &installment_amount),
+        publish_agreement(
+            &env,
+            symbol_short!("paid"),
+            &buyer_address,
+            installment_agreement,
         );
 
         Ok(true)
@@ -200,20 +215,47 @@ impl InstallmentPayment {
             "agreed amount not met yet"
         );
 
-        // send to the seller
-        let token_contract = token::TokenClient::new(&env, &installment_agreement.token);
+        // deduct the platform fee, the rest goes to the seller
+        let fee: u128 = calculate_fee(
+            installment_agreement.total_amount,
+            installment_agreement.fee_bps,
+        )?;
+        let seller_amount: u128 = installment_agreement.total_amount - fee;
 
-        token_contract.transfer(
+        // send to the seller
+        token::transfer_u128(
+            &env,
+            &installment_agreement.token,
             &env.current_contract_address(),
             &installment_agreement.seller,
-            &(installment_agreement.total_amount as i128),
-        );
+            seller_amount,
+        )?;
+
+        // the fee stays in the contract until the admin withdraws it
+        let mut collected_fees: u128 = get_collected_fees(&env, &installment_agreement.token);
+        if fee > 0 {
+            collected_fees = collected_fees
+                .checked_add(fee)
+                .ok_or(ContractError::AmountOverflow)?;
+            save_collected_fees(&env, &installment_agreement.token, collected_fees);
+        }
 
         installment_agreement.finalize();
 
-        save_installment_agreement(&env, agreement_id, installment_agreement);
-        env.events()
-            .publish(("agreement_published",), (&agreement_id, &user));
+        save_installment_agreement(&env, agreement_id, installment_agreement.clone());
+        publish(
+            &env,
+            symbol_short!("finalized"),
+            agreement_id,
+            &user,
+            AgreementSettledEvent {
+                version: EVENT_VERSION,
+                agreement: installment_agreement,
+                seller_amount,
+                fee,
+                collected_fees,
+            },
+        );
 
         Ok(true)
     }
@@ -235,7 +277,7 @@ impl InstallmentPayment {
         let mut installment_agreement: InstallmentAgreement =
             installment_agreement_optional.unwrap();
 
-        if &seller != &installment_agreement.seller {
+        if seller != installment_agreement.seller {
             return Err(ContractError::NotAuthorized);
         }
 
@@ -254,11 +296,13 @@ impl InstallmentPayment {
 
         installment_agreement.accept_agreement(accept_agreement);
 
-        save_installment_agreement(&env, agreement_id, installment_agreement);
+        save_installment_agreement(&env, agreement_id, installment_agreement.clone());
 
-        env.events().publish(
-            ("accept_agreement",),
-            (&agreement_id, &seller, &accept_agreement),
+        publish_agreement(
+            &env,
+            symbol_short!("accepted"),
+            &seller,
+            installment_agreement,
         );
         Ok(true)
     }
@@ -274,7 +318,7 @@ impl InstallmentPayment {
         let installment_agreement_optional: Option<InstallmentAgreement> =
             get_installment_agreement(&env, agreement_id);
 
-        if installment_agreement_optional.is_none() == true {
+        if installment_agreement_optional.is_none() {
             return Err(ContractError::AgreementNotFOund);
         }
 
@@ -296,30 +340,596 @@ impl InstallmentPayment {
         // check the total amount paid by the buyer and refund
         let total_installment_amount_paid: u128 = installment_agreement.amount_paid;
 
-        if total_installment_amount_paid > 0 {
-            // check if the amount paid is greater than 0
-            let token_contract: TokenClient =
-                token::TokenClient::new(&env, &installment_agreement.token);
+        // nothing moves when the buyer has not paid anything yet
+        token::transfer_u128(
+            &env,
+            &installment_agreement.token,
+            &env.current_contract_address(),
+            &installment_agreement.buyer,
+            total_installment_amount_paid,
+        )?;
 
-            // call on the token client
+        //save to the storage
+        save_installment_agreement(&env, agreement_id, installment_agreement.clone());
 
-            token_contract.transfer(
-                &env.current_contract_address(),
-                &installment_agreement.buyer,
-                &(total_installment_amount_paid as i128),
-            );
+        // the refund is the agreement's amount_paid
+        publish_agreement(
+            &env,
+            symbol_short!("cancelled"),
+            &address,
+            installment_agreement,
+        );
+        Ok(true)
+    }
+
+    // the current admin proposes a new admin, nothing changes until the new admin accepts
+    // this way a mistyped address can never take over the contract
+    pub fn propose_admin(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+    ) -> Result<bool, ContractError> {
+        InstallmentAccess::propose_admin(&env, &admin, &new_admin)?;
+        Ok(true)
+    }
+
+    // the proposed admin accepts the handover and becomes the admin
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<bool, ContractError> {
+        InstallmentAccess::accept_admin(&env, &new_admin)?;
+        Ok(true)
+    }
+
+    // the admin grants the Arbiter, Pauser or FeeManager role to an address
+    // the Admin role itself only moves through propose_admin / accept_admin
+    pub fn grant_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        address: Address,
+    ) -> Result<bool, ContractError> {
+        // the arbiter registry belongs to the signers once governance is enabled
+        if role == Role::Arbiter {
+            require_governance_disabled(&env)?;
         }
 
-        //save to the storage
-        save_installment_agreement(&env, agreement_id, installment_agreement);
+        InstallmentAccess::set_role(&env, &admin, role, &address, true)?;
+        Ok(true)
+    }
+
+    pub fn revoke_role(
+        env: Env,
+        admin: Address,
+        role: Role,
+        address: Address,
+    ) -> Result<bool, ContractError> {
+        if role == Role::Arbiter {
+            require_governance_disabled(&env)?;
+        }
+
+        InstallmentAccess::set_role(&env, &admin, role, &address, false)?;
+        Ok(true)
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, ContractError> {
+        Ok(InstallmentAccess::admin(&env)?)
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        get_pending_admin(&env)
+    }
+
+    // the admin holds every role
+    pub fn has_role(env: Env, role: Role, address: Address) -> bool {
+        InstallmentAccess::has_role(&env, role, &address)
+    }
 
-        env.events()
-            .publish(("cancel_and_refund",), (&agreement_id, &address));
+    // anyone can extend the storage ttl of an agreement and the contract settings
+    // so long-running installment plans are not archived mid-agreement
+    pub fn bump(env: Env, agreement_id: u128) -> Result<bool, ContractError> {
+        if get_installment_agreement(&env, agreement_id).is_none() {
+            return Err(ContractError::AgreementNotFOund);
+        }
+
+        extend_persistent(&env, &ADMIN);
+        extend_config(&env);
         Ok(true)
     }
 
+    // a pauser pauses the contract during an incident, blocking new installment payments
+    // finalizing and cancel_and_refund stay open so funds can still leave the contract
+    pub fn pause(env: Env, pauser: Address) -> Result<bool, ContractError> {
+        InstallmentAccess::set_paused(&env, &pauser, true)?;
+        Ok(true)
+    }
+
+    pub fn unpause(env: Env, pauser: Address) -> Result<bool, ContractError> {
+        InstallmentAccess::set_paused(&env, &pauser, false)?;
+        Ok(true)
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+
+    // only a fee manager can set the platform fee and the treasury that receives it
+    // @params: fee_bps is the fee in basis points, capped at MAX_FEE_BPS
+    pub fn set_fee(
+        env: Env,
+        fee_manager: Address,
+        fee_bps: u32,
+        treasury: Address,
+    ) -> Result<bool, ContractError> {
+        require_role(&env, Role::FeeManager, &fee_manager)?;
+        require_governance_disabled(&env)?;
+
+        apply_fee(&env, &fee_manager, fee_bps, treasury)?;
+        Ok(true)
+    }
+
+    // a fee manager withdraws fees accrued in a token to the treasury
+    pub fn withdraw_fees(
+        env: Env,
+        fee_manager: Address,
+        token: Address,
+        amount: u128,
+    ) -> Result<bool, ContractError> {
+        require_role(&env, Role::FeeManager, &fee_manager)?;
+
+        if amount == 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let collected_fees: u128 = get_collected_fees(&env, &token);
+        if amount > collected_fees {
+            return Err(ContractError::InsufficientFees);
+        }
+
+        let fee_config: FeeConfig = get_fee_config(&env).ok_or(ContractError::InvalidFee)?;
+
+        let collected_fees: u128 = collected_fees - amount;
+        save_collected_fees(&env, &token, collected_fees);
+
+        token::transfer_u128(
+            &env,
+            &token,
+            &env.current_contract_address(),
+            &fee_config.treasury,
+            amount,
+        )?;
+
+        publish(
+            &env,
+            symbol_short!("fee_wdraw"),
+            CONTRACT_WIDE,
+            &fee_manager,
+            FeeWithdrawalEvent {
+                version: EVENT_VERSION,
+                token,
+                treasury: fee_config.treasury,
+                amount,
+                collected_fees,
+            },
+        );
+        Ok(true)
+    }
+
+    // only the admin can replace the contract wasm, stored agreements are kept
+    // @params: new_wasm_hash is the hash of the wasm previously uploaded to the network
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<bool, ContractError> {
+        require_admin(&env, &admin)?;
+        require_governance_disabled(&env)?;
+
+        apply_upgrade(&env, &admin, new_wasm_hash);
+        Ok(true)
+    }
+
+    // the admin hands fee changes, upgrades and the arbiter registry over to M-of-N signers
+    // from then on those actions only run through proposals approved by enough signers
+    pub fn enable_governance(
+        env: Env,
+        admin: Address,
+        config: GovernanceConfig,
+    ) -> Result<bool, ContractError> {
+        require_admin(&env, &admin)?;
+        require_governance_disabled(&env)?;
+        validate_governance_config(&config)?;
+
+        save_governance(&env, &config);
+        publish_governance(&env, &admin, config);
+        Ok(true)
+    }
+
+    // a signer proposes a governance action, the proposer's approval is counted
+    pub fn propose_action(
+        env: Env,
+        signer: Address,
+        action: GovernanceAction,
+    ) -> Result<u128, ContractError> {
+        let config: GovernanceConfig = require_signer(&env, &signer)?;
+        if let GovernanceAction::UpdateGovernance(new_config) = &action {
+            validate_governance_config(new_config)?;
+        }
+
+        let new_proposal_id: u128 = get_proposal_id(&env) + 1;
+        let proposal: GovernanceProposal = GovernanceProposal {
+            id: new_proposal_id,
+            proposer: signer.clone(),
+            action: action.clone(),
+            approvals: vec![&env, signer.clone()],
+            expires_at: env.ledger().timestamp().saturating_add(config.proposal_ttl),
+            executed: false,
+        };
+
+        save_new_proposal_id(&env, new_proposal_id);
+        save_proposal(&env, &proposal);
+
+        publish_proposal(&env, symbol_short!("gov_prop"), &signer, proposal);
+        Ok(new_proposal_id)
+    }
+
+    pub fn approve_action(
+        env: Env,
+        signer: Address,
+        proposal_id: u128,
+    ) -> Result<bool, ContractError> {
+        require_signer(&env, &signer)?;
+
+        let mut proposal: GovernanceProposal = get_open_proposal(&env, proposal_id)?;
+        if proposal.approvals.contains(&signer) {
+            return Err(ContractError::ProposalAlreadyApproved);
+        }
+
+        proposal.approvals.push_back(signer.clone());
+        save_proposal(&env, &proposal);
+
+        publish_proposal(&env, symbol_short!("gov_appr"), &signer, proposal);
+        Ok(true)
+    }
+
+    // anyone can run a proposal once enough current signers approved it and it has not expired
+    pub fn execute_action(env: Env, proposal_id: u128) -> Result<bool, ContractError> {
+        let config: GovernanceConfig =
+            get_governance(&env).ok_or(ContractError::GovernanceNotEnabled)?;
+        let mut proposal: GovernanceProposal = get_open_proposal(&env, proposal_id)?;
+
+        // approvals of signers removed since the proposal was made no longer count
+        let approvals: u32 = proposal
+            .approvals
+            .iter()
+            .filter(|approver| config.signers.contains(approver))
+            .count() as u32;
+        if approvals < config.threshold {
+            return Err(ContractError::ThresholdNotMet);
+        }
+
+        proposal.executed = true;
+        save_proposal(&env, &proposal);
+
+        match proposal.action.clone() {
+            GovernanceAction::SetFee(fee_bps, treasury) => {
+                apply_fee(&env, &proposal.proposer, fee_bps, treasury)?
+            }
+            GovernanceAction::Upgrade(new_wasm_hash) => {
+                apply_upgrade(&env, &proposal.proposer, new_wasm_hash)
+            }
+            GovernanceAction::GrantArbiter(address) => {
+                save_role(&env, Role::Arbiter, &address);
+                InstallmentAccess::publish_role(
+                    &env,
+                    &proposal.proposer,
+                    Role::Arbiter,
+                    &address,
+                    true,
+                );
+            }
+            GovernanceAction::RevokeArbiter(address) => {
+                remove_role(&env, Role::Arbiter, &address);
+                InstallmentAccess::publish_role(
+                    &env,
+                    &proposal.proposer,
+                    Role::Arbiter,
+                    &address,
+                    false,
+                );
+            }
+            GovernanceAction::UpdateGovernance(new_config) => {
+                save_governance(&env, &new_config);
+                publish_governance(&env, &proposal.proposer, new_config);
+            }
+            GovernanceAction::DisableGovernance => {
+                remove_governance(&env);
+                publish(
+                    &env,
+                    symbol_short!("gov_off"),
+                    CONTRACT_WIDE,
+                    &proposal.proposer,
+                    GovernanceDisabledEvent {
+                        version: EVENT_VERSION,
+                    },
+                );
+            }
+        }
+
+        let proposer: Address = proposal.proposer.clone();
+        publish_proposal(&env, symbol_short!("gov_exec"), &proposer, proposal);
+        Ok(true)
+    }
+
+    pub fn get_governance(env: Env) -> Option<GovernanceConfig> {
+        get_governance(&env)
+    }
+
+    pub fn get_governance_proposal(env: Env, proposal_id: u128) -> Option<GovernanceProposal> {
+        get_proposal(&env, proposal_id)
+    }
+
+    // after an upgrade, the admin rewrites up to `limit` stored agreements into the current layout
+    // the migration resumes where the previous call stopped and bumps the schema version once done
+    // version 1 fixes the fee on each agreement, version 0 agreements take the current fee
+    pub fn migrate(env: Env, admin: Address, limit: u32) -> Result<u32, ContractError> {
+        require_admin(&env, &admin)?;
+
+        if get_schema_version(&env) >= CURRENT_SCHEMA_VERSION {
+            return Err(ContractError::AlreadyMigrated);
+        }
+
+        let last_agreement_id: u128 = get_agreement_id(&env);
+        let mut cursor: u128 = get_migration_cursor(&env);
+        let mut migrated: u32 = 0;
+
+        while migrated < limit && cursor < last_agreement_id {
+            cursor += 1;
+            if let Some(legacy) = get_installment_agreement_v0(&env, cursor) {
+                let installment_agreement: InstallmentAgreement =
+                    InstallmentAgreement::from_v0(legacy, current_fee_bps(&env));
+                save_installment_agreement(&env, cursor, installment_agreement.clone());
+                publish_agreement(
+                    &env,
+                    symbol_short!("mig_rec"),
+                    &admin,
+                    installment_agreement,
+                );
+                migrated += 1;
+            }
+        }
+
+        if cursor >= last_agreement_id {
+            save_schema_version(&env, CURRENT_SCHEMA_VERSION);
+            remove_migration_cursor(&env);
+        } else {
+            save_migration_cursor(&env, cursor);
+        }
+
+        publish(
+            &env,
+            symbol_short!("migrated"),
+            CONTRACT_WIDE,
+            &admin,
+            MigrationEvent {
+                version: EVENT_VERSION,
+                migrated,
+                schema_version: get_schema_version(&env),
+            },
+        );
+        Ok(migrated)
+    }
+
+    pub fn get_schema_version(env: Env) -> u32 {
+        get_schema_version(&env)
+    }
+
     pub fn get_installment_agreement(env: Env, agreement_id: u128) -> Option<InstallmentAgreement> {
         // Err(String::from_str(&env, ""))
         get_installment_agreement(&env, agreement_id)
     }
+
+    pub fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        get_fee_config(&env)
+    }
+
+    pub fn get_collected_fees(env: Env, token: Address) -> u128 {
+        get_collected_fees(&env, &token)
+    }
+}
+
+// binds the shared admin, role and pause logic to this contract's storage
+struct InstallmentAccess;
+
+impl AccessControl for InstallmentAccess {
+    const CONTRACT: Symbol = symbol_short!("install");
+    const ROLES: &'static [Role] = &[Role::Arbiter, Role::Pauser, Role::FeeManager];
+
+    fn load_admin(env: &Env) -> Option<Address> {
+        get_persistent(env, &ADMIN)
+    }
+
+    fn store_admin(env: &Env, admin: &Address) {
+        set_persistent(env, &ADMIN, admin);
+    }
+
+    fn load_pending_admin(env: &Env) -> Option<Address> {
+        get_pending_admin(env)
+    }
+
+    fn store_pending_admin(env: &Env, pending_admin: Option<&Address>) {
+        match pending_admin {
+            Some(pending_admin) => save_pending_admin(env, pending_admin),
+            None => remove_pending_admin(env),
+        }
+    }
+
+    fn has_granted_role(env: &Env, role: Role, address: &Address) -> bool {
+        has_granted_role(env, role, address)
+    }
+
+    fn store_role(env: &Env, role: Role, address: &Address, granted: bool) {
+        if granted {
+            save_role(env, role, address);
+        } else {
+            remove_role(env, role, address);
+        }
+    }
+
+    fn load_paused(env: &Env) -> bool {
+        is_paused(env)
+    }
+
+    fn store_paused(env: &Env, paused: bool) {
+        save_paused(env, paused);
+    }
+}
+
+// checks the address authorized the call and is the current admin
+fn require_admin(env: &Env, address: &Address) -> Result<(), ContractError> {
+    Ok(InstallmentAccess::require_admin(env, address)?)
+}
+
+// checks the address authorized the call and holds the role, the admin holds every role
+fn require_role(env: &Env, role: Role, address: &Address) -> Result<(), ContractError> {
+    Ok(InstallmentAccess::require_role(env, role, address)?)
+}
+
+// rejects direct calls to actions that governance has taken over
+fn require_governance_disabled(env: &Env) -> Result<(), ContractError> {
+    if get_governance(env).is_some() {
+        return Err(ContractError::GovernanceRequired);
+    }
+    Ok(())
+}
+
+fn require_signer(env: &Env, signer: &Address) -> Result<GovernanceConfig, ContractError> {
+    signer.require_auth();
+
+    let config: GovernanceConfig =
+        get_governance(env).ok_or(ContractError::GovernanceNotEnabled)?;
+    if !config.signers.contains(signer) {
+        return Err(ContractError::NotSigner);
+    }
+    Ok(config)
+}
+
+fn get_open_proposal(env: &Env, proposal_id: u128) -> Result<GovernanceProposal, ContractError> {
+    let proposal: GovernanceProposal =
+        get_proposal(env, proposal_id).ok_or(ContractError::ProposalNotFound)?;
+
+    if proposal.executed {
+        return Err(ContractError::ProposalAlreadyExecuted);
+    }
+    if env.ledger().timestamp() > proposal.expires_at {
+        return Err(ContractError::ProposalExpired);
+    }
+    Ok(proposal)
+}
+
+// duplicate signers would let a single key meet the threshold
+fn validate_governance_config(config: &GovernanceConfig) -> Result<(), ContractError> {
+    let signers: &Vec<Address> = &config.signers;
+    let has_duplicates: bool = signers
+        .iter()
+        .enumerate()
+        .any(|(i, signer)| signers.first_index_of(&signer) != Some(i as u32));
+
+    if config.threshold == 0
+        || config.threshold > signers.len()
+        || config.proposal_ttl == 0
+        || config.proposal_ttl > MAX_PROPOSAL_TTL
+        || has_duplicates
+    {
+        return Err(ContractError::InvalidGovernanceConfig);
+    }
+    Ok(())
+}
+
+fn apply_fee(
+    env: &Env,
+    initiator: &Address,
+    fee_bps: u32,
+    treasury: Address,
+) -> Result<(), ContractError> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(ContractError::InvalidFee);
+    }
+
+    let fee_config: FeeConfig = FeeConfig { fee_bps, treasury };
+    save_fee_config(env, fee_config.clone());
+
+    publish(
+        env,
+        symbol_short!("fee_set"),
+        CONTRACT_WIDE,
+        initiator,
+        FeeEvent {
+            version: EVENT_VERSION,
+            fee_config,
+        },
+    );
+    Ok(())
+}
+
+fn apply_upgrade(env: &Env, initiator: &Address, new_wasm_hash: BytesN<32>) {
+    env.deployer()
+        .update_current_contract_wasm(new_wasm_hash.clone());
+
+    publish(
+        env,
+        symbol_short!("upgraded"),
+        CONTRACT_WIDE,
+        initiator,
+        UpgradeEvent {
+            version: EVENT_VERSION,
+            wasm_hash: new_wasm_hash,
+        },
+    );
+}
+
+// the platform fee in basis points, zero when no fee has been set
+fn current_fee_bps(env: &Env) -> u32 {
+    get_fee_config(env).map_or(0, |fee_config| fee_config.fee_bps)
+}
+
+// the platform fee owed on a settlement amount at the agreement's fee
+fn calculate_fee(total: u128, fee_bps: u32) -> Result<u128, ContractError> {
+    let fee: i128 = amount::bps_share(amount::to_i128(total)?, fee_bps)?;
+    Ok(amount::to_u128(fee)?)
+}
+
+fn publish_agreement(env: &Env, action: Symbol, actor: &Address, agreement: InstallmentAgreement) {
+    let agreement_id: u128 = agreement.id;
+    let event: AgreementEvent = AgreementEvent {
+        version: EVENT_VERSION,
+        agreement,
+    };
+    publish(env, action, agreement_id, actor, event);
+}
+
+fn publish_governance(env: &Env, initiator: &Address, config: GovernanceConfig) {
+    let event: GovernanceEvent = GovernanceEvent {
+        version: EVENT_VERSION,
+        config,
+    };
+    publish(
+        env,
+        symbol_short!("gov_set"),
+        CONTRACT_WIDE,
+        initiator,
+        event,
+    );
+}
+
+fn publish_proposal(env: &Env, action: Symbol, actor: &Address, proposal: GovernanceProposal) {
+    let proposal_id: u128 = proposal.id;
+    let event: ProposalEvent = ProposalEvent {
+        version: EVENT_VERSION,
+        proposal,
+    };
+    publish(env, action, proposal_id, actor, event);
+}
+
+// every event uses the (contract, action, id, actor) topics shared by the paystar contracts
+fn publish<D: IntoVal<Env, Val>>(env: &Env, action: Symbol, id: u128, actor: &Address, data: D) {
+    events::publish(env, InstallmentAccess::CONTRACT, action, id, actor, data);
 }
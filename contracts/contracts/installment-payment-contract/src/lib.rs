@@ -1,18 +1,26 @@
 #![no_std]
 
+#[allow(clippy::module_inception)]
 pub mod test {
     pub mod test;
 }
 
+#[allow(clippy::module_inception)]
 pub mod errors {
     pub mod errors;
 }
 
+#[allow(clippy::module_inception)]
 pub mod storage {
     pub mod contracts;
     pub mod storage;
 }
 
+#[allow(clippy::module_inception)]
+pub mod events {
+    pub mod events;
+}
+
 pub mod installment_payment {
     pub mod installment;
 }
@@ -1,12 +1,55 @@
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
 
 #[contracttype]
+#[derive(Clone, Debug, PartialEq)]
 pub struct PaidHistory {
     pub amount: u128,
     pub timeline: u64,
 }
 
 #[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeConfig {
+    pub fee_bps: u32, // platform fee in basis points, 1 bps = 0.01%
+    pub treasury: Address,
+}
+
+// the admin holds every role, the others are granted and revoked by the admin
+pub use paystar_common::Role;
+
+// M-of-N signers that take over fee changes, upgrades and the arbiter registry once enabled
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GovernanceConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,    // approvals needed before an action can run
+    pub proposal_ttl: u64, // seconds a proposal stays open
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum GovernanceAction {
+    SetFee(u32, Address),
+    Upgrade(BytesN<32>),
+    GrantArbiter(Address),
+    RevokeArbiter(Address),
+    UpdateGovernance(GovernanceConfig),
+    DisableGovernance,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GovernanceProposal {
+    pub id: u128,
+    pub proposer: Address,
+    pub action: GovernanceAction,
+    pub approvals: Vec<Address>,
+    pub expires_at: u64,
+    pub executed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
 pub struct InstallmentAgreement {
     pub id: u128,
     pub buyer: Address,
@@ -28,9 +71,31 @@ pub struct InstallmentAgreement {
     pub description: String,
 
     pub token: Address,
+
+    pub fee_bps: u32, // platform fee fixed when the agreement was created
+}
+
+// the agreement layout written by schema version 0, before the fee was fixed at creation
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstallmentAgreementV0 {
+    pub id: u128,
+    pub buyer: Address,
+    pub seller: Address,
+    pub is_accepted: bool,
+    pub amount_paid: u128,
+    pub paid_history: Vec<PaidHistory>,
+    pub total_amount: u128,
+    pub deadline: u64,
+    pub is_finalized: bool,
+    pub is_canceled: bool,
+    pub arbitrator: Address,
+    pub description: String,
+    pub token: Address,
 }
 
 impl InstallmentAgreement {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         env: &Env,
         id: u128,
@@ -41,6 +106,7 @@ impl InstallmentAgreement {
         arbitrator: Address,
         description: String,
         token: Address,
+        fee_bps: u32,
     ) -> Self {
         Self {
             id,
@@ -49,24 +115,45 @@ impl InstallmentAgreement {
             total_amount: amount,
             is_accepted: false,
             amount_paid: 0,
-            paid_history: Vec::new(&env),
+            paid_history: Vec::new(env),
             deadline: env.ledger().timestamp() + deadline,
             is_finalized: false,
             is_canceled: false,
             arbitrator,
             description,
             token,
+            fee_bps,
+        }
+    }
+
+    // version 0 agreements settle at the fee in effect when they are migrated
+    pub fn from_v0(legacy: InstallmentAgreementV0, fee_bps: u32) -> Self {
+        Self {
+            id: legacy.id,
+            buyer: legacy.buyer,
+            seller: legacy.seller,
+            is_accepted: legacy.is_accepted,
+            amount_paid: legacy.amount_paid,
+            paid_history: legacy.paid_history,
+            total_amount: legacy.total_amount,
+            deadline: legacy.deadline,
+            is_finalized: legacy.is_finalized,
+            is_canceled: legacy.is_canceled,
+            arbitrator: legacy.arbitrator,
+            description: legacy.description,
+            token: legacy.token,
+            fee_bps,
         }
     }
 
     pub fn update_installment_agreement_payment_and_history(&mut self, env: &Env, amount: u128) {
         let payment_history: PaidHistory = PaidHistory {
-            amount: amount,
+            amount,
             timeline: env.ledger().timestamp(),
         };
 
         self.paid_history.push_back(payment_history);
-        self.amount_paid += &amount;
+        self.amount_paid += amount;
     }
 
     pub fn finalize(&mut self) {
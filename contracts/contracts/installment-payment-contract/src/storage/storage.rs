@@ -1,14 +1,113 @@
-use soroban_sdk::{symbol_short, Env, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Map, Symbol, TryFromVal, Val};
 
-use crate::storage::contracts::InstallmentAgreement;
+use crate::storage::contracts::{
+    FeeConfig, GovernanceConfig, GovernanceProposal, InstallmentAgreement, InstallmentAgreementV0,
+    Role,
+};
 
 // const ADMIN: Symbol = symbol_short!("i_p_admin"); // length cannot be more than 9, hence, i = installment, p = payment,
 const AGREEMENT_ID: Symbol = symbol_short!("agree_id");
 const AGREEMENT: Symbol = symbol_short!("agreement");
+const FEE_CONFIG: Symbol = symbol_short!("fee_cfg");
+const FEES: Symbol = symbol_short!("fees");
+const SCHEMA_VERSION: Symbol = symbol_short!("schema_v");
+const MIGRATION_CURSOR: Symbol = symbol_short!("mig_cur");
+const PAUSED: Symbol = symbol_short!("paused");
+const PENDING_ADMIN: Symbol = symbol_short!("pend_adm");
+const ROLE: Symbol = symbol_short!("role");
+const GOVERNANCE: Symbol = symbol_short!("gov");
+const PROPOSAL_ID: Symbol = symbol_short!("prop_id");
+const PROPOSAL: Symbol = symbol_short!("proposal");
+
+const DAY_IN_LEDGERS: u32 = 17_280; // ledgers close roughly every five seconds
+pub const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS; // every access keeps a record alive for 30 more days
+pub const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS; // ttls are only extended below this
+
+// keeps the contract instance and its code from being archived
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+// extends a persistent entry if it exists
+pub fn extend_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    if env.storage().persistent().has(key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    }
+}
+
+// the contract wide settings live in persistent storage, so every read and write
+// extends them along with the contract instance
+pub fn get_persistent<K, V>(env: &Env, key: &K) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+{
+    let value: Option<V> = env.storage().persistent().get(key);
+    if value.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+        extend_instance(env);
+    }
+    value
+}
+
+pub fn set_persistent<K, V>(env: &Env, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    extend_instance(env);
+}
+
+// extends the settings a quiet contract might not touch before they expire
+pub fn extend_config(env: &Env) {
+    for key in [
+        AGREEMENT_ID,
+        FEE_CONFIG,
+        SCHEMA_VERSION,
+        MIGRATION_CURSOR,
+        PAUSED,
+        PENDING_ADMIN,
+        GOVERNANCE,
+        PROPOSAL_ID,
+    ] {
+        extend_persistent(env, &key);
+    }
+    extend_instance(env);
+}
 
 pub fn get_installment_agreement(env: &Env, agreement_id: u128) -> Option<InstallmentAgreement> {
     let agreement_key: (u128, Symbol) = (agreement_id, AGREEMENT);
 
+    let installment_agreement: Option<InstallmentAgreement> =
+        env.storage().persistent().get(&agreement_key);
+    if installment_agreement.is_some() {
+        extend_persistent(env, &agreement_key);
+        extend_instance(env);
+    }
+    installment_agreement
+}
+
+// an agreement still in the version 0 layout, agreements already in the current layout are skipped
+pub fn get_installment_agreement_v0(
+    env: &Env,
+    agreement_id: u128,
+) -> Option<InstallmentAgreementV0> {
+    let agreement_key: (u128, Symbol) = (agreement_id, AGREEMENT);
+
+    let fields: Map<Symbol, Val> = env.storage().persistent().get(&agreement_key)?;
+    if fields.contains_key(symbol_short!("fee_bps")) {
+        return None;
+    }
     env.storage().persistent().get(&agreement_key)
 }
 
@@ -21,14 +120,126 @@ pub fn save_installment_agreement(
     env.storage()
         .persistent()
         .set(&agreement_key, &installment_agreement);
+    extend_persistent(env, &agreement_key);
+    extend_instance(env);
 }
 
 pub fn get_agreement_id(env: &Env) -> u128 {
-    env.storage().persistent().get(&AGREEMENT_ID).unwrap_or(0)
+    get_persistent(env, &AGREEMENT_ID).unwrap_or(0)
 }
 
 pub fn save_new_agreement_id(env: &Env, new_agreement_id: u128) {
-    env.storage()
-        .persistent()
-        .set(&AGREEMENT_ID, &new_agreement_id);
+    set_persistent(env, &AGREEMENT_ID, &new_agreement_id);
+}
+
+pub fn get_fee_config(env: &Env) -> Option<FeeConfig> {
+    get_persistent(env, &FEE_CONFIG)
+}
+
+pub fn save_fee_config(env: &Env, fee_config: FeeConfig) {
+    set_persistent(env, &FEE_CONFIG, &fee_config);
+}
+
+// fees are accounted per token since agreements can be settled in different tokens
+pub fn get_collected_fees(env: &Env, token: &Address) -> u128 {
+    let fees_key: (Address, Symbol) = (token.clone(), FEES);
+
+    get_persistent(env, &fees_key).unwrap_or(0)
+}
+
+pub fn save_collected_fees(env: &Env, token: &Address, amount: u128) {
+    let fees_key: (Address, Symbol) = (token.clone(), FEES);
+    set_persistent(env, &fees_key, &amount);
+}
+
+// contracts deployed before schema versioning existed are on version 0
+pub fn get_schema_version(env: &Env) -> u32 {
+    get_persistent(env, &SCHEMA_VERSION).unwrap_or(0)
+}
+
+pub fn save_schema_version(env: &Env, version: u32) {
+    set_persistent(env, &SCHEMA_VERSION, &version);
+}
+
+// the last agreement id rewritten by an in-progress migration
+pub fn get_migration_cursor(env: &Env) -> u128 {
+    get_persistent(env, &MIGRATION_CURSOR).unwrap_or(0)
+}
+
+pub fn save_migration_cursor(env: &Env, agreement_id: u128) {
+    set_persistent(env, &MIGRATION_CURSOR, &agreement_id);
+}
+
+pub fn remove_migration_cursor(env: &Env) {
+    env.storage().persistent().remove(&MIGRATION_CURSOR);
+}
+
+pub fn is_paused(env: &Env) -> bool {
+    get_persistent(env, &PAUSED).unwrap_or(false)
+}
+
+pub fn save_paused(env: &Env, paused: bool) {
+    set_persistent(env, &PAUSED, &paused);
+}
+
+// the admin proposed by the current admin, waiting to accept the handover
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    get_persistent(env, &PENDING_ADMIN)
+}
+
+pub fn save_pending_admin(env: &Env, pending_admin: &Address) {
+    set_persistent(env, &PENDING_ADMIN, pending_admin);
+}
+
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().persistent().remove(&PENDING_ADMIN);
+}
+
+// roles explicitly granted to an address, the admin is not stored here
+pub fn has_granted_role(env: &Env, role: Role, address: &Address) -> bool {
+    let role_key: (Symbol, Role, Address) = (ROLE, role, address.clone());
+
+    get_persistent::<_, bool>(env, &role_key).is_some()
+}
+
+pub fn save_role(env: &Env, role: Role, address: &Address) {
+    let role_key: (Symbol, Role, Address) = (ROLE, role, address.clone());
+    set_persistent(env, &role_key, &true);
+}
+
+pub fn remove_role(env: &Env, role: Role, address: &Address) {
+    let role_key: (Symbol, Role, Address) = (ROLE, role, address.clone());
+    env.storage().persistent().remove(&role_key);
+}
+
+// governance is disabled while no config is stored
+pub fn get_governance(env: &Env) -> Option<GovernanceConfig> {
+    get_persistent(env, &GOVERNANCE)
+}
+
+pub fn save_governance(env: &Env, config: &GovernanceConfig) {
+    set_persistent(env, &GOVERNANCE, config);
+}
+
+pub fn remove_governance(env: &Env) {
+    env.storage().persistent().remove(&GOVERNANCE);
+}
+
+pub fn get_proposal_id(env: &Env) -> u128 {
+    get_persistent(env, &PROPOSAL_ID).unwrap_or(0)
+}
+
+pub fn save_new_proposal_id(env: &Env, new_proposal_id: u128) {
+    set_persistent(env, &PROPOSAL_ID, &new_proposal_id);
+}
+
+pub fn get_proposal(env: &Env, proposal_id: u128) -> Option<GovernanceProposal> {
+    let proposal_key: (u128, Symbol) = (proposal_id, PROPOSAL);
+
+    get_persistent(env, &proposal_key)
+}
+
+pub fn save_proposal(env: &Env, proposal: &GovernanceProposal) {
+    let proposal_key: (u128, Symbol) = (proposal.id, PROPOSAL);
+    set_persistent(env, &proposal_key, proposal);
 }
@@ -1,14 +1,33 @@
 #[cfg(test)]
+// the original tests are kept as written rather than reworded for clippy
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::needless_borrow,
+    clippy::needless_return,
+    clippy::unit_arg
+)]
 mod test {
 
-    use crate::installment_payment::installment::{InstallmentPayment, InstallmentPaymentClient};
+    use crate::errors::errors::ContractError;
+    use crate::events::events::{AgreementEvent, AgreementSettledEvent};
+    use crate::installment_payment::installment::{
+        InstallmentPayment, InstallmentPaymentClient, CURRENT_SCHEMA_VERSION,
+    };
+    use crate::storage::contracts::{GovernanceAction, GovernanceConfig, Role};
+    use crate::storage::storage::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+    use paystar_common::events::EVENT_VERSION;
     use soroban_sdk::{
-        log,
-        testutils::{Address as _, Ledger},
+        log, symbol_short,
+        testutils::{storage::Persistent as _, Address as _, Events as _, Ledger},
         token::{self, StellarAssetClient},
-        Address, Env, String,
+        vec, Address, Env, FromVal, IntoVal, String, Symbol, Val, Vec,
     };
 
+    #[allow(clippy::too_many_arguments)]
+    mod installment_payment_v0 {
+        soroban_sdk::contractimport!(file = "testdata/installment_payment_contract_v0.wasm");
+    }
+
     fn create_contract_variables() -> (Env, Address, Address) {
         let env: Env = Env::default();
         env.mock_all_auths();
@@ -318,6 +337,104 @@ mod test {
         installed_payment_instance.pay_on_installment(&buyer, &30, &1);
     }
 
+    #[test]
+    fn test_pay_on_installment_checks_balance_and_amount() {
+        let (env, contract_address, mocked_address) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        let (token, token_client) = create_token(&env, &mocked_address);
+
+        let seller: Address = Address::generate(&env);
+        let buyer: Address = Address::generate(&env);
+
+        token_client.mint(&buyer, &50);
+
+        let deadline: u64 = env.ledger().timestamp() + 100;
+        let description: String = String::from_str(&env, "agreement btw A and B");
+
+        installed_payment_instance.create_installment_agreement(
+            &seller,
+            &buyer,
+            &80,
+            &deadline,
+            &mocked_address,
+            &token,
+            &description,
+        );
+        installed_payment_instance.accept_installment_agreement(&seller, &true, &1);
+
+        let result = installed_payment_instance.try_pay_on_installment(&buyer, &60, &1);
+        assert_eq!(result, Err(Ok(ContractError::InsufficientBalance)));
+
+        // amounts that do not fit the token's i128 are rejected instead of wrapping
+        let result = installed_payment_instance.try_pay_on_installment(&buyer, &u128::MAX, &1);
+        assert_eq!(result, Err(Ok(ContractError::AmountOverflow)));
+    }
+
+    // the topics and payload of the last event published by the contract
+    fn last_event(env: &Env, contract_address: &Address) -> (Vec<Val>, Val) {
+        let (address, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(&address, contract_address);
+        (topics, data)
+    }
+
+    #[test]
+    fn test_events_carry_full_agreement_state() {
+        let (env, contract_address, mocked_address) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        let (token, token_client) = create_token(&env, &mocked_address);
+
+        let seller: Address = Address::generate(&env);
+        let buyer: Address = Address::generate(&env);
+
+        token_client.mint(&buyer, &100);
+
+        let deadline: u64 = env.ledger().timestamp() + 100;
+        let description: String = String::from_str(&env, "agreement btw A and B");
+
+        installed_payment_instance.create_installment_agreement(
+            &seller,
+            &buyer,
+            &80,
+            &deadline,
+            &mocked_address,
+            &token,
+            &description,
+        );
+        installed_payment_instance.accept_installment_agreement(&seller, &true, &1);
+        installed_payment_instance.pay_on_installment(&buyer, &80, &1);
+
+        // topics are (contract, action, agreement id, actor)
+        let (topics, data) = last_event(&env, &contract_address);
+        let expected_topics: Vec<Val> = (
+            symbol_short!("install"),
+            symbol_short!("paid"),
+            1_u128,
+            buyer.clone(),
+        )
+            .into_val(&env);
+        assert_eq!(topics, expected_topics);
+
+        let event: AgreementEvent = AgreementEvent::from_val(&env, &data);
+        assert_eq!(event.version, EVENT_VERSION);
+        assert_eq!(
+            Some(event.agreement),
+            installed_payment_instance.get_installment_agreement(&1)
+        );
+
+        installed_payment_instance.finalize_agreement(&1, &seller);
+
+        let (topics, data) = last_event(&env, &contract_address);
+        assert_eq!(
+            Symbol::from_val(&env, &topics.get(1).unwrap()),
+            symbol_short!("finalized")
+        );
+        let event: AgreementSettledEvent = AgreementSettledEvent::from_val(&env, &data);
+        assert!(event.agreement.is_finalized);
+        assert_eq!(event.seller_amount, 80);
+        assert_eq!(event.fee, 0);
+        assert_eq!(event.collected_fees, 0);
+    }
+
     #[test]
     fn test_cancel_and_refund() {
         let (env, contract_address, mocked_address) = create_contract_variables();
@@ -414,4 +531,580 @@ mod test {
         assert_eq!(agree_ment.is_finalized, true);
         // env.ledger().set_timestamp(300);
     }
+
+    #[test]
+    fn test_finalize_installment_with_platform_fee() {
+        let (env, contract_address, mocked_address) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        let (token, token_client) = create_token(&env, &mocked_address);
+
+        let admin: Address = Address::generate(&env);
+        let treasury: Address = Address::generate(&env);
+        let seller: Address = Address::generate(&env);
+        let buyer: Address = Address::generate(&env);
+
+        installed_payment_instance.initialize(&admin);
+        installed_payment_instance.grant_role(&admin, &Role::Arbiter, &mocked_address);
+        // 5% platform fee
+        installed_payment_instance.set_fee(&admin, &500, &treasury);
+
+        token_client.mint(&buyer, &200);
+
+        let deadline: u64 = env.ledger().timestamp() + 100;
+        let description: String = String::from_str(&env, "agreement btw A and B");
+
+        installed_payment_instance.create_installment_agreement(
+            &seller,
+            &buyer,
+            &100,
+            &deadline,
+            &mocked_address,
+            &token,
+            &description,
+        );
+        installed_payment_instance.accept_installment_agreement(&seller, &true, &1);
+        installed_payment_instance.pay_on_installment(&buyer, &100, &1);
+        // the fee was fixed when the agreement was created
+        installed_payment_instance.set_fee(&admin, &1_000, &treasury);
+        installed_payment_instance.finalize_agreement(&1, &seller);
+
+        let token_contract = token::TokenClient::new(&env, &token);
+        assert_eq!(token_contract.balance(&seller), 95);
+        assert_eq!(token_contract.balance(&contract_address), 5);
+        assert_eq!(installed_payment_instance.get_collected_fees(&token), 5);
+
+        installed_payment_instance.withdraw_fees(&admin, &token, &5);
+
+        assert_eq!(token_contract.balance(&treasury), 5);
+        assert_eq!(installed_payment_instance.get_collected_fees(&token), 0);
+    }
+
+    #[test]
+    fn test_only_fee_manager_can_set_fee() {
+        let (env, contract_address, admin) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+
+        installed_payment_instance.initialize(&admin);
+
+        let not_admin: Address = Address::generate(&env);
+        let result = installed_payment_instance.try_set_fee(&not_admin, &100, &not_admin);
+        assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+
+        let result = installed_payment_instance.try_set_fee(&admin, &5_000, &admin);
+        assert_eq!(result, Err(Ok(ContractError::InvalidFee)));
+    }
+
+    #[test]
+    fn test_upgrade_replaces_wasm() {
+        let (env, contract_address, admin) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+
+        installed_payment_instance.initialize(&admin);
+        assert_eq!(
+            installed_payment_instance.get_schema_version(),
+            CURRENT_SCHEMA_VERSION
+        );
+
+        let not_admin: Address = Address::generate(&env);
+        let wasm_hash = env
+            .deployer()
+            .upload_contract_wasm(installment_payment_v0::WASM);
+        let result = installed_payment_instance.try_upgrade(&not_admin, &wasm_hash);
+        assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+
+        installed_payment_instance.upgrade(&admin, &wasm_hash);
+
+        // version 0 has no schema version entry point
+        assert!(installed_payment_instance.try_get_schema_version().is_err());
+    }
+
+    #[test]
+    fn test_migrate_v0_agreements_after_upgrade() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+
+        let admin: Address = Address::generate(&env);
+        let arbitrator: Address = Address::generate(&env);
+        let seller: Address = Address::generate(&env);
+        let buyer: Address = Address::generate(&env);
+        let (token, token_client) = create_token(&env, &admin);
+        token_client.mint(&buyer, &200);
+
+        // deploy version 0 and create agreements there
+        let contract_address: Address = env.register(installment_payment_v0::WASM, ());
+        let v0 = installment_payment_v0::Client::new(&env, &contract_address);
+        v0.initialize(&admin);
+
+        let deadline: u64 = env.ledger().timestamp() + 100;
+        let description: String = String::from_str(&env, "agreement btw A and B");
+        v0.create_installment_agreement(
+            &seller,
+            &buyer,
+            &80,
+            &deadline,
+            &arbitrator,
+            &token,
+            &description,
+        );
+        v0.create_installment_agreement(
+            &seller,
+            &buyer,
+            &50,
+            &deadline,
+            &arbitrator,
+            &token,
+            &description,
+        );
+        v0.accept_installment_agreement(&seller, &true, &1);
+        v0.pay_on_installment(&buyer, &30, &1);
+
+        // swap in the current code at the same address, keeping storage
+        env.register_at(&contract_address, InstallmentPayment, ());
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        assert_eq!(installed_payment_instance.get_schema_version(), 0);
+
+        // an agreement created on the new code before the migration has run
+        let treasury: Address = Address::generate(&env);
+        installed_payment_instance.set_fee(&admin, &500, &treasury);
+        installed_payment_instance.grant_role(&admin, &Role::Arbiter, &arbitrator);
+        installed_payment_instance.create_installment_agreement(
+            &seller,
+            &buyer,
+            &40,
+            &deadline,
+            &arbitrator,
+            &token,
+            &description,
+        );
+
+        // only the two version 0 agreements are rewritten
+        assert_eq!(installed_payment_instance.migrate(&admin, &1), 1);
+        assert_eq!(installed_payment_instance.migrate(&admin, &5), 1);
+        assert_eq!(
+            installed_payment_instance.get_schema_version(),
+            CURRENT_SCHEMA_VERSION
+        );
+        let result = installed_payment_instance.try_migrate(&admin, &5);
+        assert_eq!(result, Err(Ok(ContractError::AlreadyMigrated)));
+
+        // agreements created on version 0 keep working
+        installed_payment_instance.pay_on_installment(&buyer, &50, &1);
+        installed_payment_instance.finalize_agreement(&1, &buyer);
+
+        let installment = installed_payment_instance
+            .get_installment_agreement(&1)
+            .unwrap();
+        assert!(installment.is_finalized);
+        assert_eq!(installment.paid_history.len(), 2);
+        // version 0 agreements settle at the fee in effect when they were migrated
+        assert_eq!(installment.fee_bps, 500);
+        assert_eq!(installed_payment_instance.get_collected_fees(&token), 4);
+
+        let installment = installed_payment_instance
+            .get_installment_agreement(&3)
+            .unwrap();
+        assert_eq!(installment.total_amount, 40);
+        assert_eq!(installment.fee_bps, 500);
+    }
+
+    #[test]
+    fn test_pause_blocks_payments_but_not_refunds() {
+        let (env, contract_address, admin) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        let (token, token_client) = create_token(&env, &admin);
+
+        let arbitrator: Address = Address::generate(&env);
+        let seller: Address = Address::generate(&env);
+        let buyer: Address = Address::generate(&env);
+        token_client.mint(&buyer, &200);
+
+        installed_payment_instance.initialize(&admin);
+        installed_payment_instance.grant_role(&admin, &Role::Arbiter, &arbitrator);
+
+        let deadline: u64 = env.ledger().timestamp() + 100;
+        let description: String = String::from_str(&env, "agreement btw A and B");
+        installed_payment_instance.create_installment_agreement(
+            &seller,
+            &buyer,
+            &80,
+            &deadline,
+            &arbitrator,
+            &token,
+            &description,
+        );
+        installed_payment_instance.accept_installment_agreement(&seller, &true, &1);
+        installed_payment_instance.pay_on_installment(&buyer, &40, &1);
+
+        installed_payment_instance.pause(&admin);
+        assert!(installed_payment_instance.is_paused());
+
+        let result = installed_payment_instance.try_pay_on_installment(&buyer, &40, &1);
+        assert_eq!(result, Err(Ok(ContractError::ContractPaused)));
+
+        // refunds still go out while paused
+        installed_payment_instance.cancel_and_refund_agreement(&seller, &1);
+        let token_contract = token::TokenClient::new(&env, &token);
+        assert_eq!(token_contract.balance(&buyer), 200);
+
+        installed_payment_instance.unpause(&admin);
+        assert!(!installed_payment_instance.is_paused());
+    }
+
+    #[test]
+    fn test_only_pauser_can_pause() {
+        let (env, contract_address, admin) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        installed_payment_instance.initialize(&admin);
+
+        let not_admin: Address = Address::generate(&env);
+        let result = installed_payment_instance.try_pause(&not_admin);
+        assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    }
+
+    #[test]
+    fn test_two_step_admin_transfer() {
+        let (env, contract_address, admin) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        installed_payment_instance.initialize(&admin);
+
+        let new_admin: Address = Address::generate(&env);
+        let result = installed_payment_instance.try_propose_admin(&new_admin, &new_admin);
+        assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+
+        installed_payment_instance.propose_admin(&admin, &new_admin);
+        // nothing changes until the proposed admin accepts
+        assert_eq!(installed_payment_instance.get_admin(), admin);
+        assert_eq!(
+            installed_payment_instance.get_pending_admin(),
+            Some(new_admin.clone())
+        );
+
+        let not_proposed: Address = Address::generate(&env);
+        let result = installed_payment_instance.try_accept_admin(&not_proposed);
+        assert_eq!(result, Err(Ok(ContractError::NotPendingAdmin)));
+
+        installed_payment_instance.accept_admin(&new_admin);
+        assert_eq!(installed_payment_instance.get_admin(), new_admin);
+        assert_eq!(installed_payment_instance.get_pending_admin(), None);
+
+        // the previous admin lost its rights
+        let result = installed_payment_instance.try_pause(&admin);
+        assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+        installed_payment_instance.pause(&new_admin);
+    }
+
+    #[test]
+    fn test_grant_and_revoke_roles() {
+        let (env, contract_address, admin) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        let (token, _) = create_token(&env, &admin);
+        installed_payment_instance.initialize(&admin);
+
+        let arbitrator: Address = Address::generate(&env);
+        let pauser: Address = Address::generate(&env);
+        let seller: Address = Address::generate(&env);
+        let buyer: Address = Address::generate(&env);
+        let deadline: u64 = env.ledger().timestamp() + 100;
+        let description: String = String::from_str(&env, "agreement btw A and B");
+
+        // only arbiters of the platform can be chosen as arbitrator
+        let result = installed_payment_instance.try_create_installment_agreement(
+            &seller,
+            &buyer,
+            &80,
+            &deadline,
+            &arbitrator,
+            &token,
+            &description,
+        );
+        assert_eq!(result, Err(Ok(ContractError::ArbitratorNotAllowed)));
+
+        installed_payment_instance.grant_role(&admin, &Role::Arbiter, &arbitrator);
+        installed_payment_instance.create_installment_agreement(
+            &seller,
+            &buyer,
+            &80,
+            &deadline,
+            &arbitrator,
+            &token,
+            &description,
+        );
+
+        installed_payment_instance.grant_role(&admin, &Role::Pauser, &pauser);
+        assert!(installed_payment_instance.has_role(&Role::Pauser, &pauser));
+        assert!(installed_payment_instance.has_role(&Role::FeeManager, &admin));
+        installed_payment_instance.pause(&pauser);
+
+        // a pauser cannot manage fees
+        let result = installed_payment_instance.try_set_fee(&pauser, &100, &pauser);
+        assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+
+        installed_payment_instance.revoke_role(&admin, &Role::Pauser, &pauser);
+        assert!(!installed_payment_instance.has_role(&Role::Pauser, &pauser));
+        let result = installed_payment_instance.try_unpause(&pauser);
+        assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+
+        // the admin role only moves through propose_admin / accept_admin
+        let result = installed_payment_instance.try_grant_role(&admin, &Role::Admin, &pauser);
+        assert_eq!(result, Err(Ok(ContractError::InvalidRole)));
+
+        // seller attestation belongs to the marketplace
+        let result = installed_payment_instance.try_grant_role(&admin, &Role::Attester, &pauser);
+        assert_eq!(result, Err(Ok(ContractError::InvalidRole)));
+    }
+
+    // enables 2-of-3 governance with proposals open for an hour
+    fn enable_governance(
+        env: &Env,
+        installed_payment_instance: &InstallmentPaymentClient,
+        admin: &Address,
+    ) -> (Address, Address, Address) {
+        let signers: (Address, Address, Address) = (
+            Address::generate(env),
+            Address::generate(env),
+            Address::generate(env),
+        );
+        let config: GovernanceConfig = GovernanceConfig {
+            signers: vec![env, signers.0.clone(), signers.1.clone(), signers.2.clone()],
+            threshold: 2,
+            proposal_ttl: 3600,
+        };
+        installed_payment_instance.enable_governance(admin, &config);
+        signers
+    }
+
+    #[test]
+    fn test_governance_fee_change_needs_threshold() {
+        let (env, contract_address, admin) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        installed_payment_instance.initialize(&admin);
+        let (alice, bob, _) = enable_governance(&env, &installed_payment_instance, &admin);
+        let treasury: Address = Address::generate(&env);
+
+        // direct fee changes are rejected once governance is on
+        let result = installed_payment_instance.try_set_fee(&admin, &250, &treasury);
+        assert_eq!(result, Err(Ok(ContractError::GovernanceRequired)));
+
+        let proposal_id = installed_payment_instance
+            .propose_action(&alice, &GovernanceAction::SetFee(250, treasury.clone()));
+        let result = installed_payment_instance.try_execute_action(&proposal_id);
+        assert_eq!(result, Err(Ok(ContractError::ThresholdNotMet)));
+
+        installed_payment_instance.approve_action(&bob, &proposal_id);
+        installed_payment_instance.execute_action(&proposal_id);
+        assert_eq!(
+            installed_payment_instance.get_fee_config().unwrap().fee_bps,
+            250
+        );
+
+        let result = installed_payment_instance.try_execute_action(&proposal_id);
+        assert_eq!(result, Err(Ok(ContractError::ProposalAlreadyExecuted)));
+    }
+
+    #[test]
+    fn test_governance_proposal_expires() {
+        let (env, contract_address, admin) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        installed_payment_instance.initialize(&admin);
+        let (alice, _, carol) = enable_governance(&env, &installed_payment_instance, &admin);
+        let wasm_hash = env
+            .deployer()
+            .upload_contract_wasm(installment_payment_v0::WASM);
+
+        let result = installed_payment_instance.try_upgrade(&admin, &wasm_hash);
+        assert_eq!(result, Err(Ok(ContractError::GovernanceRequired)));
+
+        let proposal_id = installed_payment_instance
+            .propose_action(&alice, &GovernanceAction::Upgrade(wasm_hash));
+        env.ledger().with_mut(|l| l.timestamp += 3601);
+
+        let result = installed_payment_instance.try_approve_action(&carol, &proposal_id);
+        assert_eq!(result, Err(Ok(ContractError::ProposalExpired)));
+        assert_eq!(
+            installed_payment_instance.get_schema_version(),
+            CURRENT_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn test_governance_controls_arbiter_registry() {
+        let (env, contract_address, admin) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        installed_payment_instance.initialize(&admin);
+        let (alice, bob, _) = enable_governance(&env, &installed_payment_instance, &admin);
+        let arbitrator: Address = Address::generate(&env);
+
+        let result = installed_payment_instance.try_grant_role(&admin, &Role::Arbiter, &arbitrator);
+        assert_eq!(result, Err(Ok(ContractError::GovernanceRequired)));
+
+        let result = installed_payment_instance.try_propose_action(
+            &arbitrator,
+            &GovernanceAction::GrantArbiter(arbitrator.clone()),
+        );
+        assert_eq!(result, Err(Ok(ContractError::NotSigner)));
+
+        let proposal_id = installed_payment_instance
+            .propose_action(&alice, &GovernanceAction::GrantArbiter(arbitrator.clone()));
+        installed_payment_instance.approve_action(&bob, &proposal_id);
+        installed_payment_instance.execute_action(&proposal_id);
+        assert!(installed_payment_instance.has_role(&Role::Arbiter, &arbitrator));
+
+        // a single signer cannot meet the threshold alone
+        let invalid_config: GovernanceConfig = GovernanceConfig {
+            signers: vec![&env, alice.clone(), alice.clone()],
+            threshold: 2,
+            proposal_ttl: 3600,
+        };
+        let result = installed_payment_instance
+            .try_propose_action(&alice, &GovernanceAction::UpdateGovernance(invalid_config));
+        assert_eq!(result, Err(Ok(ContractError::InvalidGovernanceConfig)));
+
+        // nor can a proposal outlive its record, or its expiry overflow
+        let endless_config: GovernanceConfig = GovernanceConfig {
+            signers: vec![&env, alice.clone(), bob.clone()],
+            threshold: 2,
+            proposal_ttl: u64::MAX,
+        };
+        let result = installed_payment_instance
+            .try_propose_action(&alice, &GovernanceAction::UpdateGovernance(endless_config));
+        assert_eq!(result, Err(Ok(ContractError::InvalidGovernanceConfig)));
+    }
+
+    // remaining ttl, in ledgers, of a stored agreement
+    fn agreement_ttl(env: &Env, contract_address: &Address, agreement_id: u128) -> u32 {
+        env.as_contract(contract_address, || {
+            env.storage()
+                .persistent()
+                .get_ttl(&(agreement_id, symbol_short!("agreement")))
+        })
+    }
+
+    #[test]
+    fn test_long_running_agreement_is_not_archived() {
+        let (env, contract_address, mocked_address) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        let (token, token_client) = create_token(&env, &mocked_address);
+
+        let seller: Address = Address::generate(&env);
+        let buyer: Address = Address::generate(&env);
+        token_client.mint(&buyer, &200);
+
+        // a year long plan
+        let deadline: u64 = 365 * 24 * 60 * 60;
+        let description: String = String::from_str(&env, "agreement btw A and B");
+        installed_payment_instance.create_installment_agreement(
+            &seller,
+            &buyer,
+            &80,
+            &deadline,
+            &mocked_address,
+            &token,
+            &description,
+        );
+        installed_payment_instance.accept_installment_agreement(&seller, &true, &1);
+        assert_eq!(agreement_ttl(&env, &contract_address, 1), BUMP_AMOUNT);
+
+        // well past the network's minimum persistent ttl, every payment extends the agreement
+        for _ in 0..4 {
+            // the token contract is kept alive by its own traffic on a real network
+            env.as_contract(&token, || {
+                env.storage()
+                    .instance()
+                    .extend_ttl(LIFETIME_THRESHOLD, BUMP_AMOUNT)
+            });
+            env.ledger().with_mut(|l| {
+                l.sequence_number += LIFETIME_THRESHOLD;
+                l.timestamp += 30 * 24 * 60 * 60;
+            });
+            installed_payment_instance.pay_on_installment(&buyer, &20, &1);
+        }
+
+        installed_payment_instance.finalize_agreement(&1, &seller);
+        let installment = installed_payment_instance
+            .get_installment_agreement(&1)
+            .unwrap();
+        assert!(installment.is_finalized);
+        assert_eq!(installment.paid_history.len(), 4);
+    }
+
+    #[test]
+    fn test_settings_are_extended_on_access() {
+        let (env, contract_address, admin) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        let (token, _) = create_token(&env, &admin);
+        let arbitrator: Address = Address::generate(&env);
+        let treasury: Address = Address::generate(&env);
+
+        installed_payment_instance.initialize(&admin);
+        installed_payment_instance.grant_role(&admin, &Role::Arbiter, &arbitrator);
+        installed_payment_instance.set_fee(&admin, &500, &treasury);
+
+        // the settings drop below the threshold without anyone calling bump
+        env.ledger()
+            .with_mut(|l| l.sequence_number += LIFETIME_THRESHOLD + 1);
+        installed_payment_instance.create_installment_agreement(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &80,
+            &100,
+            &arbitrator,
+            &token,
+            &String::from_str(&env, "agreement btw A and B"),
+        );
+
+        let settings_ttl = |key: Val| {
+            env.as_contract(&contract_address, || {
+                env.storage().persistent().get_ttl(&key)
+            })
+        };
+        assert_eq!(
+            settings_ttl(symbol_short!("i_p_admin").into_val(&env)),
+            BUMP_AMOUNT
+        );
+        assert_eq!(
+            settings_ttl((symbol_short!("role"), Role::Arbiter, arbitrator).into_val(&env)),
+            BUMP_AMOUNT
+        );
+        assert_eq!(
+            settings_ttl(symbol_short!("fee_cfg").into_val(&env)),
+            BUMP_AMOUNT
+        );
+    }
+
+    #[test]
+    fn test_bump_keeps_agreement_alive() {
+        let (env, contract_address, mocked_address) = create_contract_variables();
+        let installed_payment_instance = InstallmentPaymentClient::new(&env, &contract_address);
+        let (token, _) = create_token(&env, &mocked_address);
+
+        let seller: Address = Address::generate(&env);
+        let buyer: Address = Address::generate(&env);
+        let description: String = String::from_str(&env, "agreement btw A and B");
+        installed_payment_instance.create_installment_agreement(
+            &seller,
+            &buyer,
+            &80,
+            &100,
+            &mocked_address,
+            &token,
+            &description,
+        );
+
+        // one ledger before the agreement would be archived
+        env.ledger()
+            .with_mut(|l| l.sequence_number += BUMP_AMOUNT - 1);
+        assert_eq!(agreement_ttl(&env, &contract_address, 1), 1);
+
+        installed_payment_instance.bump(&1);
+        assert_eq!(agreement_ttl(&env, &contract_address, 1), BUMP_AMOUNT);
+
+        env.ledger()
+            .with_mut(|l| l.sequence_number += BUMP_AMOUNT - 1);
+        assert!(installed_payment_instance
+            .get_installment_agreement(&1)
+            .is_some());
+
+        let result = installed_payment_instance.try_bump(&2);
+        assert_eq!(result, Err(Ok(ContractError::AgreementNotFOund)));
+    }
 }
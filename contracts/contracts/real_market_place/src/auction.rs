@@ -0,0 +1,253 @@
+//! English auctions, listed alongside fixed-price items.
+//!
+//! Bids are locked in the contract. A bid must beat the highest one by at
+//! least the auction's increment, and the bid it replaces is refunded in the
+//! same call. A bid in the last `SNIPING_WINDOW` seconds pushes the end time
+//! back so other bidders can answer it. Once the auction has ended anyone can
+//! settle it. A highest bid meeting the reserve buys the lot (`Sold`): it is
+//! listed at the winning bid and bought by the bidder in the same call, so the
+//! win becomes an order that is shipped, delivered and paid out like any other
+//! purchase, and a refunded order puts the lot back in stock like any listing.
+//! Otherwise the bid is refunded (`Unsold`), which includes a winning bid the
+//! seller may no longer accept under the seller policy.
+//!
+//! Bids are only taken while the auction's token is accepted. A winning bid
+//! already held still settles if the token is disallowed before the end.
+
+use paystar_common::{amount, token};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::order::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+use crate::seller::{self, Price};
+use crate::{event, index, tokens, Marketplace};
+
+/// Where an auction is in its lifecycle
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AuctionStatus {
+    Open,
+    Sold,
+    Unsold, // Ended without a bid meeting the reserve
+}
+
+/// A single lot sold to the highest bidder
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Auction {
+    pub id: u32,
+    pub seller: Address,
+    pub name: String,
+    pub image_url: String,
+    pub reserve_price: i128, // Lowest winning bid
+    pub min_increment: i128, // Every bid must beat the highest one by at least this
+    pub end_time: u64,       // Pushed back by bids in the last `SNIPING_WINDOW` seconds
+    pub payment_token: Address,
+    pub highest_bid: i128,
+    pub highest_bidder: Option<Address>,
+    pub bid_count: u32,
+    pub status: AuctionStatus,
+}
+
+/// A bid this close to the end extends the auction to this long after the bid, in seconds
+pub const SNIPING_WINDOW: u64 = 5 * 60;
+
+const AUCTION_COUNTER: Symbol = symbol_short!("A_COUNTER");
+const AUCTION: Symbol = symbol_short!("AUCTION");
+
+pub(crate) fn load(env: &Env, auction_id: u32) -> Option<Auction> {
+    env.storage().persistent().get(&(AUCTION, auction_id))
+}
+
+fn load_existing(env: &Env, auction_id: u32) -> Auction {
+    load(env, auction_id).unwrap_or_else(|| panic!("Auction not found"))
+}
+
+fn save(env: &Env, auction: &Auction) {
+    let key = (AUCTION, auction.id);
+    env.storage().persistent().set(&key, auction);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    Marketplace::extend_instance(env);
+}
+
+/// Number of auctions ever created
+pub(crate) fn count(env: &Env) -> u32 {
+    env.storage().instance().get(&AUCTION_COUNTER).unwrap_or(0)
+}
+
+/// Up to `limit` auctions from the `start`-th one, oldest first, capped at `MAX_PAGE_SIZE`.
+/// Auction ids are sequential, so the page is a range of ids.
+pub(crate) fn page(env: &Env, start: u32, limit: u32) -> Vec<Auction> {
+    let end = start
+        .saturating_add(limit.min(index::MAX_PAGE_SIZE))
+        .min(count(env));
+    let mut auctions = Vec::new(env);
+    for position in start..end {
+        if let Some(auction) = load(env, position + 1) {
+            auctions.push_back(auction);
+        }
+    }
+    auctions
+}
+
+/// Seller opens an auction ending at `end_time`
+pub(crate) fn create(
+    env: &Env,
+    seller: &Address,
+    name: String,
+    image_url: String,
+    reserve_price: i128,
+    min_increment: i128,
+    end_time: u64,
+) -> u32 {
+    seller.require_auth();
+    if reserve_price <= 0 || min_increment <= 0 {
+        panic!("Invalid reserve price or increment");
+    }
+    if end_time <= env.ledger().timestamp() {
+        panic!("Invalid end time");
+    }
+    let payment_token = Marketplace::payment_token(env);
+    seller::require_eligible(
+        env,
+        seller,
+        None,
+        Price::Token(&payment_token, reserve_price),
+    );
+    tokens::require_accepted(env, &payment_token);
+
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&AUCTION_COUNTER)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&AUCTION_COUNTER, &id);
+
+    let auction = Auction {
+        id,
+        seller: seller.clone(),
+        name,
+        image_url,
+        reserve_price,
+        min_increment,
+        end_time,
+        payment_token,
+        highest_bid: 0,
+        highest_bidder: None,
+        bid_count: 0,
+        status: AuctionStatus::Open,
+    };
+    save(env, &auction);
+
+    event::auction_created(env, seller, &auction);
+    id
+}
+
+/// Bidder locks `amount` in the contract as the new highest bid, refunding the bid it beats
+pub(crate) fn bid(env: &Env, bidder: &Address, auction_id: u32, amount: i128) {
+    bidder.require_auth();
+    let mut auction = load_existing(env, auction_id);
+    if auction.status != AuctionStatus::Open {
+        panic!("Auction not open");
+    }
+    let now = env.ledger().timestamp();
+    if now >= auction.end_time {
+        panic!("Auction ended");
+    }
+    if *bidder == auction.seller {
+        panic!("Seller cannot bid");
+    }
+    let reserve_price = Price::Token(&auction.payment_token, auction.reserve_price);
+    seller::require_eligible(env, &auction.seller, None, reserve_price);
+    tokens::require_accepted(env, &auction.payment_token);
+    let minimum = Marketplace::check(amount::add(auction.highest_bid, auction.min_increment));
+    if amount < minimum {
+        panic!("Bid too low");
+    }
+
+    Marketplace::check(token::deposit(env, &auction.payment_token, bidder, amount));
+    let outbid = auction.highest_bidder.clone();
+    let refund = auction.highest_bid;
+    if let Some(previous) = &outbid {
+        Marketplace::check(token::payout(env, &auction.payment_token, previous, refund));
+    }
+
+    auction.highest_bid = amount;
+    auction.highest_bidder = Some(bidder.clone());
+    auction.bid_count += 1;
+    if auction.end_time - now < SNIPING_WINDOW {
+        auction.end_time = now + SNIPING_WINDOW;
+    }
+    save(env, &auction);
+
+    event::bid_placed(env, bidder, &auction, outbid, refund);
+}
+
+/// Closes an ended auction, paying the seller or refunding a bid under the reserve
+pub(crate) fn settle(env: &Env, auction_id: u32) {
+    let mut auction = load_existing(env, auction_id);
+    if auction.status != AuctionStatus::Open {
+        panic!("Auction not open");
+    }
+    if env.ledger().timestamp() < auction.end_time {
+        panic!("Auction not ended");
+    }
+    // Anyone can settle, so it is attributed to the contract itself
+    let actor = env.current_contract_address();
+
+    // Covers auctions without bids too, since the reserve is positive. The policy may
+    // have changed since the auction opened, so the winning bid is checked again.
+    let winner = match &auction.highest_bidder {
+        Some(bidder)
+            if auction.highest_bid >= auction.reserve_price
+                && seller::is_eligible(
+                    env,
+                    &auction.seller,
+                    None,
+                    Price::Token(&auction.payment_token, auction.highest_bid),
+                ) =>
+        {
+            bidder.clone()
+        }
+        _ => {
+            auction.status = AuctionStatus::Unsold;
+            save(env, &auction);
+            if let Some(bidder) = &auction.highest_bidder {
+                Marketplace::check(token::payout(
+                    env,
+                    &auction.payment_token,
+                    bidder,
+                    auction.highest_bid,
+                ));
+            }
+            event::auction_unsold(env, &actor, &auction);
+            return;
+        }
+    };
+
+    auction.status = AuctionStatus::Sold;
+    save(env, &auction);
+
+    // The winning bid is already held, so the lot is bought without another deposit
+    let item = Marketplace::add_item(
+        env,
+        &auction.seller,
+        auction.name.clone(),
+        auction.highest_bid,
+        &auction.payment_token,
+        1,
+        auction.image_url.clone(),
+        &actor,
+    );
+    let order_id = Marketplace::purchase(
+        env,
+        &winner,
+        item.id,
+        1,
+        auction.highest_bid,
+        &auction.payment_token,
+    );
+    event::auction_sold(env, &actor, &auction, order_id);
+}
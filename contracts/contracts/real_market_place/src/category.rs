@@ -0,0 +1,293 @@
+//! Admin-managed category tree and the classification of listings in it.
+//!
+//! Categories form a tree at most `MAX_CATEGORY_DEPTH` levels deep. A listing
+//! sits in at most one category and carries up to `MAX_TAGS` tags chosen by
+//! its seller. Item ids are indexed per category (see [`crate::index`]), so
+//! the catalogue can be browsed by category, subcategories included, a page
+//! at a time without reading every listing's classification.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
+
+use crate::order::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+use crate::seller::{self, Price};
+use crate::{dutch, event, index, oracle, tokens, Item, Marketplace, ITEMS};
+
+/// A node of the category tree
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Category {
+    pub id: u32,
+    pub name: String,
+    pub parent: Option<u32>, // `None` for a top-level category
+}
+
+/// Levels of the category tree, top-level categories included
+pub const MAX_CATEGORY_DEPTH: u32 = 4;
+/// Tags a listing can carry
+pub const MAX_TAGS: u32 = 8;
+/// Longest tag, in bytes
+pub const MAX_TAG_LEN: u32 = 32;
+
+const CATEGORY_COUNTER: Symbol = symbol_short!("C_COUNTER");
+const CATEGORIES: Symbol = symbol_short!("CATS");
+const CATEGORY_ITEMS: Symbol = symbol_short!("CAT_ITEMS");
+// Position of a listing in its category's index
+const ITEM_POSITION: Symbol = symbol_short!("CAT_POS");
+
+/// Every category, keyed by id
+pub(crate) fn all(env: &Env) -> Map<u32, Category> {
+    env.storage()
+        .instance()
+        .get(&CATEGORIES)
+        .unwrap_or(Map::new(env))
+}
+
+pub(crate) fn load(env: &Env, category_id: u32) -> Option<Category> {
+    all(env).get(category_id)
+}
+
+fn load_existing(categories: &Map<u32, Category>, category_id: u32) -> Category {
+    categories
+        .get(category_id)
+        .unwrap_or_else(|| panic!("Category not found"))
+}
+
+/// Adds a category under `parent`, or at the top level
+pub(crate) fn add(env: &Env, name: String, parent: Option<u32>) -> Category {
+    require_name(&name);
+    let mut categories = all(env);
+    if let Some(parent) = parent {
+        load_existing(&categories, parent);
+        if depth(&categories, parent) >= MAX_CATEGORY_DEPTH {
+            panic!("Category too deep");
+        }
+    }
+
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&CATEGORY_COUNTER)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&CATEGORY_COUNTER, &id);
+
+    let category = Category { id, name, parent };
+    categories.set(id, category.clone());
+    env.storage().instance().set(&CATEGORIES, &categories);
+    category
+}
+
+pub(crate) fn rename(env: &Env, category_id: u32, name: String) -> Category {
+    require_name(&name);
+    let mut categories = all(env);
+    let mut category = load_existing(&categories, category_id);
+    category.name = name;
+    categories.set(category_id, category.clone());
+    env.storage().instance().set(&CATEGORIES, &categories);
+    category
+}
+
+/// Removes a category without subcategories or listings
+pub(crate) fn remove(env: &Env, category_id: u32) -> Category {
+    let mut categories = all(env);
+    let category = load_existing(&categories, category_id);
+    let has_children = categories
+        .values()
+        .iter()
+        .any(|other| other.parent == Some(category_id));
+    if has_children || index::len(env, &(CATEGORY_ITEMS, category_id)) > 0 {
+        panic!("Category not empty");
+    }
+
+    categories.remove(category_id);
+    env.storage().instance().set(&CATEGORIES, &categories);
+    category
+}
+
+/// Direct children of `parent`, or the top-level categories
+pub(crate) fn children(env: &Env, parent: Option<u32>) -> Vec<Category> {
+    let mut children = Vec::new(env);
+    for category in all(env).values().iter() {
+        if category.parent == parent {
+            children.push_back(category);
+        }
+    }
+    children
+}
+
+/// Up to `limit` listings in a category or any of its subcategories from the
+/// `start`-th one, capped at `MAX_PAGE_SIZE`. Listings are ordered by category
+/// id, then by when they joined it.
+pub(crate) fn items(env: &Env, category_id: u32, start: u32, limit: u32) -> Vec<Item> {
+    let categories = all(env);
+    load_existing(&categories, category_id);
+    let listed: Map<u32, Item> = env
+        .storage()
+        .instance()
+        .get(&ITEMS)
+        .unwrap_or(Map::new(env));
+
+    let mut skip = start;
+    let mut left = limit.min(index::MAX_PAGE_SIZE);
+    let mut items = Vec::new(env);
+    for category in categories.values().iter() {
+        if left == 0 {
+            break;
+        }
+        if !is_within(&categories, category.id, category_id) {
+            continue;
+        }
+        let list = (CATEGORY_ITEMS, category.id);
+        let len = index::len(env, &list);
+        if skip >= len {
+            skip -= len;
+            continue;
+        }
+        let ids: Vec<u32> = index::page(env, &list, skip, left);
+        skip = 0;
+        left -= ids.len();
+        for item_id in ids.iter() {
+            if let Some(item) = listed.get(item_id) {
+                items.push_back(item);
+            }
+        }
+    }
+    items
+}
+
+/// Number of listings in a category and its subcategories
+pub(crate) fn item_count(env: &Env, category_id: u32) -> u32 {
+    let categories = all(env);
+    load_existing(&categories, category_id);
+    categories
+        .values()
+        .iter()
+        .filter(|category| is_within(&categories, category.id, category_id))
+        .map(|category| index::len(env, &(CATEGORY_ITEMS, category.id)))
+        .sum()
+}
+
+/// Seller moves one of their listings into a category, or out of every category
+pub(crate) fn set_item_category(
+    env: &Env,
+    seller: &Address,
+    item_id: u32,
+    category_id: Option<u32>,
+) {
+    let mut item = owned_item(env, seller, item_id);
+    if let Some(category_id) = category_id {
+        load_existing(&all(env), category_id);
+    }
+    // The tier covers the listed price and whatever the listing can be bought at, as
+    // `purchase` checks
+    let now = env.ledger().timestamp();
+    let listed_price = item.price.max(dutch::unit_price(env, &item, now));
+    seller::require_eligible(
+        env,
+        seller,
+        category_id,
+        Price::Token(&item.token, listed_price),
+    );
+    for (token, price) in tokens::prices(env, &item).iter() {
+        seller::require_eligible(env, seller, category_id, Price::Token(&token, price));
+    }
+    if let Some(price) = oracle::reference_price(env, item_id) {
+        seller::require_eligible(env, seller, category_id, Price::Reference(price));
+    }
+
+    if item.category != category_id {
+        if let Some(previous) = item.category {
+            remove_item_id(env, previous, item_id);
+        }
+        if let Some(category_id) = category_id {
+            add_item_id(env, category_id, item_id);
+        }
+        item.category = category_id;
+        Marketplace::save_item(env, &item);
+    }
+    event::item_categorized(env, seller, &item);
+}
+
+/// Seller replaces the tags of one of their listings
+pub(crate) fn set_item_tags(env: &Env, seller: &Address, item_id: u32, tags: Vec<String>) {
+    let mut item = owned_item(env, seller, item_id);
+    if tags.len() > MAX_TAGS {
+        panic!("Too many tags");
+    }
+    for (index, tag) in tags.iter().enumerate() {
+        if tag.is_empty() || tag.len() > MAX_TAG_LEN {
+            panic!("Invalid tag");
+        }
+        if tags.first_index_of(&tag) != Some(index as u32) {
+            panic!("Duplicate tag");
+        }
+    }
+
+    item.tags = tags;
+    Marketplace::save_item(env, &item);
+    event::item_tagged(env, seller, &item);
+}
+
+fn require_name(name: &String) {
+    if name.is_empty() {
+        panic!("Invalid category name");
+    }
+}
+
+// Levels from the top of the tree down to the category, the category included
+fn depth(categories: &Map<u32, Category>, category_id: u32) -> u32 {
+    let mut depth = 0;
+    let mut current = Some(category_id);
+    while let Some(id) = current {
+        depth += 1;
+        current = categories.get(id).and_then(|category| category.parent);
+    }
+    depth
+}
+
+/// Whether a category is `ancestor` or one of its subcategories
+pub(crate) fn is_within(categories: &Map<u32, Category>, category_id: u32, ancestor: u32) -> bool {
+    let mut current = Some(category_id);
+    while let Some(id) = current {
+        if id == ancestor {
+            return true;
+        }
+        current = categories.get(id).and_then(|category| category.parent);
+    }
+    false
+}
+
+fn add_item_id(env: &Env, category_id: u32, item_id: u32) {
+    let position = index::push(env, &(CATEGORY_ITEMS, category_id), &item_id);
+    save_position(env, item_id, position);
+}
+
+fn remove_item_id(env: &Env, category_id: u32, item_id: u32) {
+    let key = (ITEM_POSITION, item_id);
+    let Some(position) = env.storage().persistent().get::<_, u32>(&key) else {
+        return;
+    };
+    env.storage().persistent().remove(&key);
+    if let Some(moved) = index::swap_remove::<_, u32>(env, &(CATEGORY_ITEMS, category_id), position)
+    {
+        save_position(env, moved, position);
+    }
+}
+
+fn save_position(env: &Env, item_id: u32, position: u32) {
+    let key = (ITEM_POSITION, item_id);
+    env.storage().persistent().set(&key, &position);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+fn owned_item(env: &Env, seller: &Address, item_id: u32) -> Item {
+    seller.require_auth();
+    Marketplace::require_migrated(env);
+    let item = Marketplace::load_item(env, item_id);
+    if item.seller != *seller {
+        panic!("Not the seller");
+    }
+    item
+}
@@ -0,0 +1,106 @@
+//! Dutch auctions: listings whose price falls from a start price to a floor
+//! price over a time window.
+//!
+//! The schedule is kept next to the item rather than in it, so `Item` keeps
+//! its layout. Purchases of the item pay the price at the ledger timestamp of
+//! the purchase:
+//!
+//! * before `start_time`: the start price
+//! * during the window: the start price minus the elapsed share of the drop,
+//!   either continuously (`Linear`) or in equal steps (`Stepwise`)
+//! * from `end_time` on: the floor price
+
+use paystar_common::amount;
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::order::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+use crate::{event, Item, Marketplace};
+
+/// How the price moves from the start price to the floor price
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PriceCurve {
+    Linear,
+    Stepwise(u32), // Number of equal drops, the last one landing on the floor at `end_time`
+}
+
+/// Descending price schedule of a listing
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DutchAuction {
+    pub item_id: u32,
+    pub start_price: i128,
+    pub floor_price: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub curve: PriceCurve,
+}
+
+const DUTCH: Symbol = symbol_short!("DUTCH");
+
+pub(crate) fn load(env: &Env, item_id: u32) -> Option<DutchAuction> {
+    env.storage().persistent().get(&(DUTCH, item_id))
+}
+
+/// Seller puts one of their listings on a descending price schedule
+pub(crate) fn set(env: &Env, seller: &Address, auction: &DutchAuction) {
+    seller.require_auth();
+    let item = Marketplace::load_item(env, auction.item_id);
+    if item.seller != *seller {
+        panic!("Not the seller");
+    }
+    if auction.floor_price <= 0 || auction.start_price <= auction.floor_price {
+        panic!("Invalid price range");
+    }
+    if auction.end_time <= auction.start_time {
+        panic!("Invalid time window");
+    }
+    if auction.curve == PriceCurve::Stepwise(0) {
+        panic!("Invalid step count");
+    }
+
+    let key = (DUTCH, auction.item_id);
+    env.storage().persistent().set(&key, auction);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+
+    event::dutch_auction_set(env, seller, auction);
+}
+
+/// Unit price of an item at `timestamp`: its schedule's price, or the listed price
+pub(crate) fn unit_price(env: &Env, item: &Item, timestamp: u64) -> i128 {
+    match load(env, item.id) {
+        Some(auction) => price_at(&auction, timestamp),
+        None => item.price,
+    }
+}
+
+/// Price of a schedule at `timestamp`. Drops are rounded down, so the price never falls
+/// below the curve.
+pub(crate) fn price_at(auction: &DutchAuction, timestamp: u64) -> i128 {
+    if timestamp <= auction.start_time {
+        return auction.start_price;
+    }
+    if timestamp >= auction.end_time {
+        return auction.floor_price;
+    }
+
+    let drop = auction.start_price - auction.floor_price;
+    let elapsed = (timestamp - auction.start_time) as i128;
+    let duration = (auction.end_time - auction.start_time) as i128;
+    let dropped = match auction.curve {
+        PriceCurve::Linear => mul_div(drop, elapsed, duration),
+        PriceCurve::Stepwise(steps) => {
+            let steps = steps as i128;
+            let taken = elapsed * steps / duration;
+            mul_div(drop, taken, steps)
+        }
+    };
+    auction.start_price - dropped
+}
+
+// `value * numerator / denominator` for a numerator no larger than the denominator
+fn mul_div(value: i128, numerator: i128, denominator: i128) -> i128 {
+    Marketplace::check(amount::mul(value, numerator)) / denominator
+}
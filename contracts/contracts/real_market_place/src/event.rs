@@ -0,0 +1,624 @@
+//! Events published by the marketplace.
+//!
+//! Topics follow the `(contract, action, id, actor)` layout shared by every PayStar
+//! contract, with the item id as the primary id, or the order, auction,
+//! offer or category id for the events of those records. Each payload carries
+//! `EVENT_VERSION` and the state after the change.
+
+use paystar_common::{
+    events::{self, MigrationEvent, UpgradeEvent, CONTRACT_WIDE, EVENT_VERSION},
+    AccessControl,
+};
+use soroban_sdk::{
+    contracttype, symbol_short, Address, BytesN, Env, IntoVal, Map, Symbol, Val, Vec,
+};
+
+use crate::{
+    Auction, Category, DutchAuction, FeeConfig, FulfillmentConfig, Item, MarketplaceAccess, Offer,
+    OracleConfig, Order, SellerProfile, VerificationPolicy,
+};
+
+/// Payload of `init`: the token purchases are paid in.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MarketInitEvent {
+    pub version: u32,
+    pub payment_token: Address,
+}
+
+/// Payload of `list`, `restock`, `item_cat`, `item_tags` and `mig_rec`: the item as stored
+/// after the change.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ItemEvent {
+    pub version: u32,
+    pub item: Item,
+}
+
+/// Payload of `buy`: the item after the purchase and what the buyer paid. The payment is
+/// held until the order is delivered.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PurchaseEvent {
+    pub version: u32,
+    pub item: Item,
+    pub qty: u32,
+    pub payment_token: Address,
+    pub total: i128,
+    pub fee: i128,
+    pub collected_fees: i128, // Accrued fees in the payment token after the purchase
+}
+
+/// Payload of every order transition except the payout: `ordered`, `shipped`, `delivered`,
+/// `reclaimed`, `ret_req`, `ret_acc`, `ret_rej`, `ret_esc`, `returned` and `resolved`. The
+/// order is as stored after the change.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OrderEvent {
+    pub version: u32,
+    pub order: Order,
+}
+
+/// Payload of `released`: the order whose payment went to the seller, and the split.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OrderSettledEvent {
+    pub version: u32,
+    pub order: Order,
+    pub seller_amount: i128,
+    pub fee: i128,
+    pub collected_fees: i128, // Accrued fees in the payment token after the settlement
+}
+
+/// Payload of `auction` and `unsold`: the auction as stored after the change.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AuctionEvent {
+    pub version: u32,
+    pub auction: Auction,
+}
+
+/// Payload of `bid`: the auction with the new highest bid, and the bid it replaced.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BidEvent {
+    pub version: u32,
+    pub auction: Auction,
+    pub outbid: Option<Address>, // Previous highest bidder, refunded in the same call
+    pub refund: i128,
+}
+
+/// Payload of `sold`: the settled auction and the order it placed for the winning bidder.
+/// It follows the `list`, `buy` and `ordered` events of that order.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AuctionSoldEvent {
+    pub version: u32,
+    pub auction: Auction,
+    pub order_id: u64, // Order of the winning bidder, paid out to the seller on delivery
+}
+
+/// Payload of `dutch`: the price schedule a listing now follows.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DutchAuctionEvent {
+    pub version: u32,
+    pub auction: DutchAuction,
+}
+
+/// Payload of `offer`, `offer_ctr`, `offer_acc`, `offer_rej` and `offer_wd`: the offer as
+/// stored after the change. An accepted offer is followed by the `buy` and `ordered` events
+/// of its purchase.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OfferEvent {
+    pub version: u32,
+    pub offer: Offer,
+}
+
+/// Payload of `prices`: what a listing costs in each token it accepts.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ItemPricesEvent {
+    pub version: u32,
+    pub item_id: u32,
+    pub prices: Map<Address, i128>,
+}
+
+/// Payload of `ref_price`: a listing's price in the oracle's base asset, or `None` once
+/// cleared.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ReferencePriceEvent {
+    pub version: u32,
+    pub item_id: u32,
+    pub reference_price: Option<i128>,
+}
+
+/// Payload of `oracle`.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OracleEvent {
+    pub version: u32,
+    pub config: OracleConfig,
+}
+
+/// Payload of `cat_add`, `cat_ren` and `cat_rm`: the category as stored after the change,
+/// or as it was before its removal.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CategoryEvent {
+    pub version: u32,
+    pub category: Category,
+}
+
+/// Payload of `seller`, `verified` and `revoked`: the seller's profile after the change.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SellerEvent {
+    pub version: u32,
+    pub profile: SellerProfile,
+}
+
+/// Payload of `ver_pol`.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VerificationPolicyEvent {
+    pub version: u32,
+    pub policy: VerificationPolicy,
+}
+
+/// Payload of `tok_add` and `tok_rm`: the token added or removed and the resulting allowlist.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AcceptedTokenEvent {
+    pub version: u32,
+    pub token: Address,
+    pub accepted_tokens: Vec<Address>,
+}
+
+/// Payload of `ret_win`: the return window a listing offers on new orders.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ReturnWindowEvent {
+    pub version: u32,
+    pub item_id: u32,
+    pub return_window: u64,
+}
+
+/// Payload of `ful_set`.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FulfillmentEvent {
+    pub version: u32,
+    pub config: FulfillmentConfig,
+}
+
+/// Payload of `fee_set`.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FeeEvent {
+    pub version: u32,
+    pub fee_config: FeeConfig,
+}
+
+/// Payload of `fee_wdraw`.
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FeeWithdrawalEvent {
+    pub version: u32,
+    pub token: Address,
+    pub treasury: Address,
+    pub amount: i128,
+    pub collected_fees: i128, // Accrued fees left in the token after the withdrawal
+}
+
+pub(crate) fn initialized(env: &Env, admin: &Address, payment_token: &Address) {
+    let event = MarketInitEvent {
+        version: EVENT_VERSION,
+        payment_token: payment_token.clone(),
+    };
+    publish(env, symbol_short!("init"), CONTRACT_WIDE, admin, event);
+}
+
+pub(crate) fn item_listed(env: &Env, seller: &Address, item: &Item) {
+    publish_item(env, symbol_short!("list"), seller, item);
+}
+
+pub(crate) fn item_bought(
+    env: &Env,
+    buyer: &Address,
+    item: &Item,
+    order: &Order,
+    collected_fees: i128,
+) {
+    let event = PurchaseEvent {
+        version: EVENT_VERSION,
+        item: item.clone(),
+        qty: order.qty,
+        payment_token: order.payment_token.clone(),
+        total: order.total,
+        fee: order.fee,
+        collected_fees,
+    };
+    publish(env, symbol_short!("buy"), item.id.into(), buyer, event);
+}
+
+pub(crate) fn item_prices_set(
+    env: &Env,
+    seller: &Address,
+    item_id: u32,
+    prices: &Map<Address, i128>,
+) {
+    let event = ItemPricesEvent {
+        version: EVENT_VERSION,
+        item_id,
+        prices: prices.clone(),
+    };
+    publish(env, symbol_short!("prices"), item_id.into(), seller, event);
+}
+
+pub(crate) fn reference_price_set(
+    env: &Env,
+    seller: &Address,
+    item_id: u32,
+    reference_price: Option<i128>,
+) {
+    let event = ReferencePriceEvent {
+        version: EVENT_VERSION,
+        item_id,
+        reference_price,
+    };
+    publish(
+        env,
+        symbol_short!("ref_price"),
+        item_id.into(),
+        seller,
+        event,
+    );
+}
+
+pub(crate) fn oracle_updated(env: &Env, admin: &Address, config: &OracleConfig) {
+    let event = OracleEvent {
+        version: EVENT_VERSION,
+        config: config.clone(),
+    };
+    publish(env, symbol_short!("oracle"), CONTRACT_WIDE, admin, event);
+}
+
+pub(crate) fn token_allowed(env: &Env, admin: &Address, token: &Address, tokens: &Vec<Address>) {
+    publish_token(env, symbol_short!("tok_add"), admin, token, tokens);
+}
+
+pub(crate) fn token_disallowed(env: &Env, admin: &Address, token: &Address, tokens: &Vec<Address>) {
+    publish_token(env, symbol_short!("tok_rm"), admin, token, tokens);
+}
+
+pub(crate) fn order_placed(env: &Env, buyer: &Address, order: &Order) {
+    publish_order(env, symbol_short!("ordered"), buyer, order);
+}
+
+pub(crate) fn order_shipped(env: &Env, seller: &Address, order: &Order) {
+    publish_order(env, symbol_short!("shipped"), seller, order);
+}
+
+/// Published with the buyer as actor on confirmation, or the contract itself once the
+/// delivery window has passed.
+pub(crate) fn order_delivered(env: &Env, actor: &Address, order: &Order) {
+    publish_order(env, symbol_short!("delivered"), actor, order);
+}
+
+/// Published when the seller is paid.
+pub(crate) fn order_released(
+    env: &Env,
+    actor: &Address,
+    order: &Order,
+    fee: i128,
+    collected_fees: i128,
+) {
+    let event = OrderSettledEvent {
+        version: EVENT_VERSION,
+        order: order.clone(),
+        seller_amount: order.total - fee,
+        fee,
+        collected_fees,
+    };
+    publish(
+        env,
+        symbol_short!("released"),
+        order.id.into(),
+        actor,
+        event,
+    );
+}
+
+pub(crate) fn order_reclaimed(env: &Env, buyer: &Address, order: &Order) {
+    publish_order(env, symbol_short!("reclaimed"), buyer, order);
+}
+
+pub(crate) fn return_window_set(env: &Env, seller: &Address, item_id: u32, return_window: u64) {
+    let event = ReturnWindowEvent {
+        version: EVENT_VERSION,
+        item_id,
+        return_window,
+    };
+    publish(env, symbol_short!("ret_win"), item_id.into(), seller, event);
+}
+
+pub(crate) fn return_requested(env: &Env, buyer: &Address, order: &Order) {
+    publish_order(env, symbol_short!("ret_req"), buyer, order);
+}
+
+pub(crate) fn return_accepted(env: &Env, seller: &Address, order: &Order) {
+    publish_order(env, symbol_short!("ret_acc"), seller, order);
+}
+
+pub(crate) fn return_rejected(env: &Env, seller: &Address, order: &Order) {
+    publish_order(env, symbol_short!("ret_rej"), seller, order);
+}
+
+/// Published when the buyer escalates a return the seller left past its deadline.
+pub(crate) fn return_escalated(env: &Env, buyer: &Address, order: &Order) {
+    publish_order(env, symbol_short!("ret_esc"), buyer, order);
+}
+
+/// Published when the seller has the item back and the buyer is refunded.
+pub(crate) fn order_returned(env: &Env, seller: &Address, order: &Order) {
+    publish_order(env, symbol_short!("returned"), seller, order);
+}
+
+/// Published when an arbiter settles a rejected return, refunding the buyer or not.
+pub(crate) fn return_dispute_resolved(env: &Env, arbiter: &Address, order: &Order) {
+    publish_order(env, symbol_short!("resolved"), arbiter, order);
+}
+
+pub(crate) fn dutch_auction_set(env: &Env, seller: &Address, auction: &DutchAuction) {
+    let event = DutchAuctionEvent {
+        version: EVENT_VERSION,
+        auction: auction.clone(),
+    };
+    publish(
+        env,
+        symbol_short!("dutch"),
+        auction.item_id.into(),
+        seller,
+        event,
+    );
+}
+
+pub(crate) fn offer_made(env: &Env, buyer: &Address, offer: &Offer) {
+    publish_offer(env, symbol_short!("offer"), buyer, offer);
+}
+
+pub(crate) fn offer_countered(env: &Env, seller: &Address, offer: &Offer) {
+    publish_offer(env, symbol_short!("offer_ctr"), seller, offer);
+}
+
+/// Published with the seller as actor for an offer, or the buyer for a counter-offer
+pub(crate) fn offer_accepted(env: &Env, actor: &Address, offer: &Offer) {
+    publish_offer(env, symbol_short!("offer_acc"), actor, offer);
+}
+
+pub(crate) fn offer_rejected(env: &Env, seller: &Address, offer: &Offer) {
+    publish_offer(env, symbol_short!("offer_rej"), seller, offer);
+}
+
+pub(crate) fn offer_withdrawn(env: &Env, buyer: &Address, offer: &Offer) {
+    publish_offer(env, symbol_short!("offer_wd"), buyer, offer);
+}
+
+pub(crate) fn auction_created(env: &Env, seller: &Address, auction: &Auction) {
+    publish_auction(env, symbol_short!("auction"), seller, auction);
+}
+
+pub(crate) fn bid_placed(
+    env: &Env,
+    bidder: &Address,
+    auction: &Auction,
+    outbid: Option<Address>,
+    refund: i128,
+) {
+    let event = BidEvent {
+        version: EVENT_VERSION,
+        auction: auction.clone(),
+        outbid,
+        refund,
+    };
+    publish(env, symbol_short!("bid"), auction.id.into(), bidder, event);
+}
+
+/// Published by the contract itself, since anyone can settle
+pub(crate) fn auction_sold(env: &Env, actor: &Address, auction: &Auction, order_id: u64) {
+    let event = AuctionSoldEvent {
+        version: EVENT_VERSION,
+        auction: auction.clone(),
+        order_id,
+    };
+    publish(env, symbol_short!("sold"), auction.id.into(), actor, event);
+}
+
+/// Published by the contract itself when an auction ends under its reserve
+pub(crate) fn auction_unsold(env: &Env, actor: &Address, auction: &Auction) {
+    publish_auction(env, symbol_short!("unsold"), actor, auction);
+}
+
+pub(crate) fn item_categorized(env: &Env, seller: &Address, item: &Item) {
+    publish_item(env, symbol_short!("item_cat"), seller, item);
+}
+
+pub(crate) fn item_tagged(env: &Env, seller: &Address, item: &Item) {
+    publish_item(env, symbol_short!("item_tags"), seller, item);
+}
+
+pub(crate) fn category_added(env: &Env, admin: &Address, category: &Category) {
+    publish_category(env, symbol_short!("cat_add"), admin, category);
+}
+
+pub(crate) fn category_renamed(env: &Env, admin: &Address, category: &Category) {
+    publish_category(env, symbol_short!("cat_ren"), admin, category);
+}
+
+pub(crate) fn category_removed(env: &Env, admin: &Address, category: &Category) {
+    publish_category(env, symbol_short!("cat_rm"), admin, category);
+}
+
+pub(crate) fn seller_registered(env: &Env, profile: &SellerProfile) {
+    publish_seller(env, symbol_short!("seller"), &profile.seller, profile);
+}
+
+pub(crate) fn seller_verified(env: &Env, attester: &Address, profile: &SellerProfile) {
+    publish_seller(env, symbol_short!("verified"), attester, profile);
+}
+
+pub(crate) fn seller_revoked(env: &Env, attester: &Address, profile: &SellerProfile) {
+    publish_seller(env, symbol_short!("revoked"), attester, profile);
+}
+
+pub(crate) fn verification_policy_updated(env: &Env, admin: &Address, policy: &VerificationPolicy) {
+    let event = VerificationPolicyEvent {
+        version: EVENT_VERSION,
+        policy: policy.clone(),
+    };
+    publish(env, symbol_short!("ver_pol"), CONTRACT_WIDE, admin, event);
+}
+
+pub(crate) fn item_restocked(env: &Env, actor: &Address, item: &Item) {
+    publish_item(env, symbol_short!("restock"), actor, item);
+}
+
+pub(crate) fn fulfillment_updated(env: &Env, admin: &Address, config: &FulfillmentConfig) {
+    let event = FulfillmentEvent {
+        version: EVENT_VERSION,
+        config: config.clone(),
+    };
+    publish(env, symbol_short!("ful_set"), CONTRACT_WIDE, admin, event);
+}
+
+pub(crate) fn fee_updated(env: &Env, fee_manager: &Address, fee_config: &FeeConfig) {
+    let event = FeeEvent {
+        version: EVENT_VERSION,
+        fee_config: fee_config.clone(),
+    };
+    publish(
+        env,
+        symbol_short!("fee_set"),
+        CONTRACT_WIDE,
+        fee_manager,
+        event,
+    );
+}
+
+pub(crate) fn fees_withdrawn(
+    env: &Env,
+    fee_manager: &Address,
+    token: &Address,
+    treasury: &Address,
+    amount: i128,
+    collected_fees: i128,
+) {
+    let event = FeeWithdrawalEvent {
+        version: EVENT_VERSION,
+        token: token.clone(),
+        treasury: treasury.clone(),
+        amount,
+        collected_fees,
+    };
+    publish(
+        env,
+        symbol_short!("fee_wdraw"),
+        CONTRACT_WIDE,
+        fee_manager,
+        event,
+    );
+}
+
+pub(crate) fn contract_upgraded(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>) {
+    let event = UpgradeEvent {
+        version: EVENT_VERSION,
+        wasm_hash: new_wasm_hash.clone(),
+    };
+    publish(env, symbol_short!("upgraded"), CONTRACT_WIDE, admin, event);
+}
+
+/// Published for every item rewritten into the current layout.
+pub(crate) fn item_migrated(env: &Env, admin: &Address, item: &Item) {
+    publish_item(env, symbol_short!("mig_rec"), admin, item);
+}
+
+pub(crate) fn items_migrated(env: &Env, admin: &Address, migrated: u32, schema_version: u32) {
+    let event = MigrationEvent {
+        version: EVENT_VERSION,
+        migrated,
+        schema_version,
+    };
+    publish(env, symbol_short!("migrated"), CONTRACT_WIDE, admin, event);
+}
+
+fn publish_item(env: &Env, action: Symbol, actor: &Address, item: &Item) {
+    let event = ItemEvent {
+        version: EVENT_VERSION,
+        item: item.clone(),
+    };
+    publish(env, action, item.id.into(), actor, event);
+}
+
+// Profiles are keyed by address, so the seller is in the payload rather than the id topic
+fn publish_seller(env: &Env, action: Symbol, actor: &Address, profile: &SellerProfile) {
+    let event = SellerEvent {
+        version: EVENT_VERSION,
+        profile: profile.clone(),
+    };
+    publish(env, action, CONTRACT_WIDE, actor, event);
+}
+
+fn publish_category(env: &Env, action: Symbol, admin: &Address, category: &Category) {
+    let event = CategoryEvent {
+        version: EVENT_VERSION,
+        category: category.clone(),
+    };
+    publish(env, action, category.id.into(), admin, event);
+}
+
+fn publish_auction(env: &Env, action: Symbol, actor: &Address, auction: &Auction) {
+    let event = AuctionEvent {
+        version: EVENT_VERSION,
+        auction: auction.clone(),
+    };
+    publish(env, action, auction.id.into(), actor, event);
+}
+
+fn publish_token(
+    env: &Env,
+    action: Symbol,
+    admin: &Address,
+    token: &Address,
+    tokens: &Vec<Address>,
+) {
+    let event = AcceptedTokenEvent {
+        version: EVENT_VERSION,
+        token: token.clone(),
+        accepted_tokens: tokens.clone(),
+    };
+    publish(env, action, CONTRACT_WIDE, admin, event);
+}
+
+fn publish_offer(env: &Env, action: Symbol, actor: &Address, offer: &Offer) {
+    let event = OfferEvent {
+        version: EVENT_VERSION,
+        offer: offer.clone(),
+    };
+    publish(env, action, offer.id.into(), actor, event);
+}
+
+fn publish_order(env: &Env, action: Symbol, actor: &Address, order: &Order) {
+    let event = OrderEvent {
+        version: EVENT_VERSION,
+        order: order.clone(),
+    };
+    publish(env, action, order.id.into(), actor, event);
+}
+
+/// Publishes under the `(contract, action, id, actor)` topics shared by every PayStar contract
+fn publish<D: IntoVal<Env, Val>>(env: &Env, action: Symbol, id: u128, actor: &Address, data: D) {
+    events::publish(env, MarketplaceAccess::CONTRACT, action, id, actor, data);
+}
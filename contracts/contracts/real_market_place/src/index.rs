@@ -0,0 +1,98 @@
+//! Lists of record ids, such as a buyer's orders, the offers on an item or the
+//! listings in a category.
+//!
+//! A list keeps one persistent entry per position next to a count, so adding
+//! to it touches two small entries however long it grows, and it is read a
+//! page of at most `MAX_PAGE_SIZE` ids at a time. Ids are appended in order;
+//! removing one moves the last id into its place.
+
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val, Vec};
+
+use crate::order::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+
+/// Most records a single page of a list returns
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Number of ids in the list stored under `list`
+pub(crate) fn len<K>(env: &Env, list: &K) -> u32
+where
+    K: Clone,
+    Val: TryFromVal<Env, K>,
+{
+    let storage = env.storage().persistent();
+    match storage.get::<_, u32>(list) {
+        Some(len) => {
+            storage.extend_ttl(list, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+            len
+        }
+        None => 0,
+    }
+}
+
+/// Adds an id to the end of a list, stored under its position. Returns the position.
+pub(crate) fn push<K, V>(env: &Env, list: &K, id: &V) -> u32
+where
+    K: Clone,
+    Val: TryFromVal<Env, K>,
+    V: IntoVal<Env, Val>,
+{
+    let position = len(env, list);
+    let entry = (list.clone(), position);
+    let storage = env.storage().persistent();
+    storage.set(&entry, id);
+    storage.extend_ttl(&entry, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    storage.set(list, &(position + 1));
+    storage.extend_ttl(list, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    position
+}
+
+/// Removes the id at `position` by moving the last id of the list into it.
+/// Returns the id that moved, if any, so the caller can record its new position.
+pub(crate) fn swap_remove<K, V>(env: &Env, list: &K, position: u32) -> Option<V>
+where
+    K: Clone,
+    Val: TryFromVal<Env, K>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let last = len(env, list).checked_sub(1)?;
+    let storage = env.storage().persistent();
+    let last_entry = (list.clone(), last);
+    let moved = if position < last {
+        let id: V = storage.get(&last_entry)?;
+        let entry = (list.clone(), position);
+        storage.set(&entry, &id);
+        storage.extend_ttl(&entry, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+        Some(id)
+    } else {
+        None
+    };
+    storage.remove(&last_entry);
+    if last == 0 {
+        storage.remove(list);
+    } else {
+        storage.set(list, &last);
+    }
+    moved
+}
+
+/// Up to `limit` ids of a list from the `start`-th one, capped at `MAX_PAGE_SIZE`
+pub(crate) fn page<K, V>(env: &Env, list: &K, start: u32, limit: u32) -> Vec<V>
+where
+    K: Clone,
+    Val: TryFromVal<Env, K>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(len(env, list));
+    let storage = env.storage().persistent();
+    let mut ids = Vec::new(env);
+    for position in start..end {
+        let entry = (list.clone(), position);
+        if let Some(id) = storage.get::<_, V>(&entry) {
+            storage.extend_ttl(&entry, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+            ids.push_back(id);
+        }
+    }
+    ids
+}
@@ -1,19 +1,68 @@
 #![no_std]
 
+use paystar_common::{amount, token, AccessControl, CommonError};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, IntoVal, Map, String,
+    Symbol, Val, Vec,
+};
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
+pub use auction::{Auction, AuctionStatus};
+pub use category::Category;
+pub use dutch::{DutchAuction, PriceCurve};
+pub use offer::{Offer, OfferStatus};
+pub use oracle::{Asset, OracleConfig, PriceData};
+pub use order::{FulfillmentConfig, Order, OrderStatus};
+pub use paystar_common::Role;
+use seller::Price;
+pub use seller::{SellerProfile, VerificationPolicy};
 
+pub mod auction;
+pub mod category;
+pub mod dutch;
+pub mod event;
+pub mod index;
+pub mod offer;
+pub mod oracle;
+pub mod order;
+pub mod returns;
+pub mod seller;
+pub mod tokens;
 
+#[cfg(test)]
+mod test;
 
 #[contracttype]
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Item {
     pub id: u32,
     pub name: String,
-    pub price: i128, // token amount
+    pub price: i128,    // Amount of `token`
+    pub token: Address, // Primary token, which `buy_item` pays in
     pub quantity: u32,
     pub seller: Address,
     pub image_url: String, // New field for image link
+    pub category: Option<u32>,
+    pub tags: Vec<String>,
+}
+
+/// `Item` as stored by schema versions 0 and 1, before categories and tags
+#[contracttype(export = false)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct LegacyItem {
+    pub id: u32,
+    pub name: String,
+    pub price: i128,
+    pub quantity: u32,
+    pub seller: Address,
+    pub image_url: String,
+}
+
+/// Platform fee taken on every purchase
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FeeConfig {
+    pub fee_bps: u32, // basis points, 1 bps = 0.01%
+    pub treasury: Address,
 }
 
 #[contract]
@@ -21,22 +70,877 @@ pub struct Marketplace;
 
 const ITEM_COUNTER: Symbol = symbol_short!("I_COUNTER");
 const ITEMS: Symbol = symbol_short!("ITEMS");
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const PAY_TOKEN: Symbol = symbol_short!("PAY_TOKEN");
+const FEE_CONFIG: Symbol = symbol_short!("FEE_CFG");
+const FEES: Symbol = symbol_short!("FEES");
+const SCHEMA_VERSION: Symbol = symbol_short!("SCHEMA_V");
+const PAUSED: Symbol = symbol_short!("PAUSED");
+const PENDING_ADMIN: Symbol = symbol_short!("PEND_ADM");
+const ROLE: Symbol = symbol_short!("ROLE");
+
+/// Platform fee can never exceed 10%
+pub const MAX_FEE_BPS: u32 = 1_000;
+/// Layout version of the records written by this build
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
 
 #[contractimpl]
 impl Marketplace {
-    /// Seller lists a new item
+    /// Sets the admin and the token purchases are paid in (one-time)
+    pub fn initialize(env: Env, admin: Address, payment_token: Address) {
+        if env.storage().instance().has(&ADMIN) {
+            panic!("Already initialized");
+        }
+        admin.require_auth();
+
+        MarketplaceAccess::initialize_admin(&env, &admin);
+        env.storage().instance().set(&PAY_TOKEN, &payment_token);
+        event::initialized(&env, &admin, &payment_token);
+        // Fresh deployments start on the current layout; existing listings wait for `migrate`
+        if !env.storage().instance().has(&ITEM_COUNTER) {
+            env.storage()
+                .instance()
+                .set(&SCHEMA_VERSION, &CURRENT_SCHEMA_VERSION);
+        }
+    }
+
+    /// Seller lists a new item, priced in the marketplace's payment token
     pub fn list_item(
         env: Env,
         seller: Address,
         name: String,
         price: i128,
         quantity: u32,
-        image_url: String // Image URL from AWS, Cloudinary, IPFS, etc.
+        image_url: String, // Image URL from AWS, Cloudinary, IPFS, etc.
+    ) -> u32 {
+        let payment_token = Self::payment_token(&env);
+        Self::list_item_with_token(env, seller, name, price, quantity, image_url, payment_token)
+    }
+
+    /// Seller lists a new item priced in an accepted token, which becomes its primary token
+    pub fn list_item_with_token(
+        env: Env,
+        seller: Address,
+        name: String,
+        price: i128,
+        quantity: u32,
+        image_url: String,
+        token: Address,
     ) -> u32 {
+        seller.require_auth();
+        Self::require_not_paused(&env);
+        Self::require_migrated(&env);
+        tokens::require_accepted(&env, &token);
         if price <= 0 || quantity == 0 {
             panic!("Invalid price or quantity");
         }
 
+        seller::require_eligible(&env, &seller, None, Price::Token(&token, price));
+        Self::add_item(
+            &env, &seller, name, price, &token, quantity, image_url, &seller,
+        )
+        .id
+    }
+
+    /// Retrieve all items
+    pub fn get_items(env: Env) -> Vec<Item> {
+        Self::extend_instance(&env);
+        let items: Map<u32, Item> = env
+            .storage()
+            .instance()
+            .get(&ITEMS)
+            .unwrap_or(Map::new(&env));
+        let mut list = Vec::new(&env);
+        for id in items.keys() {
+            list.push_back(items.get(id).unwrap());
+        }
+        list
+    }
+
+    /// Buy item (qty units) in the listing's primary token. The payment is held until the order is delivered and its
+    /// return window has closed, when the seller is paid the total price minus the
+    /// platform fee. Returns the id of the order.
+    pub fn buy_item(env: Env, buyer: Address, item_id: u32, qty: u32) -> u64 {
+        // Checked before reading the item for its token
+        Self::require_migrated(&env);
+        let token = Self::load_item(&env, item_id).token;
+        Self::buy_item_with_token(env, buyer, item_id, qty, token)
+    }
+
+    /// Buy item (qty units) paying in one of the accepted tokens the listing is priced in.
+    /// Returns the id of the order.
+    pub fn buy_item_with_token(
+        env: Env,
+        buyer: Address,
+        item_id: u32,
+        qty: u32,
+        payment_token: Address,
+    ) -> u64 {
+        Self::buy(&env, &buyer, item_id, qty, &payment_token, None)
+    }
+
+    /// Buy item (qty units) paying in an accepted token, spending at most `max_spend`.
+    /// Required for listings priced in the oracle's base asset, whose price in the token
+    /// can move before the purchase executes. Returns the id of the order.
+    pub fn buy_item_with_limit(
+        env: Env,
+        buyer: Address,
+        item_id: u32,
+        qty: u32,
+        payment_token: Address,
+        max_spend: i128,
+    ) -> u64 {
+        Self::buy(&env, &buyer, item_id, qty, &payment_token, Some(max_spend))
+    }
+
+    /// Admin sets the SEP-40 oracle reference prices are converted with, and how old and
+    /// how far from the previous quote its prices may be. The oracle must quote in the
+    /// configured base asset.
+    pub fn set_oracle(env: Env, admin: Address, config: OracleConfig) {
+        Self::require_admin(&env, &admin);
+        oracle::set_config(&env, &config);
+        event::oracle_updated(&env, &admin, &config);
+    }
+
+    /// Retrieve the oracle configuration, if one is set
+    pub fn get_oracle_config(env: Env) -> Option<OracleConfig> {
+        oracle::config(&env)
+    }
+
+    /// Seller prices a listing in the oracle's base asset, with 7 decimals, or clears that
+    /// price with `None`. Buyers pay the converted amount in any accepted token.
+    pub fn set_reference_price(env: Env, seller: Address, item_id: u32, price: Option<i128>) {
+        oracle::set_reference_price(&env, &seller, item_id, price);
+    }
+
+    /// Retrieve the reference price of a listing, if it has one
+    pub fn get_reference_price(env: Env, item_id: u32) -> Option<i128> {
+        oracle::reference_price(&env, item_id)
+    }
+
+    /// Seller prices a listing in an accepted token, alongside its primary token or, for
+    /// the primary token itself, instead of its earlier price
+    pub fn set_item_price(env: Env, seller: Address, item_id: u32, token: Address, price: i128) {
+        tokens::set_price(&env, &seller, item_id, &token, price);
+    }
+
+    /// Seller stops accepting a token for a listing. Removing the primary token's price
+    /// makes another of its prices primary; a listing keeps at least one.
+    pub fn remove_item_price(env: Env, seller: Address, item_id: u32, token: Address) {
+        tokens::remove_price(&env, &seller, item_id, &token);
+    }
+
+    /// Price of a listing in every token it accepts
+    pub fn get_item_prices(env: Env, item_id: u32) -> Map<Address, i128> {
+        tokens::prices(&env, &Self::load_item(&env, item_id))
+    }
+
+    /// Admin adds a Stellar Asset Contract token to the ones purchases can be paid in
+    pub fn allow_token(env: Env, admin: Address, token: Address) {
+        Self::require_admin(&env, &admin);
+        let accepted = tokens::allow(&env, &token);
+        event::token_allowed(&env, &admin, &token, &accepted);
+    }
+
+    /// Admin stops accepting a token for purchases
+    pub fn disallow_token(env: Env, admin: Address, token: Address) {
+        Self::require_admin(&env, &admin);
+        let accepted = tokens::disallow(&env, &token);
+        event::token_disallowed(&env, &admin, &token, &accepted);
+    }
+
+    /// Tokens purchases can be paid in
+    pub fn get_accepted_tokens(env: Env) -> Vec<Address> {
+        tokens::accepted(&env)
+    }
+
+    /// Seller creates or updates their public profile. Sales are paid out to `payout_address`.
+    /// Changing the display name or payout address of a verified seller drops the badge.
+    pub fn register_seller(
+        env: Env,
+        seller: Address,
+        display_name: String,
+        metadata_uri: String,
+        contact_hash: BytesN<32>,
+        payout_address: Address,
+    ) {
+        let profile = seller::register(
+            &env,
+            &seller,
+            display_name,
+            metadata_uri,
+            contact_hash,
+            payout_address,
+        );
+        event::seller_registered(&env, &profile);
+    }
+
+    /// Retrieve a seller's profile, if they registered one
+    pub fn get_seller_profile(env: Env, seller: Address) -> Option<SellerProfile> {
+        seller::load(&env, &seller)
+    }
+
+    /// Attester vouches for a registered seller
+    pub fn verify_seller(env: Env, attester: Address, seller: Address) {
+        Self::require_role(&env, Role::Attester, &attester);
+        let profile = seller::verify(&env, &attester, &seller);
+        event::seller_verified(&env, &attester, &profile);
+    }
+
+    /// Attester takes a seller's badge away
+    pub fn revoke_seller_verification(env: Env, attester: Address, seller: Address) {
+        Self::require_role(&env, Role::Attester, &attester);
+        let profile = seller::revoke(&env, &seller);
+        event::seller_revoked(&env, &attester, &profile);
+    }
+
+    /// Whether a seller holds the verified badge
+    pub fn is_verified_seller(env: Env, seller: Address) -> bool {
+        seller::is_verified(&env, &seller)
+    }
+
+    /// Admin reserves categories, subcategories included, and prices for verified sellers:
+    /// from `min_prices` up in each token listed there, and from `min_reference_price` up
+    /// in the oracle's base asset. Applies to existing listings from their next sale.
+    pub fn set_verification_policy(env: Env, admin: Address, policy: VerificationPolicy) {
+        Self::require_admin(&env, &admin);
+        seller::set_policy(&env, &policy);
+        event::verification_policy_updated(&env, &admin, &policy);
+    }
+
+    /// Retrieve the listings reserved for verified sellers
+    pub fn get_verification_policy(env: Env) -> VerificationPolicy {
+        seller::policy(&env)
+    }
+
+    /// Admin adds a category under `parent`, or at the top level. Returns its id.
+    pub fn add_category(env: Env, admin: Address, name: String, parent: Option<u32>) -> u32 {
+        Self::require_admin(&env, &admin);
+        let category = category::add(&env, name, parent);
+        event::category_added(&env, &admin, &category);
+        category.id
+    }
+
+    /// Admin renames a category
+    pub fn rename_category(env: Env, admin: Address, category_id: u32, name: String) {
+        Self::require_admin(&env, &admin);
+        let category = category::rename(&env, category_id, name);
+        event::category_renamed(&env, &admin, &category);
+    }
+
+    /// Admin removes a category that has no subcategories and no listings
+    pub fn remove_category(env: Env, admin: Address, category_id: u32) {
+        Self::require_admin(&env, &admin);
+        let category = category::remove(&env, category_id);
+        event::category_removed(&env, &admin, &category);
+    }
+
+    /// Retrieve a category
+    pub fn get_category(env: Env, category_id: u32) -> Option<Category> {
+        category::load(&env, category_id)
+    }
+
+    /// Retrieve every category; `parent` links them into a tree
+    pub fn get_categories(env: Env) -> Vec<Category> {
+        category::all(&env).values()
+    }
+
+    /// Retrieve the direct subcategories of `parent`, or the top-level categories for `None`
+    pub fn get_subcategories(env: Env, parent: Option<u32>) -> Vec<Category> {
+        category::children(&env, parent)
+    }
+
+    /// Seller moves a listing into a category, or out of every category with `None`
+    pub fn set_item_category(env: Env, seller: Address, item_id: u32, category_id: Option<u32>) {
+        category::set_item_category(&env, &seller, item_id, category_id);
+    }
+
+    /// Seller replaces the tags of a listing
+    pub fn set_item_tags(env: Env, seller: Address, item_id: u32, tags: Vec<String>) {
+        category::set_item_tags(&env, &seller, item_id, tags);
+    }
+
+    /// Up to `limit` listings in a category and its subcategories from the `start`-th one,
+    /// ordered by category and then by when they joined it. A page holds at most
+    /// `MAX_PAGE_SIZE` listings.
+    pub fn get_items_in_category(env: Env, category_id: u32, start: u32, limit: u32) -> Vec<Item> {
+        category::items(&env, category_id, start, limit)
+    }
+
+    /// Number of listings in a category and its subcategories
+    pub fn get_category_item_count(env: Env, category_id: u32) -> u32 {
+        category::item_count(&env, category_id)
+    }
+
+    /// Seller puts a listing on a Dutch auction: its price falls from `start_price` to
+    /// `floor_price` between `start_time` and `end_time`, continuously or in equal steps.
+    /// Buyers pay the price at the time of purchase. Replaces any earlier schedule.
+    pub fn set_dutch_auction(env: Env, seller: Address, auction: DutchAuction) {
+        dutch::set(&env, &seller, &auction);
+    }
+
+    /// Retrieve the price schedule of a listing on a Dutch auction
+    pub fn get_dutch_auction(env: Env, item_id: u32) -> Option<DutchAuction> {
+        dutch::load(&env, item_id)
+    }
+
+    /// Unit price of a listing in its primary token at `timestamp`, following its Dutch
+    /// auction if it has one
+    pub fn get_price_at(env: Env, item_id: u32, timestamp: u64) -> i128 {
+        let item = Self::load_item(&env, item_id);
+        tokens::unit_price(&env, &item, &item.token, timestamp)
+    }
+
+    /// Seller opens an English auction for a single lot, ending at `end_time`. Bids are
+    /// locked in the contract; a winning bid must meet the reserve price. Returns the id of
+    /// the auction.
+    pub fn create_auction(
+        env: Env,
+        seller: Address,
+        name: String,
+        image_url: String,
+        reserve_price: i128,
+        min_increment: i128,
+        end_time: u64,
+    ) -> u32 {
+        Self::require_not_paused(&env);
+        auction::create(
+            &env,
+            &seller,
+            name,
+            image_url,
+            reserve_price,
+            min_increment,
+            end_time,
+        )
+    }
+
+    /// Bidder outbids the highest bid by at least the auction's increment, locking the bid
+    /// and refunding the previous bidder. Bids near the end extend the auction.
+    pub fn place_bid(env: Env, bidder: Address, auction_id: u32, amount: i128) {
+        Self::require_not_paused(&env);
+        auction::bid(&env, &bidder, auction_id, amount);
+    }
+
+    /// Once the auction has ended, sells the lot to the highest bidder as an order that is
+    /// shipped, delivered and paid out like any purchase. Refunds a highest bid under the
+    /// reserve, or one the seller may no longer accept under the seller policy. Permissionless.
+    pub fn settle_auction(env: Env, auction_id: u32) {
+        auction::settle(&env, auction_id);
+    }
+
+    /// Retrieve an auction
+    pub fn get_auction(env: Env, auction_id: u32) -> Option<Auction> {
+        auction::load(&env, auction_id)
+    }
+
+    /// Up to `limit` auctions from the `start`-th one, oldest first. A page holds at most
+    /// `MAX_PAGE_SIZE` auctions.
+    pub fn get_auctions(env: Env, start: u32, limit: u32) -> Vec<Auction> {
+        auction::page(&env, start, limit)
+    }
+
+    /// Number of auctions ever created
+    pub fn get_auction_count(env: Env) -> u32 {
+        auction::count(&env)
+    }
+
+    /// Buyer offers `unit_price` per unit for `qty` units, below the current price. The
+    /// total is escrowed until the offer is accepted, rejected or withdrawn; nothing can be
+    /// accepted after `expires_at`. Returns the id of the offer.
+    pub fn make_offer(
+        env: Env,
+        buyer: Address,
+        item_id: u32,
+        qty: u32,
+        unit_price: i128,
+        expires_at: u64,
+    ) -> u64 {
+        Self::require_not_paused(&env);
+        offer::make(&env, &buyer, item_id, qty, unit_price, expires_at)
+    }
+
+    /// Seller accepts an open offer; the units are bought at the offered price.
+    /// Returns the id of the order.
+    pub fn accept_offer(env: Env, seller: Address, offer_id: u64) -> u64 {
+        Self::require_not_paused(&env);
+        offer::accept(&env, &seller, offer_id)
+    }
+
+    /// Seller counters an open offer with a higher price per unit
+    pub fn counter_offer(env: Env, seller: Address, offer_id: u64, counter_price: i128) {
+        offer::counter(&env, &seller, offer_id, counter_price);
+    }
+
+    /// Buyer accepts a counter-offer, paying the difference; the units are bought at the
+    /// countered price. Returns the id of the order.
+    pub fn accept_counter_offer(env: Env, buyer: Address, offer_id: u64) -> u64 {
+        Self::require_not_paused(&env);
+        offer::accept_counter(&env, &buyer, offer_id)
+    }
+
+    /// Seller rejects an offer or retracts a counter-offer; the buyer is refunded
+    pub fn reject_offer(env: Env, seller: Address, offer_id: u64) {
+        offer::reject(&env, &seller, offer_id);
+    }
+
+    /// Buyer withdraws an offer that was not accepted and is refunded
+    pub fn withdraw_offer(env: Env, buyer: Address, offer_id: u64) {
+        offer::withdraw(&env, &buyer, offer_id);
+    }
+
+    /// Retrieve an offer
+    pub fn get_offer(env: Env, offer_id: u64) -> Option<Offer> {
+        offer::load(&env, offer_id)
+    }
+
+    /// Up to `limit` offers made on an item from the `start`-th one, oldest first. A page
+    /// holds at most `MAX_PAGE_SIZE` offers.
+    pub fn get_item_offers(env: Env, item_id: u32, start: u32, limit: u32) -> Vec<Offer> {
+        offer::of_item(&env, item_id, start, limit)
+    }
+
+    /// Number of offers made on an item
+    pub fn get_item_offer_count(env: Env, item_id: u32) -> u32 {
+        offer::count(&env, item_id)
+    }
+
+    /// Retrieve an order
+    pub fn get_order(env: Env, order_id: u64) -> Option<Order> {
+        order::load(&env, order_id)
+    }
+
+    /// Up to `limit` orders placed by a buyer from the `start`-th one, oldest first. A page
+    /// holds at most `MAX_PAGE_SIZE` orders.
+    pub fn get_buyer_orders(env: Env, buyer: Address, start: u32, limit: u32) -> Vec<Order> {
+        order::of_buyer(&env, &buyer, start, limit)
+    }
+
+    /// Number of orders placed by a buyer
+    pub fn get_buyer_order_count(env: Env, buyer: Address) -> u32 {
+        order::buyer_count(&env, &buyer)
+    }
+
+    /// Up to `limit` orders received by a seller from the `start`-th one, oldest first. A
+    /// page holds at most `MAX_PAGE_SIZE` orders.
+    pub fn get_seller_orders(env: Env, seller: Address, start: u32, limit: u32) -> Vec<Order> {
+        order::of_seller(&env, &seller, start, limit)
+    }
+
+    /// Number of orders received by a seller
+    pub fn get_seller_order_count(env: Env, seller: Address) -> u32 {
+        order::seller_count(&env, &seller)
+    }
+
+    /// Seller marks a paid order as shipped before its shipping deadline, with a hash of
+    /// the tracking details. Starts the buyer's delivery window.
+    pub fn mark_shipped(env: Env, seller: Address, order_id: u64, tracking_hash: BytesN<32>) {
+        order::mark_shipped(&env, &seller, order_id, tracking_hash);
+    }
+
+    /// Buyer confirms a shipped order arrived. The seller is paid, or, if the listing accepts
+    /// returns, the buyer's return window starts.
+    pub fn confirm_delivery(env: Env, buyer: Address, order_id: u64) {
+        order::confirm_delivery(&env, &buyer, order_id);
+    }
+
+    /// Moves a held payment along once its deadline has passed: a shipped order counts as
+    /// delivered after its delivery window, and a delivered order pays the seller after its
+    /// return window. Permissionless.
+    pub fn release_order(env: Env, order_id: u64) {
+        order::release(&env, order_id);
+    }
+
+    /// Buyer reclaims the payment of an order that was not shipped by its deadline;
+    /// the units go back into stock
+    pub fn reclaim_order(env: Env, buyer: Address, order_id: u64) {
+        order::reclaim(&env, &buyer, order_id);
+    }
+
+    /// Seller sets how long buyers of a listing can request a return after delivery, in
+    /// seconds; zero disables returns. Applies to orders placed afterwards.
+    pub fn set_return_window(env: Env, seller: Address, item_id: u32, return_window: u64) {
+        returns::set_return_window(&env, &seller, item_id, return_window);
+    }
+
+    /// Retrieve the return window of a listing
+    pub fn get_return_window(env: Env, item_id: u32) -> u64 {
+        returns::return_window(&env, item_id)
+    }
+
+    /// Buyer requests a return of a delivered order within its return window
+    pub fn request_return(env: Env, buyer: Address, order_id: u64, reason: String) {
+        returns::request(&env, &buyer, order_id, reason);
+    }
+
+    /// Seller accepts a requested return; the buyer sends the item back
+    pub fn accept_return(env: Env, seller: Address, order_id: u64) {
+        returns::accept(&env, &seller, order_id);
+    }
+
+    /// Seller confirms the returned item arrived; the buyer is refunded and the units go
+    /// back into stock
+    pub fn confirm_return_received(env: Env, seller: Address, order_id: u64) {
+        returns::confirm_received(&env, &seller, order_id);
+    }
+
+    /// Seller rejects a requested return, escalating it to a dispute
+    pub fn reject_return(env: Env, seller: Address, order_id: u64) {
+        returns::reject(&env, &seller, order_id);
+    }
+
+    /// Buyer escalates a return to a dispute once the seller has missed the deadline to
+    /// answer it (`RESPONSE_WINDOW`) or to confirm the item came back (`RECEIPT_WINDOW`)
+    pub fn escalate_return(env: Env, buyer: Address, order_id: u64) {
+        returns::escalate(&env, &buyer, order_id);
+    }
+
+    /// Arbiter resolves a disputed return by refunding the buyer or paying the seller
+    pub fn resolve_return(env: Env, arbiter: Address, order_id: u64, refund_buyer: bool) {
+        returns::resolve(&env, &arbiter, order_id, refund_buyer);
+    }
+
+    /// Admin sets how long sellers have to ship and buyers to confirm delivery, in seconds.
+    /// Applies to orders placed or shipped afterwards.
+    pub fn set_fulfillment_windows(
+        env: Env,
+        admin: Address,
+        ship_window: u64,
+        delivery_window: u64,
+    ) {
+        Self::require_admin(&env, &admin);
+
+        let config = FulfillmentConfig {
+            ship_window,
+            delivery_window,
+        };
+        order::set_fulfillment_config(&env, &config);
+
+        event::fulfillment_updated(&env, &admin, &config);
+    }
+
+    /// Retrieve the shipping and delivery windows
+    pub fn get_fulfillment_config(env: Env) -> FulfillmentConfig {
+        order::fulfillment_config(&env)
+    }
+
+    /// Admin proposes a new admin; nothing changes until the new admin accepts
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) {
+        Self::check(MarketplaceAccess::propose_admin(&env, &admin, &new_admin));
+    }
+
+    /// The proposed admin accepts the handover
+    pub fn accept_admin(env: Env, new_admin: Address) {
+        Self::check(MarketplaceAccess::accept_admin(&env, &new_admin));
+    }
+
+    /// Admin grants the Arbiter, Pauser, FeeManager or Attester role
+    pub fn grant_role(env: Env, admin: Address, role: Role, account: Address) {
+        Self::check(MarketplaceAccess::set_role(
+            &env, &admin, role, &account, true,
+        ));
+    }
+
+    /// Admin revokes a previously granted role
+    pub fn revoke_role(env: Env, admin: Address, role: Role, account: Address) {
+        Self::check(MarketplaceAccess::set_role(
+            &env, &admin, role, &account, false,
+        ));
+    }
+
+    /// Retrieve the current admin
+    pub fn get_admin(env: Env) -> Address {
+        MarketplaceAccess::load_admin(&env).unwrap_or_else(|| panic!("Marketplace not initialized"))
+    }
+
+    /// Retrieve the admin awaiting acceptance, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        MarketplaceAccess::load_pending_admin(&env)
+    }
+
+    /// Whether an account holds a role; the admin holds every role
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        MarketplaceAccess::has_role(&env, role, &account)
+    }
+
+    /// Pauser pauses the marketplace during an incident: no new listings or purchases
+    pub fn pause(env: Env, pauser: Address) {
+        Self::check(MarketplaceAccess::set_paused(&env, &pauser, true));
+    }
+
+    /// Pauser lifts a pause
+    pub fn unpause(env: Env, pauser: Address) {
+        Self::check(MarketplaceAccess::set_paused(&env, &pauser, false));
+    }
+
+    /// Whether the marketplace is paused
+    pub fn is_paused(env: Env) -> bool {
+        MarketplaceAccess::load_paused(&env)
+    }
+
+    /// Fee manager sets the platform fee (in basis points) and the treasury that receives it
+    pub fn set_fee(env: Env, fee_manager: Address, fee_bps: u32, treasury: Address) {
+        Self::require_role(&env, Role::FeeManager, &fee_manager);
+        if fee_bps > MAX_FEE_BPS {
+            panic!("Invalid fee");
+        }
+
+        let fee_config = FeeConfig { fee_bps, treasury };
+        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+
+        event::fee_updated(&env, &fee_manager, &fee_config);
+    }
+
+    /// Fee manager withdraws accrued fees of a token to the treasury
+    pub fn withdraw_fees(env: Env, fee_manager: Address, token: Address, amount: i128) {
+        Self::require_role(&env, Role::FeeManager, &fee_manager);
+
+        let collected = Self::get_collected_fees(env.clone(), token.clone());
+        if amount <= 0 || amount > collected {
+            panic!("Invalid withdrawal amount");
+        }
+        let fee_config: FeeConfig = env
+            .storage()
+            .instance()
+            .get(&FEE_CONFIG)
+            .unwrap_or_else(|| panic!("Fee not configured"));
+
+        Self::store_fees(&env, &token, collected - amount);
+        Self::check(token::payout(&env, &token, &fee_config.treasury, amount));
+
+        event::fees_withdrawn(
+            &env,
+            &fee_manager,
+            &token,
+            &fee_config.treasury,
+            amount,
+            collected,
+        );
+    }
+
+    /// Admin replaces the contract WASM; stored items are kept
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
+        Self::require_admin(&env, &admin);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        event::contract_upgraded(&env, &admin, &new_wasm_hash);
+    }
+
+    /// Admin rewrites stored items into the current layout after an upgrade; items
+    /// cannot be read, listed or changed until then.
+    /// Version 0 wrote purchases to a separate persistent copy of the item map;
+    /// those entries are merged back into the instance map, keeping the lowest
+    /// quantity since purchases only ever decrease it. Items stored before version 2
+    /// get no category and no tags. Every rewritten item is published as `mig_rec`, so
+    /// indexers see its new layout. Returns the number of items merged back.
+    pub fn migrate(env: Env, admin: Address) -> u32 {
+        Self::require_admin(&env, &admin);
+        if Self::get_schema_version(env.clone()) >= CURRENT_SCHEMA_VERSION {
+            panic!("Already migrated");
+        }
+
+        let mut legacy_items: Map<u32, LegacyItem> = env
+            .storage()
+            .instance()
+            .get(&ITEMS)
+            .unwrap_or(Map::new(&env));
+        let persistent_items: Map<u32, LegacyItem> = env
+            .storage()
+            .persistent()
+            .get(&ITEMS)
+            .unwrap_or(Map::new(&env));
+
+        let mut merged = Vec::new(&env);
+        for (id, persistent_item) in persistent_items.iter() {
+            let keep_persistent = match legacy_items.get(id) {
+                Some(item) => persistent_item.quantity < item.quantity,
+                None => true,
+            };
+            if keep_persistent {
+                legacy_items.set(id, persistent_item);
+                merged.push_back(id);
+            }
+        }
+
+        let payment_token = Self::payment_token(&env);
+        let mut items: Map<u32, Item> = Map::new(&env);
+        for (id, legacy_item) in legacy_items.iter() {
+            let item = Item {
+                id: legacy_item.id,
+                name: legacy_item.name,
+                price: legacy_item.price,
+                token: payment_token.clone(),
+                quantity: legacy_item.quantity,
+                seller: legacy_item.seller,
+                image_url: legacy_item.image_url,
+                category: None,
+                tags: Vec::new(&env),
+            };
+            event::item_migrated(&env, &admin, &item);
+            items.set(id, item);
+        }
+        let migrated = merged.len();
+
+        env.storage().instance().set(&ITEMS, &items);
+        env.storage().persistent().remove(&ITEMS);
+        env.storage()
+            .instance()
+            .set(&SCHEMA_VERSION, &CURRENT_SCHEMA_VERSION);
+
+        event::items_migrated(&env, &admin, migrated, CURRENT_SCHEMA_VERSION);
+        migrated
+    }
+
+    /// Layout version of the stored records; deployments that predate versioning are on 0
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEMA_VERSION).unwrap_or(0)
+    }
+
+    /// Retrieve the platform fee configuration
+    pub fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        env.storage().instance().get(&FEE_CONFIG)
+    }
+
+    /// Fees accrued in a token and not yet withdrawn
+    pub fn get_collected_fees(env: Env, token: Address) -> i128 {
+        let key = (FEES, token);
+        match env.storage().persistent().get(&key) {
+            Some(collected) => {
+                Self::extend_persistent(&env, &key);
+                collected
+            }
+            None => 0,
+        }
+    }
+}
+
+/// Binds the shared admin, role and pause logic to the marketplace's storage keys
+struct MarketplaceAccess;
+
+impl AccessControl for MarketplaceAccess {
+    const CONTRACT: Symbol = symbol_short!("market");
+    const ROLES: &'static [Role] = &[
+        Role::Arbiter,
+        Role::Pauser,
+        Role::FeeManager,
+        Role::Attester,
+    ];
+
+    fn load_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&ADMIN)
+    }
+
+    fn store_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&ADMIN, admin);
+    }
+
+    fn load_pending_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&PENDING_ADMIN)
+    }
+
+    fn store_pending_admin(env: &Env, pending_admin: Option<&Address>) {
+        match pending_admin {
+            Some(pending_admin) => env.storage().instance().set(&PENDING_ADMIN, pending_admin),
+            None => env.storage().instance().remove(&PENDING_ADMIN),
+        }
+    }
+
+    fn has_granted_role(env: &Env, role: Role, account: &Address) -> bool {
+        let key = (ROLE, role, account.clone());
+        let granted = env.storage().persistent().has(&key);
+        if granted {
+            Marketplace::extend_persistent(env, &key);
+        }
+        granted
+    }
+
+    fn store_role(env: &Env, role: Role, account: &Address, granted: bool) {
+        let key = (ROLE, role, account.clone());
+        if granted {
+            env.storage().persistent().set(&key, &true);
+            Marketplace::extend_persistent(env, &key);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    fn load_paused(env: &Env) -> bool {
+        env.storage().instance().get(&PAUSED).unwrap_or(false)
+    }
+
+    fn store_paused(env: &Env, paused: bool) {
+        env.storage().instance().set(&PAUSED, &paused);
+    }
+}
+
+impl Marketplace {
+    /// Unwraps a shared-crate result, panicking with its message like the rest of the marketplace
+    fn check<T>(result: Result<T, CommonError>) -> T {
+        result.unwrap_or_else(|error| panic!("{}", error.message()))
+    }
+
+    fn require_admin(env: &Env, admin: &Address) {
+        Self::check(MarketplaceAccess::require_admin(env, admin));
+    }
+
+    fn require_role(env: &Env, role: Role, account: &Address) {
+        Self::check(MarketplaceAccess::require_role(env, role, account));
+    }
+
+    fn require_not_paused(env: &Env) {
+        Self::check(MarketplaceAccess::require_not_paused(env));
+    }
+
+    /// Items written before `migrate` has run would be mixed into the legacy map
+    fn require_migrated(env: &Env) {
+        if Self::get_schema_version(env.clone()) < CURRENT_SCHEMA_VERSION {
+            panic!("Migration pending");
+        }
+    }
+
+    fn buy(
+        env: &Env,
+        buyer: &Address,
+        item_id: u32,
+        qty: u32,
+        payment_token: &Address,
+        max_spend: Option<i128>,
+    ) -> u64 {
+        buyer.require_auth();
+        Self::require_not_paused(env);
+        // Unmigrated items do not decode as the current layout
+        Self::require_migrated(env);
+        tokens::require_accepted(env, payment_token);
+
+        let item = Self::load_item(env, item_id);
+        if max_spend.is_none() && oracle::reference_price(env, item_id).is_some() {
+            panic!("Maximum spend required");
+        }
+        let unit_price = tokens::unit_price(env, &item, payment_token, env.ledger().timestamp());
+        let total = Self::check(amount::mul(unit_price, qty as i128));
+        if max_spend.is_some_and(|max_spend| total > max_spend) {
+            panic!("Price above maximum spend");
+        }
+
+        // The buyer must cover the whole price before anything moves
+        Self::check(token::deposit(env, payment_token, buyer, total));
+        Self::purchase(env, buyer, item_id, qty, unit_price, payment_token)
+    }
+
+    /// Stores a new listing and announces it on behalf of `actor`
+    #[allow(clippy::too_many_arguments)]
+    fn add_item(
+        env: &Env,
+        seller: &Address,
+        name: String,
+        price: i128,
+        token: &Address,
+        quantity: u32,
+        image_url: String,
+        actor: &Address,
+    ) -> Item {
         let mut counter: u32 = env.storage().instance().get(&ITEM_COUNTER).unwrap_or(0);
         counter += 1;
 
@@ -44,48 +948,178 @@ impl Marketplace {
             id: counter,
             name,
             price,
+            token: token.clone(),
             quantity,
             seller: seller.clone(),
             image_url,
+            category: None,
+            tags: Vec::new(env),
         };
 
         // let mut items: Vec<Item> = env.storage().persistent().get(&ITEMS).unwrap_or(Vec::new(&env));
-        let mut items: Map<u32, Item> = env.storage().persistent().get(&ITEMS).unwrap_or(Map::new(&env));
-        items.set(counter, item);
+        let mut items: Map<u32, Item> = env
+            .storage()
+            .instance()
+            .get(&ITEMS)
+            .unwrap_or(Map::new(env));
+        items.set(counter, item.clone());
         env.storage().instance().set(&ITEMS, &items);
         env.storage().instance().set(&ITEM_COUNTER, &counter);
+        Self::extend_instance(env);
 
-         env.events()
-            .publish(("list",), &counter);
-        counter
+        event::item_listed(env, actor, &item);
+        item
     }
 
-    /// Retrieve all items
-    pub fn get_items(env: Env) -> Vec<Item> {
-        let items: Map<u32, Item> = env.storage().instance().get(&ITEMS).unwrap_or(Map::new(&env));
-        let mut list = Vec::new(&env);
-        for id in items.keys() {
-            list.push_back(items.get(id).unwrap());
-        }
-        list
+    fn save_item(env: &Env, item: &Item) {
+        let mut items: Map<u32, Item> = env.storage().instance().get(&ITEMS).unwrap();
+        items.set(item.id, item.clone());
+        env.storage().instance().set(&ITEMS, &items);
+    }
+
+    fn load_item(env: &Env, item_id: u32) -> Item {
+        Self::extend_instance(env);
+        let items: Map<u32, Item> = env
+            .storage()
+            .instance()
+            .get(&ITEMS)
+            .unwrap_or(Map::new(env));
+        items
+            .get(item_id)
+            .unwrap_or_else(|| panic!("Item not found"))
     }
 
-    /// Buy item (qty units)
-    pub fn buy_item(env: Env, buyer: Address, item_id: u32, qty: u32) {
-        let mut items: Map<u32, Item> = env.storage().instance().get(&ITEMS).unwrap_or(Map::new(&env));
-        let mut item = items.get(item_id).unwrap();
+    /// Takes units out of stock and records the order, for a payment the contract already
+    /// holds. The token was checked when the payment came in, so a bid or offer made before
+    /// the token was disallowed can still complete. Returns the id of the order.
+    fn purchase(
+        env: &Env,
+        buyer: &Address,
+        item_id: u32,
+        qty: u32,
+        unit_price: i128,
+        payment_token: &Address,
+    ) -> u64 {
+        Self::require_migrated(env);
+        let mut items: Map<u32, Item> = env
+            .storage()
+            .instance()
+            .get(&ITEMS)
+            .unwrap_or(Map::new(env));
+        let mut item = items
+            .get(item_id)
+            .unwrap_or_else(|| panic!("Item not found"));
 
         if qty == 0 || qty > item.quantity {
             panic!("Invalid quantity");
         }
+        // The tier covers the listed price and whatever this sale is charged at
+        let listed_price = Price::Token(&item.token, item.price);
+        seller::require_eligible(env, &item.seller, item.category, listed_price);
+        let charged_price = Price::Token(payment_token, unit_price);
+        seller::require_eligible(env, &item.seller, item.category, charged_price);
 
-        // Payment logic to be added later
+        let total = Self::check(amount::mul(unit_price, qty as i128));
+        let fee = Self::calculate_fee(env, total);
+        let collected = Self::get_collected_fees(env.clone(), payment_token.clone());
 
         item.quantity -= qty;
         items.set(item_id, item.clone());
-        env.storage().persistent().set(&ITEMS, &items);
+        env.storage().instance().set(&ITEMS, &items);
 
-         env.events()
-            .publish(("buy",), (&buyer, &item_id));
+        let now = env.ledger().timestamp();
+        let order = Order {
+            id: order::next_id(env),
+            item_id,
+            buyer: buyer.clone(),
+            seller: item.seller.clone(),
+            qty,
+            unit_price,
+            total,
+            fee,
+            payment_token: payment_token.clone(),
+            created_at: now,
+            status: OrderStatus::Paid,
+            ship_by: now + order::fulfillment_config(env).ship_window,
+            deliver_by: None,
+            tracking_hash: None,
+            return_window: returns::return_window(env, item_id),
+            return_by: None,
+            return_reason: None,
+            respond_by: None,
+            seller_paid: false,
+        };
+        order::create(env, &order);
+
+        event::item_bought(env, buyer, &item, &order, collected);
+        event::order_placed(env, buyer, &order);
+        order.id
+    }
+
+    fn payment_token(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&PAY_TOKEN)
+            .unwrap_or_else(|| panic!("Marketplace not initialized"))
+    }
+
+    /// Adds a fee to the accrued fees of a token, held until the fee manager withdraws them.
+    /// Returns the fees now accrued.
+    fn accrue_fee(env: &Env, token: &Address, fee: i128) -> i128 {
+        let collected = Self::get_collected_fees(env.clone(), token.clone());
+        if fee == 0 {
+            return collected;
+        }
+        let collected = Self::check(amount::add(collected, fee));
+        Self::store_fees(env, token, collected);
+        collected
+    }
+
+    fn store_fees(env: &Env, token: &Address, collected: i128) {
+        let key = (FEES, token.clone());
+        env.storage().persistent().set(&key, &collected);
+        Self::extend_persistent(env, &key);
+    }
+
+    /// Extends the instance, which holds the listings and settings, so it
+    /// lives as long as the records stored next to it
+    fn extend_instance(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(order::LIFETIME_THRESHOLD, order::BUMP_AMOUNT);
+    }
+
+    /// Extends a persistent entry along with the instance
+    fn extend_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, order::LIFETIME_THRESHOLD, order::BUMP_AMOUNT);
+        Self::extend_instance(env);
+    }
+
+    /// Puts units of an order back into stock
+    fn restock(env: &Env, item_id: u32, qty: u32, actor: &Address) {
+        Self::require_migrated(env);
+        let mut items: Map<u32, Item> = env
+            .storage()
+            .instance()
+            .get(&ITEMS)
+            .unwrap_or(Map::new(env));
+        let Some(mut item) = items.get(item_id) else {
+            return;
+        };
+        item.quantity += qty;
+        items.set(item_id, item.clone());
+        env.storage().instance().set(&ITEMS, &items);
+
+        event::item_restocked(env, actor, &item);
+    }
+
+    fn calculate_fee(env: &Env, amount: i128) -> i128 {
+        let fee_config: Option<FeeConfig> = env.storage().instance().get(&FEE_CONFIG);
+        match fee_config {
+            Some(fee_config) => Self::check(amount::bps_share(amount, fee_config.fee_bps)),
+            None => 0,
+        }
     }
 }
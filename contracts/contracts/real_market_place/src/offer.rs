@@ -0,0 +1,276 @@
+//! Offers below the list price of a fixed-price item.
+//!
+//! Offers are made in the marketplace's payment token, and the offered total
+//! is escrowed in the contract until the offer is settled:
+//!
+//! * `Open`: the seller accepts, which buys the units at the offered price,
+//!   rejects, or counters with a higher price (`Countered`)
+//! * `Countered`: the buyer accepts by topping up the difference, which buys
+//!   the units at the countered price
+//! * `Open` or `Countered`: the buyer can withdraw at any time, and the seller
+//!   can reject; both refund the escrow (`Withdrawn`, `Rejected`)
+//!
+//! Nothing can be accepted after `expires_at`, but the buyer can still
+//! withdraw. Accepting goes through the same purchase as `buy_item`, so the
+//! order is recorded and fulfilled like any other.
+
+use paystar_common::{amount, token};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::order::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+use crate::{event, index, tokens, Marketplace};
+
+/// Where an offer is in the negotiation
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OfferStatus {
+    Open,
+    Countered,
+    Accepted,
+    Rejected,
+    Withdrawn,
+}
+
+/// A buyer's offer for units of an item
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Offer {
+    pub id: u64,
+    pub item_id: u32,
+    pub buyer: Address,
+    pub seller: Address,
+    pub qty: u32,
+    pub unit_price: i128, // Offered price per unit
+    pub escrowed: i128,   // Held by the contract until the offer is settled
+    pub payment_token: Address,
+    pub counter_price: Option<i128>,
+    pub expires_at: u64,
+    pub status: OfferStatus,
+    pub order_id: Option<u64>, // Set once accepted
+}
+
+const OFFER_COUNTER: Symbol = symbol_short!("OF_COUNTR");
+const OFFER: Symbol = symbol_short!("OFFER");
+const ITEM_OFFERS: Symbol = symbol_short!("ITEM_OFRS");
+
+pub(crate) fn load(env: &Env, offer_id: u64) -> Option<Offer> {
+    env.storage().persistent().get(&(OFFER, offer_id))
+}
+
+fn load_existing(env: &Env, offer_id: u64) -> Offer {
+    load(env, offer_id).unwrap_or_else(|| panic!("Offer not found"))
+}
+
+fn save(env: &Env, offer: &Offer) {
+    let key = (OFFER, offer.id);
+    env.storage().persistent().set(&key, offer);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    Marketplace::extend_instance(env);
+}
+
+/// Up to `limit` offers made on an item from the `start`-th one, oldest first
+pub(crate) fn of_item(env: &Env, item_id: u32, start: u32, limit: u32) -> Vec<Offer> {
+    let ids: Vec<u64> = index::page(env, &(ITEM_OFFERS, item_id), start, limit);
+    let mut offers = Vec::new(env);
+    for id in ids.iter() {
+        if let Some(offer) = load(env, id) {
+            offers.push_back(offer);
+        }
+    }
+    offers
+}
+
+/// Number of offers made on an item
+pub(crate) fn count(env: &Env, item_id: u32) -> u32 {
+    index::len(env, &(ITEM_OFFERS, item_id))
+}
+
+/// Buyer offers `unit_price` per unit, below the current price, escrowing the total
+pub(crate) fn make(
+    env: &Env,
+    buyer: &Address,
+    item_id: u32,
+    qty: u32,
+    unit_price: i128,
+    expires_at: u64,
+) -> u64 {
+    buyer.require_auth();
+    let item = Marketplace::load_item(env, item_id);
+    if *buyer == item.seller {
+        panic!("Seller cannot make an offer");
+    }
+    if qty == 0 || qty > item.quantity {
+        panic!("Invalid quantity");
+    }
+    let now = env.ledger().timestamp();
+    let payment_token = item.token.clone();
+    tokens::require_accepted(env, &payment_token);
+    let list_price = tokens::unit_price(env, &item, &payment_token, now);
+    if unit_price <= 0 || unit_price >= list_price {
+        panic!("Offer must be below the list price");
+    }
+    if expires_at <= now {
+        panic!("Invalid expiry");
+    }
+
+    let escrowed = Marketplace::check(amount::mul(unit_price, qty as i128));
+    Marketplace::check(token::deposit(env, &payment_token, buyer, escrowed));
+
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&OFFER_COUNTER)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&OFFER_COUNTER, &id);
+
+    let offer = Offer {
+        id,
+        item_id,
+        buyer: buyer.clone(),
+        seller: item.seller,
+        qty,
+        unit_price,
+        escrowed,
+        payment_token,
+        counter_price: None,
+        expires_at,
+        status: OfferStatus::Open,
+        order_id: None,
+    };
+    save(env, &offer);
+
+    index::push(env, &(ITEM_OFFERS, item_id), &id);
+
+    event::offer_made(env, buyer, &offer);
+    id
+}
+
+/// Seller accepts an open offer, buying the units for the buyer at the offered price
+pub(crate) fn accept(env: &Env, seller: &Address, offer_id: u64) -> u64 {
+    let mut offer = addressed_to_seller(env, seller, offer_id);
+    if offer.status != OfferStatus::Open {
+        panic!("Offer not open");
+    }
+    require_unexpired(env, &offer);
+
+    let order_id = Marketplace::purchase(
+        env,
+        &offer.buyer,
+        offer.item_id,
+        offer.qty,
+        offer.unit_price,
+        &offer.payment_token,
+    );
+    offer.status = OfferStatus::Accepted;
+    offer.order_id = Some(order_id);
+    save(env, &offer);
+
+    event::offer_accepted(env, seller, &offer);
+    order_id
+}
+
+/// Seller answers an open offer with a higher price per unit
+pub(crate) fn counter(env: &Env, seller: &Address, offer_id: u64, counter_price: i128) {
+    let mut offer = addressed_to_seller(env, seller, offer_id);
+    if offer.status != OfferStatus::Open {
+        panic!("Offer not open");
+    }
+    require_unexpired(env, &offer);
+    if counter_price <= offer.unit_price {
+        panic!("Counter must be above the offer");
+    }
+
+    offer.status = OfferStatus::Countered;
+    offer.counter_price = Some(counter_price);
+    save(env, &offer);
+
+    event::offer_countered(env, seller, &offer);
+}
+
+/// Buyer accepts a counter-offer, paying the difference, and buys the units at that price
+pub(crate) fn accept_counter(env: &Env, buyer: &Address, offer_id: u64) -> u64 {
+    let mut offer = addressed_to_buyer(env, buyer, offer_id);
+    if offer.status != OfferStatus::Countered {
+        panic!("Offer not countered");
+    }
+    require_unexpired(env, &offer);
+
+    let unit_price = offer.counter_price.unwrap();
+    let total = Marketplace::check(amount::mul(unit_price, offer.qty as i128));
+    let top_up = Marketplace::check(amount::sub(total, offer.escrowed));
+    Marketplace::check(token::deposit(env, &offer.payment_token, buyer, top_up));
+
+    let order_id = Marketplace::purchase(
+        env,
+        buyer,
+        offer.item_id,
+        offer.qty,
+        unit_price,
+        &offer.payment_token,
+    );
+    offer.status = OfferStatus::Accepted;
+    offer.escrowed = total;
+    offer.order_id = Some(order_id);
+    save(env, &offer);
+
+    event::offer_accepted(env, buyer, &offer);
+    order_id
+}
+
+/// Seller turns down an offer, refunding the buyer
+pub(crate) fn reject(env: &Env, seller: &Address, offer_id: u64) {
+    let mut offer = addressed_to_seller(env, seller, offer_id);
+    close(env, &mut offer, OfferStatus::Rejected);
+
+    event::offer_rejected(env, seller, &offer);
+}
+
+/// Buyer takes back an offer that was not accepted, expired or not
+pub(crate) fn withdraw(env: &Env, buyer: &Address, offer_id: u64) {
+    let mut offer = addressed_to_buyer(env, buyer, offer_id);
+    close(env, &mut offer, OfferStatus::Withdrawn);
+
+    event::offer_withdrawn(env, buyer, &offer);
+}
+
+// Ends a pending offer and returns the escrow to the buyer
+fn close(env: &Env, offer: &mut Offer, status: OfferStatus) {
+    if !matches!(offer.status, OfferStatus::Open | OfferStatus::Countered) {
+        panic!("Offer already settled");
+    }
+    offer.status = status;
+    save(env, offer);
+    Marketplace::check(token::payout(
+        env,
+        &offer.payment_token,
+        &offer.buyer,
+        offer.escrowed,
+    ));
+}
+
+fn addressed_to_seller(env: &Env, seller: &Address, offer_id: u64) -> Offer {
+    seller.require_auth();
+    let offer = load_existing(env, offer_id);
+    if offer.seller != *seller {
+        panic!("Not the seller");
+    }
+    offer
+}
+
+fn addressed_to_buyer(env: &Env, buyer: &Address, offer_id: u64) -> Offer {
+    buyer.require_auth();
+    let offer = load_existing(env, offer_id);
+    if offer.buyer != *buyer {
+        panic!("Not the buyer");
+    }
+    offer
+}
+
+fn require_unexpired(env: &Env, offer: &Offer) {
+    if env.ledger().timestamp() >= offer.expires_at {
+        panic!("Offer expired");
+    }
+}
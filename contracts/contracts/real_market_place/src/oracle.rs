@@ -0,0 +1,151 @@
+//! Listings priced in a reference currency and settled in tokens at an oracle
+//! price.
+//!
+//! The oracle follows the SEP-40 price feed interface: it quotes assets in
+//! its base asset, e.g. USD, with a fixed number of decimals. The admin names
+//! that asset when setting the oracle, so an oracle quoting in another
+//! currency is refused. A listing with a reference price costs that amount of
+//! the base asset, converted at purchase time into whichever accepted token
+//! the buyer pays with. The reference price overrides the listing's token
+//! prices and any Dutch auction.
+//!
+//! Quotes are rejected when they are older than `max_age` or moved more than
+//! `max_deviation_bps` since the previous record, and buyers cap what they
+//! spend, since the converted price can move between signing and execution.
+
+use paystar_common::amount;
+use soroban_sdk::{
+    contractclient, contracttype, symbol_short, token::TokenClient, Address, Env, Symbol, Vec,
+};
+
+use crate::order::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+use crate::seller::{self, Price};
+use crate::{event, Marketplace};
+
+/// Asset quoted by a SEP-40 oracle
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// A SEP-40 price record: the asset's price in the oracle's base asset
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// The part of the SEP-40 price feed interface the marketplace reads
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn base(env: Env) -> Asset;
+    fn decimals(env: Env) -> u32;
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+    /// The latest `records` prices, most recent first
+    fn prices(env: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>>;
+}
+
+/// Oracle used to convert reference prices, and the bounds its quotes must meet
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OracleConfig {
+    pub oracle: Address,
+    pub base: Asset,  // Asset reference prices are in, which the oracle must quote in
+    pub max_age: u64, // Oldest usable quote, in seconds
+    pub max_deviation_bps: u32, // Largest move from the previous quote, 1 bps = 0.01%
+}
+
+/// Reference prices carry 7 decimals, like Stellar asset amounts
+pub const REFERENCE_DECIMALS: u32 = 7;
+
+const ORACLE: Symbol = symbol_short!("ORACLE");
+const REFERENCE_PRICE: Symbol = symbol_short!("REF_PRICE");
+
+pub(crate) fn config(env: &Env) -> Option<OracleConfig> {
+    env.storage().instance().get(&ORACLE)
+}
+
+pub(crate) fn set_config(env: &Env, config: &OracleConfig) {
+    if config.max_age == 0 || config.max_deviation_bps == 0 || config.max_deviation_bps > 10_000 {
+        panic!("Invalid oracle bounds");
+    }
+    // Existing reference prices would otherwise be read in another currency
+    if PriceOracleClient::new(env, &config.oracle).base() != config.base {
+        panic!("Oracle base asset mismatch");
+    }
+    env.storage().instance().set(&ORACLE, config);
+}
+
+/// Reference price of a listing, if it has one
+pub(crate) fn reference_price(env: &Env, item_id: u32) -> Option<i128> {
+    env.storage().persistent().get(&(REFERENCE_PRICE, item_id))
+}
+
+/// Seller prices one of their listings in the oracle's base asset, or clears the price
+pub(crate) fn set_reference_price(env: &Env, seller: &Address, item_id: u32, price: Option<i128>) {
+    seller.require_auth();
+    let item = Marketplace::load_item(env, item_id);
+    if item.seller != *seller {
+        panic!("Not the seller");
+    }
+
+    let key = (REFERENCE_PRICE, item_id);
+    match price {
+        Some(price) if price <= 0 => panic!("Invalid price or quantity"),
+        Some(price) => {
+            if config(env).is_none() {
+                panic!("Oracle not configured");
+            }
+            seller::require_eligible(env, seller, item.category, Price::Reference(price));
+            env.storage().persistent().set(&key, &price);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    event::reference_price_set(env, seller, item_id, price);
+}
+
+/// Converts a reference price into an amount of `token` at the current oracle price,
+/// rounding up so the seller is never paid less than the reference price
+pub(crate) fn convert(env: &Env, reference_price: i128, token: &Address) -> i128 {
+    let config = config(env).unwrap_or_else(|| panic!("Oracle not configured"));
+    let oracle = PriceOracleClient::new(env, &config.oracle);
+    let asset = Asset::Stellar(token.clone());
+
+    let quote = oracle
+        .lastprice(&asset)
+        .unwrap_or_else(|| panic!("No oracle price for token"));
+    if quote.price <= 0 {
+        panic!("Invalid oracle price");
+    }
+    if env.ledger().timestamp().saturating_sub(quote.timestamp) > config.max_age {
+        panic!("Oracle price stale");
+    }
+    if let Some(previous) = oracle.prices(&asset, &2).and_then(|records| records.get(1)) {
+        let moved = (quote.price - previous.price).abs();
+        let bound = Marketplace::check(amount::bps_share(previous.price, config.max_deviation_bps));
+        if moved > bound {
+            panic!("Oracle price deviation too high");
+        }
+    }
+
+    // amount = reference / 10^REFERENCE_DECIMALS / (price / 10^oracle_decimals) * 10^token_decimals
+    let oracle_scale = scale(oracle.decimals());
+    let token_scale = scale(TokenClient::new(env, token).decimals());
+    let numerator = Marketplace::check(amount::mul(reference_price, oracle_scale));
+    let numerator = Marketplace::check(amount::mul(numerator, token_scale));
+    let denominator = Marketplace::check(amount::mul(quote.price, scale(REFERENCE_DECIMALS)));
+    Marketplace::check(amount::add(numerator, denominator - 1)) / denominator
+}
+
+fn scale(decimals: u32) -> i128 {
+    10i128
+        .checked_pow(decimals)
+        .unwrap_or_else(|| panic!("Amount overflow"))
+}
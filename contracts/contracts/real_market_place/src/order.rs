@@ -0,0 +1,306 @@
+//! Orders recorded for every purchase, with buyer and seller indexes so
+//! receipts and order history can be read from the chain a page at a time
+//! (see [`crate::index`]).
+//!
+//! Payment is held by the marketplace until the order is fulfilled, following
+//! the escrow contract's confirm and release rules:
+//!
+//! * `Paid`: the seller has until `ship_by` to ship, otherwise the buyer can
+//!   reclaim the payment and the units go back into stock (`Refunded`)
+//! * `Shipped`: the buyer confirms delivery, or once `deliver_by` has passed
+//!   anyone can release the order (`Delivered`)
+//! * `Delivered`: the seller is paid right away, or, when the listing accepts
+//!   returns, once `return_by` has passed without a return request; see
+//!   [`crate::returns`]
+
+use paystar_common::{amount, token};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::{event, index, seller, Marketplace};
+
+/// Where an order is in fulfillment
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OrderStatus {
+    Paid,
+    Shipped,
+    Delivered,
+    Refunded,
+    ReturnRequested,
+    ReturnAccepted, // The buyer is sending the item back
+    Disputed,       // The seller rejected the return or missed a deadline; an arbiter decides
+}
+
+/// A purchase of one listing, priced as it was when the buyer paid
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Order {
+    pub id: u64,
+    pub item_id: u32,
+    pub buyer: Address,
+    pub seller: Address,
+    pub qty: u32,
+    pub unit_price: i128, // Item price at the time of purchase
+    pub total: i128,
+    pub fee: i128,
+    pub payment_token: Address,
+    pub created_at: u64, // Ledger timestamp of the purchase
+    pub status: OrderStatus,
+    pub ship_by: u64,                      // Seller must ship before this timestamp
+    pub deliver_by: Option<u64>,           // Set on shipping; payment can be released after it
+    pub tracking_hash: Option<BytesN<32>>, // Hash of the carrier tracking details
+    pub return_window: u64,                // Listing's return window when the order was placed
+    pub return_by: Option<u64>,            // Set on delivery when the listing accepts returns
+    pub return_reason: Option<String>,
+    pub respond_by: Option<u64>, // Seller's deadline to answer a return, then to confirm it came back
+    pub seller_paid: bool,
+}
+
+/// How long sellers have to ship an order and buyers to confirm its delivery, in seconds
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FulfillmentConfig {
+    pub ship_window: u64,
+    pub delivery_window: u64,
+}
+
+const DAY: u64 = 24 * 60 * 60;
+/// Shipping window used until the admin configures one
+pub const DEFAULT_SHIP_WINDOW: u64 = 7 * DAY;
+/// Delivery window used until the admin configures one
+pub const DEFAULT_DELIVERY_WINDOW: u64 = 14 * DAY;
+
+const ORDER_COUNTER: Symbol = symbol_short!("O_COUNTER");
+const ORDER: Symbol = symbol_short!("ORDER");
+const BUYER_ORDERS: Symbol = symbol_short!("BUY_ORDS");
+const SELLER_ORDERS: Symbol = symbol_short!("SELL_ORDS");
+const FULFILLMENT: Symbol = symbol_short!("FULFIL");
+
+/// Ledgers closed per day, at roughly five seconds per ledger
+const DAY_IN_LEDGERS: u32 = 17_280;
+/// Every write keeps an order, or any other persistent marketplace record, alive for at
+/// least 30 more days
+pub(crate) const BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Reserves the id of the next order
+pub(crate) fn next_id(env: &Env) -> u64 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&ORDER_COUNTER)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&ORDER_COUNTER, &id);
+    id
+}
+
+pub(crate) fn load(env: &Env, order_id: u64) -> Option<Order> {
+    let key = (ORDER, order_id);
+    let order = env.storage().persistent().get(&key)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    Marketplace::extend_instance(env);
+    Some(order)
+}
+
+pub(crate) fn load_existing(env: &Env, order_id: u64) -> Order {
+    load(env, order_id).unwrap_or_else(|| panic!("Order not found"))
+}
+
+pub(crate) fn save(env: &Env, order: &Order) {
+    let key = (ORDER, order.id);
+    env.storage().persistent().set(&key, order);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    Marketplace::extend_instance(env);
+}
+
+/// Stores a new order and adds it to the buyer's and seller's history
+pub(crate) fn create(env: &Env, order: &Order) {
+    save(env, order);
+    index::push(env, &(BUYER_ORDERS, order.buyer.clone()), &order.id);
+    index::push(env, &(SELLER_ORDERS, order.seller.clone()), &order.id);
+}
+
+/// Up to `limit` orders placed by a buyer from the `start`-th one, oldest first
+pub(crate) fn of_buyer(env: &Env, buyer: &Address, start: u32, limit: u32) -> Vec<Order> {
+    resolve(
+        env,
+        index::page(env, &(BUYER_ORDERS, buyer.clone()), start, limit),
+    )
+}
+
+/// Up to `limit` orders received by a seller from the `start`-th one, oldest first
+pub(crate) fn of_seller(env: &Env, seller: &Address, start: u32, limit: u32) -> Vec<Order> {
+    resolve(
+        env,
+        index::page(env, &(SELLER_ORDERS, seller.clone()), start, limit),
+    )
+}
+
+/// Number of orders placed by a buyer
+pub(crate) fn buyer_count(env: &Env, buyer: &Address) -> u32 {
+    index::len(env, &(BUYER_ORDERS, buyer.clone()))
+}
+
+/// Number of orders received by a seller
+pub(crate) fn seller_count(env: &Env, seller: &Address) -> u32 {
+    index::len(env, &(SELLER_ORDERS, seller.clone()))
+}
+
+pub(crate) fn fulfillment_config(env: &Env) -> FulfillmentConfig {
+    env.storage()
+        .instance()
+        .get(&FULFILLMENT)
+        .unwrap_or(FulfillmentConfig {
+            ship_window: DEFAULT_SHIP_WINDOW,
+            delivery_window: DEFAULT_DELIVERY_WINDOW,
+        })
+}
+
+pub(crate) fn set_fulfillment_config(env: &Env, config: &FulfillmentConfig) {
+    if config.ship_window == 0 || config.delivery_window == 0 {
+        panic!("Invalid fulfillment window");
+    }
+    env.storage().instance().set(&FULFILLMENT, config);
+}
+
+/// Seller marks a paid order as shipped before its shipping deadline
+pub(crate) fn mark_shipped(env: &Env, seller: &Address, order_id: u64, tracking_hash: BytesN<32>) {
+    seller.require_auth();
+    let mut order = load_existing(env, order_id);
+    if order.seller != *seller {
+        panic!("Not the seller");
+    }
+    if order.status != OrderStatus::Paid {
+        panic!("Order not paid");
+    }
+    let now = env.ledger().timestamp();
+    if now > order.ship_by {
+        panic!("Shipping deadline passed");
+    }
+
+    order.status = OrderStatus::Shipped;
+    order.deliver_by = Some(now + fulfillment_config(env).delivery_window);
+    order.tracking_hash = Some(tracking_hash);
+    save(env, &order);
+
+    event::order_shipped(env, seller, &order);
+}
+
+/// Buyer confirms a shipped order arrived
+pub(crate) fn confirm_delivery(env: &Env, buyer: &Address, order_id: u64) {
+    buyer.require_auth();
+    let mut order = load_existing(env, order_id);
+    if order.buyer != *buyer {
+        panic!("Not the buyer");
+    }
+    if order.status != OrderStatus::Shipped {
+        panic!("Order not shipped");
+    }
+
+    deliver(env, buyer, &mut order);
+}
+
+/// Moves a held payment along once its deadline has passed: a shipped order whose
+/// delivery window is over counts as delivered, and a delivered order whose return
+/// window is over pays the seller
+pub(crate) fn release(env: &Env, order_id: u64) {
+    let mut order = load_existing(env, order_id);
+    let now = env.ledger().timestamp();
+    // Anyone can trigger a release, so it is attributed to the contract itself
+    let actor = env.current_contract_address();
+
+    match order.status {
+        OrderStatus::Shipped => {
+            if order.deliver_by.is_some_and(|deliver_by| now < deliver_by) {
+                panic!("Delivery deadline not passed");
+            }
+            deliver(env, &actor, &mut order);
+        }
+        OrderStatus::Delivered if !order.seller_paid => {
+            if order.return_by.is_some_and(|return_by| now <= return_by) {
+                panic!("Return window still open");
+            }
+            pay_seller(env, &actor, &mut order);
+        }
+        _ => panic!("Nothing to release"),
+    }
+}
+
+/// Buyer takes back the payment of an order the seller did not ship in time
+pub(crate) fn reclaim(env: &Env, buyer: &Address, order_id: u64) {
+    buyer.require_auth();
+    let mut order = load_existing(env, order_id);
+    if order.buyer != *buyer {
+        panic!("Not the buyer");
+    }
+    if order.status != OrderStatus::Paid {
+        panic!("Order not paid");
+    }
+    if env.ledger().timestamp() <= order.ship_by {
+        panic!("Shipping deadline not passed");
+    }
+
+    refund(env, &mut order);
+    Marketplace::restock(env, order.item_id, order.qty, buyer);
+
+    event::order_reclaimed(env, buyer, &order);
+}
+
+// Marks an order delivered. Without a return window the seller is paid right away,
+// otherwise the payment stays held until the window closes.
+fn deliver(env: &Env, actor: &Address, order: &mut Order) {
+    order.status = OrderStatus::Delivered;
+    if order.return_window > 0 {
+        order.return_by = Some(env.ledger().timestamp() + order.return_window);
+    }
+    save(env, order);
+    event::order_delivered(env, actor, order);
+
+    if order.return_window == 0 {
+        pay_seller(env, actor, order);
+    }
+}
+
+/// Pays the seller of a delivered order and keeps the fee
+pub(crate) fn pay_seller(env: &Env, actor: &Address, order: &mut Order) {
+    order.status = OrderStatus::Delivered;
+    order.seller_paid = true;
+    save(env, order);
+
+    let seller_amount = Marketplace::check(amount::sub(order.total, order.fee));
+    Marketplace::check(token::payout(
+        env,
+        &order.payment_token,
+        &seller::payout_address(env, &order.seller),
+        seller_amount,
+    ));
+    let collected_fees = Marketplace::accrue_fee(env, &order.payment_token, order.fee);
+    event::order_released(env, actor, order, order.fee, collected_fees);
+}
+
+/// Returns the whole payment of an order to the buyer
+pub(crate) fn refund(env: &Env, order: &mut Order) {
+    order.status = OrderStatus::Refunded;
+    save(env, order);
+    Marketplace::check(token::payout(
+        env,
+        &order.payment_token,
+        &order.buyer,
+        order.total,
+    ));
+}
+
+fn resolve(env: &Env, ids: Vec<u64>) -> Vec<Order> {
+    let mut orders = Vec::new(env);
+    for id in ids.iter() {
+        if let Some(order) = load(env, id) {
+            orders.push_back(order);
+        }
+    }
+    orders
+}
@@ -0,0 +1,180 @@
+//! Returns of delivered orders.
+//!
+//! Each listing offers a return window, zero by default. Orders snapshot it at
+//! purchase, and a delivered order keeps its payment held until the window
+//! closes:
+//!
+//! * `Delivered`: within `return_by` the buyer can request a return with a
+//!   reason (`ReturnRequested`)
+//! * `ReturnRequested`: the seller accepts (`ReturnAccepted`) or rejects,
+//!   which escalates to an arbiter (`Disputed`), within `RESPONSE_WINDOW`
+//! * `ReturnAccepted`: once the seller confirms the item came back, the buyer
+//!   is refunded and the units go back into stock (`Refunded`). The seller
+//!   has `RECEIPT_WINDOW` to confirm it
+//! * `ReturnRequested` or `ReturnAccepted` past the seller's deadline
+//!   (`respond_by`): the buyer can escalate to an arbiter (`Disputed`)
+//! * `Disputed`: an arbiter refunds the buyer (`Refunded`) or pays the seller
+//!   (`Delivered`)
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::order::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+use crate::{event, order, Marketplace, Order, OrderStatus, Role};
+
+/// A listing can accept returns for at most 90 days after delivery
+pub const MAX_RETURN_WINDOW: u64 = 90 * 24 * 60 * 60;
+
+/// Seller has 7 days to accept or reject a return request
+pub const RESPONSE_WINDOW: u64 = 7 * 24 * 60 * 60;
+/// Seller has 30 days after accepting a return to confirm the item came back
+pub const RECEIPT_WINDOW: u64 = 30 * 24 * 60 * 60;
+
+const RETURN_WINDOW: Symbol = symbol_short!("RET_WIN");
+
+/// Return window a listing offers, in seconds
+pub(crate) fn return_window(env: &Env, item_id: u32) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&(RETURN_WINDOW, item_id))
+        .unwrap_or(0)
+}
+
+/// Seller sets the return window of one of their listings
+pub(crate) fn set_return_window(env: &Env, seller: &Address, item_id: u32, window: u64) {
+    seller.require_auth();
+    let item = Marketplace::load_item(env, item_id);
+    if item.seller != *seller {
+        panic!("Not the seller");
+    }
+    if window > MAX_RETURN_WINDOW {
+        panic!("Invalid return window");
+    }
+
+    let key = (RETURN_WINDOW, item_id);
+    env.storage().persistent().set(&key, &window);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+
+    event::return_window_set(env, seller, item_id, window);
+}
+
+/// Buyer asks to return a delivered order before its return window closes
+pub(crate) fn request(env: &Env, buyer: &Address, order_id: u64, reason: String) {
+    buyer.require_auth();
+    let mut order = order::load_existing(env, order_id);
+    if order.buyer != *buyer {
+        panic!("Not the buyer");
+    }
+    if order.status != OrderStatus::Delivered || order.seller_paid {
+        panic!("Order not returnable");
+    }
+    let open = order
+        .return_by
+        .is_some_and(|return_by| env.ledger().timestamp() <= return_by);
+    if !open {
+        panic!("Return window closed");
+    }
+
+    order.status = OrderStatus::ReturnRequested;
+    order.return_reason = Some(reason);
+    order.respond_by = Some(env.ledger().timestamp() + RESPONSE_WINDOW);
+    order::save(env, &order);
+
+    event::return_requested(env, buyer, &order);
+}
+
+/// Seller accepts a return; the buyer is refunded once the item is back
+pub(crate) fn accept(env: &Env, seller: &Address, order_id: u64) {
+    let mut order = requested(env, seller, order_id);
+    order.status = OrderStatus::ReturnAccepted;
+    order.respond_by = Some(env.ledger().timestamp() + RECEIPT_WINDOW);
+    order::save(env, &order);
+
+    event::return_accepted(env, seller, &order);
+}
+
+/// Seller rejects a return, leaving the decision to an arbiter
+pub(crate) fn reject(env: &Env, seller: &Address, order_id: u64) {
+    let mut order = requested(env, seller, order_id);
+    order.status = OrderStatus::Disputed;
+    order.respond_by = None;
+    order::save(env, &order);
+
+    event::return_rejected(env, seller, &order);
+}
+
+/// Buyer hands a return to an arbiter once the seller has missed the deadline to answer it
+/// or to confirm the item came back
+pub(crate) fn escalate(env: &Env, buyer: &Address, order_id: u64) {
+    buyer.require_auth();
+    let mut order = order::load_existing(env, order_id);
+    if order.buyer != *buyer {
+        panic!("Not the buyer");
+    }
+    if order.status != OrderStatus::ReturnRequested && order.status != OrderStatus::ReturnAccepted {
+        panic!("No return in progress");
+    }
+    let overdue = order
+        .respond_by
+        .is_some_and(|respond_by| env.ledger().timestamp() > respond_by);
+    if !overdue {
+        panic!("Seller deadline not passed");
+    }
+
+    order.status = OrderStatus::Disputed;
+    order.respond_by = None;
+    order::save(env, &order);
+
+    event::return_escalated(env, buyer, &order);
+}
+
+/// Seller confirms the returned item arrived: the buyer is refunded and the units restocked
+pub(crate) fn confirm_received(env: &Env, seller: &Address, order_id: u64) {
+    seller.require_auth();
+    let mut order = order::load_existing(env, order_id);
+    if order.seller != *seller {
+        panic!("Not the seller");
+    }
+    if order.status != OrderStatus::ReturnAccepted {
+        panic!("Return not accepted");
+    }
+
+    order.respond_by = None;
+    order::refund(env, &mut order);
+    Marketplace::restock(env, order.item_id, order.qty, seller);
+
+    event::order_returned(env, seller, &order);
+}
+
+/// Arbiter settles a disputed return by refunding the buyer or paying the seller.
+/// A refunded buyer keeps the item, so nothing is restocked.
+pub(crate) fn resolve(env: &Env, arbiter: &Address, order_id: u64, refund_buyer: bool) {
+    Marketplace::require_role(env, Role::Arbiter, arbiter);
+    let mut order = order::load_existing(env, order_id);
+    if order.status != OrderStatus::Disputed {
+        panic!("Order not disputed");
+    }
+
+    if refund_buyer {
+        order::refund(env, &mut order);
+        event::return_dispute_resolved(env, arbiter, &order);
+    } else {
+        order.status = OrderStatus::Delivered;
+        event::return_dispute_resolved(env, arbiter, &order);
+        order::pay_seller(env, arbiter, &mut order);
+    }
+}
+
+// Loads a return request addressed to the seller
+fn requested(env: &Env, seller: &Address, order_id: u64) -> Order {
+    seller.require_auth();
+    let order = order::load_existing(env, order_id);
+    if order.seller != *seller {
+        panic!("Not the seller");
+    }
+    if order.status != OrderStatus::ReturnRequested {
+        panic!("No return requested");
+    }
+    order
+}
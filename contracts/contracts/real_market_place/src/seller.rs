@@ -0,0 +1,205 @@
+//! Seller profiles and the verified-seller badge.
+//!
+//! Sellers register a display name, a metadata URI describing their store, a
+//! hash of their contact details and the address their sales are paid out to.
+//! Listing does not need a profile, but only registered sellers can be
+//! verified: an attester, or the admin, vouches that the profile belongs to a
+//! legitimate store. Changing the display name or payout address drops the
+//! badge, since those are the details it vouched for.
+//!
+//! The admin can reserve some categories, subcategories included, and prices
+//! from a threshold up for verified sellers. Tokens differ in value and
+//! decimals, so the price tier sets a threshold per token, and one for
+//! reference prices in the oracle's base asset; a price in a token without a
+//! threshold is not reserved. The tier applies to every price a listing
+//! carries. The policy is checked when a listing enters a reserved tier and
+//! again on every purchase, at the price actually charged, and every bid, so a
+//! revoked badge stops sales right away.
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol, Vec};
+
+use crate::category;
+use crate::order::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+
+/// A seller's public profile
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SellerProfile {
+    pub seller: Address,
+    pub display_name: String,
+    pub metadata_uri: String,     // Store details, e.g. an IPFS document
+    pub contact_hash: BytesN<32>, // SHA-256 of the contact details shared off-chain
+    pub payout_address: Address,  // Receives the seller's share of every sale
+    pub registered_at: u64,
+    pub verified_by: Option<Address>, // Attester that granted the badge, if verified
+    pub verified_at: Option<u64>,
+}
+
+/// Listings only verified sellers can offer
+#[contracttype]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VerificationPolicy {
+    pub categories: Vec<u32>,              // Subcategories included
+    pub min_prices: Map<Address, i128>,    // Lowest reserved price per token, in its units
+    pub min_reference_price: Option<i128>, // Lowest reserved price in the oracle's base asset
+}
+
+/// A price checked against the price tier, with what it is denominated in
+#[derive(Clone, Copy)]
+pub(crate) enum Price<'a> {
+    Token(&'a Address, i128),
+    Reference(i128), // In the oracle's base asset
+}
+
+/// Longest display name, in bytes
+pub const MAX_DISPLAY_NAME_LEN: u32 = 64;
+
+const SELLER: Symbol = symbol_short!("SELLER");
+const POLICY: Symbol = symbol_short!("VER_POL");
+
+pub(crate) fn load(env: &Env, seller: &Address) -> Option<SellerProfile> {
+    env.storage().persistent().get(&(SELLER, seller.clone()))
+}
+
+fn load_existing(env: &Env, seller: &Address) -> SellerProfile {
+    load(env, seller).unwrap_or_else(|| panic!("Seller not registered"))
+}
+
+fn save(env: &Env, profile: &SellerProfile) {
+    let key = (SELLER, profile.seller.clone());
+    env.storage().persistent().set(&key, profile);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+}
+
+/// Seller creates or updates their profile
+pub(crate) fn register(
+    env: &Env,
+    seller: &Address,
+    display_name: String,
+    metadata_uri: String,
+    contact_hash: BytesN<32>,
+    payout_address: Address,
+) -> SellerProfile {
+    seller.require_auth();
+    if display_name.is_empty() || display_name.len() > MAX_DISPLAY_NAME_LEN {
+        panic!("Invalid display name");
+    }
+
+    let profile = match load(env, seller) {
+        Some(mut profile) => {
+            if profile.display_name != display_name || profile.payout_address != payout_address {
+                profile.verified_by = None;
+                profile.verified_at = None;
+            }
+            profile.display_name = display_name;
+            profile.metadata_uri = metadata_uri;
+            profile.contact_hash = contact_hash;
+            profile.payout_address = payout_address;
+            profile
+        }
+        None => SellerProfile {
+            seller: seller.clone(),
+            display_name,
+            metadata_uri,
+            contact_hash,
+            payout_address,
+            registered_at: env.ledger().timestamp(),
+            verified_by: None,
+            verified_at: None,
+        },
+    };
+    save(env, &profile);
+    profile
+}
+
+/// Grants the badge to a registered seller
+pub(crate) fn verify(env: &Env, attester: &Address, seller: &Address) -> SellerProfile {
+    let mut profile = load_existing(env, seller);
+    if profile.verified_by.is_some() {
+        panic!("Seller already verified");
+    }
+    profile.verified_by = Some(attester.clone());
+    profile.verified_at = Some(env.ledger().timestamp());
+    save(env, &profile);
+    profile
+}
+
+/// Takes the badge away from a seller
+pub(crate) fn revoke(env: &Env, seller: &Address) -> SellerProfile {
+    let mut profile = load_existing(env, seller);
+    if profile.verified_by.is_none() {
+        panic!("Seller not verified");
+    }
+    profile.verified_by = None;
+    profile.verified_at = None;
+    save(env, &profile);
+    profile
+}
+
+pub(crate) fn is_verified(env: &Env, seller: &Address) -> bool {
+    load(env, seller).is_some_and(|profile| profile.verified_by.is_some())
+}
+
+/// Where a seller's sales are paid: their payout address, or the seller itself
+pub(crate) fn payout_address(env: &Env, seller: &Address) -> Address {
+    load(env, seller).map_or_else(|| seller.clone(), |profile| profile.payout_address)
+}
+
+pub(crate) fn policy(env: &Env) -> VerificationPolicy {
+    env.storage()
+        .instance()
+        .get(&POLICY)
+        .unwrap_or(VerificationPolicy {
+            categories: Vec::new(env),
+            min_prices: Map::new(env),
+            min_reference_price: None,
+        })
+}
+
+pub(crate) fn set_policy(env: &Env, policy: &VerificationPolicy) {
+    let invalid = |min_price: i128| min_price <= 0;
+    if policy.min_prices.values().iter().any(invalid)
+        || policy.min_reference_price.is_some_and(invalid)
+    {
+        panic!("Invalid price tier");
+    }
+    let categories = category::all(env);
+    for category_id in policy.categories.iter() {
+        if !categories.contains_key(category_id) {
+            panic!("Category not found");
+        }
+    }
+    env.storage().instance().set(&POLICY, policy);
+}
+
+/// Panics unless `seller` may offer a listing in `category` at `price`
+pub(crate) fn require_eligible(env: &Env, seller: &Address, category: Option<u32>, price: Price) {
+    if !is_eligible(env, seller, category, price) {
+        panic!("Verified seller required");
+    }
+}
+
+/// Whether `seller` may offer a listing in `category` at `price`
+pub(crate) fn is_eligible(
+    env: &Env,
+    seller: &Address,
+    category: Option<u32>,
+    price: Price,
+) -> bool {
+    let policy = policy(env);
+    let (min_price, amount) = match price {
+        Price::Token(token, amount) => (policy.min_prices.get(token.clone()), amount),
+        Price::Reference(amount) => (policy.min_reference_price, amount),
+    };
+    let reserved_price = min_price.is_some_and(|min_price| amount >= min_price);
+    let reserved_category = category.is_some_and(|category_id| {
+        let categories = category::all(env);
+        policy
+            .categories
+            .iter()
+            .any(|reserved| category::is_within(&categories, category_id, reserved))
+    });
+    !(reserved_price || reserved_category) || is_verified(env, seller)
+}
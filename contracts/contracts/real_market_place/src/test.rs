@@ -1,21 +1,2524 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{vec, Env, String};
+use event::{
+    AuctionSoldEvent, BidEvent, CategoryEvent, DutchAuctionEvent, ItemEvent, OfferEvent,
+    OrderEvent, OrderSettledEvent, PurchaseEvent, ReferencePriceEvent, SellerEvent,
+};
+use order::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+use paystar_common::events::EVENT_VERSION;
+use soroban_sdk::{
+    testutils::{
+        storage::{Instance as _, Persistent as _},
+        Address as _, Events as _, Ledger as _,
+    },
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, FromVal, IntoVal, String, Val,
+};
+
+mod marketplace_v0 {
+    soroban_sdk::contractimport!(file = "testdata/real_market_place_v0.wasm");
+}
+
+/// SEP-40 price feed quoting in USD with 14 decimals, whose prices the tests set
+mod mock_oracle {
+    use crate::oracle::{Asset, PriceData, PriceOracle};
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Map, Vec};
+
+    #[contract]
+    pub struct MockOracle;
+
+    #[contractimpl]
+    impl PriceOracle for MockOracle {
+        fn base(_env: Env) -> Asset {
+            Asset::Other(symbol_short!("USD"))
+        }
+
+        fn decimals(_env: Env) -> u32 {
+            14
+        }
+
+        fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+            history(&env, &asset).first()
+        }
+
+        fn prices(env: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>> {
+            let history = history(&env, &asset);
+            if history.is_empty() {
+                return None;
+            }
+            Some(history.slice(..records.min(history.len())))
+        }
+    }
+
+    #[contractimpl]
+    impl MockOracle {
+        /// Records a price of `token`, most recent first
+        pub fn set_price(env: Env, token: Address, price: i128, timestamp: u64) {
+            let asset = Asset::Stellar(token);
+            let mut history = history(&env, &asset);
+            history.push_front(PriceData { price, timestamp });
+            let mut all: Map<Asset, Vec<PriceData>> = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("PRICES"))
+                .unwrap_or(Map::new(&env));
+            all.set(asset, history);
+            env.storage().instance().set(&symbol_short!("PRICES"), &all);
+        }
+    }
+
+    fn history(env: &Env, asset: &Asset) -> Vec<PriceData> {
+        env.storage()
+            .instance()
+            .get::<_, Map<Asset, Vec<PriceData>>>(&symbol_short!("PRICES"))
+            .and_then(|all| all.get(asset.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+}
+
+/// Token with 2 decimals, like a fiat stablecoin counted in cents, with just what payments use
+mod mock_cents {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct MockCents;
+
+    #[contractimpl]
+    impl MockCents {
+        pub fn decimals(_env: Env) -> u32 {
+            2
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            env.storage().persistent().get(&id).unwrap_or(0)
+        }
+
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage().persistent().set(&to, &(balance + amount));
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let balance = Self::balance(env.clone(), from.clone());
+            env.storage().persistent().set(&from, &(balance - amount));
+            Self::mint(env, to, amount);
+        }
+    }
+}
+
+struct MarketTest<'a> {
+    env: Env,
+    contract: MarketplaceClient<'a>,
+    token: TokenClient<'a>,
+    admin: Address,
+    seller: Address,
+    buyer: Address,
+}
+
+impl MarketTest<'_> {
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let seller = Address::generate(&env);
+        let buyer = Address::generate(&env);
+
+        let token_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        StellarAssetClient::new(&env, &token_address).mint(&buyer, &10_000);
+
+        let contract_id = env.register(Marketplace, ());
+        let contract = MarketplaceClient::new(&env, &contract_id);
+        contract.initialize(&admin, &token_address);
+
+        MarketTest {
+            token: TokenClient::new(&env, &token_address),
+            env,
+            contract,
+            admin,
+            seller,
+            buyer,
+        }
+    }
+
+    fn list(&self, price: i128, quantity: u32) -> u32 {
+        self.contract.list_item(
+            &self.seller,
+            &String::from_str(&self.env, "Guitar"),
+            &price,
+            &quantity,
+            &String::from_str(&self.env, "ipfs://guitar"),
+        )
+    }
+
+    /// Seller ships an order with a dummy tracking hash
+    fn ship(&self, order_id: u64) {
+        self.contract.mark_shipped(
+            &self.seller,
+            &order_id,
+            &BytesN::from_array(&self.env, &[7; 32]),
+        );
+    }
+
+    fn advance(&self, seconds: u64) {
+        self.env
+            .ledger()
+            .set_timestamp(self.env.ledger().timestamp() + seconds);
+    }
+
+    /// Lists an item accepting returns for `return_window` seconds and takes one order of
+    /// two units through delivery
+    fn delivered_with_returns(&self, return_window: u64) -> (u32, u64) {
+        let item_id = self.list(100, 5);
+        self.contract
+            .set_return_window(&self.seller, &item_id, &return_window);
+        let order_id = self.contract.buy_item(&self.buyer, &item_id, &2);
+        self.ship(order_id);
+        self.contract.confirm_delivery(&self.buyer, &order_id);
+        (item_id, order_id)
+    }
+
+    /// Puts an item on a Dutch auction from 1_000 down to 400 over the next 600 seconds
+    fn dutch(&self, item_id: u32, curve: PriceCurve) {
+        let now = self.env.ledger().timestamp();
+        self.contract.set_dutch_auction(
+            &self.seller,
+            &DutchAuction {
+                item_id,
+                start_price: 1_000,
+                floor_price: 400,
+                start_time: now,
+                end_time: now + 600,
+                curve,
+            },
+        );
+    }
+
+    /// A second Stellar Asset Contract token, with the buyer funded in it
+    fn second_token(&self) -> TokenClient<'_> {
+        let address = self
+            .env
+            .register_stellar_asset_contract_v2(self.admin.clone())
+            .address();
+        StellarAssetClient::new(&self.env, &address).mint(&self.buyer, &10_000);
+        TokenClient::new(&self.env, &address)
+    }
+
+    /// Points the marketplace at a mock oracle accepting quotes up to 10 minutes old that
+    /// moved at most 10%, and funds the buyer for purchases priced in USD
+    fn oracle(&self) -> mock_oracle::MockOracleClient<'_> {
+        let oracle = self.env.register(mock_oracle::MockOracle, ());
+        self.contract.set_oracle(
+            &self.admin,
+            &OracleConfig {
+                oracle: oracle.clone(),
+                base: Asset::Other(symbol_short!("USD")),
+                max_age: 600,
+                max_deviation_bps: 1_000,
+            },
+        );
+        StellarAssetClient::new(&self.env, &self.token.address).mint(&self.buyer, &1_000_000_000);
+        mock_oracle::MockOracleClient::new(&self.env, &oracle)
+    }
+
+    /// Opens an auction with a reserve of 500 and an increment of 50, ending in an hour
+    fn auction(&self) -> u32 {
+        self.contract.create_auction(
+            &self.seller,
+            &String::from_str(&self.env, "Camera"),
+            &String::from_str(&self.env, "ipfs://camera"),
+            &500,
+            &50,
+            &(self.env.ledger().timestamp() + 3_600),
+        )
+    }
+
+    /// Reserves prices in the payment token from `min_price` up for verified sellers
+    fn price_tier(&self, min_price: i128) {
+        self.contract.set_verification_policy(
+            &self.admin,
+            &VerificationPolicy {
+                categories: Vec::new(&self.env),
+                min_prices: Map::from_array(&self.env, [(self.token.address.clone(), min_price)]),
+                min_reference_price: None,
+            },
+        );
+    }
+
+    /// A second funded bidder
+    fn bidder(&self) -> Address {
+        let bidder = Address::generate(&self.env);
+        StellarAssetClient::new(&self.env, &self.token.address).mint(&bidder, &10_000);
+        bidder
+    }
+
+    /// Registers the seller as "Guitar Shop", paid out to a separate address
+    fn register_seller(&self) -> Address {
+        let payout = Address::generate(&self.env);
+        self.contract.register_seller(
+            &self.seller,
+            &String::from_str(&self.env, "Guitar Shop"),
+            &String::from_str(&self.env, "ipfs://guitar-shop"),
+            &BytesN::from_array(&self.env, &[9; 32]),
+            &payout,
+        );
+        payout
+    }
+
+    /// Grants the Attester role to a new address
+    fn attester(&self) -> Address {
+        let attester = Address::generate(&self.env);
+        self.contract
+            .grant_role(&self.admin, &Role::Attester, &attester);
+        attester
+    }
+
+    /// Topics and payload of the last event published by the marketplace
+    fn last_event(&self) -> (Vec<Val>, Val) {
+        let (address, topics, data) = self.env.events().all().last().unwrap();
+        assert_eq!(address, self.contract.address);
+        (topics, data)
+    }
+
+    /// Topics and payload of the last marketplace event published under `action`
+    fn event(&self, action: Symbol) -> (Vec<Val>, Val) {
+        self.env
+            .events()
+            .all()
+            .iter()
+            .rev()
+            .find(|(address, topics, _)| {
+                *address == self.contract.address
+                    && Symbol::from_val(&self.env, &topics.get(1).unwrap()) == action
+            })
+            .map(|(_, topics, data)| (topics, data))
+            .unwrap()
+    }
+}
+
+#[test]
+fn test_list_multiple_items() {
+    let test = MarketTest::setup();
+    test.list(100, 1);
+    test.list(200, 2);
+
+    assert_eq!(test.contract.get_items().len(), 2);
+}
+
+#[test]
+fn test_listing_requires_seller_auth() {
+    let test = MarketTest::setup();
+    test.list(100, 1);
+    assert_eq!(test.env.auths()[0].0, test.seller);
+
+    // Nobody can list in a seller's name without their signature
+    test.env.set_auths(&[]);
+    let result = test.contract.try_list_item(
+        &test.seller,
+        &String::from_str(&test.env, "Guitar"),
+        &100,
+        &1,
+        &String::from_str(&test.env, "ipfs://guitar"),
+    );
+    assert!(result.is_err());
+    assert_eq!(test.contract.get_items().len(), 1);
+}
+
+#[test]
+fn test_buy_item_pays_seller_on_delivery() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &2);
+
+    // The payment is held until the buyer confirms delivery
+    assert_eq!(test.token.balance(&test.contract.address), 200);
+    assert_eq!(test.token.balance(&test.buyer), 9_800);
+    assert_eq!(test.contract.get_items().get(0).unwrap().quantity, 3);
+
+    test.ship(order_id);
+    test.contract.confirm_delivery(&test.buyer, &order_id);
+
+    assert_eq!(test.token.balance(&test.seller), 200);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+    assert_eq!(
+        test.contract.get_order(&order_id).unwrap().status,
+        OrderStatus::Delivered
+    );
+}
+
+#[test]
+fn test_buy_item_deducts_platform_fee() {
+    let test = MarketTest::setup();
+    let treasury = Address::generate(&test.env);
+    test.contract.set_fee(&test.admin, &300, &treasury); // 3%
+    let item_id = test.list(1_000, 1);
+
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &1);
+    assert_eq!(test.contract.get_collected_fees(&test.token.address), 0);
+    test.ship(order_id);
+    test.contract.confirm_delivery(&test.buyer, &order_id);
+
+    assert_eq!(test.token.balance(&test.seller), 970);
+    assert_eq!(test.contract.get_collected_fees(&test.token.address), 30);
+
+    test.contract
+        .withdraw_fees(&test.admin, &test.token.address, &30);
+    assert_eq!(test.token.balance(&treasury), 30);
+    assert_eq!(test.contract.get_collected_fees(&test.token.address), 0);
+}
 
 #[test]
-fn test() {
+fn test_events_carry_full_item_state() {
+    let test = MarketTest::setup();
+    let treasury = Address::generate(&test.env);
+    test.contract.set_fee(&test.admin, &300, &treasury); // 3%
+    let item_id = test.list(1_000, 5);
+
+    // Topics are (contract, action, item id, actor)
+    let (topics, data) = test.last_event();
+    let expected_topics: Vec<Val> = (
+        symbol_short!("market"),
+        symbol_short!("list"),
+        u128::from(item_id),
+        test.seller.clone(),
+    )
+        .into_val(&test.env);
+    assert_eq!(topics, expected_topics);
+    let event = ItemEvent::from_val(&test.env, &data);
+    assert_eq!(event.version, EVENT_VERSION);
+    assert_eq!(event.item, test.contract.get_items().get(0).unwrap());
+
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &2);
+
+    let (_, data) = test.event(symbol_short!("buy"));
+    let event = PurchaseEvent::from_val(&test.env, &data);
+    assert_eq!(event.item.quantity, 3);
+    assert_eq!(event.qty, 2);
+    assert_eq!(event.total, 2_000);
+    assert_eq!(event.fee, 60);
+    assert_eq!(event.collected_fees, 0); // Accrued on delivery
+
+    // The order event is keyed by the order id
+    let (topics, data) = test.last_event();
+    let expected_topics: Vec<Val> = (
+        symbol_short!("market"),
+        symbol_short!("ordered"),
+        u128::from(order_id),
+        test.buyer.clone(),
+    )
+        .into_val(&test.env);
+    assert_eq!(topics, expected_topics);
+    let event = OrderEvent::from_val(&test.env, &data);
+    assert_eq!(event.version, EVENT_VERSION);
+    assert_eq!(Some(event.order), test.contract.get_order(&order_id));
+
+    test.ship(order_id);
+    test.contract.confirm_delivery(&test.buyer, &order_id);
+
+    let (_, data) = test.event(symbol_short!("delivered"));
+    let event = OrderEvent::from_val(&test.env, &data);
+    assert_eq!(event.order.status, OrderStatus::Delivered);
+
+    // Without a return window the seller is paid in the same call
+    let (topics, data) = test.last_event();
+    assert_eq!(
+        Symbol::from_val(&test.env, &topics.get(1).unwrap()),
+        symbol_short!("released")
+    );
+    let event = OrderSettledEvent::from_val(&test.env, &data);
+    assert!(event.order.seller_paid);
+    assert_eq!(event.seller_amount, 1_940);
+    assert_eq!(event.fee, 60);
+    assert_eq!(event.collected_fees, 60);
+}
+
+#[test]
+fn test_purchase_records_order() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    test.env.ledger().set_timestamp(1_700_000_000);
+
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &2);
+
+    let order = test.contract.get_order(&order_id).unwrap();
+    assert_eq!(order.id, 1);
+    assert_eq!(order.item_id, item_id);
+    assert_eq!(order.buyer, test.buyer);
+    assert_eq!(order.seller, test.seller);
+    assert_eq!(order.qty, 2);
+    assert_eq!(order.unit_price, 100);
+    assert_eq!(order.total, 200);
+    assert_eq!(order.fee, 0);
+    assert_eq!(order.payment_token, test.token.address);
+    assert_eq!(order.created_at, 1_700_000_000);
+    assert_eq!(order.status, OrderStatus::Paid);
+    assert_eq!(test.contract.get_order(&2), None);
+}
+
+#[test]
+fn test_order_history_per_buyer_and_seller() {
+    let test = MarketTest::setup();
+    let other_buyer = Address::generate(&test.env);
+    let other_seller = Address::generate(&test.env);
+    StellarAssetClient::new(&test.env, &test.token.address).mint(&other_buyer, &10_000);
+    let guitar = test.list(100, 5);
+    let drum = test.contract.list_item(
+        &other_seller,
+        &String::from_str(&test.env, "Drum"),
+        &300,
+        &5,
+        &String::from_str(&test.env, "ipfs://drum"),
+    );
+
+    let first = test.contract.buy_item(&test.buyer, &guitar, &1);
+    let second = test.contract.buy_item(&other_buyer, &guitar, &2);
+    let third = test.contract.buy_item(&test.buyer, &drum, &1);
+
+    let ids = |orders: Vec<Order>| -> Vec<u64> {
+        let mut ids = Vec::new(&test.env);
+        for order in orders.iter() {
+            ids.push_back(order.id);
+        }
+        ids
+    };
+    assert_eq!(
+        ids(test.contract.get_buyer_orders(&test.buyer, &0, &10)),
+        Vec::from_array(&test.env, [first, third])
+    );
+    assert_eq!(
+        ids(test.contract.get_seller_orders(&test.seller, &0, &10)),
+        Vec::from_array(&test.env, [first, second])
+    );
+    assert_eq!(
+        ids(test.contract.get_seller_orders(&other_seller, &0, &10)),
+        Vec::from_array(&test.env, [third])
+    );
+    assert!(test
+        .contract
+        .get_buyer_orders(&test.seller, &0, &10)
+        .is_empty());
+
+    // Histories are read a page at a time
+    assert_eq!(test.contract.get_buyer_order_count(&test.buyer), 2);
+    assert_eq!(test.contract.get_seller_order_count(&other_seller), 1);
+    assert_eq!(
+        ids(test.contract.get_buyer_orders(&test.buyer, &1, &10)),
+        Vec::from_array(&test.env, [third])
+    );
+    assert_eq!(
+        ids(test.contract.get_seller_orders(&test.seller, &0, &1)),
+        Vec::from_array(&test.env, [first])
+    );
+    assert!(test
+        .contract
+        .get_buyer_orders(&test.buyer, &2, &10)
+        .is_empty());
+
+    // Each order keeps the unit price of the listing it was placed on
+    assert_eq!(test.contract.get_order(&third).unwrap().unit_price, 300);
+}
+
+#[test]
+fn test_order_history_pages_are_capped() {
+    let test = MarketTest::setup();
+    let item_id = test.list(1, 100);
+    for _ in 0..index::MAX_PAGE_SIZE + 5 {
+        test.contract.buy_item(&test.buyer, &item_id, &1);
+    }
+
+    assert_eq!(
+        test.contract.get_buyer_order_count(&test.buyer),
+        index::MAX_PAGE_SIZE + 5
+    );
+    let page = test.contract.get_buyer_orders(&test.buyer, &0, &u32::MAX);
+    assert_eq!(page.len(), index::MAX_PAGE_SIZE);
+    let rest = test
+        .contract
+        .get_buyer_orders(&test.buyer, &index::MAX_PAGE_SIZE, &u32::MAX);
+    assert_eq!(rest.len(), 5);
+    assert_eq!(rest.get(0).unwrap().id, u64::from(index::MAX_PAGE_SIZE) + 1);
+}
+
+#[test]
+#[should_panic(expected = "Missing role")]
+fn test_set_fee_fails_without_fee_manager_role() {
+    let test = MarketTest::setup();
+    test.contract.set_fee(&test.seller, &100, &test.seller);
+}
+
+#[test]
+#[should_panic(expected = "Invalid quantity")]
+fn test_buy_more_than_stock_fails() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 1);
+    test.contract.buy_item(&test.buyer, &item_id, &2);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_buy_item_fails_with_insufficient_balance() {
+    let test = MarketTest::setup();
+    let item_id = test.list(10_001, 1);
+    test.contract.buy_item(&test.buyer, &item_id, &1);
+}
+
+#[test]
+fn test_upgrade_replaces_wasm() {
+    let test = MarketTest::setup();
+    assert_eq!(test.contract.get_schema_version(), CURRENT_SCHEMA_VERSION);
+
+    let wasm_hash = test
+        .env
+        .deployer()
+        .upload_contract_wasm(marketplace_v0::WASM);
+    test.contract.upgrade(&test.admin, &wasm_hash);
+
+    // Version 0 has no schema version entry point
+    assert!(test.contract.try_get_schema_version().is_err());
+}
+
+#[test]
+#[should_panic(expected = "Not admin")]
+fn test_upgrade_fails_if_not_admin() {
+    let test = MarketTest::setup();
+    let wasm_hash = test
+        .env
+        .deployer()
+        .upload_contract_wasm(marketplace_v0::WASM);
+    test.contract.upgrade(&test.seller, &wasm_hash);
+}
+
+#[test]
+fn test_migrate_v0_items_after_upgrade() {
     let env = Env::default();
-    let contract_id = env.register(Contract, ());
-    let client = ContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+
+    // Version 0 writes purchases to a separate persistent copy of the items
+    let contract_id = env.register(marketplace_v0::WASM, ());
+    let v0 = marketplace_v0::Client::new(&env, &contract_id);
+    let name = String::from_str(&env, "Camera");
+    let image = String::from_str(&env, "ipfs://camera");
+    let first = v0.list_item(&seller, &name, &100, &5, &image);
+    v0.buy_item(&buyer, &first, &1);
+    let second = v0.list_item(&seller, &name, &200, &5, &image);
+    v0.buy_item(&buyer, &second, &2);
+    assert_eq!(v0.get_items().get(1).unwrap().quantity, 5);
+
+    // Swap in the current code at the same address, keeping storage
+    env.register_at(&contract_id, Marketplace, ());
+    let contract = MarketplaceClient::new(&env, &contract_id);
+    contract.initialize(&admin, &token_address);
+    assert_eq!(contract.get_schema_version(), 0);
+
+    assert_eq!(contract.migrate(&admin), 1);
+    assert_eq!(contract.get_schema_version(), CURRENT_SCHEMA_VERSION);
+
+    let items = contract.get_items();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items.get(0).unwrap().quantity, 4);
+    assert_eq!(items.get(1).unwrap().quantity, 3);
+}
 
-    let words = client.hello(&String::from_str(&env, "Dev"));
+#[test]
+#[should_panic(expected = "Migration pending")]
+fn test_listing_waits_for_migration() {
+    let test = MarketTest::setup();
+    // A deployment upgraded from version 1 that has not been migrated yet
+    test.env.as_contract(&test.contract.address, || {
+        test.env.storage().instance().set(&SCHEMA_VERSION, &1u32);
+    });
+
+    test.list(100, 5);
+}
+
+#[test]
+#[should_panic(expected = "Migration pending")]
+fn test_buying_waits_for_migration() {
+    let test = MarketTest::setup();
+    let legacy_item = LegacyItem {
+        id: 1,
+        name: String::from_str(&test.env, "Camera"),
+        price: 100,
+        quantity: 5,
+        seller: test.seller.clone(),
+        image_url: String::from_str(&test.env, "ipfs://camera"),
+    };
+    test.env.as_contract(&test.contract.address, || {
+        let items = Map::from_array(&test.env, [(1u32, legacy_item)]);
+        test.env.storage().instance().set(&ITEMS, &items);
+        test.env.storage().instance().set(&SCHEMA_VERSION, &1u32);
+    });
+
+    test.contract.buy_item(&test.buyer, &1, &1);
+}
+
+#[test]
+fn test_migrate_v1_items_to_categorized_layout() {
+    let test = MarketTest::setup();
+    let legacy_item = LegacyItem {
+        id: 1,
+        name: String::from_str(&test.env, "Camera"),
+        price: 100,
+        quantity: 5,
+        seller: test.seller.clone(),
+        image_url: String::from_str(&test.env, "ipfs://camera"),
+    };
+    // Version 1 stored items without a category or tags
+    test.env.as_contract(&test.contract.address, || {
+        let items = Map::from_array(&test.env, [(1u32, legacy_item)]);
+        test.env.storage().instance().set(&ITEMS, &items);
+        test.env.storage().instance().set(&SCHEMA_VERSION, &1u32);
+    });
+
+    assert_eq!(test.contract.migrate(&test.admin), 0);
+    // Rewritten without being merged, and still published
+    let (topics, data) = test.event(symbol_short!("mig_rec"));
+    assert_eq!(u128::from_val(&test.env, &topics.get(2).unwrap()), 1);
+    assert_eq!(ItemEvent::from_val(&test.env, &data).item.category, None);
+
+    let item = test.contract.get_items().get(0).unwrap();
+    assert_eq!(item.quantity, 5);
+    assert_eq!(item.category, None);
+    assert!(item.tags.is_empty());
+    // Migrated items can be classified like new ones
+    let category_id =
+        test.contract
+            .add_category(&test.admin, &String::from_str(&test.env, "Cameras"), &None);
+    test.contract
+        .set_item_category(&test.seller, &1, &Some(category_id));
+    assert_eq!(test.contract.get_category_item_count(&category_id), 1);
+}
+
+#[test]
+#[should_panic(expected = "Already migrated")]
+fn test_migrate_fails_on_current_schema() {
+    let test = MarketTest::setup();
+    test.contract.migrate(&test.admin);
+}
+
+#[test]
+fn test_pause_blocks_purchases() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+
+    test.contract.pause(&test.admin);
+    assert!(test.contract.is_paused());
+    assert!(test
+        .contract
+        .try_buy_item(&test.buyer, &item_id, &1)
+        .is_err());
+
+    test.contract.unpause(&test.admin);
+    test.contract.buy_item(&test.buyer, &item_id, &1);
+    assert_eq!(test.token.balance(&test.contract.address), 100);
+}
+
+#[test]
+#[should_panic(expected = "Contract paused")]
+fn test_pause_blocks_listings() {
+    let test = MarketTest::setup();
+    test.contract.pause(&test.admin);
+    test.list(100, 1);
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let test = MarketTest::setup();
+    let new_admin = Address::generate(&test.env);
+
+    test.contract.propose_admin(&test.admin, &new_admin);
+    assert_eq!(test.contract.get_admin(), test.admin);
+    assert_eq!(test.contract.get_pending_admin(), Some(new_admin.clone()));
+    assert!(test.contract.try_accept_admin(&test.seller).is_err());
+
+    test.contract.accept_admin(&new_admin);
+    assert_eq!(test.contract.get_admin(), new_admin);
+    assert_eq!(test.contract.get_pending_admin(), None);
+    assert!(test.contract.try_pause(&test.admin).is_err());
+}
+
+#[test]
+fn test_grant_and_revoke_roles() {
+    let test = MarketTest::setup();
+    let pauser = Address::generate(&test.env);
+    let treasury = Address::generate(&test.env);
+
+    test.contract
+        .grant_role(&test.admin, &Role::Pauser, &pauser);
+    assert!(test.contract.has_role(&Role::Pauser, &pauser));
+    assert!(test.contract.has_role(&Role::Arbiter, &test.admin));
+
+    test.contract.pause(&pauser);
+    assert!(test.contract.is_paused());
+    assert!(test.contract.try_set_fee(&pauser, &100, &treasury).is_err());
+
+    test.contract
+        .revoke_role(&test.admin, &Role::Pauser, &pauser);
+    assert!(!test.contract.has_role(&Role::Pauser, &pauser));
+    assert!(test.contract.try_unpause(&pauser).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Invalid role")]
+fn test_admin_role_cannot_be_granted() {
+    let test = MarketTest::setup();
+    test.contract
+        .grant_role(&test.admin, &Role::Admin, &test.seller);
+}
+
+#[test]
+fn test_shipping_starts_the_delivery_window() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &1);
+    let placed_at = test.env.ledger().timestamp();
+
+    let order = test.contract.get_order(&order_id).unwrap();
+    assert_eq!(order.ship_by, placed_at + order::DEFAULT_SHIP_WINDOW);
+    assert_eq!(order.deliver_by, None);
+    assert_eq!(order.tracking_hash, None);
+
+    test.advance(100);
+    test.ship(order_id);
+
+    let order = test.contract.get_order(&order_id).unwrap();
+    assert_eq!(order.status, OrderStatus::Shipped);
+    assert_eq!(
+        order.deliver_by,
+        Some(placed_at + 100 + order::DEFAULT_DELIVERY_WINDOW)
+    );
+    assert_eq!(
+        order.tracking_hash,
+        Some(BytesN::from_array(&test.env, &[7; 32]))
+    );
+}
+
+#[test]
+fn test_payment_released_after_delivery_window() {
+    let test = MarketTest::setup();
+    test.contract
+        .set_fulfillment_windows(&test.admin, &50, &100);
+    let item_id = test.list(100, 5);
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &1);
+    test.ship(order_id);
+
+    test.advance(99);
+    assert!(test.contract.try_release_order(&order_id).is_err());
+
+    test.advance(1);
+    test.contract.release_order(&order_id);
+
+    // Attributed to the contract, since anyone can release
+    let (topics, _) = test.last_event();
+    assert_eq!(
+        Address::from_val(&test.env, &topics.get(3).unwrap()),
+        test.contract.address
+    );
+    assert_eq!(test.token.balance(&test.seller), 100);
+    assert_eq!(
+        test.contract.get_order(&order_id).unwrap().status,
+        OrderStatus::Delivered
+    );
+}
+
+#[test]
+fn test_buyer_reclaims_unshipped_order() {
+    let test = MarketTest::setup();
+    test.contract
+        .set_fulfillment_windows(&test.admin, &50, &100);
+    let item_id = test.list(100, 5);
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &2);
+
+    test.advance(50);
+    assert!(test
+        .contract
+        .try_reclaim_order(&test.buyer, &order_id)
+        .is_err());
+
+    test.advance(1);
+    test.contract.reclaim_order(&test.buyer, &order_id);
+
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+    assert_eq!(test.token.balance(&test.seller), 0);
+    assert_eq!(test.contract.get_items().get(0).unwrap().quantity, 5);
+    assert_eq!(
+        test.contract.get_order(&order_id).unwrap().status,
+        OrderStatus::Refunded
+    );
+}
+
+#[test]
+#[should_panic(expected = "Shipping deadline passed")]
+fn test_cannot_ship_after_deadline() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &1);
+
+    test.advance(order::DEFAULT_SHIP_WINDOW + 1);
+    test.ship(order_id);
+}
+
+#[test]
+#[should_panic(expected = "Not the seller")]
+fn test_only_seller_can_ship() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &1);
+
+    test.contract.mark_shipped(
+        &test.buyer,
+        &order_id,
+        &BytesN::from_array(&test.env, &[7; 32]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Order not shipped")]
+fn test_cannot_confirm_unshipped_order() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &1);
+
+    test.contract.confirm_delivery(&test.buyer, &order_id);
+}
+
+#[test]
+#[should_panic(expected = "Order not paid")]
+fn test_cannot_reclaim_shipped_order() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &1);
+    test.ship(order_id);
+
+    test.advance(order::DEFAULT_SHIP_WINDOW + 1);
+    test.contract.reclaim_order(&test.buyer, &order_id);
+}
+
+#[test]
+fn test_return_refunds_buyer_and_restocks() {
+    let test = MarketTest::setup();
+    let (item_id, order_id) = test.delivered_with_returns(1_000);
+    assert_eq!(test.contract.get_return_window(&item_id), 1_000);
+
+    // The payment stays held while the buyer can still return the item
+    let order = test.contract.get_order(&order_id).unwrap();
+    assert_eq!(order.return_window, 1_000);
+    assert_eq!(order.return_by, Some(test.env.ledger().timestamp() + 1_000));
+    assert!(!order.seller_paid);
+    assert_eq!(test.token.balance(&test.seller), 0);
+
+    let reason = String::from_str(&test.env, "Arrived damaged");
+    test.contract
+        .request_return(&test.buyer, &order_id, &reason);
+    let order = test.contract.get_order(&order_id).unwrap();
+    assert_eq!(order.status, OrderStatus::ReturnRequested);
+    assert_eq!(order.return_reason, Some(reason));
+
+    test.contract.accept_return(&test.seller, &order_id);
+    assert_eq!(
+        test.contract.get_order(&order_id).unwrap().status,
+        OrderStatus::ReturnAccepted
+    );
+    assert_eq!(test.contract.get_items().get(0).unwrap().quantity, 3);
+
+    test.contract
+        .confirm_return_received(&test.seller, &order_id);
+
+    let (_, data) = test.event(symbol_short!("returned"));
+    let event = OrderEvent::from_val(&test.env, &data);
+    assert_eq!(event.order.status, OrderStatus::Refunded);
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+    assert_eq!(test.token.balance(&test.seller), 0);
+    assert_eq!(test.contract.get_items().get(0).unwrap().quantity, 5);
+}
+
+#[test]
+fn test_seller_paid_after_return_window() {
+    let test = MarketTest::setup();
+    let (_, order_id) = test.delivered_with_returns(1_000);
+
+    test.advance(1_000);
+    assert!(test.contract.try_release_order(&order_id).is_err());
+
+    test.advance(1);
+    test.contract.release_order(&order_id);
+
+    assert_eq!(test.token.balance(&test.seller), 200);
+    assert!(test.contract.get_order(&order_id).unwrap().seller_paid);
+    assert!(test.contract.try_release_order(&order_id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Return window closed")]
+fn test_cannot_request_return_after_window() {
+    let test = MarketTest::setup();
+    let (_, order_id) = test.delivered_with_returns(1_000);
+
+    test.advance(1_001);
+    test.contract.request_return(
+        &test.buyer,
+        &order_id,
+        &String::from_str(&test.env, "Changed my mind"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Order not returnable")]
+fn test_cannot_return_without_window() {
+    let test = MarketTest::setup();
+    let (_, order_id) = test.delivered_with_returns(0);
+
+    test.contract.request_return(
+        &test.buyer,
+        &order_id,
+        &String::from_str(&test.env, "Changed my mind"),
+    );
+}
+
+#[test]
+fn test_rejected_return_is_resolved_by_arbiter() {
+    let test = MarketTest::setup();
+    let arbiter = Address::generate(&test.env);
+    test.contract
+        .grant_role(&test.admin, &Role::Arbiter, &arbiter);
+    let reason = String::from_str(&test.env, "Not as described");
+
+    // The arbiter sides with the seller
+    let (_, order_id) = test.delivered_with_returns(1_000);
+    test.contract
+        .request_return(&test.buyer, &order_id, &reason);
+    test.contract.reject_return(&test.seller, &order_id);
+    assert_eq!(
+        test.contract.get_order(&order_id).unwrap().status,
+        OrderStatus::Disputed
+    );
+    assert!(test
+        .contract
+        .try_resolve_return(&test.seller, &order_id, &true)
+        .is_err());
+
+    test.contract.resolve_return(&arbiter, &order_id, &false);
+    let order = test.contract.get_order(&order_id).unwrap();
+    assert_eq!(order.status, OrderStatus::Delivered);
+    assert!(order.seller_paid);
+    assert_eq!(test.token.balance(&test.seller), 200);
+
+    // The arbiter sides with the buyer
+    let order_id = test.contract.buy_item(&test.buyer, &order.item_id, &1);
+    test.ship(order_id);
+    test.contract.confirm_delivery(&test.buyer, &order_id);
+    test.contract
+        .request_return(&test.buyer, &order_id, &reason);
+    test.contract.reject_return(&test.seller, &order_id);
+
+    test.contract.resolve_return(&arbiter, &order_id, &true);
+    assert_eq!(
+        test.contract.get_order(&order_id).unwrap().status,
+        OrderStatus::Refunded
+    );
+    assert_eq!(test.token.balance(&test.buyer), 9_800);
+    assert_eq!(test.token.balance(&test.seller), 200);
+}
+
+#[test]
+fn test_unanswered_return_can_be_escalated() {
+    let test = MarketTest::setup();
+    let (_, order_id) = test.delivered_with_returns(1_000);
+    test.contract.request_return(
+        &test.buyer,
+        &order_id,
+        &String::from_str(&test.env, "Arrived damaged"),
+    );
+    let order = test.contract.get_order(&order_id).unwrap();
     assert_eq!(
-        words,
-        vec![
-            &env,
-            String::from_str(&env, "Hello"),
-            String::from_str(&env, "Dev"),
-        ]
+        order.respond_by,
+        Some(test.env.ledger().timestamp() + returns::RESPONSE_WINDOW)
     );
+
+    test.advance(returns::RESPONSE_WINDOW);
+    assert!(test
+        .contract
+        .try_escalate_return(&test.buyer, &order_id)
+        .is_err());
+    test.advance(1);
+    assert!(test
+        .contract
+        .try_escalate_return(&test.seller, &order_id)
+        .is_err());
+    test.contract.escalate_return(&test.buyer, &order_id);
+
+    let (_, data) = test.event(symbol_short!("ret_esc"));
+    let event = OrderEvent::from_val(&test.env, &data);
+    assert_eq!(event.order.status, OrderStatus::Disputed);
+    assert_eq!(event.order.respond_by, None);
+    assert!(test
+        .contract
+        .try_accept_return(&test.seller, &order_id)
+        .is_err());
+
+    // The arbiter can now settle it
+    test.contract.resolve_return(&test.admin, &order_id, &true);
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+}
+
+#[test]
+fn test_unconfirmed_return_can_be_escalated() {
+    let test = MarketTest::setup();
+    let (_, order_id) = test.delivered_with_returns(1_000);
+    test.contract.request_return(
+        &test.buyer,
+        &order_id,
+        &String::from_str(&test.env, "Arrived damaged"),
+    );
+    test.advance(10);
+    test.contract.accept_return(&test.seller, &order_id);
+    assert_eq!(
+        test.contract.get_order(&order_id).unwrap().respond_by,
+        Some(test.env.ledger().timestamp() + returns::RECEIPT_WINDOW)
+    );
+
+    // Answering resets the deadline to the receipt window
+    test.advance(returns::RESPONSE_WINDOW + 1);
+    assert!(test
+        .contract
+        .try_escalate_return(&test.buyer, &order_id)
+        .is_err());
+    test.advance(returns::RECEIPT_WINDOW - returns::RESPONSE_WINDOW);
+    test.contract.escalate_return(&test.buyer, &order_id);
+
+    assert_eq!(
+        test.contract.get_order(&order_id).unwrap().status,
+        OrderStatus::Disputed
+    );
+    assert!(test
+        .contract
+        .try_confirm_return_received(&test.seller, &order_id)
+        .is_err());
+}
+
+#[test]
+fn test_reading_an_order_keeps_it_alive() {
+    let test = MarketTest::setup();
+    let (_, order_id) = test.delivered_with_returns(1_000);
+    test.contract.request_return(
+        &test.buyer,
+        &order_id,
+        &String::from_str(&test.env, "Arrived damaged"),
+    );
+    let order_ttl = || {
+        test.env.as_contract(&test.contract.address, || {
+            test.env
+                .storage()
+                .persistent()
+                .get_ttl(&(symbol_short!("ORDER"), order_id))
+        })
+    };
+
+    // A return waiting on the seller is only ever read until someone acts on it
+    for _ in 0..3 {
+        test.env
+            .ledger()
+            .with_mut(|l| l.sequence_number += BUMP_AMOUNT - LIFETIME_THRESHOLD + 1);
+        assert_eq!(
+            test.contract.get_order(&order_id).unwrap().status,
+            OrderStatus::ReturnRequested
+        );
+        assert_eq!(order_ttl(), BUMP_AMOUNT);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Not the seller")]
+fn test_only_seller_sets_return_window() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+
+    test.contract
+        .set_return_window(&test.buyer, &item_id, &1_000);
+}
+
+#[test]
+fn test_outbid_bidder_is_refunded() {
+    let test = MarketTest::setup();
+    let rival = test.bidder();
+    let auction_id = test.auction();
+
+    test.contract.place_bid(&test.buyer, &auction_id, &400);
+    assert_eq!(test.token.balance(&test.buyer), 9_600);
+    assert_eq!(test.token.balance(&test.contract.address), 400);
+
+    // Must beat the highest bid by the increment
+    assert!(test
+        .contract
+        .try_place_bid(&rival, &auction_id, &449)
+        .is_err());
+    test.contract.place_bid(&rival, &auction_id, &450);
+
+    let (_, data) = test.last_event();
+    let event = BidEvent::from_val(&test.env, &data);
+    assert_eq!(event.outbid, Some(test.buyer.clone()));
+    assert_eq!(event.refund, 400);
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+    assert_eq!(test.token.balance(&rival), 9_550);
+    assert_eq!(test.token.balance(&test.contract.address), 450);
+
+    let auction = test.contract.get_auction(&auction_id).unwrap();
+    assert_eq!(auction.highest_bid, 450);
+    assert_eq!(auction.highest_bidder, Some(rival));
+    assert_eq!(auction.bid_count, 2);
+}
+
+#[test]
+fn test_late_bid_extends_auction() {
+    let test = MarketTest::setup();
+    let auction_id = test.auction();
+    let end_time = test.contract.get_auction(&auction_id).unwrap().end_time;
+
+    test.advance(3_600 - auction::SNIPING_WINDOW);
+    test.contract.place_bid(&test.buyer, &auction_id, &500);
+    assert_eq!(
+        test.contract.get_auction(&auction_id).unwrap().end_time,
+        end_time
+    );
+
+    test.advance(auction::SNIPING_WINDOW - 10);
+    test.contract.place_bid(&test.bidder(), &auction_id, &550);
+    assert_eq!(
+        test.contract.get_auction(&auction_id).unwrap().end_time,
+        end_time + auction::SNIPING_WINDOW - 10
+    );
+
+    // The original end time no longer settles the auction
+    test.advance(10);
+    assert!(test.contract.try_settle_auction(&auction_id).is_err());
+}
+
+#[test]
+fn test_settle_places_order_for_winning_bid() {
+    let test = MarketTest::setup();
+    let treasury = Address::generate(&test.env);
+    test.contract.set_fee(&test.admin, &300, &treasury); // 3%
+    let auction_id = test.auction();
+    test.contract.place_bid(&test.buyer, &auction_id, &1_000);
+
+    assert!(test.contract.try_settle_auction(&auction_id).is_err());
+    test.advance(3_600);
+    test.contract.settle_auction(&auction_id);
+
+    let (topics, data) = test.last_event();
+    assert_eq!(
+        Address::from_val(&test.env, &topics.get(3).unwrap()),
+        test.contract.address
+    );
+    let event = AuctionSoldEvent::from_val(&test.env, &data);
+    assert_eq!(
+        test.contract.get_auction(&auction_id).unwrap().status,
+        AuctionStatus::Sold
+    );
+    assert!(test.contract.try_settle_auction(&auction_id).is_err());
+
+    // The lot is listed at the winning bid and sold out to the bidder
+    let order = test.contract.get_order(&event.order_id).unwrap();
+    assert_eq!(order.buyer, test.buyer);
+    assert_eq!(order.seller, test.seller);
+    assert_eq!((order.qty, order.total, order.fee), (1, 1_000, 30));
+    assert_eq!(order.status, OrderStatus::Paid);
+    let lot = test.contract.get_items().get(0).unwrap();
+    assert_eq!(lot.id, order.item_id);
+    assert_eq!((lot.price, lot.quantity), (1_000, 0));
+
+    // The bid stays held until the order is delivered
+    assert_eq!(test.token.balance(&test.seller), 0);
+    assert_eq!(test.token.balance(&test.contract.address), 1_000);
+    test.ship(order.id);
+    test.contract.confirm_delivery(&test.buyer, &order.id);
+    assert_eq!(test.token.balance(&test.seller), 970);
+    assert_eq!(test.contract.get_collected_fees(&test.token.address), 30);
+}
+
+#[test]
+fn test_unshipped_auction_win_is_refunded() {
+    let test = MarketTest::setup();
+    let auction_id = test.auction();
+    test.contract.place_bid(&test.buyer, &auction_id, &1_000);
+    test.advance(3_600);
+    test.contract.settle_auction(&auction_id);
+
+    let (_, data) = test.last_event();
+    let order_id = AuctionSoldEvent::from_val(&test.env, &data).order_id;
+    test.advance(test.contract.get_fulfillment_config().ship_window + 1);
+    test.contract.reclaim_order(&test.buyer, &order_id);
+
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+    assert_eq!(test.token.balance(&test.seller), 0);
+    assert_eq!(test.contract.get_items().get(0).unwrap().quantity, 1);
+}
+
+#[test]
+fn test_settle_refunds_bid_seller_may_no_longer_accept() {
+    let test = MarketTest::setup();
+    let auction_id = test.auction();
+    test.contract.place_bid(&test.buyer, &auction_id, &1_000);
+
+    // The policy tightens before the auction ends
+    test.price_tier(1_000);
+    test.advance(3_600);
+    test.contract.settle_auction(&auction_id);
+
+    assert_eq!(
+        test.contract.get_auction(&auction_id).unwrap().status,
+        AuctionStatus::Unsold
+    );
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+    assert_eq!(test.token.balance(&test.contract.address), 0);
+    assert_eq!(test.contract.get_items().len(), 0);
+}
+
+#[test]
+fn test_auctions_are_read_a_page_at_a_time() {
+    let test = MarketTest::setup();
+    for _ in 0..index::MAX_PAGE_SIZE + 2 {
+        test.auction();
+    }
+    assert_eq!(test.contract.get_auction_count(), index::MAX_PAGE_SIZE + 2);
+
+    let first = test.contract.get_auctions(&0, &u32::MAX);
+    assert_eq!(first.len(), index::MAX_PAGE_SIZE);
+    assert_eq!(first.get(0).unwrap().id, 1);
+    let rest = test
+        .contract
+        .get_auctions(&index::MAX_PAGE_SIZE, &index::MAX_PAGE_SIZE);
+    assert_eq!(rest.len(), 2);
+    assert_eq!(rest.get(1).unwrap().id, index::MAX_PAGE_SIZE + 2);
+    assert!(test.contract.get_auctions(&u32::MAX, &10).is_empty());
+}
+
+#[test]
+fn test_auction_settles_after_its_token_is_disallowed() {
+    let test = MarketTest::setup();
+    let auction_id = test.auction();
+    test.contract.place_bid(&test.buyer, &auction_id, &1_000);
+
+    test.contract
+        .disallow_token(&test.admin, &test.token.address);
+    assert!(test
+        .contract
+        .try_place_bid(&test.bidder(), &auction_id, &1_100)
+        .is_err());
+
+    // The winning bid is already held, so the win still becomes an order
+    test.advance(3_600);
+    test.contract.settle_auction(&auction_id);
+    let (_, data) = test.last_event();
+    let order_id = AuctionSoldEvent::from_val(&test.env, &data).order_id;
+    assert_eq!(
+        test.contract.get_order(&order_id).unwrap().status,
+        OrderStatus::Paid
+    );
+    assert_eq!(
+        test.contract.get_auction(&auction_id).unwrap().status,
+        AuctionStatus::Sold
+    );
+}
+
+#[test]
+fn test_auction_under_reserve_refunds_bidder() {
+    let test = MarketTest::setup();
+    let auction_id = test.auction();
+    test.contract.place_bid(&test.buyer, &auction_id, &450);
+
+    test.advance(3_600);
+    test.contract.settle_auction(&auction_id);
+
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+    assert_eq!(test.token.balance(&test.seller), 0);
+    assert_eq!(
+        test.contract.get_auction(&auction_id).unwrap().status,
+        AuctionStatus::Unsold
+    );
+}
+
+#[test]
+#[should_panic(expected = "Auction ended")]
+fn test_cannot_bid_after_end() {
+    let test = MarketTest::setup();
+    let auction_id = test.auction();
+
+    test.advance(3_600);
+    test.contract.place_bid(&test.buyer, &auction_id, &500);
+}
+
+#[test]
+#[should_panic(expected = "Seller cannot bid")]
+fn test_seller_cannot_bid() {
+    let test = MarketTest::setup();
+    let auction_id = test.auction();
+
+    test.contract.place_bid(&test.seller, &auction_id, &500);
+}
+
+#[test]
+fn test_dutch_auction_linear_price() {
+    let test = MarketTest::setup();
+    let item_id = test.list(1_000, 1);
+    test.dutch(item_id, PriceCurve::Linear);
+    let start = test.env.ledger().timestamp();
+
+    assert_eq!(test.contract.get_price_at(&item_id, &start), 1_000);
+    assert_eq!(test.contract.get_price_at(&item_id, &(start + 1)), 999);
+    assert_eq!(test.contract.get_price_at(&item_id, &(start + 300)), 700);
+    assert_eq!(test.contract.get_price_at(&item_id, &(start + 600)), 400);
+    assert_eq!(test.contract.get_price_at(&item_id, &(start + 9_999)), 400);
+
+    // The first buyer pays the price at the purchase
+    test.advance(150);
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &1);
+    let order = test.contract.get_order(&order_id).unwrap();
+    assert_eq!(order.unit_price, 850);
+    assert_eq!(order.total, 850);
+    assert_eq!(test.token.balance(&test.buyer), 9_150);
+    assert!(test
+        .contract
+        .try_buy_item(&test.buyer, &item_id, &1)
+        .is_err());
+}
+
+#[test]
+fn test_dutch_auction_stepwise_price() {
+    let test = MarketTest::setup();
+    let item_id = test.list(1_000, 1);
+    test.dutch(item_id, PriceCurve::Stepwise(3));
+    let start = test.env.ledger().timestamp();
+
+    let (_, data) = test.last_event();
+    let event = DutchAuctionEvent::from_val(&test.env, &data);
+    assert_eq!(
+        Some(event.auction),
+        test.contract.get_dutch_auction(&item_id)
+    );
+
+    assert_eq!(test.contract.get_price_at(&item_id, &(start + 199)), 1_000);
+    assert_eq!(test.contract.get_price_at(&item_id, &(start + 200)), 800);
+    assert_eq!(test.contract.get_price_at(&item_id, &(start + 399)), 800);
+    assert_eq!(test.contract.get_price_at(&item_id, &(start + 400)), 600);
+    assert_eq!(test.contract.get_price_at(&item_id, &(start + 600)), 400);
+}
+
+#[test]
+fn test_fixed_price_listing_has_no_curve() {
+    let test = MarketTest::setup();
+    let item_id = test.list(1_000, 1);
+
+    assert_eq!(test.contract.get_dutch_auction(&item_id), None);
+    assert_eq!(test.contract.get_price_at(&item_id, &u64::MAX), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Invalid price range")]
+fn test_dutch_floor_must_be_below_start() {
+    let test = MarketTest::setup();
+    let item_id = test.list(1_000, 1);
+    let now = test.env.ledger().timestamp();
+
+    test.contract.set_dutch_auction(
+        &test.seller,
+        &DutchAuction {
+            item_id,
+            start_price: 400,
+            floor_price: 400,
+            start_time: now,
+            end_time: now + 600,
+            curve: PriceCurve::Linear,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not the seller")]
+fn test_only_seller_sets_dutch_auction() {
+    let test = MarketTest::setup();
+    let item_id = test.list(1_000, 1);
+    let now = test.env.ledger().timestamp();
+
+    test.contract.set_dutch_auction(
+        &test.buyer,
+        &DutchAuction {
+            item_id,
+            start_price: 1_000,
+            floor_price: 400,
+            start_time: now,
+            end_time: now + 600,
+            curve: PriceCurve::Linear,
+        },
+    );
+}
+
+#[test]
+fn test_accepted_offer_buys_at_offered_price() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let expires_at = test.env.ledger().timestamp() + 600;
+    let offer_id = test
+        .contract
+        .make_offer(&test.buyer, &item_id, &2, &80, &expires_at);
+    assert_eq!(test.token.balance(&test.buyer), 9_840);
+    assert_eq!(test.token.balance(&test.contract.address), 160);
+
+    let order_id = test.contract.accept_offer(&test.seller, &offer_id);
+
+    let (_, data) = test.last_event();
+    let event = OfferEvent::from_val(&test.env, &data);
+    assert_eq!(event.offer.status, OfferStatus::Accepted);
+    assert_eq!(event.offer.order_id, Some(order_id));
+
+    let order = test.contract.get_order(&order_id).unwrap();
+    assert_eq!(order.buyer, test.buyer);
+    assert_eq!(order.unit_price, 80);
+    assert_eq!(order.total, 160);
+    assert_eq!(test.contract.get_items().get(0).unwrap().quantity, 3);
+    assert_eq!(test.token.balance(&test.contract.address), 160);
+
+    // The order is fulfilled like any other
+    test.ship(order_id);
+    test.contract.confirm_delivery(&test.buyer, &order_id);
+    assert_eq!(test.token.balance(&test.seller), 160);
+}
+
+#[test]
+fn test_counter_offer_accepted_by_buyer() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let expires_at = test.env.ledger().timestamp() + 600;
+    let offer_id = test
+        .contract
+        .make_offer(&test.buyer, &item_id, &2, &80, &expires_at);
+
+    assert!(test
+        .contract
+        .try_counter_offer(&test.seller, &offer_id, &80)
+        .is_err());
+    test.contract.counter_offer(&test.seller, &offer_id, &90);
+    assert!(test
+        .contract
+        .try_accept_offer(&test.seller, &offer_id)
+        .is_err());
+
+    let order_id = test.contract.accept_counter_offer(&test.buyer, &offer_id);
+
+    assert_eq!(test.token.balance(&test.buyer), 9_820);
+    assert_eq!(test.contract.get_order(&order_id).unwrap().total, 180);
+    let offer = test.contract.get_offer(&offer_id).unwrap();
+    assert_eq!(offer.status, OfferStatus::Accepted);
+    assert_eq!(offer.escrowed, 180);
+}
+
+#[test]
+fn test_rejected_and_withdrawn_offers_are_refunded() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let expires_at = test.env.ledger().timestamp() + 600;
+    let rejected = test
+        .contract
+        .make_offer(&test.buyer, &item_id, &1, &60, &expires_at);
+    let withdrawn = test
+        .contract
+        .make_offer(&test.buyer, &item_id, &1, &70, &expires_at);
+    assert_eq!(test.token.balance(&test.buyer), 9_870);
+
+    test.contract.reject_offer(&test.seller, &rejected);
+    test.contract.counter_offer(&test.seller, &withdrawn, &95);
+    test.contract.withdraw_offer(&test.buyer, &withdrawn);
+
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+    let offers = test.contract.get_item_offers(&item_id, &0, &10);
+    assert_eq!(offers.get(0).unwrap().status, OfferStatus::Rejected);
+    assert_eq!(offers.get(1).unwrap().status, OfferStatus::Withdrawn);
+    assert!(test
+        .contract
+        .try_withdraw_offer(&test.buyer, &rejected)
+        .is_err());
+}
+
+#[test]
+fn test_item_offers_are_read_a_page_at_a_time() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let expires_at = test.env.ledger().timestamp() + 600;
+    for _ in 0..index::MAX_PAGE_SIZE + 2 {
+        test.contract
+            .make_offer(&test.buyer, &item_id, &1, &60, &expires_at);
+    }
+
+    assert_eq!(
+        test.contract.get_item_offer_count(&item_id),
+        index::MAX_PAGE_SIZE + 2
+    );
+    let page = test.contract.get_item_offers(&item_id, &0, &u32::MAX);
+    assert_eq!(page.len(), index::MAX_PAGE_SIZE);
+    let rest = test
+        .contract
+        .get_item_offers(&item_id, &index::MAX_PAGE_SIZE, &10);
+    assert_eq!(rest.len(), 2);
+    assert_eq!(rest.get(1).unwrap().id, u64::from(index::MAX_PAGE_SIZE) + 2);
+    assert_eq!(test.contract.get_item_offer_count(&(item_id + 1)), 0);
+}
+
+#[test]
+fn test_expired_offer_cannot_be_accepted() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let expires_at = test.env.ledger().timestamp() + 600;
+    let offer_id = test
+        .contract
+        .make_offer(&test.buyer, &item_id, &1, &80, &expires_at);
+
+    test.advance(600);
+    assert!(test
+        .contract
+        .try_accept_offer(&test.seller, &offer_id)
+        .is_err());
+
+    // The buyer can still take the funds back
+    test.contract.withdraw_offer(&test.buyer, &offer_id);
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Offer must be below the list price")]
+fn test_offer_must_be_below_list_price() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let expires_at = test.env.ledger().timestamp() + 600;
+
+    test.contract
+        .make_offer(&test.buyer, &item_id, &1, &100, &expires_at);
+}
+
+#[test]
+#[should_panic(expected = "Not the seller")]
+fn test_only_seller_accepts_offer() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let expires_at = test.env.ledger().timestamp() + 600;
+    let offer_id = test
+        .contract
+        .make_offer(&test.buyer, &item_id, &1, &80, &expires_at);
+
+    test.contract.accept_offer(&test.buyer, &offer_id);
+}
+
+#[test]
+fn test_buy_item_in_second_token() {
+    let test = MarketTest::setup();
+    let usdc = test.second_token();
+    let treasury = Address::generate(&test.env);
+    test.contract.set_fee(&test.admin, &300, &treasury); // 3%
+    test.contract.allow_token(&test.admin, &usdc.address);
+    assert_eq!(
+        test.contract.get_accepted_tokens(),
+        soroban_sdk::vec![&test.env, test.token.address.clone(), usdc.address.clone()]
+    );
+
+    let item_id = test.list(100, 5);
+    test.contract
+        .set_item_price(&test.seller, &item_id, &usdc.address, &40);
+    let prices = test.contract.get_item_prices(&item_id);
+    assert_eq!(prices.get(test.token.address.clone()), Some(100));
+    assert_eq!(prices.get(usdc.address.clone()), Some(40));
+
+    let order_id = test
+        .contract
+        .buy_item_with_token(&test.buyer, &item_id, &2, &usdc.address);
+
+    let (_, data) = test.event(symbol_short!("buy"));
+    let event = PurchaseEvent::from_val(&test.env, &data);
+    assert_eq!(event.payment_token, usdc.address);
+    let order = test.contract.get_order(&order_id).unwrap();
+    assert_eq!(order.payment_token, usdc.address);
+    assert_eq!(order.total, 80);
+    assert_eq!(usdc.balance(&test.buyer), 9_920);
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+
+    // The seller is paid and the fee accrued in the token the buyer paid with
+    test.ship(order_id);
+    test.contract.confirm_delivery(&test.buyer, &order_id);
+    assert_eq!(usdc.balance(&test.seller), 78);
+    assert_eq!(test.contract.get_collected_fees(&usdc.address), 2);
+    assert_eq!(test.contract.get_collected_fees(&test.token.address), 0);
+}
+
+#[test]
+fn test_disallowed_token_is_rejected() {
+    let test = MarketTest::setup();
+    let usdc = test.second_token();
+    let item_id = test.list(100, 5);
+
+    // Not on the allowlist yet
+    assert!(test
+        .contract
+        .try_set_item_price(&test.seller, &item_id, &usdc.address, &40)
+        .is_err());
+
+    test.contract.allow_token(&test.admin, &usdc.address);
+    test.contract
+        .set_item_price(&test.seller, &item_id, &usdc.address, &40);
+    test.contract.disallow_token(&test.admin, &usdc.address);
+
+    assert!(test
+        .contract
+        .try_buy_item_with_token(&test.buyer, &item_id, &1, &usdc.address)
+        .is_err());
+    assert_eq!(usdc.balance(&test.buyer), 10_000);
+}
+
+#[test]
+fn test_seller_can_drop_any_price_but_the_last() {
+    let test = MarketTest::setup();
+    let usdc = test.second_token();
+    test.contract.allow_token(&test.admin, &usdc.address);
+    let item_id = test.list(100, 5);
+    test.contract
+        .set_item_price(&test.seller, &item_id, &usdc.address, &40);
+
+    test.contract
+        .remove_item_price(&test.seller, &item_id, &usdc.address);
+    assert!(test
+        .contract
+        .try_buy_item_with_token(&test.buyer, &item_id, &1, &usdc.address)
+        .is_err());
+
+    // Dropping the primary price makes the next one primary
+    test.contract
+        .set_item_price(&test.seller, &item_id, &usdc.address, &40);
+    test.contract
+        .remove_item_price(&test.seller, &item_id, &test.token.address);
+    let item = test.contract.get_items().get(0).unwrap();
+    assert_eq!((item.token, item.price), (usdc.address.clone(), 40));
+    test.contract.buy_item(&test.buyer, &item_id, &1);
+    assert_eq!(usdc.balance(&test.buyer), 9_960);
+    assert_eq!(test.token.balance(&test.buyer), 10_000);
+
+    assert!(test
+        .contract
+        .try_remove_item_price(&test.seller, &item_id, &usdc.address)
+        .is_err());
+}
+
+#[test]
+fn test_list_item_only_in_second_token() {
+    let test = MarketTest::setup();
+    let usdc = test.second_token();
+    let name = String::from_str(&test.env, "Guitar");
+    let image_url = String::from_str(&test.env, "ipfs://guitar");
+
+    // Not on the allowlist yet
+    assert!(test
+        .contract
+        .try_list_item_with_token(&test.seller, &name, &40, &5, &image_url, &usdc.address)
+        .is_err());
+    test.contract.allow_token(&test.admin, &usdc.address);
+    let item_id =
+        test.contract
+            .list_item_with_token(&test.seller, &name, &40, &5, &image_url, &usdc.address);
+
+    let item = test.contract.get_items().get(0).unwrap();
+    assert_eq!((item.token, item.price), (usdc.address.clone(), 40));
+    assert_eq!(
+        test.contract.get_item_prices(&item_id),
+        Map::from_array(&test.env, [(usdc.address.clone(), 40)])
+    );
+    assert_eq!(test.contract.get_price_at(&item_id, &0), 40);
+    assert!(test
+        .contract
+        .try_buy_item_with_token(&test.buyer, &item_id, &1, &test.token.address)
+        .is_err());
+
+    // Purchases and offers default to the listing's token
+    test.contract.buy_item(&test.buyer, &item_id, &1);
+    assert_eq!(usdc.balance(&test.buyer), 9_960);
+    let offer_id = test.contract.make_offer(
+        &test.buyer,
+        &item_id,
+        &1,
+        &30,
+        &(test.env.ledger().timestamp() + 3_600),
+    );
+    assert_eq!(
+        test.contract.get_offer(&offer_id).unwrap().payment_token,
+        usdc.address
+    );
+    assert_eq!(usdc.balance(&test.buyer), 9_930);
+}
+
+#[test]
+#[should_panic(expected = "Item not priced in token")]
+fn test_cannot_buy_in_unpriced_token() {
+    let test = MarketTest::setup();
+    let usdc = test.second_token();
+    test.contract.allow_token(&test.admin, &usdc.address);
+    let item_id = test.list(100, 5);
+
+    test.contract
+        .buy_item_with_token(&test.buyer, &item_id, &1, &usdc.address);
+}
+
+#[test]
+fn test_only_admin_curates_tokens() {
+    let test = MarketTest::setup();
+    let usdc = test.second_token();
+
+    assert!(test
+        .contract
+        .try_allow_token(&test.seller, &usdc.address)
+        .is_err());
+}
+
+#[test]
+fn test_reference_price_settles_at_oracle_price() {
+    let test = MarketTest::setup();
+    test.env.ledger().set_timestamp(1_700_000_000);
+    let oracle = test.oracle();
+    // 1 token = $0.50
+    oracle.set_price(&test.token.address, &50_000_000_000_000, &1_699_999_940);
+    let item_id = test.list(100, 5);
+
+    // $5.00, with 7 decimals
+    test.contract
+        .set_reference_price(&test.seller, &item_id, &Some(50_000_000));
+
+    let (topics, data) = test.last_event();
+    assert_eq!(
+        Symbol::from_val(&test.env, &topics.get(1).unwrap()),
+        symbol_short!("ref_price")
+    );
+    let event = ReferencePriceEvent::from_val(&test.env, &data);
+    assert_eq!(event.item_id, item_id);
+    assert_eq!(event.reference_price, Some(50_000_000));
+    assert_eq!(
+        test.contract.get_reference_price(&item_id),
+        Some(50_000_000)
+    );
+
+    // 10 tokens of 7 decimals each
+    let now = test.env.ledger().timestamp();
+    assert_eq!(test.contract.get_price_at(&item_id, &now), 100_000_000);
+    let order_id = test.contract.buy_item_with_limit(
+        &test.buyer,
+        &item_id,
+        &2,
+        &test.token.address,
+        &200_000_000,
+    );
+
+    let order = test.contract.get_order(&order_id).unwrap();
+    assert_eq!(order.unit_price, 100_000_000);
+    assert_eq!(order.total, 200_000_000);
+    assert_eq!(test.token.balance(&test.contract.address), 200_000_000);
+
+    // Clearing the reference price restores the listed price
+    test.contract
+        .set_reference_price(&test.seller, &item_id, &None);
+    assert_eq!(test.contract.get_price_at(&item_id, &now), 100);
+}
+
+#[test]
+fn test_reference_price_conversion_rounds_up() {
+    let test = MarketTest::setup();
+    let oracle = test.oracle();
+    // 1 token = $3
+    let now = test.env.ledger().timestamp();
+    oracle.set_price(&test.token.address, &300_000_000_000_000, &now);
+    let item_id = test.list(100, 5);
+
+    // $10 is 3.3333333... tokens
+    test.contract
+        .set_reference_price(&test.seller, &item_id, &Some(100_000_000));
+
+    assert_eq!(test.contract.get_price_at(&item_id, &now), 33_333_334);
+}
+
+#[test]
+#[should_panic(expected = "Oracle price stale")]
+fn test_stale_oracle_price_is_rejected() {
+    let test = MarketTest::setup();
+    let oracle = test.oracle();
+    let now = test.env.ledger().timestamp();
+    oracle.set_price(&test.token.address, &50_000_000_000_000, &now);
+    let item_id = test.list(100, 5);
+    test.contract
+        .set_reference_price(&test.seller, &item_id, &Some(50_000_000));
+
+    test.advance(601);
+    test.contract.buy_item_with_limit(
+        &test.buyer,
+        &item_id,
+        &1,
+        &test.token.address,
+        &1_000_000_000,
+    );
+}
+
+#[test]
+fn test_oracle_config_is_validated() {
+    let test = MarketTest::setup();
+    let oracle = test.oracle();
+    let config = test.contract.get_oracle_config().unwrap();
+
+    // The oracle quotes in USD, so reference prices cannot be read as EUR
+    let eur = OracleConfig {
+        base: Asset::Other(symbol_short!("EUR")),
+        ..config.clone()
+    };
+    assert!(test.contract.try_set_oracle(&test.admin, &eur).is_err());
+    let unbounded = OracleConfig {
+        max_deviation_bps: 0,
+        ..config.clone()
+    };
+    assert!(test
+        .contract
+        .try_set_oracle(&test.admin, &unbounded)
+        .is_err());
+
+    // The longest possible age cannot overflow the staleness check
+    let patient = OracleConfig {
+        max_age: u64::MAX,
+        ..config
+    };
+    test.contract.set_oracle(&test.admin, &patient);
+    let now = test.env.ledger().timestamp();
+    oracle.set_price(&test.token.address, &50_000_000_000_000, &now);
+    let item_id = test.list(100, 5);
+    test.contract
+        .set_reference_price(&test.seller, &item_id, &Some(50_000_000));
+    test.advance(1_000_000);
+    test.contract.buy_item_with_limit(
+        &test.buyer,
+        &item_id,
+        &1,
+        &test.token.address,
+        &1_000_000_000,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Oracle price deviation too high")]
+fn test_oracle_price_jump_is_rejected() {
+    let test = MarketTest::setup();
+    let oracle = test.oracle();
+    let now = test.env.ledger().timestamp();
+    oracle.set_price(&test.token.address, &50_000_000_000_000, &now);
+    // An 11% jump from the previous quote
+    oracle.set_price(&test.token.address, &55_500_000_000_000, &now);
+    let item_id = test.list(100, 5);
+    test.contract
+        .set_reference_price(&test.seller, &item_id, &Some(50_000_000));
+
+    test.contract.buy_item_with_limit(
+        &test.buyer,
+        &item_id,
+        &1,
+        &test.token.address,
+        &1_000_000_000,
+    );
+}
+
+#[test]
+fn test_max_spend_guards_reference_priced_purchase() {
+    let test = MarketTest::setup();
+    let oracle = test.oracle();
+    let now = test.env.ledger().timestamp();
+    oracle.set_price(&test.token.address, &50_000_000_000_000, &now);
+    let item_id = test.list(100, 5);
+    test.contract
+        .set_reference_price(&test.seller, &item_id, &Some(50_000_000));
+
+    // The buyer signed for 10 tokens, then the token fell 5% to $0.475
+    oracle.set_price(&test.token.address, &47_500_000_000_000, &now);
+    let balance = test.token.balance(&test.buyer);
+    assert!(test
+        .contract
+        .try_buy_item_with_limit(&test.buyer, &item_id, &1, &test.token.address, &100_000_000)
+        .is_err());
+    // Purchases priced in USD need a limit
+    assert!(test
+        .contract
+        .try_buy_item(&test.buyer, &item_id, &1)
+        .is_err());
+    assert_eq!(test.token.balance(&test.buyer), balance);
+
+    test.contract
+        .buy_item_with_limit(&test.buyer, &item_id, &1, &test.token.address, &110_000_000);
+    assert_eq!(test.token.balance(&test.buyer), balance - 105_263_158);
+}
+
+#[test]
+fn test_only_seller_sets_reference_price() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+
+    // No oracle to convert with yet
+    assert!(test
+        .contract
+        .try_set_reference_price(&test.seller, &item_id, &Some(50_000_000))
+        .is_err());
+
+    test.oracle();
+    assert!(test
+        .contract
+        .try_set_reference_price(&test.buyer, &item_id, &Some(50_000_000))
+        .is_err());
+    assert!(test
+        .contract
+        .try_set_reference_price(&test.seller, &item_id, &Some(0))
+        .is_err());
+    assert_eq!(test.contract.get_reference_price(&item_id), None);
+}
+
+#[test]
+fn test_browse_items_by_category() {
+    let test = MarketTest::setup();
+    let name = |name| String::from_str(&test.env, name);
+    let instruments = test
+        .contract
+        .add_category(&test.admin, &name("Instruments"), &None);
+
+    let (topics, data) = test.last_event();
+    assert_eq!(
+        Symbol::from_val(&test.env, &topics.get(1).unwrap()),
+        symbol_short!("cat_add")
+    );
+    let event = CategoryEvent::from_val(&test.env, &data);
+    assert_eq!(event.category.name, name("Instruments"));
+
+    let guitars = test
+        .contract
+        .add_category(&test.admin, &name("Guitars"), &Some(instruments));
+    let electronics = test
+        .contract
+        .add_category(&test.admin, &name("Electronics"), &None);
+    assert_eq!(
+        test.contract.get_subcategories(&Some(instruments)),
+        soroban_sdk::vec![&test.env, test.contract.get_category(&guitars).unwrap()]
+    );
+    assert_eq!(test.contract.get_subcategories(&None).len(), 2);
+
+    let piano = test.list(900, 1);
+    let guitar = test.list(300, 2);
+    let radio = test.list(50, 4);
+    test.contract
+        .set_item_category(&test.seller, &piano, &Some(instruments));
+    test.contract
+        .set_item_category(&test.seller, &guitar, &Some(guitars));
+    test.contract
+        .set_item_category(&test.seller, &radio, &Some(electronics));
+
+    // Browsing a category includes its subcategories
+    let ids = |category_id| {
+        let mut ids = soroban_sdk::Vec::new(&test.env);
+        let items = test
+            .contract
+            .get_items_in_category(&category_id, &0, &index::MAX_PAGE_SIZE);
+        for item in items.iter() {
+            ids.push_back(item.id);
+        }
+        ids
+    };
+    assert_eq!(
+        ids(instruments),
+        soroban_sdk::vec![&test.env, piano, guitar]
+    );
+    assert_eq!(ids(guitars), soroban_sdk::vec![&test.env, guitar]);
+    assert_eq!(ids(electronics), soroban_sdk::vec![&test.env, radio]);
+    assert_eq!(test.contract.get_category_item_count(&instruments), 2);
+
+    // Pages run on from one subcategory into the next
+    let page = test.contract.get_items_in_category(&instruments, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, guitar);
+    assert!(test
+        .contract
+        .get_items_in_category(&instruments, &2, &10)
+        .is_empty());
+
+    // Moving a listing updates both indexes
+    test.contract
+        .set_item_category(&test.seller, &radio, &Some(instruments));
+    assert!(ids(electronics).is_empty());
+    assert_eq!(
+        ids(instruments),
+        soroban_sdk::vec![&test.env, piano, radio, guitar]
+    );
+    test.contract.set_item_category(&test.seller, &piano, &None);
+    assert_eq!(
+        ids(instruments),
+        soroban_sdk::vec![&test.env, radio, guitar]
+    );
+    test.contract.set_item_category(&test.seller, &radio, &None);
+    assert_eq!(test.contract.get_items().get(2).unwrap().category, None);
+    assert_eq!(ids(instruments), soroban_sdk::vec![&test.env, guitar]);
+    assert_eq!(test.contract.get_category_item_count(&instruments), 1);
+}
+
+#[test]
+fn test_item_tags_are_bounded() {
+    let test = MarketTest::setup();
+    let item_id = test.list(100, 5);
+    let tag = |tag| String::from_str(&test.env, tag);
+
+    test.contract.set_item_tags(
+        &test.seller,
+        &item_id,
+        &soroban_sdk::vec![&test.env, tag("vintage"), tag("acoustic")],
+    );
+    let (_, data) = test.last_event();
+    let event = ItemEvent::from_val(&test.env, &data);
+    assert_eq!(event.item.tags.len(), 2);
+    assert_eq!(
+        test.contract.get_items().get(0).unwrap().tags,
+        event.item.tags
+    );
+
+    let too_many = soroban_sdk::Vec::from_array(
+        &test.env,
+        ["a", "b", "c", "d", "e", "f", "g", "h", "i"].map(tag),
+    );
+    assert!(test
+        .contract
+        .try_set_item_tags(&test.seller, &item_id, &too_many)
+        .is_err());
+    let duplicate = soroban_sdk::vec![&test.env, tag("vintage"), tag("vintage")];
+    assert!(test
+        .contract
+        .try_set_item_tags(&test.seller, &item_id, &duplicate)
+        .is_err());
+    let empty = soroban_sdk::vec![&test.env, tag("")];
+    assert!(test
+        .contract
+        .try_set_item_tags(&test.seller, &item_id, &empty)
+        .is_err());
+    let long = soroban_sdk::vec![&test.env, tag("a-tag-that-is-longer-than-32-bytes")];
+    assert!(test
+        .contract
+        .try_set_item_tags(&test.seller, &item_id, &long)
+        .is_err());
+    assert!(test
+        .contract
+        .try_set_item_tags(&test.buyer, &item_id, &soroban_sdk::Vec::new(&test.env))
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Category too deep")]
+fn test_category_depth_is_bounded() {
+    let test = MarketTest::setup();
+    let name = String::from_str(&test.env, "Level");
+    let mut parent = None;
+    for _ in 0..category::MAX_CATEGORY_DEPTH {
+        parent = Some(test.contract.add_category(&test.admin, &name, &parent));
+    }
+
+    test.contract.add_category(&test.admin, &name, &parent);
+}
+
+#[test]
+fn test_remove_only_empty_category() {
+    let test = MarketTest::setup();
+    let name = String::from_str(&test.env, "Instruments");
+    let instruments = test.contract.add_category(&test.admin, &name, &None);
+    let guitars = test
+        .contract
+        .add_category(&test.admin, &name, &Some(instruments));
+    let item_id = test.list(100, 5);
+    test.contract
+        .set_item_category(&test.seller, &item_id, &Some(guitars));
+
+    assert!(test
+        .contract
+        .try_remove_category(&test.admin, &instruments)
+        .is_err());
+    assert!(test
+        .contract
+        .try_remove_category(&test.admin, &guitars)
+        .is_err());
+
+    test.contract
+        .set_item_category(&test.seller, &item_id, &None);
+    test.contract.remove_category(&test.admin, &guitars);
+    test.contract.remove_category(&test.admin, &instruments);
+    assert!(test.contract.get_categories().is_empty());
+}
+
+#[test]
+fn test_only_admin_manages_categories() {
+    let test = MarketTest::setup();
+    let name = String::from_str(&test.env, "Instruments");
+
+    assert!(test
+        .contract
+        .try_add_category(&test.seller, &name, &None)
+        .is_err());
+    let category_id = test.contract.add_category(&test.admin, &name, &None);
+    assert!(test
+        .contract
+        .try_rename_category(&test.seller, &category_id, &name)
+        .is_err());
+
+    // Only the seller classifies a listing, and only into an existing category
+    let item_id = test.list(100, 5);
+    assert!(test
+        .contract
+        .try_set_item_category(&test.buyer, &item_id, &Some(category_id))
+        .is_err());
+    assert!(test
+        .contract
+        .try_set_item_category(&test.seller, &item_id, &Some(category_id + 1))
+        .is_err());
+}
+
+#[test]
+fn test_registered_seller_is_paid_at_payout_address() {
+    let test = MarketTest::setup();
+    let payout = test.register_seller();
+
+    let (topics, data) = test.last_event();
+    assert_eq!(
+        Symbol::from_val(&test.env, &topics.get(1).unwrap()),
+        symbol_short!("seller")
+    );
+    let event = SellerEvent::from_val(&test.env, &data);
+    assert_eq!(event.profile.payout_address, payout);
+    assert_eq!(
+        test.contract.get_seller_profile(&test.seller),
+        Some(event.profile)
+    );
+    assert_eq!(test.contract.get_seller_profile(&test.buyer), None);
+
+    let item_id = test.list(100, 5);
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &2);
+    test.ship(order_id);
+    test.contract.confirm_delivery(&test.buyer, &order_id);
+
+    assert_eq!(test.token.balance(&payout), 200);
+    assert_eq!(test.token.balance(&test.seller), 0);
+}
+
+#[test]
+fn test_attester_verifies_seller() {
+    let test = MarketTest::setup();
+    let attester = test.attester();
+
+    // Only registered sellers can be verified
+    assert!(test
+        .contract
+        .try_verify_seller(&attester, &test.seller)
+        .is_err());
+    let payout = test.register_seller();
+    assert!(test
+        .contract
+        .try_verify_seller(&test.buyer, &test.seller)
+        .is_err());
+
+    test.env.ledger().set_timestamp(1_700_000_000);
+    test.contract.verify_seller(&attester, &test.seller);
+
+    let (_, data) = test.last_event();
+    let event = SellerEvent::from_val(&test.env, &data);
+    assert_eq!(event.profile.verified_by, Some(attester.clone()));
+    assert_eq!(event.profile.verified_at, Some(1_700_000_000));
+    assert!(test.contract.is_verified_seller(&test.seller));
+
+    // New metadata keeps the badge, a new display name drops it
+    test.contract.register_seller(
+        &test.seller,
+        &String::from_str(&test.env, "Guitar Shop"),
+        &String::from_str(&test.env, "ipfs://guitar-shop-v2"),
+        &BytesN::from_array(&test.env, &[9; 32]),
+        &payout,
+    );
+    assert!(test.contract.is_verified_seller(&test.seller));
+    test.contract.register_seller(
+        &test.seller,
+        &String::from_str(&test.env, "Totally Legit Guitars"),
+        &String::from_str(&test.env, "ipfs://guitar-shop-v2"),
+        &BytesN::from_array(&test.env, &[9; 32]),
+        &payout,
+    );
+    assert!(!test.contract.is_verified_seller(&test.seller));
+
+    // The admin holds every role
+    test.contract.verify_seller(&test.admin, &test.seller);
+    test.contract
+        .revoke_seller_verification(&attester, &test.seller);
+    assert!(!test.contract.is_verified_seller(&test.seller));
+}
+
+#[test]
+fn test_reserved_category_needs_verified_seller() {
+    let test = MarketTest::setup();
+    let attester = test.attester();
+    test.register_seller();
+    let name = String::from_str(&test.env, "Luxury");
+    let luxury = test.contract.add_category(&test.admin, &name, &None);
+    let watches = test
+        .contract
+        .add_category(&test.admin, &name, &Some(luxury));
+    test.contract.set_verification_policy(
+        &test.admin,
+        &VerificationPolicy {
+            categories: soroban_sdk::vec![&test.env, luxury],
+            min_prices: Map::new(&test.env),
+            min_reference_price: None,
+        },
+    );
+    let item_id = test.list(100, 5);
+
+    // Subcategories are reserved too
+    assert!(test
+        .contract
+        .try_set_item_category(&test.seller, &item_id, &Some(watches))
+        .is_err());
+
+    test.contract.verify_seller(&attester, &test.seller);
+    test.contract
+        .set_item_category(&test.seller, &item_id, &Some(watches));
+    test.contract.buy_item(&test.buyer, &item_id, &1);
+
+    // A revoked badge stops sales of listings already in the category
+    test.contract
+        .revoke_seller_verification(&attester, &test.seller);
+    assert!(test
+        .contract
+        .try_buy_item(&test.buyer, &item_id, &1)
+        .is_err());
+    assert_eq!(test.token.balance(&test.buyer), 9_900);
+}
+
+#[test]
+fn test_price_tier_needs_verified_seller() {
+    let test = MarketTest::setup();
+    test.price_tier(1_000);
+
+    assert!(test
+        .contract
+        .try_list_item(
+            &test.seller,
+            &String::from_str(&test.env, "Guitar"),
+            &1_000,
+            &1,
+            &String::from_str(&test.env, "ipfs://guitar"),
+        )
+        .is_err());
+    let item_id = test.list(999, 1);
+    assert!(test
+        .contract
+        .try_set_item_price(&test.seller, &item_id, &test.token.address, &1_500)
+        .is_err());
+    assert!(test
+        .contract
+        .try_create_auction(
+            &test.seller,
+            &String::from_str(&test.env, "Camera"),
+            &String::from_str(&test.env, "ipfs://camera"),
+            &1_000,
+            &50,
+            &(test.env.ledger().timestamp() + 3_600),
+        )
+        .is_err());
+
+    test.register_seller();
+    test.contract.verify_seller(&test.admin, &test.seller);
+    test.contract
+        .set_item_price(&test.seller, &item_id, &test.token.address, &1_500);
+}
+
+#[test]
+fn test_price_tier_covers_every_token_and_reference_price() {
+    let test = MarketTest::setup();
+    let usdc = test.second_token();
+    test.contract.allow_token(&test.admin, &usdc.address);
+    test.oracle();
+    let item_id = test.list(999, 2);
+    // Priced in the second token before the tier existed
+    test.contract
+        .set_item_price(&test.seller, &item_id, &usdc.address, &1_500);
+
+    test.contract.set_verification_policy(
+        &test.admin,
+        &VerificationPolicy {
+            categories: soroban_sdk::Vec::new(&test.env),
+            min_prices: Map::from_array(
+                &test.env,
+                [
+                    (test.token.address.clone(), 1_000),
+                    (usdc.address.clone(), 1_000),
+                ],
+            ),
+            min_reference_price: Some(1_000),
+        },
+    );
+
+    assert!(test
+        .contract
+        .try_set_item_price(&test.seller, &item_id, &usdc.address, &2_000)
+        .is_err());
+    assert!(test
+        .contract
+        .try_set_reference_price(&test.seller, &item_id, &Some(1_000))
+        .is_err());
+    // Sales are checked at the price actually charged
+    assert!(test
+        .contract
+        .try_buy_item_with_token(&test.buyer, &item_id, &1, &usdc.address)
+        .is_err());
+    test.contract.buy_item(&test.buyer, &item_id, &1);
+    // So is moving the listing into a category
+    let music =
+        test.contract
+            .add_category(&test.admin, &String::from_str(&test.env, "Music"), &None);
+    assert!(test
+        .contract
+        .try_set_item_category(&test.seller, &item_id, &Some(music))
+        .is_err());
+
+    test.register_seller();
+    test.contract.verify_seller(&test.admin, &test.seller);
+    test.contract
+        .buy_item_with_token(&test.buyer, &item_id, &1, &usdc.address);
+    test.contract
+        .set_item_category(&test.seller, &item_id, &Some(music));
+}
+
+#[test]
+fn test_price_tier_is_set_per_token() {
+    let test = MarketTest::setup();
+    let cents = test.env.register(mock_cents::MockCents, ());
+    let cents_client = mock_cents::MockCentsClient::new(&test.env, &cents);
+    cents_client.mint(&test.buyer, &1_000_000);
+    test.contract.allow_token(&test.admin, &cents);
+    // 1,000 of each token: 10^10 units of the 7-decimal payment token, 10^5 cents
+    test.contract.set_verification_policy(
+        &test.admin,
+        &VerificationPolicy {
+            categories: Vec::new(&test.env),
+            min_prices: Map::from_array(
+                &test.env,
+                [
+                    (test.token.address.clone(), 10_000_000_000),
+                    (cents.clone(), 100_000),
+                ],
+            ),
+            min_reference_price: None,
+        },
+    );
+
+    // 500 of the payment token is below its threshold, however many units that is
+    let item_id = test.list(5_000_000_000, 2);
+    // 1,500.00 is reserved, though as many units of the payment token would not be
+    assert!(test
+        .contract
+        .try_set_item_price(&test.seller, &item_id, &cents, &150_000)
+        .is_err());
+    test.contract
+        .set_item_price(&test.seller, &item_id, &cents, &50_000);
+    test.contract
+        .buy_item_with_token(&test.buyer, &item_id, &1, &cents);
+    assert_eq!(cents_client.balance(&test.buyer), 950_000);
+
+    // A token without a threshold is not reserved
+    let usdc = test.second_token();
+    test.contract.allow_token(&test.admin, &usdc.address);
+    test.contract
+        .set_item_price(&test.seller, &item_id, &usdc.address, &100_000_000_000);
+}
+
+#[test]
+fn test_verification_policy_is_validated() {
+    let test = MarketTest::setup();
+    let policy = |categories, min_price: Option<i128>| VerificationPolicy {
+        categories,
+        min_prices: min_price.map_or(Map::new(&test.env), |min_price| {
+            Map::from_array(&test.env, [(test.token.address.clone(), min_price)])
+        }),
+        min_reference_price: None,
+    };
+
+    assert!(test
+        .contract
+        .try_set_verification_policy(
+            &test.admin,
+            &policy(soroban_sdk::Vec::new(&test.env), Some(0))
+        )
+        .is_err());
+    let free_reference = VerificationPolicy {
+        min_reference_price: Some(0),
+        ..policy(soroban_sdk::Vec::new(&test.env), None)
+    };
+    assert!(test
+        .contract
+        .try_set_verification_policy(&test.admin, &free_reference)
+        .is_err());
+    assert!(test
+        .contract
+        .try_set_verification_policy(&test.admin, &policy(soroban_sdk::vec![&test.env, 1], None))
+        .is_err());
+    assert!(test
+        .contract
+        .try_set_verification_policy(
+            &test.seller,
+            &policy(soroban_sdk::Vec::new(&test.env), Some(1_000))
+        )
+        .is_err());
+    assert_eq!(
+        test.contract.get_verification_policy(),
+        policy(soroban_sdk::Vec::new(&test.env), None)
+    );
+}
+
+#[test]
+fn test_roles_fees_and_listings_stay_alive() {
+    let test = MarketTest::setup();
+    let treasury = Address::generate(&test.env);
+    test.contract.set_fee(&test.admin, &300, &treasury); // 3%
+    let attester = test.attester();
+    let item_id = test.list(1_000, 1);
+    let order_id = test.contract.buy_item(&test.buyer, &item_id, &1);
+    test.ship(order_id);
+    test.contract.confirm_delivery(&test.buyer, &order_id);
+
+    let ttls = || {
+        test.env.as_contract(&test.contract.address, || {
+            let storage = test.env.storage();
+            (
+                storage
+                    .persistent()
+                    .get_ttl(&(ROLE, Role::Attester, attester.clone())),
+                storage
+                    .persistent()
+                    .get_ttl(&(FEES, test.token.address.clone())),
+                storage.instance().get_ttl(),
+            )
+        })
+    };
+    assert_eq!(ttls(), (BUMP_AMOUNT, BUMP_AMOUNT, BUMP_AMOUNT));
+
+    // Reading them extends them once they are within the threshold
+    test.env
+        .ledger()
+        .with_mut(|l| l.sequence_number += BUMP_AMOUNT - LIFETIME_THRESHOLD + 1);
+    assert!(test.contract.has_role(&Role::Attester, &attester));
+    assert_eq!(test.contract.get_collected_fees(&test.token.address), 30);
+    assert_eq!(test.contract.get_items().len(), 1);
+    assert_eq!(ttls(), (BUMP_AMOUNT, BUMP_AMOUNT, BUMP_AMOUNT));
 }
@@ -0,0 +1,151 @@
+//! Accepted payment tokens and per-listing prices.
+//!
+//! The admin curates the Stellar Asset Contract tokens purchases can be paid
+//! in. Until the list is first changed it holds only the token the marketplace
+//! was initialized with, so existing deployments keep working unchanged.
+//!
+//! A listing is priced at `Item.price` in its primary token, `Item.token`,
+//! which is the marketplace's payment token unless the seller listed it in
+//! another accepted token. Its seller can add prices in other accepted tokens
+//! and drop them again; dropping the primary token's price makes another price
+//! primary, so a listing always keeps one. Offers are made in the primary
+//! token. The other prices are kept next to the item rather than in it. A
+//! Dutch auction schedules the price in the primary token only, and a
+//! reference price (see [`crate::oracle`]) overrides both.
+
+use soroban_sdk::{symbol_short, Address, Env, Map, Symbol, Vec};
+
+use crate::order::{BUMP_AMOUNT, LIFETIME_THRESHOLD};
+use crate::seller::{self, Price};
+use crate::{dutch, event, oracle, Item, Marketplace};
+
+const ACCEPTED_TOKENS: Symbol = symbol_short!("TOKENS");
+const PRICES: Symbol = symbol_short!("PRICES");
+
+/// Tokens purchases can be paid in
+pub(crate) fn accepted(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&ACCEPTED_TOKENS)
+        .unwrap_or_else(|| Vec::from_array(env, [Marketplace::payment_token(env)]))
+}
+
+pub(crate) fn is_accepted(env: &Env, token: &Address) -> bool {
+    accepted(env).contains(token)
+}
+
+pub(crate) fn require_accepted(env: &Env, token: &Address) {
+    if !is_accepted(env, token) {
+        panic!("Token not accepted");
+    }
+}
+
+/// Adds a token to the allowlist. Returns the accepted tokens.
+pub(crate) fn allow(env: &Env, token: &Address) -> Vec<Address> {
+    let mut tokens = accepted(env);
+    if tokens.contains(token) {
+        panic!("Token already accepted");
+    }
+    tokens.push_back(token.clone());
+    env.storage().instance().set(&ACCEPTED_TOKENS, &tokens);
+    tokens
+}
+
+/// Removes a token from the allowlist. Listings keep their prices in it, but nothing can be
+/// bought with it until it is allowed again; bids and offers already paid in it can still
+/// complete. Returns the accepted tokens.
+pub(crate) fn disallow(env: &Env, token: &Address) -> Vec<Address> {
+    let mut tokens = accepted(env);
+    let Some(index) = tokens.first_index_of(token) else {
+        panic!("Token not accepted");
+    };
+    tokens.remove(index);
+    env.storage().instance().set(&ACCEPTED_TOKENS, &tokens);
+    tokens
+}
+
+/// Price of a listing in every token it accepts
+pub(crate) fn prices(env: &Env, item: &Item) -> Map<Address, i128> {
+    env.storage()
+        .persistent()
+        .get(&(PRICES, item.id))
+        .unwrap_or_else(|| Map::from_array(env, [(item.token.clone(), item.price)]))
+}
+
+/// Seller prices a listing in an accepted token, replacing any earlier price in it.
+/// A price in the listing's primary token is also written to `Item.price`.
+pub(crate) fn set_price(env: &Env, seller: &Address, item_id: u32, token: &Address, price: i128) {
+    Marketplace::require_migrated(env);
+    let mut item = owned_item(env, seller, item_id);
+    require_accepted(env, token);
+    if price <= 0 {
+        panic!("Invalid price or quantity");
+    }
+
+    seller::require_eligible(env, seller, item.category, Price::Token(token, price));
+
+    let mut prices = prices(env, &item);
+    prices.set(token.clone(), price);
+    if *token == item.token {
+        item.price = price;
+        Marketplace::save_item(env, &item);
+    }
+    save_prices(env, seller, item_id, &prices);
+}
+
+/// Seller stops accepting a token for a listing. Dropping the primary token's price makes
+/// the listing's next price primary.
+pub(crate) fn remove_price(env: &Env, seller: &Address, item_id: u32, token: &Address) {
+    let mut item = owned_item(env, seller, item_id);
+    let mut prices = prices(env, &item);
+    if prices.remove(token.clone()).is_none() {
+        panic!("Item not priced in token");
+    }
+    if *token == item.token {
+        let Some((primary, price)) = prices.iter().next() else {
+            panic!("Listing needs a price");
+        };
+        // The schedule is in amounts of the primary token
+        if dutch::load(env, item_id).is_some() {
+            panic!("Listing on a Dutch auction");
+        }
+        item.token = primary;
+        item.price = price;
+        Marketplace::save_item(env, &item);
+    }
+    save_prices(env, seller, item_id, &prices);
+}
+
+/// Unit price of an item in `token` at `timestamp`. A listing with a reference price is
+/// quoted at the latest oracle price instead.
+pub(crate) fn unit_price(env: &Env, item: &Item, token: &Address, timestamp: u64) -> i128 {
+    if let Some(reference_price) = oracle::reference_price(env, item.id) {
+        return oracle::convert(env, reference_price, token);
+    }
+    let prices = prices(env, item);
+    if *token == item.token && prices.contains_key(token.clone()) {
+        return dutch::unit_price(env, item, timestamp);
+    }
+    prices
+        .get(token.clone())
+        .unwrap_or_else(|| panic!("Item not priced in token"))
+}
+
+fn owned_item(env: &Env, seller: &Address, item_id: u32) -> Item {
+    seller.require_auth();
+    let item = Marketplace::load_item(env, item_id);
+    if item.seller != *seller {
+        panic!("Not the seller");
+    }
+    item
+}
+
+fn save_prices(env: &Env, seller: &Address, item_id: u32, prices: &Map<Address, i128>) {
+    let key = (PRICES, item_id);
+    env.storage().persistent().set(&key, prices);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+
+    event::item_prices_set(env, seller, item_id, prices);
+}
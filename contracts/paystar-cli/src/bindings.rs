@@ -0,0 +1,272 @@
+//! TypeScript clients generated from the contract specs.
+//!
+//! Each contract gets one module with its types, its error codes and a
+//! `Client` built on `contract.Client` from `@stellar/stellar-sdk`. The spec
+//! entries are embedded as base64 XDR, so the SDK encodes arguments and
+//! decodes results exactly as the contract declares them. The frontend keeps
+//! the generated modules in `lib/soroban/contracts`; regenerate them with
+//! `paystar bindings <dir>` whenever a contract interface changes.
+
+use std::{fmt::Write as _, fs, path::Path};
+
+use stellar_xdr::curr::{
+    Limits, ScSpecEntry, ScSpecFunctionV0, ScSpecTypeDef, ScSpecUdtEnumV0, ScSpecUdtErrorEnumV0,
+    ScSpecUdtStructV0, ScSpecUdtUnionCaseV0, ScSpecUdtUnionV0, WriteXdr,
+};
+
+use crate::{
+    error::Result,
+    spec::{Contract, Spec},
+};
+
+/// Name of the generated module of a contract, e.g. `escrow.ts`.
+pub fn file_name(contract: Contract) -> String {
+    format!("{}.ts", contract.name())
+}
+
+/// Writes the module of every contract into `dir` and returns the paths written.
+pub fn write(dir: &Path) -> Result<Vec<String>> {
+    fs::create_dir_all(dir)?;
+    Contract::ALL
+        .iter()
+        .map(|&contract| {
+            let path = dir.join(file_name(contract));
+            fs::write(&path, typescript(contract))?;
+            Ok(path.display().to_string())
+        })
+        .collect()
+}
+
+/// The TypeScript module of a contract.
+pub fn typescript(contract: Contract) -> String {
+    let spec = contract.spec();
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "// Generated from the {} contract spec by `cargo run -p paystar-cli -- bindings`.",
+        contract.name()
+    )
+    .unwrap();
+    out.push_str("// Do not edit by hand: regenerate it when the contract interface changes.\n\n");
+    out.push_str("import { Buffer } from \"buffer\"\n");
+    out.push_str("import { contract } from \"@stellar/stellar-sdk\"\n\n");
+    out.push_str("export type MethodOptions = {\n");
+    out.push_str("  fee?: number\n");
+    out.push_str("  timeoutInSeconds?: number\n");
+    out.push_str("  simulate?: boolean\n");
+    out.push_str("}\n");
+
+    for entry in spec.entries() {
+        match entry {
+            ScSpecEntry::UdtStructV0(udt) => struct_type(&mut out, udt),
+            ScSpecEntry::UdtUnionV0(udt) => union_type(&mut out, udt),
+            ScSpecEntry::UdtEnumV0(udt) => enum_type(&mut out, udt),
+            ScSpecEntry::UdtErrorEnumV0(udt) => errors(&mut out, udt),
+            ScSpecEntry::FunctionV0(_) => {}
+        }
+    }
+
+    client_interface(&mut out, &spec);
+    client_class(&mut out, &spec);
+    out
+}
+
+/// The TypeScript type the SDK converts a spec type to and from.
+pub fn ts_type(def: &ScSpecTypeDef) -> String {
+    match def {
+        ScSpecTypeDef::Bool => "boolean".to_string(),
+        ScSpecTypeDef::Void => "void".to_string(),
+        ScSpecTypeDef::U32 | ScSpecTypeDef::I32 => "number".to_string(),
+        ScSpecTypeDef::U64
+        | ScSpecTypeDef::I64
+        | ScSpecTypeDef::U128
+        | ScSpecTypeDef::I128
+        | ScSpecTypeDef::U256
+        | ScSpecTypeDef::I256
+        | ScSpecTypeDef::Timepoint
+        | ScSpecTypeDef::Duration => "bigint".to_string(),
+        ScSpecTypeDef::Bytes | ScSpecTypeDef::BytesN(_) => "Buffer".to_string(),
+        ScSpecTypeDef::String | ScSpecTypeDef::Symbol | ScSpecTypeDef::Address => {
+            "string".to_string()
+        }
+        ScSpecTypeDef::Val | ScSpecTypeDef::Error => "unknown".to_string(),
+        ScSpecTypeDef::Option(option) => format!("{} | undefined", ts_type(&option.value_type)),
+        ScSpecTypeDef::Vec(vec) => format!("Array<{}>", ts_type(&vec.element_type)),
+        ScSpecTypeDef::Map(map) => format!(
+            "Map<{}, {}>",
+            ts_type(&map.key_type),
+            ts_type(&map.value_type)
+        ),
+        // `()` is specced as the empty tuple
+        ScSpecTypeDef::Tuple(tuple) if tuple.value_types.is_empty() => "void".to_string(),
+        ScSpecTypeDef::Tuple(tuple) => format!("readonly [{}]", tuple_types(&tuple.value_types)),
+        ScSpecTypeDef::Result(result) => format!("contract.Result<{}>", ts_type(&result.ok_type)),
+        ScSpecTypeDef::Udt(udt) => udt.name.to_utf8_string_lossy(),
+    }
+}
+
+fn tuple_types(types: &[ScSpecTypeDef]) -> String {
+    types.iter().map(ts_type).collect::<Vec<_>>().join(", ")
+}
+
+// `/** ... */` above a declaration, indented to match it
+fn doc(out: &mut String, indent: &str, doc: &str) {
+    let doc = doc.trim();
+    if doc.is_empty() {
+        return;
+    }
+    let mut lines = doc.lines();
+    match (lines.next(), lines.next()) {
+        (Some(line), None) => writeln!(out, "{indent}/** {} */", line.trim()).unwrap(),
+        _ => {
+            writeln!(out, "{indent}/**").unwrap();
+            for line in doc.lines() {
+                writeln!(out, "{indent} * {}", line.trim()).unwrap();
+            }
+            writeln!(out, "{indent} */").unwrap();
+        }
+    }
+}
+
+fn struct_type(out: &mut String, udt: &ScSpecUdtStructV0) {
+    out.push('\n');
+    doc(out, "", &udt.doc.to_utf8_string_lossy());
+    writeln!(
+        out,
+        "export interface {} {{",
+        udt.name.to_utf8_string_lossy()
+    )
+    .unwrap();
+    for field in udt.fields.iter() {
+        doc(out, "  ", &field.doc.to_utf8_string_lossy());
+        writeln!(
+            out,
+            "  {}: {}",
+            field.name.to_utf8_string_lossy(),
+            ts_type(&field.type_)
+        )
+        .unwrap();
+    }
+    out.push_str("}\n");
+}
+
+fn union_type(out: &mut String, udt: &ScSpecUdtUnionV0) {
+    out.push('\n');
+    doc(out, "", &udt.doc.to_utf8_string_lossy());
+    writeln!(out, "export type {} =", udt.name.to_utf8_string_lossy()).unwrap();
+    for case in udt.cases.iter() {
+        let (name, values) = match case {
+            ScSpecUdtUnionCaseV0::VoidV0(case) => (case.name.to_utf8_string_lossy(), "void".into()),
+            ScSpecUdtUnionCaseV0::TupleV0(case) => (
+                case.name.to_utf8_string_lossy(),
+                format!("readonly [{}]", tuple_types(&case.type_)),
+            ),
+        };
+        writeln!(out, "  | {{ tag: \"{name}\"; values: {values} }}").unwrap();
+    }
+}
+
+fn enum_type(out: &mut String, udt: &ScSpecUdtEnumV0) {
+    out.push('\n');
+    doc(out, "", &udt.doc.to_utf8_string_lossy());
+    writeln!(out, "export enum {} {{", udt.name.to_utf8_string_lossy()).unwrap();
+    for case in udt.cases.iter() {
+        doc(out, "  ", &case.doc.to_utf8_string_lossy());
+        writeln!(
+            out,
+            "  {} = {},",
+            case.name.to_utf8_string_lossy(),
+            case.value
+        )
+        .unwrap();
+    }
+    out.push_str("}\n");
+}
+
+// Keyed by code so a failed call's `contract.Result` error can be looked up
+fn errors(out: &mut String, udt: &ScSpecUdtErrorEnumV0) {
+    out.push('\n');
+    writeln!(
+        out,
+        "/** Error codes of `{}`, keyed by code. */",
+        udt.name.to_utf8_string_lossy()
+    )
+    .unwrap();
+    out.push_str("export const Errors = {\n");
+    let mut cases: Vec<_> = udt.cases.iter().collect();
+    cases.sort_by_key(|case| case.value);
+    for case in cases {
+        writeln!(
+            out,
+            "  {}: {{ message: \"{}\" }},",
+            case.value,
+            case.name.to_utf8_string_lossy()
+        )
+        .unwrap();
+    }
+    out.push_str("}\n");
+}
+
+fn client_interface(out: &mut String, spec: &Spec) {
+    out.push('\n');
+    out.push_str("export interface Client {\n");
+    for (i, function) in spec.functions().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        method(out, function);
+    }
+    out.push_str("}\n");
+}
+
+// Each method builds an `AssembledTransaction` that is simulated on creation
+// and can then be signed and sent
+fn method(out: &mut String, function: &ScSpecFunctionV0) {
+    doc(out, "  ", &function.doc.to_utf8_string_lossy());
+    let output = function
+        .outputs
+        .first()
+        .map(ts_type)
+        .unwrap_or_else(|| "void".to_string());
+    let name = function.name.to_utf8_string_lossy();
+    let returns = format!("Promise<contract.AssembledTransaction<{output}>>");
+
+    if function.inputs.is_empty() {
+        writeln!(out, "  {name}: (options?: MethodOptions) => {returns}").unwrap();
+        return;
+    }
+
+    let names: Vec<String> = function
+        .inputs
+        .iter()
+        .map(|input| input.name.to_utf8_string_lossy())
+        .collect();
+    writeln!(out, "  {name}: (").unwrap();
+    writeln!(out, "    {{ {} }}: {{", names.join(", ")).unwrap();
+    for (input, name) in function.inputs.iter().zip(&names) {
+        writeln!(out, "      {name}: {}", ts_type(&input.type_)).unwrap();
+    }
+    out.push_str("    },\n");
+    out.push_str("    options?: MethodOptions\n");
+    writeln!(out, "  ) => {returns}").unwrap();
+}
+
+fn client_class(out: &mut String, spec: &Spec) {
+    out.push('\n');
+    out.push_str("export class Client extends contract.Client {\n");
+    out.push_str("  constructor(public readonly options: contract.ClientOptions) {\n");
+    out.push_str("    super(\n");
+    out.push_str("      new contract.Spec([\n");
+    for entry in spec.entries() {
+        let xdr = entry
+            .to_xdr_base64(Limits::none())
+            .expect("spec entries encode");
+        writeln!(out, "        \"{xdr}\",").unwrap();
+    }
+    out.push_str("      ]),\n");
+    out.push_str("      options\n");
+    out.push_str("    )\n");
+    out.push_str("  }\n");
+    out.push_str("}\n");
+}
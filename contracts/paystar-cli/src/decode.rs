@@ -0,0 +1,34 @@
+//! Decoding stored records into readable JSON.
+
+use serde_json::Value;
+use stellar_xdr::curr::{LedgerEntry, LedgerEntryData, Limits, ReadXdr, ScVal};
+
+use crate::error::{Error, Result};
+use crate::spec::Contract;
+use crate::value;
+
+/// Decodes base64 XDR of a value of type `type_name` (e.g. `Escrow`,
+/// `InstallmentAgreement` or `Map<u32, Item>`). The XDR can be the bare
+/// `ScVal`, or the contract data `LedgerEntryData` / `LedgerEntry` that
+/// `getLedgerEntries` returns for the storage key holding it.
+pub fn decode(contract: Contract, type_name: &str, xdr: &str) -> Result<Value> {
+    let spec = contract.spec();
+    let def = spec.parse_type(type_name)?;
+    value::to_json(&spec, &def, &stored_value(xdr.trim())?)
+}
+
+fn stored_value(xdr: &str) -> Result<ScVal> {
+    if let Ok(val) = ScVal::from_xdr_base64(xdr, Limits::none()) {
+        return Ok(val);
+    }
+    let data = match LedgerEntryData::from_xdr_base64(xdr, Limits::none()) {
+        Ok(data) => data,
+        Err(_) => LedgerEntry::from_xdr_base64(xdr, Limits::none())?.data,
+    };
+    match data {
+        LedgerEntryData::ContractData(entry) => Ok(entry.val),
+        _ => Err(Error::Invalid(
+            "the ledger entry is not contract data".to_string(),
+        )),
+    }
+}
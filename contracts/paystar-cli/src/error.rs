@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// Everything that can stop a command.
+#[derive(Debug)]
+pub enum Error {
+    Usage(String),
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Xdr(stellar_xdr::curr::Error),
+    /// The contract has no function or type by that name.
+    Unknown(String),
+    /// An argument or stored value does not match the contract spec.
+    Invalid(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Usage(message) | Error::Unknown(message) | Error::Invalid(message) => {
+                f.write_str(message)
+            }
+            Error::Io(error) => write!(f, "{error}"),
+            Error::Json(error) => write!(f, "invalid JSON: {error}"),
+            Error::Xdr(error) => write!(f, "invalid XDR: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(error)
+    }
+}
+
+impl From<stellar_xdr::curr::Error> for Error {
+    fn from(error: stellar_xdr::curr::Error) -> Self {
+        Error::Xdr(error)
+    }
+}
@@ -0,0 +1,198 @@
+//! Building contract invocations from named arguments.
+
+use serde_json::{json, Value};
+use stellar_xdr::curr::{
+    HostFunction, InvokeContractArgs, Limits, ScAddress, ScSpecTypeDef, ScSymbol, ScVal, WriteXdr,
+};
+
+use crate::error::{Error, Result};
+use crate::spec::{type_name, Contract};
+use crate::value;
+
+/// A call to a contract function with its arguments in spec order.
+pub struct Invocation {
+    pub contract: Contract,
+    pub function: String,
+    pub args: Vec<Argument>,
+}
+
+pub struct Argument {
+    pub name: String,
+    pub def: ScSpecTypeDef,
+    pub json: Value, // The value as the stellar CLI expects it
+    pub val: ScVal,
+}
+
+/// Where the invocation goes and who signs it; left as shell variables when unset.
+#[derive(Default)]
+pub struct Target {
+    pub contract_id: Option<String>,
+    pub source: Option<String>,
+    pub network: Option<String>,
+}
+
+impl Invocation {
+    /// Matches `(name, value)` pairs to the function's parameters. Every
+    /// parameter must be given once, except `Option` ones which default to none.
+    pub fn build(contract: Contract, function: &str, named: &[(String, String)]) -> Result<Self> {
+        let spec = contract.spec();
+        let spec_fn = spec.function(function)?;
+
+        for (i, (name, _)) in named.iter().enumerate() {
+            if !spec_fn
+                .inputs
+                .iter()
+                .any(|input| input.name.to_utf8_string_lossy() == *name)
+            {
+                return Err(Error::Invalid(format!(
+                    "`{function}` has no argument `{name}`; expected {}",
+                    signature(
+                        spec_fn
+                            .inputs
+                            .iter()
+                            .map(|input| { (input.name.to_utf8_string_lossy(), &input.type_) })
+                    )
+                )));
+            }
+            if named[..i].iter().any(|(earlier, _)| earlier == name) {
+                return Err(Error::Invalid(format!("argument `{name}` given twice")));
+            }
+        }
+
+        let args = spec_fn
+            .inputs
+            .iter()
+            .map(|input| {
+                let name = input.name.to_utf8_string_lossy();
+                let raw = named.iter().find(|(given, _)| *given == name);
+                let val = match (raw, &input.type_) {
+                    (Some((_, raw)), def) => value::from_arg(&spec, def, raw),
+                    (None, ScSpecTypeDef::Option(_)) => Ok(ScVal::Void),
+                    (None, def) => Err(Error::Invalid(format!(
+                        "missing argument `{name}` ({})",
+                        type_name(def)
+                    ))),
+                }
+                .map_err(|error| Error::Invalid(format!("`{name}`: {error}")))?;
+
+                Ok(Argument {
+                    json: value::to_json(&spec, &input.type_, &val)?,
+                    name,
+                    def: input.type_.clone(),
+                    val,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Invocation {
+            contract,
+            function: function.to_string(),
+            args,
+        })
+    }
+
+    /// The arguments as a base64 `ScVal::Vec`, ready for a transaction builder.
+    pub fn args_xdr(&self) -> Result<String> {
+        let args: Vec<ScVal> = self.args.iter().map(|arg| arg.val.clone()).collect();
+        Ok(ScVal::Vec(Some(args.try_into()?)).to_xdr_base64(Limits::none())?)
+    }
+
+    /// The base64 `HostFunction` of an `InvokeHostFunction` operation.
+    pub fn host_function_xdr(&self, contract_id: &str) -> Result<String> {
+        let contract_address: ScAddress = contract_id
+            .parse()
+            .map_err(|_| Error::Invalid(format!("invalid contract id `{contract_id}`")))?;
+        let args: Vec<ScVal> = self.args.iter().map(|arg| arg.val.clone()).collect();
+        let host_function = HostFunction::InvokeContract(InvokeContractArgs {
+            contract_address,
+            function_name: ScSymbol(self.function.as_str().try_into()?),
+            args: args.try_into()?,
+        });
+        Ok(host_function.to_xdr_base64(Limits::none())?)
+    }
+
+    /// The `stellar contract invoke` command for this call.
+    pub fn command(&self, target: &Target) -> String {
+        let mut words = vec![
+            "stellar".to_string(),
+            "contract".to_string(),
+            "invoke".to_string(),
+            "--id".to_string(),
+            target.contract_id.clone().unwrap_or_else(|| {
+                format!("\"${}_CONTRACT_ID\"", self.contract.name().to_uppercase())
+            }),
+            "--source".to_string(),
+            target
+                .source
+                .clone()
+                .unwrap_or_else(|| "\"$SOURCE\"".to_string()),
+            "--network".to_string(),
+            target
+                .network
+                .clone()
+                .unwrap_or_else(|| "\"$NETWORK\"".to_string()),
+            "--".to_string(),
+            self.function.clone(),
+        ];
+        for arg in &self.args {
+            // Omitted options are left out rather than passed as null
+            if arg.val == ScVal::Void {
+                continue;
+            }
+            words.push(format!("--{}", arg.name));
+            words.push(quote(&match &arg.json {
+                Value::String(string) => string.clone(),
+                json => json.to_string(),
+            }));
+        }
+        words.join(" ")
+    }
+
+    pub fn to_json(&self, target: &Target) -> Result<Value> {
+        let args: Vec<Value> = self
+            .args
+            .iter()
+            .map(|arg| {
+                json!({
+                    "name": arg.name,
+                    "type": type_name(&arg.def),
+                    "value": arg.json,
+                })
+            })
+            .collect();
+
+        let mut output = json!({
+            "contract": self.contract.name(),
+            "function": self.function,
+            "args": args,
+            "args_xdr": self.args_xdr()?,
+            "command": self.command(target),
+        });
+        if let Some(contract_id) = &target.contract_id {
+            output["contract_id"] = json!(contract_id);
+            output["host_function_xdr"] = json!(self.host_function_xdr(contract_id)?);
+        }
+        Ok(output)
+    }
+}
+
+/// `name: Type, ...` for listings and error messages.
+pub fn signature<'a>(inputs: impl Iterator<Item = (String, &'a ScSpecTypeDef)>) -> String {
+    inputs
+        .map(|(name, def)| format!("{name}: {}", type_name(def)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Single-quotes a shell word unless it is plainly safe
+fn quote(word: &str) -> String {
+    let safe = !word.is_empty()
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_-.:/@".contains(c));
+    if safe {
+        word.to_string()
+    } else {
+        format!("'{}'", word.replace('\'', r"'\''"))
+    }
+}
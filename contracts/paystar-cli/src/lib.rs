@@ -0,0 +1,20 @@
+//! Operator tooling for the PayStar contracts.
+//!
+//! Builds invocation payloads for every contract function from named
+//! arguments checked against the contract specs, and decodes stored records
+//! such as `Escrow`, `InstallmentAgreement` and `Item` into readable JSON.
+//! Also generates the TypeScript clients the frontend uses.
+
+pub mod bindings;
+pub mod decode;
+pub mod error;
+pub mod invoke;
+pub mod spec;
+pub mod value;
+
+#[cfg(test)]
+mod test;
+
+pub use error::{Error, Result};
+pub use invoke::{Invocation, Target};
+pub use spec::Contract;
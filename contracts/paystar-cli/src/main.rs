@@ -0,0 +1,128 @@
+use std::{path::Path, process::ExitCode};
+
+use paystar_cli::{
+    bindings, decode, invoke, spec::type_name, Contract, Error, Invocation, Result, Target,
+};
+
+const USAGE: &str = "usage:
+  paystar functions <contract>
+  paystar invoke <contract> <function> [--id <contract-id>] [--source <account>] [--network <network>] -- [--<argument> <value>]...
+  paystar decode <contract> <type> <xdr>
+  paystar bindings <dir>
+
+<contract> is escrow, installment or marketplace.
+
+`functions` lists every function with its arguments in order. `invoke` checks
+the arguments against the contract spec and prints the `stellar contract invoke`
+command and the XDR payloads for the call. Struct and enum arguments are JSON,
+e.g. `--role Arbiter` or `--action '{\"SetFee\": [250, \"G...\"]}'`. `decode` turns
+base64 XDR of a stored value, e.g. `Escrow`, `InstallmentAgreement` or
+`Map<u32, Item>`, into JSON. `bindings` writes a typed TypeScript client for
+every contract into <dir>.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(Error::Usage(message)) => {
+            eprintln!("{message}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<String> {
+    match args {
+        [command, contract] if command == "functions" => functions(Contract::parse(contract)?),
+        [command, contract, function, rest @ ..] if command == "invoke" => {
+            let (options, named) = split_invoke_args(rest)?;
+            let target = Target {
+                contract_id: option(&options, "id"),
+                source: option(&options, "source"),
+                network: option(&options, "network"),
+            };
+            if let Some((unknown, _)) = options
+                .iter()
+                .find(|(name, _)| !["id", "source", "network"].contains(&name.as_str()))
+            {
+                return Err(Error::Usage(format!("unknown option `--{unknown}`")));
+            }
+
+            let invocation = Invocation::build(Contract::parse(contract)?, function, &named)?;
+            Ok(serde_json::to_string_pretty(&invocation.to_json(&target)?)?)
+        }
+        [command, contract, type_name, xdr] if command == "decode" => {
+            let decoded = decode::decode(Contract::parse(contract)?, type_name, xdr)?;
+            Ok(serde_json::to_string_pretty(&decoded)?)
+        }
+        [command, dir] if command == "bindings" => Ok(bindings::write(Path::new(dir))?.join("\n")),
+        [] => Err(Error::Usage("missing command".to_string())),
+        _ => Err(Error::Usage(format!(
+            "invalid command `{}`",
+            args.join(" ")
+        ))),
+    }
+}
+
+fn functions(contract: Contract) -> Result<String> {
+    let spec = contract.spec();
+    let lines: Vec<String> = spec
+        .functions()
+        .map(|function| {
+            let inputs = invoke::signature(
+                function
+                    .inputs
+                    .iter()
+                    .map(|input| (input.name.to_utf8_string_lossy(), &input.type_)),
+            );
+            match function.outputs.first() {
+                Some(output) => format!(
+                    "{}({inputs}) -> {}",
+                    function.name.to_utf8_string_lossy(),
+                    type_name(output)
+                ),
+                None => format!("{}({inputs})", function.name.to_utf8_string_lossy()),
+            }
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+type Named = Vec<(String, String)>;
+
+// Options come before `--`, the function's arguments after it
+fn split_invoke_args(args: &[String]) -> Result<(Named, Named)> {
+    let (options, arguments) = match args.iter().position(|arg| arg == "--") {
+        Some(split) => (&args[..split], &args[split + 1..]),
+        None => (args, &[][..]),
+    };
+    Ok((pairs(options)?, pairs(arguments)?))
+}
+
+fn pairs(args: &[String]) -> Result<Named> {
+    args.chunks(2)
+        .map(|pair| match pair {
+            [flag, value] if flag.starts_with("--") => {
+                Ok((flag.trim_start_matches("--").to_string(), value.clone()))
+            }
+            _ => Err(Error::Usage(format!(
+                "expected `--name value` pairs, got `{}`",
+                pair.join(" ")
+            ))),
+        })
+        .collect()
+}
+
+fn option(options: &Named, name: &str) -> Option<String> {
+    options
+        .iter()
+        .find(|(option, _)| option == name)
+        .map(|(_, value)| value.clone())
+}
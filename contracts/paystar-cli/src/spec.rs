@@ -0,0 +1,445 @@
+//! Contract specs of the PayStar contracts.
+//!
+//! The specs come from the `spec_xdr` functions `#[contractimpl]` and
+//! `#[contracttype]` generate in the contract crates, so argument names,
+//! order and types always match the contracts this tool was built with.
+
+use auto_release_escrow_contract as escrow;
+use escrow::AutoReleaseEscrowContract as Escrow;
+use installment_payment_contract::{
+    installment_payment::installment::InstallmentPayment as Installment,
+    storage::contracts as agreement,
+};
+use paystar_common::Role;
+use real_market_place::{self as market, Marketplace};
+use stellar_xdr::curr::{
+    Limits, ReadXdr, ScSpecEntry, ScSpecFunctionV0, ScSpecTypeBytesN, ScSpecTypeDef, ScSpecTypeMap,
+    ScSpecTypeOption, ScSpecTypeUdt, ScSpecTypeVec,
+};
+
+use crate::error::{Error, Result};
+
+macro_rules! entries {
+    ($($spec:expr),* $(,)?) => {
+        vec![$($spec.to_vec()),*]
+    };
+}
+
+/// One of the PayStar contracts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Contract {
+    Escrow,
+    Installment,
+    Marketplace,
+}
+
+impl Contract {
+    pub const ALL: [Contract; 3] = [
+        Contract::Escrow,
+        Contract::Installment,
+        Contract::Marketplace,
+    ];
+
+    /// Accepts the contract name or its event topic name.
+    pub fn parse(name: &str) -> Result<Self> {
+        match name {
+            "escrow" => Ok(Contract::Escrow),
+            "installment" | "install" => Ok(Contract::Installment),
+            "marketplace" | "market" => Ok(Contract::Marketplace),
+            _ => Err(Error::Unknown(format!(
+                "unknown contract `{name}`, expected escrow, installment or marketplace"
+            ))),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Contract::Escrow => "escrow",
+            Contract::Installment => "installment",
+            Contract::Marketplace => "marketplace",
+        }
+    }
+
+    pub fn spec(self) -> Spec {
+        let raw = match self {
+            Contract::Escrow => escrow_spec(),
+            Contract::Installment => installment_spec(),
+            Contract::Marketplace => marketplace_spec(),
+        };
+        Spec {
+            contract: self,
+            entries: raw
+                .iter()
+                .map(|xdr| ScSpecEntry::from_xdr(xdr, Limits::none()))
+                .collect::<std::result::Result<_, _>>()
+                .expect("contract specs are valid XDR"),
+        }
+    }
+}
+
+/// The functions and types of one contract.
+pub struct Spec {
+    contract: Contract,
+    entries: Vec<ScSpecEntry>,
+}
+
+impl Spec {
+    /// Every function and type definition, in the order the contract declares them.
+    pub fn entries(&self) -> &[ScSpecEntry] {
+        &self.entries
+    }
+
+    pub fn functions(&self) -> impl Iterator<Item = &ScSpecFunctionV0> {
+        self.entries.iter().filter_map(|entry| match entry {
+            ScSpecEntry::FunctionV0(function) => Some(function),
+            _ => None,
+        })
+    }
+
+    pub fn function(&self, name: &str) -> Result<&ScSpecFunctionV0> {
+        self.functions()
+            .find(|function| function.name.to_utf8_string_lossy() == name)
+            .ok_or_else(|| {
+                Error::Unknown(format!(
+                    "the {} contract has no function `{name}`",
+                    self.contract.name()
+                ))
+            })
+    }
+
+    /// The definition of a `#[contracttype]` or `#[contracterror]` type.
+    pub fn udt(&self, name: &str) -> Result<&ScSpecEntry> {
+        self.entries
+            .iter()
+            .find(|entry| match entry {
+                ScSpecEntry::UdtStructV0(udt) => udt.name.to_utf8_string_lossy() == name,
+                ScSpecEntry::UdtUnionV0(udt) => udt.name.to_utf8_string_lossy() == name,
+                ScSpecEntry::UdtEnumV0(udt) => udt.name.to_utf8_string_lossy() == name,
+                ScSpecEntry::UdtErrorEnumV0(udt) => udt.name.to_utf8_string_lossy() == name,
+                ScSpecEntry::FunctionV0(_) => false,
+            })
+            .ok_or_else(|| {
+                Error::Unknown(format!(
+                    "the {} contract has no type `{name}`",
+                    self.contract.name()
+                ))
+            })
+    }
+
+    /// Parses a Rust-like type name such as `Escrow`, `Vec<Item>` or `Map<u32, Item>`.
+    pub fn parse_type(&self, name: &str) -> Result<ScSpecTypeDef> {
+        let name = name.trim();
+        if let Some(inner) = generic(name, "Option") {
+            return Ok(ScSpecTypeDef::Option(Box::new(ScSpecTypeOption {
+                value_type: Box::new(self.parse_type(inner)?),
+            })));
+        }
+        if let Some(inner) = generic(name, "Vec") {
+            return Ok(ScSpecTypeDef::Vec(Box::new(ScSpecTypeVec {
+                element_type: Box::new(self.parse_type(inner)?),
+            })));
+        }
+        if let Some(inner) = generic(name, "Map") {
+            let (key, value) = inner
+                .split_once(',')
+                .ok_or_else(|| Error::Invalid(format!("expected Map<K, V>, got `{name}`")))?;
+            return Ok(ScSpecTypeDef::Map(Box::new(ScSpecTypeMap {
+                key_type: Box::new(self.parse_type(key)?),
+                value_type: Box::new(self.parse_type(value)?),
+            })));
+        }
+        if let Some(n) = generic(name, "BytesN") {
+            let n = n
+                .parse()
+                .map_err(|_| Error::Invalid(format!("invalid length in `{name}`")))?;
+            return Ok(ScSpecTypeDef::BytesN(ScSpecTypeBytesN { n }));
+        }
+
+        Ok(match name {
+            "bool" => ScSpecTypeDef::Bool,
+            "u32" => ScSpecTypeDef::U32,
+            "i32" => ScSpecTypeDef::I32,
+            "u64" => ScSpecTypeDef::U64,
+            "i64" => ScSpecTypeDef::I64,
+            "u128" => ScSpecTypeDef::U128,
+            "i128" => ScSpecTypeDef::I128,
+            "Bytes" => ScSpecTypeDef::Bytes,
+            "String" => ScSpecTypeDef::String,
+            "Symbol" => ScSpecTypeDef::Symbol,
+            "Address" => ScSpecTypeDef::Address,
+            _ => {
+                self.udt(name)?;
+                ScSpecTypeDef::Udt(ScSpecTypeUdt {
+                    name: name
+                        .try_into()
+                        .map_err(|_| Error::Invalid(format!("type name `{name}` is too long")))?,
+                })
+            }
+        })
+    }
+}
+
+// The text between `Name<` and the closing `>`
+fn generic<'a>(name: &'a str, outer: &str) -> Option<&'a str> {
+    name.strip_prefix(outer)?
+        .trim_start()
+        .strip_prefix('<')?
+        .strip_suffix('>')
+}
+
+/// Rust-like name of a spec type, as accepted by [`Spec::parse_type`].
+pub fn type_name(def: &ScSpecTypeDef) -> String {
+    match def {
+        ScSpecTypeDef::Option(option) => format!("Option<{}>", type_name(&option.value_type)),
+        ScSpecTypeDef::Vec(vec) => format!("Vec<{}>", type_name(&vec.element_type)),
+        ScSpecTypeDef::Map(map) => format!(
+            "Map<{}, {}>",
+            type_name(&map.key_type),
+            type_name(&map.value_type)
+        ),
+        ScSpecTypeDef::Tuple(tuple) => format!(
+            "({})",
+            tuple
+                .value_types
+                .iter()
+                .map(type_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ScSpecTypeDef::Result(result) => format!(
+            "Result<{}, {}>",
+            type_name(&result.ok_type),
+            type_name(&result.error_type)
+        ),
+        ScSpecTypeDef::BytesN(bytes) => format!("BytesN<{}>", bytes.n),
+        ScSpecTypeDef::Udt(udt) => udt.name.to_utf8_string_lossy(),
+        ScSpecTypeDef::Void => "()".to_string(),
+        ScSpecTypeDef::Val => "Val".to_string(),
+        ScSpecTypeDef::Bool => "bool".to_string(),
+        ScSpecTypeDef::Error => "Error".to_string(),
+        ScSpecTypeDef::U32 => "u32".to_string(),
+        ScSpecTypeDef::I32 => "i32".to_string(),
+        ScSpecTypeDef::U64 => "u64".to_string(),
+        ScSpecTypeDef::I64 => "i64".to_string(),
+        ScSpecTypeDef::Timepoint => "Timepoint".to_string(),
+        ScSpecTypeDef::Duration => "Duration".to_string(),
+        ScSpecTypeDef::U128 => "u128".to_string(),
+        ScSpecTypeDef::I128 => "i128".to_string(),
+        ScSpecTypeDef::U256 => "U256".to_string(),
+        ScSpecTypeDef::I256 => "I256".to_string(),
+        ScSpecTypeDef::Bytes => "Bytes".to_string(),
+        ScSpecTypeDef::String => "String".to_string(),
+        ScSpecTypeDef::Symbol => "Symbol".to_string(),
+        ScSpecTypeDef::Address => "Address".to_string(),
+    }
+}
+
+fn escrow_spec() -> Vec<Vec<u8>> {
+    entries![
+        Escrow::spec_xdr_initialize(),
+        Escrow::spec_xdr_propose_admin(),
+        Escrow::spec_xdr_accept_admin(),
+        Escrow::spec_xdr_grant_role(),
+        Escrow::spec_xdr_revoke_role(),
+        Escrow::spec_xdr_create_escrow(),
+        Escrow::spec_xdr_create_funded_escrow(),
+        Escrow::spec_xdr_acknowledge_escrow(),
+        Escrow::spec_xdr_cancel_escrow(),
+        Escrow::spec_xdr_confirm_receipt(),
+        Escrow::spec_xdr_propose_extension(),
+        Escrow::spec_xdr_accept_extension(),
+        Escrow::spec_xdr_release_funds(),
+        Escrow::spec_xdr_release_matured(),
+        Escrow::spec_xdr_dispute_escrow(),
+        Escrow::spec_xdr_bump(),
+        Escrow::spec_xdr_bump_role(),
+        Escrow::spec_xdr_resolve_dispute_and_refund(),
+        Escrow::spec_xdr_pause(),
+        Escrow::spec_xdr_unpause(),
+        Escrow::spec_xdr_set_fee(),
+        Escrow::spec_xdr_withdraw_fees(),
+        Escrow::spec_xdr_set_keeper_reward(),
+        Escrow::spec_xdr_upgrade(),
+        Escrow::spec_xdr_enable_governance(),
+        Escrow::spec_xdr_propose_action(),
+        Escrow::spec_xdr_approve_action(),
+        Escrow::spec_xdr_execute_action(),
+        Escrow::spec_xdr_migrate(),
+        Escrow::spec_xdr_get_admin(),
+        Escrow::spec_xdr_get_pending_admin(),
+        Escrow::spec_xdr_get_governance(),
+        Escrow::spec_xdr_get_governance_proposal(),
+        Escrow::spec_xdr_has_role(),
+        Escrow::spec_xdr_get_escrow(),
+        Escrow::spec_xdr_get_extension_proposal(),
+        Escrow::spec_xdr_get_release_bucket(),
+        Escrow::spec_xdr_get_release_cursor(),
+        Escrow::spec_xdr_is_paused(),
+        Escrow::spec_xdr_get_schema_version(),
+        Escrow::spec_xdr_get_fee_config(),
+        Escrow::spec_xdr_get_collected_fees(),
+        escrow::Escrow::spec_xdr(),
+        escrow::EscrowStatus::spec_xdr(),
+        escrow::ExtensionProposal::spec_xdr(),
+        escrow::ReleaseExtension::spec_xdr(),
+        escrow::FeeConfig::spec_xdr(),
+        escrow::GovernanceConfig::spec_xdr(),
+        escrow::GovernanceAction::spec_xdr(),
+        escrow::GovernanceProposal::spec_xdr(),
+        escrow::ContractError::spec_xdr(),
+        Role::spec_xdr(),
+    ]
+}
+
+fn installment_spec() -> Vec<Vec<u8>> {
+    entries![
+        Installment::spec_xdr_initialize(),
+        Installment::spec_xdr_create_installment_agreement(),
+        Installment::spec_xdr_pay_on_installment(),
+        Installment::spec_xdr_finalize_agreement(),
+        Installment::spec_xdr_accept_installment_agreement(),
+        Installment::spec_xdr_cancel_and_refund_agreement(),
+        Installment::spec_xdr_propose_admin(),
+        Installment::spec_xdr_accept_admin(),
+        Installment::spec_xdr_grant_role(),
+        Installment::spec_xdr_revoke_role(),
+        Installment::spec_xdr_get_admin(),
+        Installment::spec_xdr_get_pending_admin(),
+        Installment::spec_xdr_has_role(),
+        Installment::spec_xdr_bump(),
+        Installment::spec_xdr_pause(),
+        Installment::spec_xdr_unpause(),
+        Installment::spec_xdr_is_paused(),
+        Installment::spec_xdr_set_fee(),
+        Installment::spec_xdr_withdraw_fees(),
+        Installment::spec_xdr_upgrade(),
+        Installment::spec_xdr_enable_governance(),
+        Installment::spec_xdr_propose_action(),
+        Installment::spec_xdr_approve_action(),
+        Installment::spec_xdr_execute_action(),
+        Installment::spec_xdr_get_governance(),
+        Installment::spec_xdr_get_governance_proposal(),
+        Installment::spec_xdr_migrate(),
+        Installment::spec_xdr_get_schema_version(),
+        Installment::spec_xdr_get_installment_agreement(),
+        Installment::spec_xdr_get_fee_config(),
+        Installment::spec_xdr_get_collected_fees(),
+        agreement::InstallmentAgreement::spec_xdr(),
+        agreement::PaidHistory::spec_xdr(),
+        agreement::FeeConfig::spec_xdr(),
+        agreement::GovernanceConfig::spec_xdr(),
+        agreement::GovernanceAction::spec_xdr(),
+        agreement::GovernanceProposal::spec_xdr(),
+        installment_payment_contract::errors::errors::ContractError::spec_xdr(),
+        Role::spec_xdr(),
+    ]
+}
+
+fn marketplace_spec() -> Vec<Vec<u8>> {
+    entries![
+        Marketplace::spec_xdr_initialize(),
+        Marketplace::spec_xdr_list_item(),
+        Marketplace::spec_xdr_list_item_with_token(),
+        Marketplace::spec_xdr_get_items(),
+        Marketplace::spec_xdr_buy_item(),
+        Marketplace::spec_xdr_buy_item_with_token(),
+        Marketplace::spec_xdr_buy_item_with_limit(),
+        Marketplace::spec_xdr_set_item_price(),
+        Marketplace::spec_xdr_remove_item_price(),
+        Marketplace::spec_xdr_get_item_prices(),
+        Marketplace::spec_xdr_allow_token(),
+        Marketplace::spec_xdr_disallow_token(),
+        Marketplace::spec_xdr_get_accepted_tokens(),
+        Marketplace::spec_xdr_set_oracle(),
+        Marketplace::spec_xdr_get_oracle_config(),
+        Marketplace::spec_xdr_set_reference_price(),
+        Marketplace::spec_xdr_get_reference_price(),
+        Marketplace::spec_xdr_register_seller(),
+        Marketplace::spec_xdr_get_seller_profile(),
+        Marketplace::spec_xdr_verify_seller(),
+        Marketplace::spec_xdr_revoke_seller_verification(),
+        Marketplace::spec_xdr_is_verified_seller(),
+        Marketplace::spec_xdr_set_verification_policy(),
+        Marketplace::spec_xdr_get_verification_policy(),
+        Marketplace::spec_xdr_add_category(),
+        Marketplace::spec_xdr_rename_category(),
+        Marketplace::spec_xdr_remove_category(),
+        Marketplace::spec_xdr_get_category(),
+        Marketplace::spec_xdr_get_categories(),
+        Marketplace::spec_xdr_get_subcategories(),
+        Marketplace::spec_xdr_set_item_category(),
+        Marketplace::spec_xdr_set_item_tags(),
+        Marketplace::spec_xdr_get_items_in_category(),
+        Marketplace::spec_xdr_get_category_item_count(),
+        Marketplace::spec_xdr_set_dutch_auction(),
+        Marketplace::spec_xdr_get_dutch_auction(),
+        Marketplace::spec_xdr_get_price_at(),
+        Marketplace::spec_xdr_create_auction(),
+        Marketplace::spec_xdr_place_bid(),
+        Marketplace::spec_xdr_settle_auction(),
+        Marketplace::spec_xdr_get_auction(),
+        Marketplace::spec_xdr_get_auctions(),
+        Marketplace::spec_xdr_get_auction_count(),
+        Marketplace::spec_xdr_make_offer(),
+        Marketplace::spec_xdr_accept_offer(),
+        Marketplace::spec_xdr_counter_offer(),
+        Marketplace::spec_xdr_accept_counter_offer(),
+        Marketplace::spec_xdr_reject_offer(),
+        Marketplace::spec_xdr_withdraw_offer(),
+        Marketplace::spec_xdr_get_offer(),
+        Marketplace::spec_xdr_get_item_offers(),
+        Marketplace::spec_xdr_get_item_offer_count(),
+        Marketplace::spec_xdr_get_order(),
+        Marketplace::spec_xdr_get_buyer_orders(),
+        Marketplace::spec_xdr_get_buyer_order_count(),
+        Marketplace::spec_xdr_get_seller_orders(),
+        Marketplace::spec_xdr_get_seller_order_count(),
+        Marketplace::spec_xdr_mark_shipped(),
+        Marketplace::spec_xdr_confirm_delivery(),
+        Marketplace::spec_xdr_release_order(),
+        Marketplace::spec_xdr_reclaim_order(),
+        Marketplace::spec_xdr_set_return_window(),
+        Marketplace::spec_xdr_get_return_window(),
+        Marketplace::spec_xdr_request_return(),
+        Marketplace::spec_xdr_accept_return(),
+        Marketplace::spec_xdr_confirm_return_received(),
+        Marketplace::spec_xdr_reject_return(),
+        Marketplace::spec_xdr_escalate_return(),
+        Marketplace::spec_xdr_resolve_return(),
+        Marketplace::spec_xdr_set_fulfillment_windows(),
+        Marketplace::spec_xdr_get_fulfillment_config(),
+        Marketplace::spec_xdr_propose_admin(),
+        Marketplace::spec_xdr_accept_admin(),
+        Marketplace::spec_xdr_grant_role(),
+        Marketplace::spec_xdr_revoke_role(),
+        Marketplace::spec_xdr_get_admin(),
+        Marketplace::spec_xdr_get_pending_admin(),
+        Marketplace::spec_xdr_has_role(),
+        Marketplace::spec_xdr_pause(),
+        Marketplace::spec_xdr_unpause(),
+        Marketplace::spec_xdr_is_paused(),
+        Marketplace::spec_xdr_set_fee(),
+        Marketplace::spec_xdr_withdraw_fees(),
+        Marketplace::spec_xdr_upgrade(),
+        Marketplace::spec_xdr_migrate(),
+        Marketplace::spec_xdr_get_schema_version(),
+        Marketplace::spec_xdr_get_fee_config(),
+        Marketplace::spec_xdr_get_collected_fees(),
+        market::Item::spec_xdr(),
+        market::Category::spec_xdr(),
+        market::SellerProfile::spec_xdr(),
+        market::VerificationPolicy::spec_xdr(),
+        market::Order::spec_xdr(),
+        market::OrderStatus::spec_xdr(),
+        market::FulfillmentConfig::spec_xdr(),
+        market::DutchAuction::spec_xdr(),
+        market::PriceCurve::spec_xdr(),
+        market::OracleConfig::spec_xdr(),
+        market::Offer::spec_xdr(),
+        market::OfferStatus::spec_xdr(),
+        market::Auction::spec_xdr(),
+        market::AuctionStatus::spec_xdr(),
+        market::FeeConfig::spec_xdr(),
+        Role::spec_xdr(),
+    ]
+}
@@ -0,0 +1,298 @@
+use auto_release_escrow_contract::{AutoReleaseEscrowContract, AutoReleaseEscrowContractClient};
+use installment_payment_contract::installment_payment::installment::{
+    InstallmentPayment, InstallmentPaymentClient,
+};
+use paystar_common::Role;
+use real_market_place::{Marketplace, MarketplaceClient};
+use serde_json::json;
+use soroban_sdk::{
+    testutils::Address as _,
+    token::StellarAssetClient,
+    xdr::{Limits, ScVal, WriteXdr},
+    Address, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::{bindings, decode, Contract, Error, Invocation, Target};
+
+fn strkey(env: &Env, address: &Address) -> std::string::String {
+    match ScVal::try_from_val(env, &address.to_val()).unwrap() {
+        ScVal::Address(address) => address.to_string(),
+        _ => unreachable!(),
+    }
+}
+
+fn to_xdr<T: IntoVal<Env, Val>>(env: &Env, value: T) -> std::string::String {
+    ScVal::try_from_val(env, &value.into_val(env))
+        .unwrap()
+        .to_xdr_base64(Limits::none())
+        .unwrap()
+}
+
+fn named(pairs: &[(&str, &str)]) -> std::vec::Vec<(std::string::String, std::string::String)> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Runs the invocation against a contract in the test environment.
+fn call(env: &Env, contract_id: &Address, invocation: &Invocation) -> Val {
+    let mut args: Vec<Val> = Vec::new(env);
+    for arg in &invocation.args {
+        args.push_back(Val::try_from_val(env, &arg.val).unwrap());
+    }
+    env.invoke_contract(contract_id, &Symbol::new(env, &invocation.function), args)
+}
+
+#[test]
+fn test_invocation_orders_arguments_by_spec() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AutoReleaseEscrowContract, ());
+    let client = AutoReleaseEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize(&admin);
+
+    // Arguments given in the wrong order still reach the contract in the right one
+    let invocation = Invocation::build(
+        Contract::Escrow,
+        "set_fee",
+        &named(&[
+            ("treasury", &strkey(&env, &treasury)),
+            ("fee_bps", "250"),
+            ("fee_manager", &strkey(&env, &admin)),
+        ]),
+    )
+    .unwrap();
+    let names: std::vec::Vec<_> = invocation
+        .args
+        .iter()
+        .map(|arg| arg.name.as_str())
+        .collect();
+    assert_eq!(names, ["fee_manager", "fee_bps", "treasury"]);
+
+    call(&env, &contract_id, &invocation);
+    assert_eq!(client.get_fee_config().unwrap().fee_bps, 250);
+
+    let command = invocation.command(&Target {
+        contract_id: Some(strkey(&env, &contract_id)),
+        source: Some("admin".to_string()),
+        network: Some("testnet".to_string()),
+    });
+    assert_eq!(
+        command,
+        format!(
+            "stellar contract invoke --id {} --source admin --network testnet -- set_fee --fee_manager {} --fee_bps 250 --treasury {}",
+            strkey(&env, &contract_id),
+            strkey(&env, &admin),
+            strkey(&env, &treasury),
+        )
+    );
+}
+
+#[test]
+fn test_enum_and_struct_arguments_reach_the_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(InstallmentPayment, ());
+    let client = InstallmentPaymentClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+    client.initialize(&admin);
+
+    let grant = Invocation::build(
+        Contract::Installment,
+        "grant_role",
+        &named(&[
+            ("admin", &strkey(&env, &admin)),
+            ("role", "Arbiter"),
+            ("address", &strkey(&env, &arbiter)),
+        ]),
+    )
+    .unwrap();
+    call(&env, &contract_id, &grant);
+    assert!(client.has_role(&Role::Arbiter, &arbiter));
+
+    let config = json!({
+        "signers": [strkey(&env, &admin), strkey(&env, &arbiter)],
+        "threshold": 2,
+        "proposal_ttl": 3600,
+    });
+    let enable = Invocation::build(
+        Contract::Installment,
+        "enable_governance",
+        &named(&[
+            ("admin", &strkey(&env, &admin)),
+            ("config", &config.to_string()),
+        ]),
+    )
+    .unwrap();
+    call(&env, &contract_id, &enable);
+    assert_eq!(client.get_governance().unwrap().threshold, 2);
+
+    let propose = Invocation::build(
+        Contract::Installment,
+        "propose_action",
+        &named(&[
+            ("signer", &strkey(&env, &admin)),
+            (
+                "action",
+                &json!({ "SetFee": [100, strkey(&env, &admin)] }).to_string(),
+            ),
+        ]),
+    )
+    .unwrap();
+    call(&env, &contract_id, &propose);
+    assert!(client.get_governance_proposal(&1).is_some());
+}
+
+#[test]
+fn test_invalid_arguments_are_rejected() {
+    let missing = Invocation::build(Contract::Marketplace, "pause", &[]);
+    assert!(
+        matches!(missing, Err(Error::Invalid(message)) if message.contains("missing argument `pauser`"))
+    );
+
+    let unknown = Invocation::build(Contract::Marketplace, "pause", &named(&[("admin", "GA")]));
+    assert!(matches!(unknown, Err(Error::Invalid(message)) if message.contains("pauser: Address")));
+
+    let invalid = Invocation::build(
+        Contract::Escrow,
+        "release_funds",
+        &named(&[("escrow_id", "-1")]),
+    );
+    assert!(matches!(invalid, Err(Error::Invalid(message)) if message.contains("expected u64")));
+
+    let function = Invocation::build(Contract::Escrow, "set_admin", &[]);
+    assert!(matches!(function, Err(Error::Unknown(_))));
+}
+
+#[test]
+fn test_decode_stored_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+
+    let escrow =
+        AutoReleaseEscrowContractClient::new(&env, &env.register(AutoReleaseEscrowContract, ()));
+    escrow.initialize(&admin);
+    let escrow_id = escrow.create_escrow(&buyer, &seller, &1_000, &token, &100);
+    let decoded = decode::decode(
+        Contract::Escrow,
+        "Escrow",
+        &to_xdr(&env, escrow.get_escrow(&escrow_id)),
+    )
+    .unwrap();
+    assert_eq!(decoded["id"], json!("1"));
+    assert_eq!(decoded["amount"], json!("1000"));
+    assert_eq!(decoded["buyer"], json!(strkey(&env, &buyer)));
+    assert_eq!(decoded["status"], json!("Active"));
+    assert_eq!(decoded["dispute_reason"], json!(null));
+
+    let installment = InstallmentPaymentClient::new(&env, &env.register(InstallmentPayment, ()));
+    installment.create_installment_agreement(
+        &seller,
+        &buyer,
+        &800,
+        &1_000,
+        &admin,
+        &token,
+        &String::from_str(&env, "laptop"),
+    );
+    let decoded = decode::decode(
+        Contract::Installment,
+        "InstallmentAgreement",
+        &to_xdr(&env, installment.get_installment_agreement(&1).unwrap()),
+    )
+    .unwrap();
+    assert_eq!(decoded["total_amount"], json!("800"));
+    assert_eq!(decoded["description"], json!("laptop"));
+    assert_eq!(decoded["paid_history"], json!([]));
+
+    let market = MarketplaceClient::new(&env, &env.register(Marketplace, ()));
+    market.initialize(&admin, &token);
+    market.list_item(
+        &seller,
+        &String::from_str(&env, "Guitar"),
+        &100,
+        &5,
+        &String::from_str(&env, "ipfs://guitar"),
+    );
+    let decoded = decode::decode(
+        Contract::Marketplace,
+        "Vec<Item>",
+        &to_xdr(&env, market.get_items()),
+    )
+    .unwrap();
+    assert_eq!(decoded[0]["name"], json!("Guitar"));
+    assert_eq!(decoded[0]["quantity"], json!(5));
+
+    // Decoding as the wrong type fails instead of printing garbage
+    let wrong = decode::decode(Contract::Marketplace, "Item", &to_xdr(&env, 5_u32));
+    assert!(matches!(wrong, Err(Error::Invalid(_))));
+}
+
+// Where the frontend keeps the generated clients
+fn checked_in_bindings() -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../frontend/lib/soroban/contracts")
+}
+
+#[test]
+fn test_checked_in_bindings_match_contracts() {
+    let dir = checked_in_bindings();
+    for contract in Contract::ALL {
+        let path = dir.join(bindings::file_name(contract));
+        let checked_in = std::fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("cannot read {}: {error}", path.display()));
+        assert!(
+            checked_in == bindings::typescript(contract),
+            "{} is out of date with the {} contract; regenerate it with \
+             `cargo run -p paystar-cli -- bindings ../frontend/lib/soroban/contracts`",
+            path.display(),
+            contract.name()
+        );
+    }
+}
+
+#[test]
+fn test_bindings_type_every_function_and_record() {
+    for contract in Contract::ALL {
+        let spec = contract.spec();
+        let module = bindings::typescript(contract);
+
+        for function in spec.functions() {
+            let name = function.name.to_utf8_string_lossy();
+            assert!(
+                module.contains(&format!("\n  {name}: (")),
+                "{} client has no `{name}` method",
+                contract.name()
+            );
+        }
+        // Every spec entry is embedded for the SDK to encode and decode with
+        let embedded = module
+            .lines()
+            .filter(|line| line.trim_start().starts_with("\"AAAA"))
+            .count();
+        assert_eq!(embedded, spec.entries().len());
+    }
+
+    let module = bindings::typescript(Contract::Installment);
+    assert!(module.contains("export interface InstallmentAgreement {\n  amount_paid: bigint\n"));
+    assert!(module.contains("  paid_history: Array<PaidHistory>\n"));
+    assert!(module.contains(
+        "  ) => Promise<contract.AssembledTransaction<InstallmentAgreement | undefined>>"
+    ));
+    assert!(module.contains("  | { tag: \"SetFee\"; values: readonly [number, string] }\n"));
+
+    let module = bindings::typescript(Contract::Escrow);
+    assert!(module.contains("  dispute_reason: string | undefined\n"));
+    assert!(module.contains("  | { tag: \"Active\"; values: void }\n"));
+    assert!(module.contains("  1: { message: \"AlreadyInitialized\" },\n"));
+}
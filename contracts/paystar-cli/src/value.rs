@@ -0,0 +1,363 @@
+//! Conversions between JSON and `ScVal`, driven by the contract spec.
+//!
+//! * integers are JSON numbers; `u64` and wider also accept decimal strings
+//!   and are always printed as strings, since JSON numbers lose precision
+//! * addresses are strkeys (`G...` or `C...`) and bytes are hex
+//! * structs are objects keyed by field name
+//! * enums are the variant name, or `{"Variant": [values]}` for variants with data
+
+use serde_json::{Map, Value};
+use stellar_xdr::curr::{
+    ScMap, ScMapEntry, ScSpecEntry, ScSpecTypeDef, ScSpecUdtUnionCaseV0, ScString, ScSymbol, ScVal,
+    ScVec,
+};
+
+use crate::error::{Error, Result};
+use crate::spec::{type_name, Spec};
+
+/// Converts a command-line argument. Strings, symbols, addresses and bytes
+/// are taken as they are; every other type is parsed as JSON, falling back
+/// to a plain string so enum variants need no quotes.
+pub fn from_arg(spec: &Spec, def: &ScSpecTypeDef, raw: &str) -> Result<ScVal> {
+    let json = match def {
+        ScSpecTypeDef::String
+        | ScSpecTypeDef::Symbol
+        | ScSpecTypeDef::Address
+        | ScSpecTypeDef::Bytes
+        | ScSpecTypeDef::BytesN(_) => Value::String(raw.to_string()),
+        _ => serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string())),
+    };
+    from_json(spec, def, &json)
+}
+
+pub fn from_json(spec: &Spec, def: &ScSpecTypeDef, json: &Value) -> Result<ScVal> {
+    let invalid = || Error::Invalid(format!("expected {}, got `{json}`", type_name(def)));
+
+    Ok(match def {
+        ScSpecTypeDef::Void => match json {
+            Value::Null => ScVal::Void,
+            _ => return Err(invalid()),
+        },
+        ScSpecTypeDef::Bool => ScVal::Bool(json.as_bool().ok_or_else(invalid)?),
+        ScSpecTypeDef::U32 => ScVal::U32(integer(json).ok_or_else(invalid)?),
+        ScSpecTypeDef::I32 => ScVal::I32(integer(json).ok_or_else(invalid)?),
+        ScSpecTypeDef::U64 => ScVal::U64(integer(json).ok_or_else(invalid)?),
+        ScSpecTypeDef::I64 => ScVal::I64(integer(json).ok_or_else(invalid)?),
+        ScSpecTypeDef::U128 => integer::<u128>(json).ok_or_else(invalid)?.into(),
+        ScSpecTypeDef::I128 => integer::<i128>(json).ok_or_else(invalid)?.into(),
+        ScSpecTypeDef::String => ScVal::String(ScString(
+            string(json)
+                .ok_or_else(invalid)?
+                .try_into()
+                .map_err(|_| invalid())?,
+        )),
+        ScSpecTypeDef::Symbol => ScVal::Symbol(ScSymbol(
+            string(json)
+                .ok_or_else(invalid)?
+                .try_into()
+                .map_err(|_| invalid())?,
+        )),
+        ScSpecTypeDef::Address => ScVal::Address(
+            string(json)
+                .ok_or_else(invalid)?
+                .parse()
+                .map_err(|_| invalid())?,
+        ),
+        ScSpecTypeDef::Bytes => {
+            let decoded = hex_decode(string(json).ok_or_else(invalid)?).ok_or_else(invalid)?;
+            ScVal::Bytes(decoded.try_into()?)
+        }
+        ScSpecTypeDef::BytesN(bytes) => {
+            let decoded = hex_decode(string(json).ok_or_else(invalid)?).ok_or_else(invalid)?;
+            if decoded.len() != bytes.n as usize {
+                return Err(invalid());
+            }
+            ScVal::Bytes(decoded.try_into()?)
+        }
+        ScSpecTypeDef::Option(option) => match json {
+            Value::Null => ScVal::Void,
+            _ => from_json(spec, &option.value_type, json)?,
+        },
+        ScSpecTypeDef::Vec(vec) => {
+            let values = json
+                .as_array()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(|value| from_json(spec, &vec.element_type, value))
+                .collect::<Result<Vec<_>>>()?;
+            vec_val(values)?
+        }
+        ScSpecTypeDef::Tuple(tuple) => {
+            let values = json.as_array().ok_or_else(invalid)?;
+            if values.len() != tuple.value_types.len() {
+                return Err(invalid());
+            }
+            let values = tuple
+                .value_types
+                .iter()
+                .zip(values)
+                .map(|(def, value)| from_json(spec, def, value))
+                .collect::<Result<Vec<_>>>()?;
+            vec_val(values)?
+        }
+        ScSpecTypeDef::Map(map) => {
+            let entries = json
+                .as_object()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(|(key, value)| {
+                    Ok(ScMapEntry {
+                        key: from_arg(spec, &map.key_type, key)?,
+                        val: from_json(spec, &map.value_type, value)?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            ScVal::Map(Some(ScMap::sorted_from(entries)?))
+        }
+        ScSpecTypeDef::Udt(udt) => udt_from_json(spec, &udt.name.to_utf8_string_lossy(), json)?,
+        _ => {
+            return Err(Error::Invalid(format!(
+                "{} arguments are not supported",
+                type_name(def)
+            )))
+        }
+    })
+}
+
+fn udt_from_json(spec: &Spec, name: &str, json: &Value) -> Result<ScVal> {
+    let invalid = || Error::Invalid(format!("expected {name}, got `{json}`"));
+
+    match spec.udt(name)? {
+        ScSpecEntry::UdtStructV0(udt) => {
+            let object = json.as_object().ok_or_else(invalid)?;
+            if let Some(unknown) = object.keys().find(|key| {
+                !udt.fields
+                    .iter()
+                    .any(|field| field.name.to_utf8_string_lossy() == **key)
+            }) {
+                return Err(Error::Invalid(format!("{name} has no field `{unknown}`")));
+            }
+            let entries = udt
+                .fields
+                .iter()
+                .map(|field| {
+                    let field_name = field.name.to_utf8_string_lossy();
+                    let value = object.get(&field_name).unwrap_or(&Value::Null);
+                    Ok(ScMapEntry {
+                        key: ScVal::Symbol(ScSymbol(field.name.to_vec().try_into()?)),
+                        val: from_json(spec, &field.type_, value).map_err(|error| {
+                            Error::Invalid(format!("{name}.{field_name}: {error}"))
+                        })?,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(ScVal::Map(Some(ScMap::sorted_from(entries)?)))
+        }
+        ScSpecEntry::UdtUnionV0(udt) => {
+            // `"Variant"` or `{"Variant": [values]}`
+            let (variant, values) = match json {
+                Value::String(variant) => (variant.as_str(), None),
+                Value::Object(object) if object.len() == 1 => {
+                    let (variant, values) = object.iter().next().unwrap();
+                    (variant.as_str(), Some(values))
+                }
+                _ => return Err(invalid()),
+            };
+            let case = udt
+                .cases
+                .iter()
+                .find(|case| case_name(case) == variant)
+                .ok_or_else(|| Error::Invalid(format!("{name} has no variant `{variant}`")))?;
+
+            let mut vals = vec![ScVal::Symbol(ScSymbol(variant.try_into()?))];
+            match (case, values) {
+                (ScSpecUdtUnionCaseV0::VoidV0(_), None) => {}
+                (ScSpecUdtUnionCaseV0::TupleV0(tuple), Some(values)) => {
+                    // A single value does not need to be wrapped in an array
+                    let values = match values {
+                        Value::Array(values) => values.clone(),
+                        value => vec![value.clone()],
+                    };
+                    if values.len() != tuple.type_.len() {
+                        return Err(invalid());
+                    }
+                    for (def, value) in tuple.type_.iter().zip(&values) {
+                        vals.push(from_json(spec, def, value)?);
+                    }
+                }
+                _ => return Err(invalid()),
+            }
+            vec_val(vals)
+        }
+        ScSpecEntry::UdtEnumV0(udt) => {
+            let value = match json {
+                Value::String(variant) => udt
+                    .cases
+                    .iter()
+                    .find(|case| case.name.to_utf8_string_lossy() == *variant)
+                    .map(|case| case.value),
+                _ => integer(json),
+            };
+            Ok(ScVal::U32(value.ok_or_else(invalid)?))
+        }
+        _ => Err(Error::Invalid(format!(
+            "{name} arguments are not supported"
+        ))),
+    }
+}
+
+/// Renders a value of the given type as JSON, the inverse of [`from_json`].
+pub fn to_json(spec: &Spec, def: &ScSpecTypeDef, val: &ScVal) -> Result<Value> {
+    let invalid = || Error::Invalid(format!("expected {}, got {val:?}", type_name(def)));
+
+    Ok(match (def, val) {
+        (ScSpecTypeDef::Void, ScVal::Void) => Value::Null,
+        (ScSpecTypeDef::Bool, ScVal::Bool(value)) => Value::Bool(*value),
+        (ScSpecTypeDef::U32, ScVal::U32(value)) => Value::from(*value),
+        (ScSpecTypeDef::I32, ScVal::I32(value)) => Value::from(*value),
+        (ScSpecTypeDef::U64, ScVal::U64(value)) => Value::String(value.to_string()),
+        (ScSpecTypeDef::I64, ScVal::I64(value)) => Value::String(value.to_string()),
+        (ScSpecTypeDef::U128, ScVal::U128(_)) => Value::String(
+            u128::try_from(val.clone())
+                .map_err(|_| invalid())?
+                .to_string(),
+        ),
+        (ScSpecTypeDef::I128, ScVal::I128(_)) => Value::String(
+            i128::try_from(val.clone())
+                .map_err(|_| invalid())?
+                .to_string(),
+        ),
+        (ScSpecTypeDef::String, ScVal::String(value)) => {
+            Value::String(value.to_utf8_string_lossy())
+        }
+        (ScSpecTypeDef::Symbol, ScVal::Symbol(value)) => {
+            Value::String(value.to_utf8_string_lossy())
+        }
+        (ScSpecTypeDef::Address, ScVal::Address(address)) => Value::String(address.to_string()),
+        (ScSpecTypeDef::Bytes | ScSpecTypeDef::BytesN(_), ScVal::Bytes(bytes)) => {
+            Value::String(hex_encode(bytes))
+        }
+        (ScSpecTypeDef::Option(_), ScVal::Void) => Value::Null,
+        (ScSpecTypeDef::Option(option), _) => to_json(spec, &option.value_type, val)?,
+        (ScSpecTypeDef::Vec(vec), ScVal::Vec(values)) => Value::Array(
+            values
+                .iter()
+                .flat_map(|values| values.iter())
+                .map(|value| to_json(spec, &vec.element_type, value))
+                .collect::<Result<_>>()?,
+        ),
+        (ScSpecTypeDef::Tuple(tuple), ScVal::Vec(Some(values))) => Value::Array(
+            tuple
+                .value_types
+                .iter()
+                .zip(values.iter())
+                .map(|(def, value)| to_json(spec, def, value))
+                .collect::<Result<_>>()?,
+        ),
+        (ScSpecTypeDef::Map(map), ScVal::Map(Some(entries))) => {
+            let mut object = Map::new();
+            for entry in entries.iter() {
+                let key = match to_json(spec, &map.key_type, &entry.key)? {
+                    Value::String(key) => key,
+                    key => key.to_string(),
+                };
+                object.insert(key, to_json(spec, &map.value_type, &entry.val)?);
+            }
+            Value::Object(object)
+        }
+        (ScSpecTypeDef::Udt(udt), _) => udt_to_json(spec, &udt.name.to_utf8_string_lossy(), val)?,
+        _ => return Err(invalid()),
+    })
+}
+
+fn udt_to_json(spec: &Spec, name: &str, val: &ScVal) -> Result<Value> {
+    let invalid = || Error::Invalid(format!("expected {name}, got {val:?}"));
+
+    match (spec.udt(name)?, val) {
+        (ScSpecEntry::UdtStructV0(udt), ScVal::Map(Some(entries))) => {
+            let mut object = Map::new();
+            for field in udt.fields.iter() {
+                let field_name = field.name.to_utf8_string_lossy();
+                let entry = entries
+                    .iter()
+                    .find(|entry| {
+                        matches!(&entry.key, ScVal::Symbol(key) if key.to_utf8_string_lossy() == field_name)
+                    })
+                    .ok_or_else(|| Error::Invalid(format!("{name} is missing `{field_name}`")))?;
+                object.insert(field_name, to_json(spec, &field.type_, &entry.val)?);
+            }
+            Ok(Value::Object(object))
+        }
+        (ScSpecEntry::UdtUnionV0(udt), ScVal::Vec(Some(values))) => {
+            let Some(ScVal::Symbol(variant)) = values.first() else {
+                return Err(invalid());
+            };
+            let variant = variant.to_utf8_string_lossy();
+            let case = udt
+                .cases
+                .iter()
+                .find(|case| case_name(case) == variant)
+                .ok_or_else(invalid)?;
+            match case {
+                ScSpecUdtUnionCaseV0::VoidV0(_) => Ok(Value::String(variant)),
+                ScSpecUdtUnionCaseV0::TupleV0(tuple) => {
+                    let values = tuple
+                        .type_
+                        .iter()
+                        .zip(values.iter().skip(1))
+                        .map(|(def, value)| to_json(spec, def, value))
+                        .collect::<Result<Vec<_>>>()?;
+                    let mut object = Map::new();
+                    object.insert(variant, Value::Array(values));
+                    Ok(Value::Object(object))
+                }
+            }
+        }
+        (ScSpecEntry::UdtEnumV0(udt), ScVal::U32(value)) => udt
+            .cases
+            .iter()
+            .find(|case| case.value == *value)
+            .map(|case| Value::String(case.name.to_utf8_string_lossy()))
+            .ok_or_else(invalid),
+        _ => Err(invalid()),
+    }
+}
+
+fn case_name(case: &ScSpecUdtUnionCaseV0) -> String {
+    match case {
+        ScSpecUdtUnionCaseV0::VoidV0(case) => case.name.to_utf8_string_lossy(),
+        ScSpecUdtUnionCaseV0::TupleV0(case) => case.name.to_utf8_string_lossy(),
+    }
+}
+
+fn vec_val(values: Vec<ScVal>) -> Result<ScVal> {
+    Ok(ScVal::Vec(Some(ScVec(values.try_into()?))))
+}
+
+fn string(json: &Value) -> Option<&str> {
+    json.as_str()
+}
+
+// A JSON number, or a decimal string for values JSON numbers cannot hold
+fn integer<T: std::str::FromStr>(json: &Value) -> Option<T> {
+    match json {
+        Value::Number(number) => number.to_string().parse().ok(),
+        Value::String(string) => string.parse().ok(),
+        _ => None,
+    }
+}
+
+fn hex_decode(hex: &str) -> Option<Vec<u8>> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
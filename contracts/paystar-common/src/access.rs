@@ -0,0 +1,191 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, IntoVal, Symbol, Val};
+
+use crate::{
+    error::CommonError,
+    events::{
+        self, AdminChangedEvent, AdminProposedEvent, PauseEvent, RoleEvent, CONTRACT_WIDE,
+        EVENT_VERSION,
+    },
+};
+
+/// Access-control roles. The admin implicitly holds every role.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    Admin,      // Manages roles, upgrades and migrations
+    Arbiter,    // Resolves disputes
+    Pauser,     // Pauses and unpauses the contract
+    FeeManager, // Configures and withdraws platform fees
+    Attester,   // Verifies marketplace sellers
+}
+
+/// Admin handover, role management and the pause switch, shared by every
+/// contract. Implementors only say where the state lives, so each contract
+/// keeps the storage layout its existing deployments already use.
+pub trait AccessControl {
+    /// Contract name used as the first event topic.
+    const CONTRACT: Symbol;
+    /// Non-admin roles the contract checks; granting or revoking any other fails.
+    const ROLES: &'static [Role];
+
+    fn load_admin(env: &Env) -> Option<Address>;
+    fn store_admin(env: &Env, admin: &Address);
+    fn load_pending_admin(env: &Env) -> Option<Address>;
+    /// Stores the proposed admin, or clears it with `None`.
+    fn store_pending_admin(env: &Env, pending_admin: Option<&Address>);
+    fn has_granted_role(env: &Env, role: Role, account: &Address) -> bool;
+    fn store_role(env: &Env, role: Role, account: &Address, granted: bool);
+    fn load_paused(env: &Env) -> bool;
+    fn store_paused(env: &Env, paused: bool);
+
+    /// Stores the first admin and announces it, so indexers see who owns the
+    /// contract from its first event. Callers check the contract is not yet initialized.
+    fn initialize_admin(env: &Env, admin: &Address) {
+        Self::store_admin(env, admin);
+        Self::publish(
+            env,
+            symbol_short!("set_admin"),
+            admin,
+            AdminChangedEvent {
+                version: EVENT_VERSION,
+                previous_admin: None,
+                admin: admin.clone(),
+            },
+        );
+    }
+
+    fn admin(env: &Env) -> Result<Address, CommonError> {
+        Self::load_admin(env).ok_or(CommonError::NotInitialized)
+    }
+
+    /// Whether `account` holds `role`; the admin holds every role.
+    fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+        Self::load_admin(env).as_ref() == Some(account)
+            || Self::has_granted_role(env, role, account)
+    }
+
+    /// Checks the caller's authorization and that it is the admin.
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), CommonError> {
+        caller.require_auth();
+        if Self::admin(env)? != *caller {
+            return Err(CommonError::NotAdmin);
+        }
+        Ok(())
+    }
+
+    /// Checks the caller's authorization and that it holds `role`.
+    fn require_role(env: &Env, role: Role, caller: &Address) -> Result<(), CommonError> {
+        caller.require_auth();
+        Self::admin(env)?;
+        if !Self::has_role(env, role, caller) {
+            return Err(CommonError::MissingRole);
+        }
+        Ok(())
+    }
+
+    /// Lets the admin propose a successor. Nothing changes until the proposed
+    /// address accepts, so a mistyped address can never take over.
+    fn propose_admin(env: &Env, admin: &Address, new_admin: &Address) -> Result<(), CommonError> {
+        Self::require_admin(env, admin)?;
+
+        Self::store_pending_admin(env, Some(new_admin));
+        Self::publish(
+            env,
+            symbol_short!("adm_prop"),
+            admin,
+            AdminProposedEvent {
+                version: EVENT_VERSION,
+                pending_admin: new_admin.clone(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Lets the proposed admin accept the handover.
+    fn accept_admin(env: &Env, new_admin: &Address) -> Result<(), CommonError> {
+        new_admin.require_auth();
+        if Self::load_pending_admin(env).as_ref() != Some(new_admin) {
+            return Err(CommonError::NotPendingAdmin);
+        }
+
+        let old_admin = Self::admin(env)?;
+        Self::store_admin(env, new_admin);
+        Self::store_pending_admin(env, None);
+        Self::publish(
+            env,
+            symbol_short!("set_admin"),
+            new_admin,
+            AdminChangedEvent {
+                version: EVENT_VERSION,
+                previous_admin: Some(old_admin),
+                admin: new_admin.clone(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Lets the admin grant or revoke one of the contract's non-admin roles. The
+    /// admin role itself only moves through `propose_admin` / `accept_admin`.
+    fn set_role(
+        env: &Env,
+        admin: &Address,
+        role: Role,
+        account: &Address,
+        granted: bool,
+    ) -> Result<(), CommonError> {
+        Self::require_admin(env, admin)?;
+        if !Self::ROLES.contains(&role) {
+            return Err(CommonError::InvalidRole);
+        }
+
+        Self::store_role(env, role, account, granted);
+        Self::publish_role(env, admin, role, account, granted);
+        Ok(())
+    }
+
+    /// Lets a pauser flip the circuit breaker.
+    fn set_paused(env: &Env, pauser: &Address, paused: bool) -> Result<(), CommonError> {
+        Self::require_role(env, Role::Pauser, pauser)?;
+
+        Self::store_paused(env, paused);
+        Self::publish(
+            env,
+            symbol_short!("paused"),
+            pauser,
+            PauseEvent {
+                version: EVENT_VERSION,
+                paused,
+            },
+        );
+        Ok(())
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), CommonError> {
+        if Self::load_paused(env) {
+            return Err(CommonError::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Announces a role change. Also used by contracts whose governance
+    /// edits roles without going through `set_role`.
+    fn publish_role(env: &Env, actor: &Address, role: Role, account: &Address, granted: bool) {
+        let action = if granted {
+            symbol_short!("role_add")
+        } else {
+            symbol_short!("role_del")
+        };
+        let event = RoleEvent {
+            version: EVENT_VERSION,
+            role,
+            account: account.clone(),
+            granted,
+        };
+        Self::publish(env, action, actor, event);
+    }
+
+    /// Publishes a contract-wide event under this contract's name.
+    fn publish<D: IntoVal<Env, Val>>(env: &Env, action: Symbol, actor: &Address, data: D) {
+        events::publish(env, Self::CONTRACT, action, CONTRACT_WIDE, actor, data);
+    }
+}
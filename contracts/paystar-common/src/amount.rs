@@ -0,0 +1,31 @@
+use crate::error::CommonError;
+
+/// Basis-point denominator (100% = 10_000 bps).
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Converts an unsigned amount into the `i128` the token interface expects.
+pub fn to_i128(amount: u128) -> Result<i128, CommonError> {
+    i128::try_from(amount).map_err(|_| CommonError::AmountOverflow)
+}
+
+/// Converts a token amount into an unsigned amount; negative amounts are rejected.
+pub fn to_u128(amount: i128) -> Result<u128, CommonError> {
+    u128::try_from(amount).map_err(|_| CommonError::InvalidAmount)
+}
+
+pub fn add(a: i128, b: i128) -> Result<i128, CommonError> {
+    a.checked_add(b).ok_or(CommonError::AmountOverflow)
+}
+
+pub fn sub(a: i128, b: i128) -> Result<i128, CommonError> {
+    a.checked_sub(b).ok_or(CommonError::AmountOverflow)
+}
+
+pub fn mul(a: i128, b: i128) -> Result<i128, CommonError> {
+    a.checked_mul(b).ok_or(CommonError::AmountOverflow)
+}
+
+/// The `bps` basis-point share of `amount`, rounded down.
+pub fn bps_share(amount: i128, bps: u32) -> Result<i128, CommonError> {
+    Ok(mul(amount, bps as i128)? / BPS_DENOMINATOR)
+}
@@ -0,0 +1,31 @@
+/// Failures raised by the shared modules. Each contract converts these into
+/// its own error type (or panic message) so callers see one error vocabulary.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CommonError {
+    NotInitialized,
+    NotAdmin,
+    MissingRole,
+    NotPendingAdmin,
+    InvalidRole,
+    ContractPaused,
+    InvalidAmount,
+    AmountOverflow,
+    InsufficientBalance,
+}
+
+impl CommonError {
+    /// Human readable message, for contracts that report errors by panicking.
+    pub fn message(&self) -> &'static str {
+        match self {
+            CommonError::NotInitialized => "Not initialized",
+            CommonError::NotAdmin => "Not admin",
+            CommonError::MissingRole => "Missing role",
+            CommonError::NotPendingAdmin => "Not pending admin",
+            CommonError::InvalidRole => "Invalid role",
+            CommonError::ContractPaused => "Contract paused",
+            CommonError::InvalidAmount => "Invalid amount",
+            CommonError::AmountOverflow => "Amount overflow",
+            CommonError::InsufficientBalance => "Insufficient balance",
+        }
+    }
+}
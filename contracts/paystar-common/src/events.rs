@@ -0,0 +1,86 @@
+//! Event layout shared by the PayStar contracts.
+//!
+//! Every event is published with the topics `(contract, action, id, actor)`:
+//!
+//! * `contract` - short name of the emitting contract (`escrow`, `install`, `market`)
+//! * `action` - what happened, e.g. `created` or `role_add`
+//! * `id` - primary id of the record the event is about, widened to `u128`;
+//!   `0` for contract-wide events such as admin, role, pause or fee changes
+//! * `actor` - the account that triggered the action, or the contract itself
+//!   for permissionless calls
+//!
+//! The data is a `#[contracttype]` payload carrying `EVENT_VERSION` and the
+//! full state after the change, so an indexer can rebuild every record from
+//! the event stream alone without reading contract storage.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Symbol, Val};
+
+use crate::access::Role;
+
+/// Version stamped into every payload. Bumped whenever a payload changes shape.
+pub const EVENT_VERSION: u32 = 1;
+
+/// Id topic of events that are not about a single record.
+pub const CONTRACT_WIDE: u128 = 0;
+
+/// `set_admin`: the admin was set on initialization or handed over.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminChangedEvent {
+    pub version: u32,
+    pub previous_admin: Option<Address>, // None on initialization
+    pub admin: Address,
+}
+
+/// `adm_prop`: the admin proposed a successor who still has to accept.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposedEvent {
+    pub version: u32,
+    pub pending_admin: Address,
+}
+
+/// `role_add` / `role_del`: a role was granted or revoked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleEvent {
+    pub version: u32,
+    pub role: Role,
+    pub account: Address,
+    pub granted: bool,
+}
+
+/// `paused`: the circuit breaker was flipped.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseEvent {
+    pub version: u32,
+    pub paused: bool,
+}
+
+/// `upgraded`: the contract WASM was replaced.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeEvent {
+    pub version: u32,
+    pub wasm_hash: BytesN<32>,
+}
+
+/// `migrated`: a migration batch ran. Each rewritten record is also published
+/// on its own under the `mig_rec` action.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationEvent {
+    pub version: u32,
+    pub migrated: u32,
+    pub schema_version: u32, // schema version after the batch
+}
+
+/// Publishes an event under the `(contract, action, id, actor)` topics.
+pub fn publish<D>(env: &Env, contract: Symbol, action: Symbol, id: u128, actor: &Address, data: D)
+where
+    D: IntoVal<Env, Val>,
+{
+    env.events()
+        .publish((contract, action, id, actor.clone()), data);
+}
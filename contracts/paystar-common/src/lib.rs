@@ -0,0 +1,17 @@
+#![no_std]
+
+//! Building blocks shared by the PayStar contracts: checked amount
+//! arithmetic, admin/role/pause access control, token transfer wrappers
+//! and the common event layout.
+
+pub mod access;
+pub mod amount;
+pub mod error;
+pub mod events;
+pub mod token;
+
+#[cfg(test)]
+mod test;
+
+pub use access::{AccessControl, Role};
+pub use error::CommonError;
@@ -0,0 +1,274 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short,
+    testutils::{Address as _, Events as _},
+    token::StellarAssetClient,
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+use crate::{
+    amount,
+    events::{AdminChangedEvent, RoleEvent, CONTRACT_WIDE, EVENT_VERSION},
+    token, AccessControl, CommonError, Role,
+};
+
+#[contracttype]
+enum TestKey {
+    Admin,
+    PendingAdmin,
+    Role(Role, Address),
+    Paused,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+enum TestError {
+    NotInitialized = 1,
+    NotAdmin = 2,
+    MissingRole = 3,
+    NotPendingAdmin = 4,
+    InvalidRole = 5,
+    ContractPaused = 6,
+    InvalidAmount = 7,
+    AmountOverflow = 8,
+    InsufficientBalance = 9,
+}
+
+impl From<CommonError> for TestError {
+    fn from(error: CommonError) -> Self {
+        match error {
+            CommonError::NotInitialized => TestError::NotInitialized,
+            CommonError::NotAdmin => TestError::NotAdmin,
+            CommonError::MissingRole => TestError::MissingRole,
+            CommonError::NotPendingAdmin => TestError::NotPendingAdmin,
+            CommonError::InvalidRole => TestError::InvalidRole,
+            CommonError::ContractPaused => TestError::ContractPaused,
+            CommonError::InvalidAmount => TestError::InvalidAmount,
+            CommonError::AmountOverflow => TestError::AmountOverflow,
+            CommonError::InsufficientBalance => TestError::InsufficientBalance,
+        }
+    }
+}
+
+/// Minimal contract wiring the shared modules the way the PayStar contracts do.
+#[contract]
+struct TestContract;
+
+#[contractimpl]
+impl TestContract {
+    pub fn init(env: Env, admin: Address) {
+        Self::initialize_admin(&env, &admin);
+    }
+
+    pub fn grant(env: Env, admin: Address, role: Role, account: Address) -> Result<(), TestError> {
+        Ok(Self::set_role(&env, &admin, role, &account, true)?)
+    }
+
+    pub fn pause(env: Env, pauser: Address) -> Result<(), TestError> {
+        Ok(Self::set_paused(&env, &pauser, true)?)
+    }
+
+    pub fn check_live(env: Env) -> Result<(), TestError> {
+        Ok(Self::require_not_paused(&env)?)
+    }
+
+    pub fn propose(env: Env, admin: Address, new_admin: Address) -> Result<(), TestError> {
+        Ok(Self::propose_admin(&env, &admin, &new_admin)?)
+    }
+
+    pub fn accept(env: Env, new_admin: Address) -> Result<(), TestError> {
+        Ok(Self::accept_admin(&env, &new_admin)?)
+    }
+
+    pub fn check_admin(env: Env, caller: Address) -> Result<(), TestError> {
+        Ok(Self::require_admin(&env, &caller)?)
+    }
+
+    pub fn check_role(env: Env, role: Role, account: Address) -> bool {
+        Self::has_role(&env, role, &account)
+    }
+
+    pub fn deposit(env: Env, token: Address, from: Address, amount: i128) -> Result<(), TestError> {
+        from.require_auth();
+        Ok(token::deposit(&env, &token, &from, amount)?)
+    }
+
+    pub fn deposit_u128(
+        env: Env,
+        token: Address,
+        from: Address,
+        amount: u128,
+    ) -> Result<(), TestError> {
+        from.require_auth();
+        let to = env.current_contract_address();
+        Ok(token::transfer_u128(&env, &token, &from, &to, amount)?)
+    }
+
+    pub fn payout(env: Env, token: Address, to: Address, amount: i128) -> Result<(), TestError> {
+        Ok(token::payout(&env, &token, &to, amount)?)
+    }
+}
+
+impl AccessControl for TestContract {
+    const CONTRACT: Symbol = symbol_short!("test");
+    const ROLES: &'static [Role] = &[Role::Arbiter, Role::Pauser, Role::FeeManager];
+
+    fn load_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&TestKey::Admin)
+    }
+
+    fn store_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&TestKey::Admin, admin);
+    }
+
+    fn load_pending_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&TestKey::PendingAdmin)
+    }
+
+    fn store_pending_admin(env: &Env, pending_admin: Option<&Address>) {
+        match pending_admin {
+            Some(pending_admin) => env
+                .storage()
+                .instance()
+                .set(&TestKey::PendingAdmin, pending_admin),
+            None => env.storage().instance().remove(&TestKey::PendingAdmin),
+        }
+    }
+
+    fn has_granted_role(env: &Env, role: Role, account: &Address) -> bool {
+        env.storage()
+            .instance()
+            .has(&TestKey::Role(role, account.clone()))
+    }
+
+    fn store_role(env: &Env, role: Role, account: &Address, granted: bool) {
+        let key = TestKey::Role(role, account.clone());
+        if granted {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+    }
+
+    fn load_paused(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&TestKey::Paused)
+            .unwrap_or(false)
+    }
+
+    fn store_paused(env: &Env, paused: bool) {
+        env.storage().instance().set(&TestKey::Paused, &paused);
+    }
+}
+
+fn setup<'a>() -> (Env, TestContractClient<'a>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(TestContract, ());
+    let client = TestContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.init(&admin);
+    (env, client, admin)
+}
+
+#[test]
+fn test_amount_conversions_are_checked() {
+    assert_eq!(amount::to_i128(80), Ok(80));
+    assert_eq!(amount::to_i128(u128::MAX), Err(CommonError::AmountOverflow));
+    assert_eq!(amount::to_u128(-1), Err(CommonError::InvalidAmount));
+    assert_eq!(amount::mul(i128::MAX, 2), Err(CommonError::AmountOverflow));
+    assert_eq!(amount::bps_share(1_000, 250), Ok(25));
+}
+
+#[test]
+fn test_transfer_checks_balance() {
+    let (env, client, admin) = setup();
+    let token_address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let holder = Address::generate(&env);
+    StellarAssetClient::new(&env, &token_address).mint(&holder, &100);
+
+    let result = client.try_deposit(&token_address, &holder, &101);
+    assert_eq!(result, Err(Ok(TestError::InsufficientBalance)));
+    let result = client.try_deposit_u128(&token_address, &holder, &u128::MAX);
+    assert_eq!(result, Err(Ok(TestError::AmountOverflow)));
+    let result = client.try_deposit(&token_address, &holder, &-1);
+    assert_eq!(result, Err(Ok(TestError::InvalidAmount)));
+
+    client.deposit(&token_address, &holder, &60);
+    client.payout(&token_address, &admin, &60);
+
+    let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&holder), 40);
+    assert_eq!(token_client.balance(&admin), 60);
+}
+
+#[test]
+fn test_roles_and_admin_handover() {
+    let (env, client, admin) = setup();
+    let pauser = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    let result = client.try_pause(&pauser);
+    assert_eq!(result, Err(Ok(TestError::MissingRole)));
+    client.grant(&admin, &Role::Pauser, &pauser);
+    client.pause(&pauser);
+    assert_eq!(client.try_check_live(), Err(Ok(TestError::ContractPaused)));
+
+    let result = client.try_grant(&admin, &Role::Admin, &pauser);
+    assert_eq!(result, Err(Ok(TestError::InvalidRole)));
+    // Roles the contract does not check cannot be granted either
+    let result = client.try_grant(&admin, &Role::Attester, &pauser);
+    assert_eq!(result, Err(Ok(TestError::InvalidRole)));
+
+    client.propose(&admin, &new_admin);
+    let result = client.try_accept(&pauser);
+    assert_eq!(result, Err(Ok(TestError::NotPendingAdmin)));
+    client.accept(&new_admin);
+
+    let result = client.try_check_admin(&admin);
+    assert_eq!(result, Err(Ok(TestError::NotAdmin)));
+    assert!(client.check_role(&Role::FeeManager, &new_admin));
+}
+
+#[test]
+fn test_events_use_shared_layout() {
+    let (env, client, admin) = setup();
+    let arbiter = Address::generate(&env);
+
+    client.grant(&admin, &Role::Arbiter, &arbiter);
+
+    let event = RoleEvent {
+        version: EVENT_VERSION,
+        role: Role::Arbiter,
+        account: arbiter.clone(),
+        granted: true,
+    };
+    let expected = (
+        client.address.clone(),
+        (
+            symbol_short!("test"),
+            symbol_short!("role_add"),
+            CONTRACT_WIDE,
+            admin.clone(),
+        )
+            .into_val(&env),
+        event.into_val(&env),
+    );
+    assert_eq!(env.events().all(), vec![&env, expected]);
+
+    let new_admin = Address::generate(&env);
+    client.propose(&admin, &new_admin);
+    client.accept(&new_admin);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let action: Symbol = topics.get(1).unwrap().into_val(&env);
+    let data: AdminChangedEvent = data.into_val(&env);
+    assert_eq!(action, symbol_short!("set_admin"));
+    assert_eq!(data.previous_admin, Some(admin));
+    assert_eq!(data.admin, new_admin);
+}
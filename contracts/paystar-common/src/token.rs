@@ -0,0 +1,54 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::{amount, error::CommonError};
+
+/// Transfers `amount` of `token` after checking the sender can cover it, so
+/// callers get `InsufficientBalance` instead of a trap inside the token.
+/// Zero amounts are a no-op; negative amounts are rejected.
+pub fn transfer(
+    env: &Env,
+    token: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), CommonError> {
+    if amount < 0 {
+        return Err(CommonError::InvalidAmount);
+    }
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let client = token::Client::new(env, token);
+    if client.balance(from) < amount {
+        return Err(CommonError::InsufficientBalance);
+    }
+    client.transfer(from, to, &amount);
+    Ok(())
+}
+
+/// `transfer` for contracts that account in unsigned amounts.
+pub fn transfer_u128(
+    env: &Env,
+    token: &Address,
+    from: &Address,
+    to: &Address,
+    amount: u128,
+) -> Result<(), CommonError> {
+    transfer(env, token, from, to, amount::to_i128(amount)?)
+}
+
+/// Pulls `amount` from `from` into the calling contract.
+pub fn deposit(
+    env: &Env,
+    token: &Address,
+    from: &Address,
+    amount: i128,
+) -> Result<(), CommonError> {
+    transfer(env, token, from, &env.current_contract_address(), amount)
+}
+
+/// Pays `amount` out of the calling contract to `to`.
+pub fn payout(env: &Env, token: &Address, to: &Address, amount: i128) -> Result<(), CommonError> {
+    transfer(env, token, &env.current_contract_address(), to, amount)
+}
@@ -0,0 +1,765 @@
+//! Decoding PayStar events into typed records.
+//!
+//! Every PayStar event is published under the topics
+//! `(contract, action, id, actor)` with a versioned `#[contracttype]` payload;
+//! see `paystar_common::events`. Structs arrive as `ScMap`s keyed by field
+//! name and enum variants as a vector holding the variant name, followed by
+//! the values of a tuple variant.
+
+use stellar_xdr::curr::{ScMap, ScMapEntry, ScVal};
+
+use crate::error::{Error, Result};
+use crate::input::ContractEvent;
+
+/// Highest payload version this build understands.
+pub const SUPPORTED_VERSION: u32 = 1;
+
+/// Which PayStar contract published an event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Contract {
+    Escrow,
+    Installment,
+    Marketplace,
+}
+
+impl Contract {
+    fn from_topic(name: &str) -> Option<Self> {
+        match name {
+            "escrow" => Some(Contract::Escrow),
+            "install" => Some(Contract::Installment),
+            "market" => Some(Contract::Marketplace),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Contract::Escrow => "escrow",
+            Contract::Installment => "install",
+            Contract::Marketplace => "market",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Escrow {
+    pub id: u64,
+    pub payer: String,
+    pub buyer: String,
+    pub seller: String,
+    pub amount: i128,
+    pub payment_token: String,
+    pub release_timestamp: u64,
+    pub status: String,
+    pub dispute_reason: Option<String>,
+    pub buyer_confirmed: bool,
+    pub seller_acknowledged: bool,
+    pub extensions: u32, // Number of accepted release-time extensions
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Agreement {
+    pub id: u128,
+    pub buyer: String,
+    pub seller: String,
+    pub is_accepted: bool,
+    pub amount_paid: u128,
+    pub payments: Vec<(u128, u64)>, // (amount, timestamp) of every installment so far
+    pub total_amount: u128,
+    pub deadline: u64,
+    pub is_finalized: bool,
+    pub is_canceled: bool,
+    pub arbitrator: String,
+    pub description: String,
+    pub token: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Item {
+    pub id: u32,
+    pub name: String,
+    pub price: i128,
+    pub token: Option<String>, // `None` for items listed before primary tokens, priced in the payment token
+    pub quantity: u32,
+    pub seller: String,
+    pub image_url: String,
+    pub category: Option<u32>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Seller {
+    pub address: String,
+    pub display_name: String,
+    pub metadata_uri: String,
+    pub contact_hash: String, // Hex
+    pub payout_address: String,
+    pub registered_at: u64,
+    pub verified_by: Option<String>,
+    pub verified_at: Option<u64>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Category {
+    pub id: u32,
+    pub name: String,
+    pub parent: Option<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Order {
+    pub id: u64,
+    pub item_id: u32,
+    pub buyer: String,
+    pub seller: String,
+    pub qty: u32,
+    pub unit_price: i128,
+    pub total: i128,
+    pub fee: i128,
+    pub payment_token: String,
+    pub created_at: u64,
+    pub status: String, // `OrderStatus` variant, e.g. `Paid`
+    pub ship_by: u64,
+    pub deliver_by: Option<u64>,
+    pub tracking_hash: Option<String>, // Hex
+    pub return_window: u64,
+    pub return_by: Option<u64>,
+    pub return_reason: Option<String>,
+    pub respond_by: Option<u64>,
+    pub seller_paid: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Auction {
+    pub id: u32,
+    pub seller: String,
+    pub name: String,
+    pub image_url: String,
+    pub reserve_price: i128,
+    pub min_increment: i128,
+    pub end_time: u64,
+    pub payment_token: String,
+    pub highest_bid: i128,
+    pub highest_bidder: Option<String>,
+    pub bid_count: u32,
+    pub status: String, // `AuctionStatus` variant, e.g. `Open`
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Offer {
+    pub id: u64,
+    pub item_id: u32,
+    pub buyer: String,
+    pub seller: String,
+    pub qty: u32,
+    pub unit_price: i128,
+    pub escrowed: i128,
+    pub payment_token: String,
+    pub counter_price: Option<i128>,
+    pub expires_at: u64,
+    pub status: String, // `OfferStatus` variant, e.g. `Open`
+    pub order_id: Option<u64>,
+}
+
+/// Descending price schedule of a listing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DutchAuction {
+    pub item_id: u32,
+    pub start_price: i128,
+    pub floor_price: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub curve: String,      // `PriceCurve` variant, e.g. `Linear`
+    pub steps: Option<u32>, // Number of drops of a `Stepwise` curve
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationPolicy {
+    pub categories: Vec<u32>,
+    pub min_prices: Vec<(String, i128)>, // (token, lowest reserved price)
+    pub min_reference_price: Option<i128>,
+}
+
+/// The payload of an event, for the actions that change indexed records.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Payload {
+    Escrow(Escrow),
+    EscrowReleased {
+        escrow: Escrow,
+        seller_amount: i128,
+        fee: i128,
+    },
+    Agreement(Agreement),
+    AgreementSettled {
+        agreement: Agreement,
+        seller_amount: u128,
+        fee: u128,
+    },
+    MarketInitialized {
+        payment_token: String,
+    },
+    Item(Item),
+    ItemBought {
+        item: Item,
+        qty: u32,
+        payment_token: String,
+        total: i128,
+        fee: i128,
+    },
+    /// A listing's price in each token it accepts, as `(token, price)`.
+    ItemPrices {
+        item_id: u32,
+        prices: Vec<(String, i128)>,
+    },
+    /// The marketplace's token allowlist after a change.
+    AcceptedTokens(Vec<String>),
+    /// A listing's price in the oracle's base asset, or `None` once cleared.
+    ReferencePrice {
+        item_id: u32,
+        price: Option<i128>,
+    },
+    /// The price schedule a listing now follows.
+    DutchAuction(DutchAuction),
+    /// The return window a listing offers on new orders, in seconds.
+    ReturnWindow {
+        item_id: u32,
+        return_window: u64,
+    },
+    /// The listings only verified sellers can offer, after a change.
+    VerificationPolicy(VerificationPolicy),
+    Order(Order),
+    OrderSettled {
+        order: Order,
+        seller_amount: i128,
+        fee: i128,
+    },
+    Offer(Offer),
+    Auction(Auction),
+    Category(Category),
+    CategoryRemoved(Category),
+    /// A seller profile after registration or a change of its verification.
+    Seller(Seller),
+    Bid {
+        auction: Auction,
+        outbid: Option<String>,
+        refund: i128,
+    },
+    /// The winning bid is paid out through the order, like any purchase.
+    AuctionSold {
+        auction: Auction,
+        order_id: u64,
+    },
+    /// Admin, role, fee, governance and upgrade events; kept in the event log only.
+    Other,
+}
+
+/// A PayStar event with its topics decoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Event {
+    pub id: String,
+    pub ledger: u32,
+    pub contract_id: String,
+    pub contract: Contract,
+    pub action: String,
+    pub record_id: u128,
+    pub actor: String,
+    pub version: u32,
+    pub payload: Payload,
+}
+
+/// Decodes a contract event. Returns `None` for events that do not use the
+/// PayStar topic layout, such as token transfers.
+pub fn decode(event: &ContractEvent) -> Result<Option<Event>> {
+    let [ScVal::Symbol(contract), ScVal::Symbol(action), id, actor] = event.topics.as_slice()
+    else {
+        return Ok(None);
+    };
+    let Some(contract) = Contract::from_topic(&contract.to_utf8_string_lossy()) else {
+        return Ok(None);
+    };
+    let action = action.to_utf8_string_lossy();
+
+    let reader = Reader {
+        event_id: &event.id,
+    };
+    let data = reader.fields(&event.data)?;
+    let version = data.u32("version")?;
+    if version > SUPPORTED_VERSION {
+        return Err(Error::UnsupportedVersion {
+            event_id: event.id.clone(),
+            version,
+        });
+    }
+
+    let payload = match (contract, action.as_str()) {
+        (
+            Contract::Escrow,
+            "created" | "confirmed" | "acked" | "cancelled" | "ext_acc" | "disputed" | "refunded"
+            | "mig_rec",
+        ) => Payload::Escrow(reader.escrow(&data.fields("escrow")?)?),
+        (Contract::Escrow, "released") => Payload::EscrowReleased {
+            escrow: reader.escrow(&data.fields("escrow")?)?,
+            seller_amount: data.i128("seller_amount")?,
+            fee: data.i128("fee")?,
+        },
+        (Contract::Installment, "created" | "accepted" | "paid" | "cancelled" | "mig_rec") => {
+            Payload::Agreement(reader.agreement(&data.fields("agreement")?)?)
+        }
+        (Contract::Installment, "finalized") => Payload::AgreementSettled {
+            agreement: reader.agreement(&data.fields("agreement")?)?,
+            seller_amount: data.u128("seller_amount")?,
+            fee: data.u128("fee")?,
+        },
+        (Contract::Marketplace, "init") => Payload::MarketInitialized {
+            payment_token: data.address("payment_token")?,
+        },
+        (Contract::Marketplace, "list" | "restock" | "item_cat" | "item_tags" | "mig_rec") => {
+            Payload::Item(reader.item(&data.fields("item")?)?)
+        }
+        (Contract::Marketplace, "buy") => Payload::ItemBought {
+            item: reader.item(&data.fields("item")?)?,
+            qty: data.u32("qty")?,
+            payment_token: data.address("payment_token")?,
+            total: data.i128("total")?,
+            fee: data.i128("fee")?,
+        },
+        (Contract::Marketplace, "prices") => Payload::ItemPrices {
+            item_id: data.u32("item_id")?,
+            prices: data
+                .map("prices")?
+                .iter()
+                .map(|entry| {
+                    let price = reader.convert(entry.val.clone(), "price")?;
+                    Ok((reader.address(&entry.key, "price token")?, price))
+                })
+                .collect::<Result<Vec<_>>>()?,
+        },
+        (Contract::Marketplace, "tok_add" | "tok_rm") => Payload::AcceptedTokens(
+            data.vec("accepted_tokens")?
+                .iter()
+                .map(|token| reader.address(token, "accepted token"))
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        (Contract::Marketplace, "ref_price") => Payload::ReferencePrice {
+            item_id: data.u32("item_id")?,
+            price: data.optional_i128("reference_price")?,
+        },
+        (Contract::Marketplace, "dutch") => {
+            Payload::DutchAuction(reader.dutch_auction(&data.fields("auction")?)?)
+        }
+        (Contract::Marketplace, "ret_win") => Payload::ReturnWindow {
+            item_id: data.u32("item_id")?,
+            return_window: data.u64("return_window")?,
+        },
+        (Contract::Marketplace, "ver_pol") => {
+            Payload::VerificationPolicy(reader.verification_policy(&data.fields("policy")?)?)
+        }
+        (
+            Contract::Marketplace,
+            "ordered" | "shipped" | "delivered" | "reclaimed" | "ret_req" | "ret_acc" | "ret_rej"
+            | "ret_esc" | "returned" | "resolved",
+        ) => Payload::Order(reader.order(&data.fields("order")?)?),
+        (Contract::Marketplace, "released") => Payload::OrderSettled {
+            order: reader.order(&data.fields("order")?)?,
+            seller_amount: data.i128("seller_amount")?,
+            fee: data.i128("fee")?,
+        },
+        (Contract::Marketplace, "offer" | "offer_ctr" | "offer_acc" | "offer_rej" | "offer_wd") => {
+            Payload::Offer(reader.offer(&data.fields("offer")?)?)
+        }
+        (Contract::Marketplace, "auction" | "unsold") => {
+            Payload::Auction(reader.auction(&data.fields("auction")?)?)
+        }
+        (Contract::Marketplace, "cat_add" | "cat_ren") => {
+            Payload::Category(reader.category(&data.fields("category")?)?)
+        }
+        (Contract::Marketplace, "cat_rm") => {
+            Payload::CategoryRemoved(reader.category(&data.fields("category")?)?)
+        }
+        (Contract::Marketplace, "seller" | "verified" | "revoked") => {
+            Payload::Seller(reader.seller(&data.fields("profile")?)?)
+        }
+        (Contract::Marketplace, "bid") => Payload::Bid {
+            auction: reader.auction(&data.fields("auction")?)?,
+            outbid: data.optional_address("outbid")?,
+            refund: data.i128("refund")?,
+        },
+        (Contract::Marketplace, "sold") => Payload::AuctionSold {
+            auction: reader.auction(&data.fields("auction")?)?,
+            order_id: data.u64("order_id")?,
+        },
+        _ => Payload::Other,
+    };
+
+    Ok(Some(Event {
+        id: event.id.clone(),
+        ledger: event.ledger,
+        contract_id: event.contract_id.clone(),
+        contract,
+        action,
+        record_id: reader.convert(id.clone(), "id topic")?,
+        actor: reader.address(actor, "actor topic")?,
+        version,
+        payload,
+    }))
+}
+
+/// Attaches the event id to decoding errors.
+struct Reader<'a> {
+    event_id: &'a str,
+}
+
+/// The fields of a `#[contracttype]` struct.
+struct Fields<'a> {
+    reader: &'a Reader<'a>,
+    map: ScMap,
+}
+
+impl Reader<'_> {
+    fn error(&self, reason: String) -> Error {
+        Error::Decode {
+            event_id: self.event_id.to_string(),
+            reason,
+        }
+    }
+
+    fn convert<T: TryFrom<ScVal>>(&self, value: ScVal, what: &str) -> Result<T> {
+        T::try_from(value).map_err(|_| self.error(format!("unexpected type for {what}")))
+    }
+
+    fn fields(&self, value: &ScVal) -> Result<Fields<'_>> {
+        match value {
+            ScVal::Map(Some(map)) => Ok(Fields {
+                reader: self,
+                map: map.clone(),
+            }),
+            _ => Err(self.error("payload is not a struct".to_string())),
+        }
+    }
+
+    fn address(&self, value: &ScVal, what: &str) -> Result<String> {
+        match value {
+            ScVal::Address(address) => Ok(address.to_string()),
+            _ => Err(self.error(format!("{what} is not an address"))),
+        }
+    }
+
+    fn escrow(&self, fields: &Fields) -> Result<Escrow> {
+        Ok(Escrow {
+            id: fields.u64("id")?,
+            payer: fields.address("payer")?,
+            buyer: fields.address("buyer")?,
+            seller: fields.address("seller")?,
+            amount: fields.i128("amount")?,
+            payment_token: fields.address("payment_token")?,
+            release_timestamp: fields.u64("release_timestamp")?,
+            status: fields.variant("status")?,
+            dispute_reason: fields.optional_string("dispute_reason")?,
+            buyer_confirmed: fields.bool("buyer_confirmed")?,
+            seller_acknowledged: fields.bool("seller_acknowledged")?,
+            extensions: fields.vec("extensions")?.len() as u32,
+        })
+    }
+
+    fn agreement(&self, fields: &Fields) -> Result<Agreement> {
+        let payments = fields
+            .vec("paid_history")?
+            .iter()
+            .map(|entry| {
+                let entry = self.fields(entry)?;
+                Ok((entry.u128("amount")?, entry.u64("timeline")?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Agreement {
+            id: fields.u128("id")?,
+            buyer: fields.address("buyer")?,
+            seller: fields.address("seller")?,
+            is_accepted: fields.bool("is_accepted")?,
+            amount_paid: fields.u128("amount_paid")?,
+            payments,
+            total_amount: fields.u128("total_amount")?,
+            deadline: fields.u64("deadline")?,
+            is_finalized: fields.bool("is_finalized")?,
+            is_canceled: fields.bool("is_canceled")?,
+            arbitrator: fields.address("arbitrator")?,
+            description: fields.string("description")?,
+            token: fields.address("token")?,
+        })
+    }
+
+    fn item(&self, fields: &Fields) -> Result<Item> {
+        Ok(Item {
+            id: fields.u32("id")?,
+            name: fields.string("name")?,
+            price: fields.i128("price")?,
+            token: if fields.has("token") {
+                Some(fields.address("token")?)
+            } else {
+                None
+            },
+            quantity: fields.u32("quantity")?,
+            seller: fields.address("seller")?,
+            image_url: fields.string("image_url")?,
+            // Items listed before categories were added carry neither field
+            category: if fields.has("category") {
+                fields.optional_u32("category")?
+            } else {
+                None
+            },
+            tags: if fields.has("tags") {
+                fields
+                    .vec("tags")?
+                    .into_iter()
+                    .map(|tag| match tag {
+                        ScVal::String(tag) => Ok(tag.to_utf8_string_lossy()),
+                        _ => Err(self.error("tag is not a string".to_string())),
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            } else {
+                Vec::new()
+            },
+        })
+    }
+
+    fn seller(&self, fields: &Fields) -> Result<Seller> {
+        Ok(Seller {
+            address: fields.address("seller")?,
+            display_name: fields.string("display_name")?,
+            metadata_uri: fields.string("metadata_uri")?,
+            contact_hash: fields.hex("contact_hash")?,
+            payout_address: fields.address("payout_address")?,
+            registered_at: fields.u64("registered_at")?,
+            verified_by: fields.optional_address("verified_by")?,
+            verified_at: fields.optional_u64("verified_at")?,
+        })
+    }
+
+    fn category(&self, fields: &Fields) -> Result<Category> {
+        Ok(Category {
+            id: fields.u32("id")?,
+            name: fields.string("name")?,
+            parent: fields.optional_u32("parent")?,
+        })
+    }
+
+    fn dutch_auction(&self, fields: &Fields) -> Result<DutchAuction> {
+        let curve = fields.vec("curve")?;
+        let steps = match curve.get(1) {
+            Some(steps) => Some(self.convert(steps.clone(), "curve steps")?),
+            None => None,
+        };
+        Ok(DutchAuction {
+            item_id: fields.u32("item_id")?,
+            start_price: fields.i128("start_price")?,
+            floor_price: fields.i128("floor_price")?,
+            start_time: fields.u64("start_time")?,
+            end_time: fields.u64("end_time")?,
+            curve: fields.variant("curve")?,
+            steps,
+        })
+    }
+
+    fn verification_policy(&self, fields: &Fields) -> Result<VerificationPolicy> {
+        Ok(VerificationPolicy {
+            categories: fields
+                .vec("categories")?
+                .into_iter()
+                .map(|category| self.convert(category, "policy category"))
+                .collect::<Result<Vec<_>>>()?,
+            min_prices: fields
+                .map("min_prices")?
+                .iter()
+                .map(|entry| {
+                    let price = self.convert(entry.val.clone(), "min price")?;
+                    Ok((self.address(&entry.key, "min price token")?, price))
+                })
+                .collect::<Result<Vec<_>>>()?,
+            min_reference_price: fields.optional_i128("min_reference_price")?,
+        })
+    }
+
+    fn order(&self, fields: &Fields) -> Result<Order> {
+        Ok(Order {
+            id: fields.u64("id")?,
+            item_id: fields.u32("item_id")?,
+            buyer: fields.address("buyer")?,
+            seller: fields.address("seller")?,
+            qty: fields.u32("qty")?,
+            unit_price: fields.i128("unit_price")?,
+            total: fields.i128("total")?,
+            fee: fields.i128("fee")?,
+            payment_token: fields.address("payment_token")?,
+            created_at: fields.u64("created_at")?,
+            status: fields.variant("status")?,
+            ship_by: fields.u64("ship_by")?,
+            deliver_by: fields.optional_u64("deliver_by")?,
+            tracking_hash: fields.optional_hex("tracking_hash")?,
+            return_window: fields.u64("return_window")?,
+            return_by: fields.optional_u64("return_by")?,
+            return_reason: fields.optional_string("return_reason")?,
+            respond_by: fields.optional_u64("respond_by")?,
+            seller_paid: fields.bool("seller_paid")?,
+        })
+    }
+
+    fn offer(&self, fields: &Fields) -> Result<Offer> {
+        Ok(Offer {
+            id: fields.u64("id")?,
+            item_id: fields.u32("item_id")?,
+            buyer: fields.address("buyer")?,
+            seller: fields.address("seller")?,
+            qty: fields.u32("qty")?,
+            unit_price: fields.i128("unit_price")?,
+            escrowed: fields.i128("escrowed")?,
+            payment_token: fields.address("payment_token")?,
+            counter_price: fields.optional_i128("counter_price")?,
+            expires_at: fields.u64("expires_at")?,
+            status: fields.variant("status")?,
+            order_id: fields.optional_u64("order_id")?,
+        })
+    }
+
+    fn auction(&self, fields: &Fields) -> Result<Auction> {
+        Ok(Auction {
+            id: fields.u32("id")?,
+            seller: fields.address("seller")?,
+            name: fields.string("name")?,
+            image_url: fields.string("image_url")?,
+            reserve_price: fields.i128("reserve_price")?,
+            min_increment: fields.i128("min_increment")?,
+            end_time: fields.u64("end_time")?,
+            payment_token: fields.address("payment_token")?,
+            highest_bid: fields.i128("highest_bid")?,
+            highest_bidder: fields.optional_address("highest_bidder")?,
+            bid_count: fields.u32("bid_count")?,
+            status: fields.variant("status")?,
+        })
+    }
+}
+
+impl Fields<'_> {
+    fn has(&self, name: &str) -> bool {
+        self.get(name).is_ok()
+    }
+
+    fn get(&self, name: &str) -> Result<&ScVal> {
+        self.map
+            .iter()
+            .find(|entry| matches!(&entry.key, ScVal::Symbol(key) if key.to_utf8_string_lossy() == name))
+            .map(|entry| &entry.val)
+            .ok_or_else(|| self.reader.error(format!("missing field `{name}`")))
+    }
+
+    fn convert<T: TryFrom<ScVal>>(&self, name: &str) -> Result<T> {
+        self.reader.convert(self.get(name)?.clone(), name)
+    }
+
+    fn u32(&self, name: &str) -> Result<u32> {
+        self.convert(name)
+    }
+
+    fn u64(&self, name: &str) -> Result<u64> {
+        self.convert(name)
+    }
+
+    fn u128(&self, name: &str) -> Result<u128> {
+        self.convert(name)
+    }
+
+    fn i128(&self, name: &str) -> Result<i128> {
+        self.convert(name)
+    }
+
+    fn bool(&self, name: &str) -> Result<bool> {
+        self.convert(name)
+    }
+
+    fn address(&self, name: &str) -> Result<String> {
+        self.reader.address(self.get(name)?, name)
+    }
+
+    fn string(&self, name: &str) -> Result<String> {
+        match self.get(name)? {
+            ScVal::String(value) => Ok(value.to_utf8_string_lossy()),
+            _ => Err(self.reader.error(format!("`{name}` is not a string"))),
+        }
+    }
+
+    fn optional_address(&self, name: &str) -> Result<Option<String>> {
+        match self.get(name)? {
+            ScVal::Void => Ok(None),
+            _ => self.address(name).map(Some),
+        }
+    }
+
+    fn optional_string(&self, name: &str) -> Result<Option<String>> {
+        match self.get(name)? {
+            ScVal::Void => Ok(None),
+            _ => self.string(name).map(Some),
+        }
+    }
+
+    fn optional_u32(&self, name: &str) -> Result<Option<u32>> {
+        match self.get(name)? {
+            ScVal::Void => Ok(None),
+            _ => self.u32(name).map(Some),
+        }
+    }
+
+    fn optional_u64(&self, name: &str) -> Result<Option<u64>> {
+        match self.get(name)? {
+            ScVal::Void => Ok(None),
+            _ => self.u64(name).map(Some),
+        }
+    }
+
+    fn optional_i128(&self, name: &str) -> Result<Option<i128>> {
+        match self.get(name)? {
+            ScVal::Void => Ok(None),
+            _ => self.i128(name).map(Some),
+        }
+    }
+
+    fn hex(&self, name: &str) -> Result<String> {
+        match self.get(name)? {
+            ScVal::Bytes(bytes) => Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect()),
+            _ => Err(self.reader.error(format!("`{name}` is not bytes"))),
+        }
+    }
+
+    fn optional_hex(&self, name: &str) -> Result<Option<String>> {
+        match self.get(name)? {
+            ScVal::Void => Ok(None),
+            _ => self.hex(name).map(Some),
+        }
+    }
+
+    fn vec(&self, name: &str) -> Result<Vec<ScVal>> {
+        match self.get(name)? {
+            ScVal::Vec(Some(values)) => Ok(values.to_vec()),
+            _ => Err(self.reader.error(format!("`{name}` is not a vector"))),
+        }
+    }
+
+    fn map(&self, name: &str) -> Result<Vec<ScMapEntry>> {
+        match self.get(name)? {
+            ScVal::Map(Some(entries)) => Ok(entries.to_vec()),
+            _ => Err(self.reader.error(format!("`{name}` is not a map"))),
+        }
+    }
+
+    fn fields(&self, name: &str) -> Result<Fields<'_>> {
+        self.reader.fields(self.get(name)?)
+    }
+
+    // Enum variants are encoded as `[Symbol(variant), values...]`
+    fn variant(&self, name: &str) -> Result<String> {
+        match self.vec(name)?.first() {
+            Some(ScVal::Symbol(variant)) => Ok(variant.to_utf8_string_lossy()),
+            _ => Err(self
+                .reader
+                .error(format!("`{name}` is not an enum variant"))),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt;
+
+/// Everything that can stop an indexing run.
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Xdr(stellar_xdr::curr::Error),
+    Sqlite(rusqlite::Error),
+    /// An event under a PayStar topic does not match the published schema.
+    Decode {
+        event_id: String,
+        reason: String,
+    },
+    /// An event carries a payload version this build does not understand.
+    UnsupportedVersion {
+        event_id: String,
+        version: u32,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "io error: {error}"),
+            Error::Json(error) => write!(f, "invalid event dump: {error}"),
+            Error::Xdr(error) => write!(f, "invalid XDR: {error}"),
+            Error::Sqlite(error) => write!(f, "database error: {error}"),
+            Error::Decode { event_id, reason } => write!(f, "event {event_id}: {reason}"),
+            Error::UnsupportedVersion { event_id, version } => {
+                write!(f, "event {event_id}: unsupported payload version {version}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(error)
+    }
+}
+
+impl From<stellar_xdr::curr::Error> for Error {
+    fn from(error: stellar_xdr::curr::Error) -> Self {
+        Error::Xdr(error)
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Error::Sqlite(error)
+    }
+}
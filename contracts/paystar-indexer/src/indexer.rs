@@ -0,0 +1,938 @@
+//! Applying decoded events to the SQLite database.
+//!
+//! Amounts are stored as decimal text since `i128` and `u128` do not fit
+//! SQLite integers. Records are keyed by the emitting contract id and their
+//! on-chain id, so several deployments can share one database.
+
+use std::path::Path;
+
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+use crate::decode::{
+    self, Agreement, Auction, Category, DutchAuction, Escrow, Event, Item, Offer, Order, Payload,
+    Seller, VerificationPolicy,
+};
+use crate::error::Result;
+use crate::input::ContractEvent;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS events (
+    id TEXT PRIMARY KEY,
+    ledger INTEGER NOT NULL,
+    contract_id TEXT NOT NULL,
+    contract TEXT NOT NULL,
+    action TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    actor TEXT NOT NULL,
+    version INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS markets (
+    contract_id TEXT PRIMARY KEY,
+    payment_token TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS items (
+    contract_id TEXT NOT NULL,
+    id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    price TEXT NOT NULL,
+    token TEXT,
+    quantity INTEGER NOT NULL,
+    seller TEXT NOT NULL,
+    image_url TEXT NOT NULL,
+    category_id INTEGER,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, id)
+);
+CREATE TABLE IF NOT EXISTS item_tags (
+    contract_id TEXT NOT NULL,
+    item_id INTEGER NOT NULL,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (contract_id, item_id, tag)
+);
+CREATE TABLE IF NOT EXISTS sellers (
+    contract_id TEXT NOT NULL,
+    address TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    metadata_uri TEXT NOT NULL,
+    contact_hash TEXT NOT NULL,
+    payout_address TEXT NOT NULL,
+    registered_at INTEGER NOT NULL,
+    verified_by TEXT,
+    verified_at INTEGER,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, address)
+);
+CREATE TABLE IF NOT EXISTS categories (
+    contract_id TEXT NOT NULL,
+    id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    parent_id INTEGER,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, id)
+);
+CREATE TABLE IF NOT EXISTS item_prices (
+    contract_id TEXT NOT NULL,
+    item_id INTEGER NOT NULL,
+    token TEXT NOT NULL,
+    price TEXT NOT NULL,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, item_id, token)
+);
+CREATE TABLE IF NOT EXISTS reference_prices (
+    contract_id TEXT NOT NULL,
+    item_id INTEGER NOT NULL,
+    price TEXT NOT NULL,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, item_id)
+);
+CREATE TABLE IF NOT EXISTS accepted_tokens (
+    contract_id TEXT NOT NULL,
+    token TEXT NOT NULL,
+    PRIMARY KEY (contract_id, token)
+);
+CREATE TABLE IF NOT EXISTS dutch_auctions (
+    contract_id TEXT NOT NULL,
+    item_id INTEGER NOT NULL,
+    start_price TEXT NOT NULL,
+    floor_price TEXT NOT NULL,
+    start_time INTEGER NOT NULL,
+    end_time INTEGER NOT NULL,
+    curve TEXT NOT NULL,
+    steps INTEGER,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, item_id)
+);
+CREATE TABLE IF NOT EXISTS return_windows (
+    contract_id TEXT NOT NULL,
+    item_id INTEGER NOT NULL,
+    return_window INTEGER NOT NULL,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, item_id)
+);
+CREATE TABLE IF NOT EXISTS verification_policies (
+    contract_id TEXT PRIMARY KEY,
+    min_reference_price TEXT,
+    updated_ledger INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS policy_categories (
+    contract_id TEXT NOT NULL,
+    category_id INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, category_id)
+);
+CREATE TABLE IF NOT EXISTS policy_min_prices (
+    contract_id TEXT NOT NULL,
+    token TEXT NOT NULL,
+    price TEXT NOT NULL,
+    PRIMARY KEY (contract_id, token)
+);
+CREATE TABLE IF NOT EXISTS orders (
+    contract_id TEXT NOT NULL,
+    id INTEGER NOT NULL,
+    item_id INTEGER NOT NULL,
+    buyer TEXT NOT NULL,
+    seller TEXT NOT NULL,
+    qty INTEGER NOT NULL,
+    unit_price TEXT NOT NULL,
+    total TEXT NOT NULL,
+    fee TEXT NOT NULL,
+    payment_token TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    ship_by INTEGER NOT NULL,
+    deliver_by INTEGER,
+    tracking_hash TEXT,
+    return_window INTEGER NOT NULL,
+    return_by INTEGER,
+    return_reason TEXT,
+    respond_by INTEGER,
+    seller_paid INTEGER NOT NULL,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, id)
+);
+CREATE TABLE IF NOT EXISTS offers (
+    contract_id TEXT NOT NULL,
+    id INTEGER NOT NULL,
+    item_id INTEGER NOT NULL,
+    buyer TEXT NOT NULL,
+    seller TEXT NOT NULL,
+    qty INTEGER NOT NULL,
+    unit_price TEXT NOT NULL,
+    escrowed TEXT NOT NULL,
+    payment_token TEXT NOT NULL,
+    counter_price TEXT,
+    expires_at INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    order_id INTEGER,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, id)
+);
+CREATE TABLE IF NOT EXISTS auctions (
+    contract_id TEXT NOT NULL,
+    id INTEGER NOT NULL,
+    seller TEXT NOT NULL,
+    name TEXT NOT NULL,
+    image_url TEXT NOT NULL,
+    reserve_price TEXT NOT NULL,
+    min_increment TEXT NOT NULL,
+    end_time INTEGER NOT NULL,
+    payment_token TEXT NOT NULL,
+    highest_bid TEXT NOT NULL,
+    highest_bidder TEXT,
+    bid_count INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, id)
+);
+CREATE TABLE IF NOT EXISTS escrows (
+    contract_id TEXT NOT NULL,
+    id INTEGER NOT NULL,
+    payer TEXT NOT NULL,
+    buyer TEXT NOT NULL,
+    seller TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    payment_token TEXT NOT NULL,
+    release_timestamp INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    buyer_confirmed INTEGER NOT NULL,
+    seller_acknowledged INTEGER NOT NULL,
+    extensions INTEGER NOT NULL,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, id)
+);
+CREATE TABLE IF NOT EXISTS agreements (
+    contract_id TEXT NOT NULL,
+    id TEXT NOT NULL,
+    buyer TEXT NOT NULL,
+    seller TEXT NOT NULL,
+    total_amount TEXT NOT NULL,
+    amount_paid TEXT NOT NULL,
+    deadline INTEGER NOT NULL,
+    is_accepted INTEGER NOT NULL,
+    is_finalized INTEGER NOT NULL,
+    is_canceled INTEGER NOT NULL,
+    arbitrator TEXT NOT NULL,
+    description TEXT NOT NULL,
+    token TEXT NOT NULL,
+    updated_ledger INTEGER NOT NULL,
+    PRIMARY KEY (contract_id, id)
+);
+CREATE TABLE IF NOT EXISTS payments (
+    event_id TEXT NOT NULL,
+    contract_id TEXT NOT NULL,
+    contract TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    from_account TEXT NOT NULL,
+    to_account TEXT NOT NULL,
+    token TEXT,
+    amount TEXT NOT NULL,
+    ledger INTEGER NOT NULL,
+    PRIMARY KEY (event_id, kind) -- A bid both locks the new bid and refunds the one it beats
+);
+CREATE TABLE IF NOT EXISTS disputes (
+    contract_id TEXT NOT NULL,
+    escrow_id INTEGER NOT NULL,
+    buyer TEXT NOT NULL,
+    reason TEXT,
+    amount TEXT NOT NULL,
+    status TEXT NOT NULL,
+    opened_ledger INTEGER NOT NULL,
+    resolved_by TEXT,
+    resolved_ledger INTEGER,
+    PRIMARY KEY (contract_id, escrow_id)
+);
+";
+
+/// Counts of one ingestion run.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Summary {
+    pub indexed: usize,
+    pub duplicates: usize, // Already indexed by an earlier run
+    pub ignored: usize,    // Not published by a PayStar contract
+}
+
+/// Keeps a normalized SQLite copy of the PayStar contracts' state.
+pub struct Indexer {
+    conn: Connection,
+}
+
+impl Indexer {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Indexer { conn })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Applies events in order. The run is atomic, and events indexed by an
+    /// earlier run are skipped, so overlapping dumps can be replayed safely.
+    pub fn ingest(&mut self, events: &[ContractEvent]) -> Result<Summary> {
+        let tx = self.conn.transaction()?;
+        let mut summary = Summary::default();
+
+        for event in events {
+            let Some(event) = decode::decode(event)? else {
+                summary.ignored += 1;
+                continue;
+            };
+            if record_event(&tx, &event)? {
+                apply(&tx, &event)?;
+                summary.indexed += 1;
+            } else {
+                summary.duplicates += 1;
+            }
+        }
+
+        tx.commit()?;
+        Ok(summary)
+    }
+}
+
+// Returns false when the event was already indexed
+fn record_event(tx: &Transaction, event: &Event) -> Result<bool> {
+    let inserted = tx.execute(
+        "INSERT OR IGNORE INTO events
+            (id, ledger, contract_id, contract, action, record_id, actor, version)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            event.id,
+            event.ledger,
+            event.contract_id,
+            event.contract.name(),
+            event.action,
+            event.record_id.to_string(),
+            event.actor,
+            event.version,
+        ],
+    )?;
+    Ok(inserted == 1)
+}
+
+fn apply(tx: &Transaction, event: &Event) -> Result<()> {
+    match &event.payload {
+        Payload::Escrow(escrow) => {
+            save_escrow(tx, event, escrow)?;
+            match event.action.as_str() {
+                "created" => {
+                    save_payment(
+                        tx,
+                        event,
+                        "deposit",
+                        &escrow.payer,
+                        &event.contract_id,
+                        Some(&escrow.payment_token),
+                        &escrow.amount.to_string(),
+                    )?;
+                }
+                "cancelled" | "refunded" => {
+                    save_payment(
+                        tx,
+                        event,
+                        "refund",
+                        &event.contract_id,
+                        &escrow.payer,
+                        Some(&escrow.payment_token),
+                        &escrow.amount.to_string(),
+                    )?;
+                }
+                _ => {}
+            }
+            match event.action.as_str() {
+                "disputed" => open_dispute(tx, event, escrow)?,
+                "refunded" => close_dispute(tx, event, escrow)?,
+                _ => {}
+            }
+        }
+        Payload::EscrowReleased {
+            escrow,
+            seller_amount,
+            ..
+        } => {
+            save_escrow(tx, event, escrow)?;
+            save_payment(
+                tx,
+                event,
+                "release",
+                &event.contract_id,
+                &escrow.seller,
+                Some(&escrow.payment_token),
+                &seller_amount.to_string(),
+            )?;
+        }
+        Payload::Agreement(agreement) => {
+            save_agreement(tx, event, agreement)?;
+            match event.action.as_str() {
+                "paid" => {
+                    if let Some((amount, _)) = agreement.payments.last() {
+                        save_payment(
+                            tx,
+                            event,
+                            "installment",
+                            &agreement.buyer,
+                            &event.contract_id,
+                            Some(&agreement.token),
+                            &amount.to_string(),
+                        )?;
+                    }
+                }
+                "cancelled" if agreement.amount_paid > 0 => {
+                    save_payment(
+                        tx,
+                        event,
+                        "refund",
+                        &event.contract_id,
+                        &agreement.buyer,
+                        Some(&agreement.token),
+                        &agreement.amount_paid.to_string(),
+                    )?;
+                }
+                _ => {}
+            }
+        }
+        Payload::AgreementSettled {
+            agreement,
+            seller_amount,
+            ..
+        } => {
+            save_agreement(tx, event, agreement)?;
+            save_payment(
+                tx,
+                event,
+                "settlement",
+                &event.contract_id,
+                &agreement.seller,
+                Some(&agreement.token),
+                &seller_amount.to_string(),
+            )?;
+        }
+        Payload::MarketInitialized { payment_token } => {
+            tx.execute(
+                "INSERT OR REPLACE INTO markets (contract_id, payment_token) VALUES (?1, ?2)",
+                params![event.contract_id, payment_token],
+            )?;
+        }
+        Payload::Item(item) => save_item(tx, event, item)?,
+        // The marketplace holds the payment until the order is delivered
+        Payload::ItemBought {
+            item,
+            payment_token,
+            total,
+            ..
+        } => {
+            save_item(tx, event, item)?;
+            save_payment(
+                tx,
+                event,
+                "purchase",
+                &event.actor,
+                &event.contract_id,
+                Some(payment_token),
+                &total.to_string(),
+            )?;
+        }
+        Payload::ItemPrices { item_id, prices } => {
+            tx.execute(
+                "DELETE FROM item_prices WHERE contract_id = ?1 AND item_id = ?2",
+                params![event.contract_id, item_id],
+            )?;
+            for (token, price) in prices {
+                tx.execute(
+                    "INSERT INTO item_prices (contract_id, item_id, token, price, updated_ledger)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        event.contract_id,
+                        item_id,
+                        token,
+                        price.to_string(),
+                        event.ledger
+                    ],
+                )?;
+            }
+        }
+        Payload::ReferencePrice {
+            item_id,
+            price: Some(price),
+        } => {
+            tx.execute(
+                "INSERT OR REPLACE INTO reference_prices (contract_id, item_id, price, updated_ledger)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![event.contract_id, item_id, price.to_string(), event.ledger],
+            )?;
+        }
+        Payload::ReferencePrice {
+            item_id,
+            price: None,
+        } => {
+            tx.execute(
+                "DELETE FROM reference_prices WHERE contract_id = ?1 AND item_id = ?2",
+                params![event.contract_id, item_id],
+            )?;
+        }
+        Payload::AcceptedTokens(tokens) => {
+            tx.execute(
+                "DELETE FROM accepted_tokens WHERE contract_id = ?1",
+                params![event.contract_id],
+            )?;
+            for token in tokens {
+                tx.execute(
+                    "INSERT INTO accepted_tokens (contract_id, token) VALUES (?1, ?2)",
+                    params![event.contract_id, token],
+                )?;
+            }
+        }
+        Payload::DutchAuction(auction) => save_dutch_auction(tx, event, auction)?,
+        Payload::ReturnWindow {
+            item_id,
+            return_window,
+        } => {
+            tx.execute(
+                "INSERT OR REPLACE INTO return_windows (contract_id, item_id, return_window, updated_ledger)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![event.contract_id, item_id, return_window, event.ledger],
+            )?;
+        }
+        Payload::VerificationPolicy(policy) => save_verification_policy(tx, event, policy)?,
+        Payload::Order(order) => {
+            save_order(tx, event, order)?;
+            // Reclaimed, returned and disputed orders can all end with the buyer refunded
+            if order.status == "Refunded" {
+                save_payment(
+                    tx,
+                    event,
+                    "refund",
+                    &event.contract_id,
+                    &order.buyer,
+                    Some(&order.payment_token),
+                    &order.total.to_string(),
+                )?;
+            }
+        }
+        Payload::OrderSettled {
+            order,
+            seller_amount,
+            ..
+        } => {
+            save_order(tx, event, order)?;
+            save_payment(
+                tx,
+                event,
+                "release",
+                &event.contract_id,
+                &payout_address(tx, event, &order.seller)?,
+                Some(&order.payment_token),
+                &seller_amount.to_string(),
+            )?;
+        }
+        // The escrow only shows on the offer; an accepted offer is paid as its order's purchase
+        Payload::Offer(offer) => save_offer(tx, event, offer)?,
+        Payload::Seller(seller) => save_seller(tx, event, seller)?,
+        Payload::Category(category) => save_category(tx, event, category)?,
+        Payload::CategoryRemoved(category) => {
+            tx.execute(
+                "DELETE FROM categories WHERE contract_id = ?1 AND id = ?2",
+                params![event.contract_id, category.id],
+            )?;
+        }
+        Payload::Auction(auction) => {
+            save_auction(tx, event, auction)?;
+            // An auction ending under its reserve refunds the highest bid
+            if let (Some(bidder), "Unsold") = (&auction.highest_bidder, auction.status.as_str()) {
+                save_payment(
+                    tx,
+                    event,
+                    "refund",
+                    &event.contract_id,
+                    bidder,
+                    Some(&auction.payment_token),
+                    &auction.highest_bid.to_string(),
+                )?;
+            }
+        }
+        Payload::Bid {
+            auction,
+            outbid,
+            refund,
+        } => {
+            save_auction(tx, event, auction)?;
+            save_payment(
+                tx,
+                event,
+                "bid",
+                &event.actor,
+                &event.contract_id,
+                Some(&auction.payment_token),
+                &auction.highest_bid.to_string(),
+            )?;
+            if let Some(outbid) = outbid {
+                save_payment(
+                    tx,
+                    event,
+                    "refund",
+                    &event.contract_id,
+                    outbid,
+                    Some(&auction.payment_token),
+                    &refund.to_string(),
+                )?;
+            }
+        }
+        Payload::AuctionSold { auction, order_id } => {
+            save_auction(tx, event, auction)?;
+            // The winning bid was recorded when it was placed, and the purchase of the lot
+            // that placed the order only reused it
+            tx.execute(
+                "DELETE FROM payments
+                 WHERE contract_id = ?1 AND kind = 'purchase' AND record_id = (
+                     SELECT CAST(item_id AS TEXT) FROM orders WHERE contract_id = ?1 AND id = ?2
+                 )",
+                params![event.contract_id, order_id],
+            )?;
+        }
+        Payload::Other => {}
+    }
+    Ok(())
+}
+
+fn save_escrow(tx: &Transaction, event: &Event, escrow: &Escrow) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO escrows
+            (contract_id, id, payer, buyer, seller, amount, payment_token, release_timestamp,
+             status, buyer_confirmed, seller_acknowledged, extensions, updated_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        params![
+            event.contract_id,
+            escrow.id,
+            escrow.payer,
+            escrow.buyer,
+            escrow.seller,
+            escrow.amount.to_string(),
+            escrow.payment_token,
+            escrow.release_timestamp,
+            escrow.status,
+            escrow.buyer_confirmed,
+            escrow.seller_acknowledged,
+            escrow.extensions,
+            event.ledger,
+        ],
+    )?;
+    Ok(())
+}
+
+fn save_agreement(tx: &Transaction, event: &Event, agreement: &Agreement) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO agreements
+            (contract_id, id, buyer, seller, total_amount, amount_paid, deadline, is_accepted,
+             is_finalized, is_canceled, arbitrator, description, token, updated_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        params![
+            event.contract_id,
+            agreement.id.to_string(),
+            agreement.buyer,
+            agreement.seller,
+            agreement.total_amount.to_string(),
+            agreement.amount_paid.to_string(),
+            agreement.deadline,
+            agreement.is_accepted,
+            agreement.is_finalized,
+            agreement.is_canceled,
+            agreement.arbitrator,
+            agreement.description,
+            agreement.token,
+            event.ledger,
+        ],
+    )?;
+    Ok(())
+}
+
+fn save_item(tx: &Transaction, event: &Event, item: &Item) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO items
+            (contract_id, id, name, price, token, quantity, seller, image_url, category_id,
+             updated_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            event.contract_id,
+            item.id,
+            item.name,
+            item.price.to_string(),
+            item.token,
+            item.quantity,
+            item.seller,
+            item.image_url,
+            item.category,
+            event.ledger,
+        ],
+    )?;
+    tx.execute(
+        "DELETE FROM item_tags WHERE contract_id = ?1 AND item_id = ?2",
+        params![event.contract_id, item.id],
+    )?;
+    for tag in &item.tags {
+        tx.execute(
+            "INSERT INTO item_tags (contract_id, item_id, tag) VALUES (?1, ?2, ?3)",
+            params![event.contract_id, item.id, tag],
+        )?;
+    }
+    Ok(())
+}
+
+fn save_seller(tx: &Transaction, event: &Event, seller: &Seller) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO sellers
+            (contract_id, address, display_name, metadata_uri, contact_hash, payout_address,
+             registered_at, verified_by, verified_at, updated_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            event.contract_id,
+            seller.address,
+            seller.display_name,
+            seller.metadata_uri,
+            seller.contact_hash,
+            seller.payout_address,
+            seller.registered_at,
+            seller.verified_by,
+            seller.verified_at,
+            event.ledger,
+        ],
+    )?;
+    Ok(())
+}
+
+// Sales are paid to the payout address of a registered seller
+fn payout_address(tx: &Transaction, event: &Event, seller: &str) -> Result<String> {
+    let payout = tx
+        .query_row(
+            "SELECT payout_address FROM sellers WHERE contract_id = ?1 AND address = ?2",
+            params![event.contract_id, seller],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(payout.unwrap_or_else(|| seller.to_string()))
+}
+
+fn save_category(tx: &Transaction, event: &Event, category: &Category) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO categories (contract_id, id, name, parent_id, updated_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            event.contract_id,
+            category.id,
+            category.name,
+            category.parent,
+            event.ledger,
+        ],
+    )?;
+    Ok(())
+}
+
+fn save_dutch_auction(tx: &Transaction, event: &Event, auction: &DutchAuction) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO dutch_auctions
+            (contract_id, item_id, start_price, floor_price, start_time, end_time, curve, steps,
+             updated_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            event.contract_id,
+            auction.item_id,
+            auction.start_price.to_string(),
+            auction.floor_price.to_string(),
+            auction.start_time,
+            auction.end_time,
+            auction.curve,
+            auction.steps,
+            event.ledger,
+        ],
+    )?;
+    Ok(())
+}
+
+fn save_verification_policy(
+    tx: &Transaction,
+    event: &Event,
+    policy: &VerificationPolicy,
+) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO verification_policies
+            (contract_id, min_reference_price, updated_ledger)
+         VALUES (?1, ?2, ?3)",
+        params![
+            event.contract_id,
+            policy.min_reference_price.map(|price| price.to_string()),
+            event.ledger,
+        ],
+    )?;
+    tx.execute(
+        "DELETE FROM policy_categories WHERE contract_id = ?1",
+        params![event.contract_id],
+    )?;
+    for category_id in &policy.categories {
+        tx.execute(
+            "INSERT INTO policy_categories (contract_id, category_id) VALUES (?1, ?2)",
+            params![event.contract_id, category_id],
+        )?;
+    }
+    tx.execute(
+        "DELETE FROM policy_min_prices WHERE contract_id = ?1",
+        params![event.contract_id],
+    )?;
+    for (token, price) in &policy.min_prices {
+        tx.execute(
+            "INSERT INTO policy_min_prices (contract_id, token, price) VALUES (?1, ?2, ?3)",
+            params![event.contract_id, token, price.to_string()],
+        )?;
+    }
+    Ok(())
+}
+
+fn save_order(tx: &Transaction, event: &Event, order: &Order) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO orders
+            (contract_id, id, item_id, buyer, seller, qty, unit_price, total, fee, payment_token,
+             created_at, status, ship_by, deliver_by, tracking_hash, return_window, return_by,
+             return_reason, respond_by, seller_paid, updated_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17,
+                 ?18, ?19, ?20, ?21)",
+        params![
+            event.contract_id,
+            order.id,
+            order.item_id,
+            order.buyer,
+            order.seller,
+            order.qty,
+            order.unit_price.to_string(),
+            order.total.to_string(),
+            order.fee.to_string(),
+            order.payment_token,
+            order.created_at,
+            order.status,
+            order.ship_by,
+            order.deliver_by,
+            order.tracking_hash,
+            order.return_window,
+            order.return_by,
+            order.return_reason,
+            order.respond_by,
+            order.seller_paid,
+            event.ledger,
+        ],
+    )?;
+    Ok(())
+}
+
+fn save_offer(tx: &Transaction, event: &Event, offer: &Offer) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO offers
+            (contract_id, id, item_id, buyer, seller, qty, unit_price, escrowed, payment_token,
+             counter_price, expires_at, status, order_id, updated_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        params![
+            event.contract_id,
+            offer.id,
+            offer.item_id,
+            offer.buyer,
+            offer.seller,
+            offer.qty,
+            offer.unit_price.to_string(),
+            offer.escrowed.to_string(),
+            offer.payment_token,
+            offer.counter_price.map(|price| price.to_string()),
+            offer.expires_at,
+            offer.status,
+            offer.order_id,
+            event.ledger,
+        ],
+    )?;
+    Ok(())
+}
+
+fn save_auction(tx: &Transaction, event: &Event, auction: &Auction) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO auctions
+            (contract_id, id, seller, name, image_url, reserve_price, min_increment, end_time,
+             payment_token, highest_bid, highest_bidder, bid_count, status, updated_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        params![
+            event.contract_id,
+            auction.id,
+            auction.seller,
+            auction.name,
+            auction.image_url,
+            auction.reserve_price.to_string(),
+            auction.min_increment.to_string(),
+            auction.end_time,
+            auction.payment_token,
+            auction.highest_bid.to_string(),
+            auction.highest_bidder,
+            auction.bid_count,
+            auction.status,
+            event.ledger,
+        ],
+    )?;
+    Ok(())
+}
+
+fn save_payment(
+    tx: &Transaction,
+    event: &Event,
+    kind: &str,
+    from: &str,
+    to: &str,
+    token: Option<&str>,
+    amount: &str,
+) -> Result<()> {
+    tx.execute(
+        "INSERT INTO payments
+            (event_id, contract_id, contract, record_id, kind, from_account, to_account, token,
+             amount, ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            event.id,
+            event.contract_id,
+            event.contract.name(),
+            event.record_id.to_string(),
+            kind,
+            from,
+            to,
+            token,
+            amount,
+            event.ledger,
+        ],
+    )?;
+    Ok(())
+}
+
+fn open_dispute(tx: &Transaction, event: &Event, escrow: &Escrow) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO disputes
+            (contract_id, escrow_id, buyer, reason, amount, status, opened_ledger)
+         VALUES (?1, ?2, ?3, ?4, ?5, 'open', ?6)",
+        params![
+            event.contract_id,
+            escrow.id,
+            escrow.buyer,
+            escrow.dispute_reason,
+            escrow.amount.to_string(),
+            event.ledger,
+        ],
+    )?;
+    Ok(())
+}
+
+// Disputes only end in a refund to the payer
+fn close_dispute(tx: &Transaction, event: &Event, escrow: &Escrow) -> Result<()> {
+    tx.execute(
+        "UPDATE disputes SET status = 'refunded', resolved_by = ?1, resolved_ledger = ?2
+         WHERE contract_id = ?3 AND escrow_id = ?4",
+        params![event.actor, event.ledger, event.contract_id, escrow.id],
+    )?;
+    Ok(())
+}
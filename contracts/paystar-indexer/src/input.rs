@@ -0,0 +1,92 @@
+//! Reading event dumps.
+//!
+//! The input is the JSON returned by the Soroban RPC `getEvents` method, either
+//! the whole response, its `result` object or just the `events` array. Recorded
+//! fixtures use the same shape, so a dump taken from a live network and one
+//! captured in a test replay the same way.
+
+use serde::Deserialize;
+use stellar_xdr::curr::{Limits, ReadXdr, ScVal};
+
+use crate::error::Result;
+
+/// A contract event with its topics and data decoded from XDR.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractEvent {
+    pub id: String, // RPC event id, unique and ordered across the network
+    pub ledger: u32,
+    pub contract_id: String,
+    pub topics: Vec<ScVal>,
+    pub data: ScVal,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Dump {
+    Response { result: EventPage },
+    Page(EventPage),
+    Events(Vec<RpcEvent>),
+}
+
+#[derive(Deserialize)]
+struct EventPage {
+    events: Vec<RpcEvent>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcEvent {
+    id: String,
+    ledger: u32,
+    contract_id: String,
+    topic: Vec<String>,
+    value: RpcValue,
+    #[serde(default = "successful")]
+    in_successful_contract_call: bool,
+}
+
+// Older RPC versions wrap the value as `{ "xdr": "..." }`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RpcValue {
+    Xdr(String),
+    Wrapped { xdr: String },
+}
+
+fn successful() -> bool {
+    true
+}
+
+/// Parses an event dump, dropping events of failed contract calls since
+/// their state changes were rolled back.
+pub fn read_events(json: &str) -> Result<Vec<ContractEvent>> {
+    let events = match serde_json::from_str(json)? {
+        Dump::Response { result } => result.events,
+        Dump::Page(page) => page.events,
+        Dump::Events(events) => events,
+    };
+
+    events
+        .into_iter()
+        .filter(|event| event.in_successful_contract_call)
+        .map(|event| {
+            let topics = event
+                .topic
+                .iter()
+                .map(|topic| ScVal::from_xdr_base64(topic, Limits::none()))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            let data = match &event.value {
+                RpcValue::Xdr(xdr) | RpcValue::Wrapped { xdr } => {
+                    ScVal::from_xdr_base64(xdr, Limits::none())?
+                }
+            };
+            Ok(ContractEvent {
+                id: event.id,
+                ledger: event.ledger,
+                contract_id: event.contract_id,
+                topics,
+                data,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,16 @@
+//! Off-chain indexer for the PayStar contracts.
+//!
+//! Reads `getEvents` dumps of the escrow, installment and marketplace
+//! contracts and keeps a normalized SQLite database of items, orders, escrows,
+//! agreements, payments and disputes. Every record is rebuilt from the event
+//! payloads alone, so the indexer never needs to read contract storage.
+
+pub mod decode;
+pub mod error;
+pub mod indexer;
+pub mod input;
+
+pub use decode::{decode, Contract, Event, Payload};
+pub use error::{Error, Result};
+pub use indexer::{Indexer, Summary};
+pub use input::{read_events, ContractEvent};
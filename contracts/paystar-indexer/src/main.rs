@@ -0,0 +1,60 @@
+use std::io::Read;
+use std::process::ExitCode;
+
+use paystar_indexer::{read_events, Indexer, Result, Summary};
+
+const USAGE: &str = "usage: paystar-indexer --db <database> <events.json>...
+
+Indexes PayStar contract events into a SQLite database. Each input is the JSON
+returned by the RPC `getEvents` method, or a fixture recorded in the same
+shape; `-` reads from stdin. Events already in the database are skipped.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some((db, inputs)) = parse_args(&args) else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    match run(db, inputs) {
+        Ok(summary) => {
+            println!(
+                "indexed {} events into {db} ({} already indexed, {} ignored)",
+                summary.indexed, summary.duplicates, summary.ignored
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Option<(&str, &[String])> {
+    match args {
+        [flag, db, inputs @ ..] if flag == "--db" && !inputs.is_empty() => Some((db, inputs)),
+        _ => None,
+    }
+}
+
+fn run(db: &str, inputs: &[String]) -> Result<Summary> {
+    let mut indexer = Indexer::open(db)?;
+    let mut total = Summary::default();
+
+    for input in inputs {
+        let json = if input == "-" {
+            let mut json = String::new();
+            std::io::stdin().read_to_string(&mut json)?;
+            json
+        } else {
+            std::fs::read_to_string(input)?
+        };
+
+        let summary = indexer.ingest(&read_events(&json)?)?;
+        total.indexed += summary.indexed;
+        total.duplicates += summary.duplicates;
+        total.ignored += summary.ignored;
+    }
+    Ok(total)
+}
@@ -0,0 +1,610 @@
+//! Replays events captured from the contracts running under the soroban-sdk
+//! testutils, in the same JSON shape the RPC `getEvents` method returns.
+
+use auto_release_escrow_contract::{AutoReleaseEscrowContract, AutoReleaseEscrowContractClient};
+use installment_payment_contract::installment_payment::installment::{
+    InstallmentPayment, InstallmentPaymentClient,
+};
+use paystar_indexer::{read_events, Indexer, Summary};
+use real_market_place::{
+    DutchAuction, Marketplace, MarketplaceClient, PriceCurve, Role, VerificationPolicy,
+};
+use rusqlite::params;
+use serde_json::{json, Value};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger as _},
+    token::StellarAssetClient,
+    xdr::{Limits, ScVal, WriteXdr},
+    Address, BytesN, Env, String, TryFromVal, Val,
+};
+
+/// Records every event of the last invocation as a `getEvents` entry.
+struct Recorder {
+    events: Vec<Value>,
+}
+
+impl Recorder {
+    fn new() -> Self {
+        Recorder { events: Vec::new() }
+    }
+
+    fn capture(&mut self, env: &Env) {
+        let ledger = env.ledger().sequence();
+        for (contract, topics, data) in env.events().all().iter() {
+            let topic: Vec<std::string::String> =
+                topics.iter().map(|topic| to_xdr(env, &topic)).collect();
+            self.events.push(json!({
+                "type": "contract",
+                "ledger": ledger,
+                "contractId": address(env, &contract),
+                "id": format!("{:019}-{:010}", u64::from(ledger) << 32, self.events.len()),
+                "topic": topic,
+                "value": to_xdr(env, &data),
+                "inSuccessfulContractCall": true,
+            }));
+        }
+        // Later invocations land in later ledgers, like on a live network
+        env.ledger().with_mut(|info| info.sequence_number += 1);
+    }
+
+    fn dump(&self) -> std::string::String {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": { "events": self.events, "latestLedger": 0 },
+        })
+        .to_string()
+    }
+}
+
+fn to_xdr(env: &Env, value: &Val) -> std::string::String {
+    ScVal::try_from_val(env, value)
+        .unwrap()
+        .to_xdr_base64(Limits::none())
+        .unwrap()
+}
+
+fn address(env: &Env, address: &Address) -> std::string::String {
+    match ScVal::try_from_val(env, &address.to_val()).unwrap() {
+        ScVal::Address(address) => address.to_string(),
+        _ => unreachable!(),
+    }
+}
+
+struct Flow {
+    env: Env,
+    recorder: Recorder,
+    escrow: Address,
+    installment: Address,
+    market: Address,
+    buyer: Address,
+    seller: Address,
+}
+
+/// Drives every contract through its main paths and records the events.
+fn record_flow() -> Flow {
+    let env = Env::default();
+    env.mock_all_auths();
+    let mut recorder = Recorder::new();
+
+    let admin = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+
+    // Escrow: one released, one disputed and refunded
+    let escrow =
+        AutoReleaseEscrowContractClient::new(&env, &env.register(AutoReleaseEscrowContract, ()));
+    escrow.initialize(&admin);
+    recorder.capture(&env);
+    let now = env.ledger().timestamp();
+    let released = escrow.create_escrow(&buyer, &seller, &1_000, &token, &(now + 100));
+    recorder.capture(&env);
+    let disputed = escrow.create_escrow(&buyer, &seller, &500, &token, &(now + 100));
+    recorder.capture(&env);
+    escrow.dispute_escrow(&buyer, &disputed, &String::from_str(&env, "never shipped"));
+    recorder.capture(&env);
+    escrow.resolve_dispute_and_refund(&admin, &disputed);
+    recorder.capture(&env);
+    env.ledger().with_mut(|info| info.timestamp = now + 101);
+    escrow.release_funds(&released);
+    recorder.capture(&env);
+
+    // Installment: paid in two installments and finalized
+    let installment = InstallmentPaymentClient::new(&env, &env.register(InstallmentPayment, ()));
+    let arbitrator = Address::generate(&env);
+    installment.initialize(&admin);
+    recorder.capture(&env);
+    installment.grant_role(&admin, &Role::Arbiter, &arbitrator);
+    recorder.capture(&env);
+    installment.create_installment_agreement(
+        &seller,
+        &buyer,
+        &800,
+        &1_000,
+        &arbitrator,
+        &token,
+        &String::from_str(&env, "laptop"),
+    );
+    recorder.capture(&env);
+    installment.accept_installment_agreement(&seller, &true, &1);
+    recorder.capture(&env);
+    installment.pay_on_installment(&buyer, &300, &1);
+    recorder.capture(&env);
+    installment.pay_on_installment(&buyer, &500, &1);
+    recorder.capture(&env);
+    installment.finalize_agreement(&1, &seller);
+    recorder.capture(&env);
+
+    // Marketplace: an item listed, partly bought, shipped and delivered
+    let market = MarketplaceClient::new(&env, &env.register(Marketplace, ()));
+    market.initialize(&admin, &token);
+    recorder.capture(&env);
+    let item = market.list_item(
+        &seller,
+        &String::from_str(&env, "Guitar"),
+        &100,
+        &5,
+        &String::from_str(&env, "ipfs://guitar"),
+    );
+    recorder.capture(&env);
+    let order = market.buy_item(&buyer, &item, &2);
+    recorder.capture(&env);
+    market.mark_shipped(&seller, &order, &BytesN::from_array(&env, &[0xab; 32]));
+    recorder.capture(&env);
+    market.confirm_delivery(&buyer, &order);
+    recorder.capture(&env);
+
+    // Marketplace: an auction where the buyer outbids themselves, then settled and delivered
+    let end_time = env.ledger().timestamp() + 3_600;
+    let auction = market.create_auction(
+        &seller,
+        &String::from_str(&env, "Piano"),
+        &String::from_str(&env, "ipfs://piano"),
+        &500,
+        &50,
+        &end_time,
+    );
+    recorder.capture(&env);
+    market.place_bid(&buyer, &auction, &500);
+    recorder.capture(&env);
+    market.place_bid(&buyer, &auction, &600);
+    recorder.capture(&env);
+    env.ledger().set_timestamp(end_time);
+    market.settle_auction(&auction);
+    recorder.capture(&env);
+    let auction_order = order + 1; // Settling placed the next order
+    market.mark_shipped(
+        &seller,
+        &auction_order,
+        &BytesN::from_array(&env, &[0xef; 32]),
+    );
+    recorder.capture(&env);
+    market.confirm_delivery(&buyer, &auction_order);
+    recorder.capture(&env);
+
+    // Marketplace: an offer countered by the seller, then withdrawn
+    let offer = market.make_offer(&buyer, &item, &1, &80, &(end_time + 600));
+    recorder.capture(&env);
+    market.counter_offer(&seller, &offer, &90);
+    recorder.capture(&env);
+    market.withdraw_offer(&buyer, &offer);
+    recorder.capture(&env);
+
+    // Marketplace: a second token allowed and added to the item's prices
+    let usdc = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    market.allow_token(&admin, &usdc);
+    recorder.capture(&env);
+    market.set_item_price(&seller, &item, &usdc, &40);
+    recorder.capture(&env);
+
+    // Marketplace: the item filed under a subcategory and tagged
+    let instruments = market.add_category(&admin, &String::from_str(&env, "Instruments"), &None);
+    recorder.capture(&env);
+    let guitars = market.add_category(
+        &admin,
+        &String::from_str(&env, "Guitars"),
+        &Some(instruments),
+    );
+    recorder.capture(&env);
+    market.set_item_category(&seller, &item, &Some(guitars));
+    recorder.capture(&env);
+    market.set_item_tags(
+        &seller,
+        &item,
+        &soroban_sdk::vec![&env, String::from_str(&env, "acoustic")],
+    );
+    recorder.capture(&env);
+
+    // Marketplace: the seller registers a payout address and is verified
+    let payout = Address::generate(&env);
+    market.register_seller(
+        &seller,
+        &String::from_str(&env, "Guitar Shop"),
+        &String::from_str(&env, "ipfs://guitar-shop"),
+        &BytesN::from_array(&env, &[0xcd; 32]),
+        &payout,
+    );
+    recorder.capture(&env);
+    market.verify_seller(&admin, &seller);
+    recorder.capture(&env);
+
+    // Marketplace: the guitars reserved for verified sellers, and the item put on a
+    // stepwise Dutch auction with a return window
+    market.set_verification_policy(
+        &admin,
+        &VerificationPolicy {
+            categories: soroban_sdk::vec![&env, guitars],
+            min_prices: soroban_sdk::map![&env, (token.clone(), 1_000)],
+            min_reference_price: None,
+        },
+    );
+    recorder.capture(&env);
+    let now = env.ledger().timestamp();
+    market.set_dutch_auction(
+        &seller,
+        &DutchAuction {
+            item_id: item,
+            start_price: 120,
+            floor_price: 60,
+            start_time: now,
+            end_time: now + 3_600,
+            curve: PriceCurve::Stepwise(4),
+        },
+    );
+    recorder.capture(&env);
+    market.set_return_window(&seller, &item, &(7 * 86_400));
+    recorder.capture(&env);
+
+    Flow {
+        recorder,
+        escrow: escrow.address,
+        installment: installment.address,
+        market: market.address,
+        buyer,
+        seller,
+        env,
+    }
+}
+
+fn count(indexer: &Indexer, sql: &str) -> i64 {
+    indexer
+        .connection()
+        .query_row(sql, [], |row| row.get(0))
+        .unwrap()
+}
+
+#[test]
+fn test_replay_rebuilds_state_from_events() {
+    let flow = record_flow();
+    let env = &flow.env;
+    let mut indexer = Indexer::open_in_memory().unwrap();
+
+    let summary = indexer
+        .ingest(&read_events(&flow.recorder.dump()).unwrap())
+        .unwrap();
+    assert!(summary.indexed > 0);
+    assert!(summary.ignored > 0); // Token transfers are not PayStar events
+    assert_eq!(summary.duplicates, 0);
+
+    let conn = indexer.connection();
+    let escrow_id = address(env, &flow.escrow);
+    let escrows: Vec<(i64, std::string::String, std::string::String)> = conn
+        .prepare("SELECT id, status, amount FROM escrows WHERE contract_id = ?1 ORDER BY id")
+        .unwrap()
+        .query_map(params![escrow_id], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        escrows,
+        vec![
+            (1, "Released".into(), "1000".into()),
+            (2, "Refunded".into(), "500".into()),
+        ]
+    );
+
+    let dispute: (
+        std::string::String,
+        std::string::String,
+        std::string::String,
+    ) = conn
+        .query_row(
+            "SELECT reason, status, buyer FROM disputes WHERE escrow_id = 2",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(
+        dispute,
+        (
+            "never shipped".into(),
+            "refunded".into(),
+            address(env, &flow.buyer)
+        )
+    );
+
+    let agreement: (std::string::String, std::string::String, bool) = conn
+        .query_row(
+            "SELECT amount_paid, seller, is_finalized FROM agreements WHERE contract_id = ?1 AND id = '1'",
+            params![address(env, &flow.installment)],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(agreement, ("800".into(), address(env, &flow.seller), true));
+
+    let installments: Vec<std::string::String> = conn
+        .prepare("SELECT amount FROM payments WHERE kind = 'installment' ORDER BY ledger")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(installments, vec!["300".to_string(), "500".to_string()]);
+
+    let item: (i64, std::string::String) = conn
+        .query_row(
+            "SELECT quantity, price FROM items WHERE contract_id = ?1 AND id = 1",
+            params![address(env, &flow.market)],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(item, (3, "100".into()));
+
+    let order: (
+        i64,
+        std::string::String,
+        i64,
+        std::string::String,
+        std::string::String,
+        std::string::String,
+    ) = conn
+        .query_row(
+            "SELECT id, buyer, qty, unit_price, total, status FROM orders
+             WHERE tracking_hash = ?1",
+            params!["ab".repeat(32)],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                ))
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        order,
+        (
+            1,
+            address(env, &flow.buyer),
+            2,
+            "100".into(),
+            "200".into(),
+            "Delivered".into()
+        )
+    );
+
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM orders WHERE seller_paid = 1 AND return_window = 0"
+        ),
+        2 // The purchase and the auction win
+    );
+
+    assert_eq!(count(&indexer, "SELECT COUNT(*) FROM accepted_tokens"), 2);
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM sellers
+             WHERE display_name = 'Guitar Shop' AND verified_by IS NOT NULL"
+        ),
+        1
+    );
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM categories WHERE name = 'Guitars' AND parent_id = 1"
+        ),
+        1
+    );
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM items i JOIN item_tags t ON t.item_id = i.id
+             WHERE i.category_id = 2 AND t.tag = 'acoustic'"
+        ),
+        1
+    );
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM item_prices WHERE price IN ('100', '40')"
+        ),
+        2
+    );
+
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM offers
+             WHERE status = 'Withdrawn' AND escrowed = '80' AND counter_price = '90'"
+        ),
+        1
+    );
+
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM auctions
+             WHERE status = 'Sold' AND highest_bid = '600' AND bid_count = 2"
+        ),
+        1
+    );
+
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM payments WHERE kind = 'refund'"
+        ),
+        2 // The disputed escrow and the outbid auction bid
+    );
+    assert_eq!(
+        count(&indexer, "SELECT COUNT(*) FROM payments WHERE kind = 'bid'"),
+        2
+    );
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM payments WHERE kind = 'release'"
+        ),
+        3 // The escrow, the marketplace order and the auction
+    );
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM payments WHERE kind = 'settlement'"
+        ),
+        1
+    );
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM payments WHERE kind = 'purchase' AND token IS NOT NULL"
+        ),
+        1 // The winning bid is only recorded as a bid
+    );
+}
+
+#[test]
+fn test_replaying_a_dump_twice_is_idempotent() {
+    let flow = record_flow();
+    let events = read_events(&flow.recorder.dump()).unwrap();
+    let mut indexer = Indexer::open_in_memory().unwrap();
+
+    let first = indexer.ingest(&events).unwrap();
+    let second = indexer.ingest(&events).unwrap();
+
+    assert_eq!(
+        second,
+        Summary {
+            indexed: 0,
+            duplicates: first.indexed,
+            ignored: first.ignored,
+        }
+    );
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM payments WHERE kind = 'installment'"
+        ),
+        2
+    );
+}
+
+#[test]
+fn test_binary_indexes_recorded_fixture() {
+    let flow = record_flow();
+    let dir = std::env::temp_dir().join(format!("paystar-indexer-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let fixture = dir.join("events.json");
+    let db = dir.join("paystar.db");
+    std::fs::write(&fixture, flow.recorder.dump()).unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_paystar-indexer"))
+        .arg("--db")
+        .arg(&db)
+        .arg(&fixture)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let indexer = Indexer::open(&db).unwrap();
+    assert_eq!(count(&indexer, "SELECT COUNT(*) FROM escrows"), 2);
+    assert_eq!(count(&indexer, "SELECT COUNT(*) FROM agreements"), 1);
+    assert_eq!(count(&indexer, "SELECT COUNT(*) FROM items"), 2); // The guitar and the piano lot
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_replay_indexes_dutch_auctions() {
+    let flow = record_flow();
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    indexer
+        .ingest(&read_events(&flow.recorder.dump()).unwrap())
+        .unwrap();
+
+    let auction: (
+        std::string::String,
+        std::string::String,
+        std::string::String,
+        Option<i64>,
+    ) = indexer
+        .connection()
+        .query_row(
+            "SELECT start_price, floor_price, curve, steps FROM dutch_auctions
+             WHERE contract_id = ?1 AND item_id = 1",
+            params![address(&flow.env, &flow.market)],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .unwrap();
+    assert_eq!(
+        auction,
+        ("120".into(), "60".into(), "Stepwise".into(), Some(4))
+    );
+}
+
+#[test]
+fn test_replay_indexes_return_windows() {
+    let flow = record_flow();
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    indexer
+        .ingest(&read_events(&flow.recorder.dump()).unwrap())
+        .unwrap();
+
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT return_window FROM return_windows WHERE item_id = 1"
+        ),
+        7 * 86_400
+    );
+}
+
+#[test]
+fn test_replay_indexes_verification_policy() {
+    let flow = record_flow();
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    indexer
+        .ingest(&read_events(&flow.recorder.dump()).unwrap())
+        .unwrap();
+
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM verification_policies WHERE min_reference_price IS NULL"
+        ),
+        1
+    );
+    assert_eq!(
+        count(&indexer, "SELECT category_id FROM policy_categories"),
+        2 // Guitars
+    );
+    assert_eq!(
+        count(
+            &indexer,
+            "SELECT COUNT(*) FROM policy_min_prices WHERE price = '1000'"
+        ),
+        1
+    );
+}
@@ -6,7 +6,6 @@ import { Card, CardContent, CardHeader, CardTitle } from "@/components/ui/card"
 import { Input } from "@/components/ui/input"
 import { Label } from "@/components/ui/label"
 import { Textarea } from "@/components/ui/textarea"
-import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select"
 import { usePayStarContract } from "@/hooks/use-paystar-contract"
 import { useToast } from "@/hooks/use-toast"
 import { type WalletConnection } from "@/lib/soroban/wallet-client"
@@ -15,11 +14,26 @@ interface ContractInteractionProps {
   wallet: WalletConnection | null
 }
 
+// Token amounts are entered with 7 decimals and sent in base units
+const UNIT = BigInt(10_000_000)
+
+const toBaseUnits = (amount: string): bigint => {
+  const [whole, fraction = ""] = amount.split(".")
+  return BigInt(whole || "0") * UNIT + BigInt(fraction.padEnd(7, "0").slice(0, 7))
+}
+
+const fromBaseUnits = (amount: bigint): string => {
+  const fraction = (amount % UNIT).toString().padStart(7, "0").replace(/0+$/, "")
+  return fraction ? `${amount / UNIT}.${fraction}` : `${amount / UNIT}`
+}
+
 export default function ContractInteraction({ wallet }: ContractInteractionProps) {
   const [formData, setFormData] = useState({
     seller: "",
     amount: "",
-    installments: "1",
+    deadline: "",
+    arbitrator: "",
+    token: "",
     itemDescription: "",
     agreementId: "",
     paymentAmount: ""
@@ -29,7 +43,7 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
     createAgreement,
     makePayment,
     getAgreement,
-    releaseFunds,
+    finalizeAgreement,
     isLoading
   } = usePayStarContract()
 
@@ -45,7 +59,14 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
       return
     }
 
-    if (!formData.seller || !formData.amount || !formData.itemDescription) {
+    if (
+      !formData.seller ||
+      !formData.amount ||
+      !formData.deadline ||
+      !formData.arbitrator ||
+      !formData.token ||
+      !formData.itemDescription
+    ) {
       toast({
         title: "Missing Information",
         description: "Please fill in all required fields",
@@ -54,21 +75,22 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
       return
     }
 
-    const result = await createAgreement(
-      wallet.publicKey,
-      formData.seller,
-      formData.amount,
-      parseInt(formData.installments),
-      formData.itemDescription
-    )
+    const result = await createAgreement(wallet.publicKey, {
+      seller: formData.seller,
+      amount: toBaseUnits(formData.amount),
+      deadline: BigInt(Math.floor(new Date(formData.deadline).getTime() / 1000)),
+      arbitrator: formData.arbitrator,
+      token: formData.token,
+      description: formData.itemDescription,
+    })
 
     if (result) {
-      console.log("Agreement created with transaction hash:", result)
       // Reset form
       setFormData(prev => ({
         ...prev,
         seller: "",
         amount: "",
+        deadline: "",
         itemDescription: ""
       }))
     }
@@ -95,12 +117,11 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
 
     const result = await makePayment(
       wallet.publicKey,
-      formData.agreementId,
-      formData.paymentAmount
+      BigInt(formData.agreementId),
+      toBaseUnits(formData.paymentAmount)
     )
 
     if (result) {
-      console.log("Payment made with transaction hash:", result)
       // Reset payment form
       setFormData(prev => ({
         ...prev,
@@ -120,17 +141,23 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
       return
     }
 
-    const agreement = await getAgreement(formData.agreementId)
+    const agreement = await getAgreement(BigInt(formData.agreementId))
     if (agreement) {
-      console.log("Agreement details:", agreement)
+      const status = agreement.is_canceled
+        ? "cancelled"
+        : agreement.is_finalized
+          ? "finalized"
+          : agreement.is_accepted
+            ? "active"
+            : "awaiting seller"
       toast({
         title: "Agreement Found",
-        description: `Status: ${agreement.status}, Amount: ${agreement.amount} XLM`,
+        description: `Status: ${status}, Paid: ${fromBaseUnits(agreement.amount_paid)} of ${fromBaseUnits(agreement.total_amount)}`,
       })
     }
   }
 
-  const handleReleaseFunds = async () => {
+  const handleFinalizeAgreement = async () => {
     if (!wallet?.publicKey) {
       toast({
         title: "Wallet Required",
@@ -149,10 +176,7 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
       return
     }
 
-    const result = await releaseFunds(wallet.publicKey, formData.agreementId)
-    if (result) {
-      console.log("Funds released with transaction hash:", result)
-    }
+    await finalizeAgreement(wallet.publicKey, BigInt(formData.agreementId))
   }
 
   if (!wallet?.isConnected) {
@@ -189,7 +213,7 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
           </div>
           
           <div>
-            <Label htmlFor="amount">Total Amount (XLM)</Label>
+            <Label htmlFor="amount">Total Amount</Label>
             <Input
               id="amount"
               type="number"
@@ -201,23 +225,33 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
           </div>
 
           <div>
-            <Label htmlFor="installments">Number of Installments</Label>
-            <Select
-              value={formData.installments}
-              onValueChange={(value) => setFormData(prev => ({ ...prev, installments: value }))}
-            >
-              <SelectTrigger>
-                <SelectValue />
-              </SelectTrigger>
-              <SelectContent>
-                <SelectItem value="1">1 (Full Payment)</SelectItem>
-                <SelectItem value="2">2 Installments</SelectItem>
-                <SelectItem value="3">3 Installments</SelectItem>
-                <SelectItem value="4">4 Installments</SelectItem>
-                <SelectItem value="6">6 Installments</SelectItem>
-                <SelectItem value="12">12 Installments</SelectItem>
-              </SelectContent>
-            </Select>
+            <Label htmlFor="deadline">Payment Deadline</Label>
+            <Input
+              id="deadline"
+              type="date"
+              value={formData.deadline}
+              onChange={(e) => setFormData(prev => ({ ...prev, deadline: e.target.value }))}
+            />
+          </div>
+
+          <div>
+            <Label htmlFor="arbitrator">Arbitrator Address</Label>
+            <Input
+              id="arbitrator"
+              placeholder="Stellar public key of the arbitrator"
+              value={formData.arbitrator}
+              onChange={(e) => setFormData(prev => ({ ...prev, arbitrator: e.target.value }))}
+            />
+          </div>
+
+          <div>
+            <Label htmlFor="token">Payment Token</Label>
+            <Input
+              id="token"
+              placeholder="Contract ID of the token to pay with"
+              value={formData.token}
+              onChange={(e) => setFormData(prev => ({ ...prev, token: e.target.value }))}
+            />
           </div>
 
           <div>
@@ -253,7 +287,7 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
           </div>
 
           <div>
-            <Label htmlFor="paymentAmount">Payment Amount (XLM)</Label>
+            <Label htmlFor="paymentAmount">Payment Amount</Label>
             <Input
               id="paymentAmount"
               type="number"
@@ -273,7 +307,7 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
               Get Details
             </Button>
             
-            <Button onClick={handleReleaseFunds} variant="secondary" disabled={isLoading}>
+            <Button onClick={handleFinalizeAgreement} variant="secondary" disabled={isLoading}>
               Release Funds
             </Button>
           </div>
@@ -290,7 +324,7 @@ export default function ContractInteraction({ wallet }: ContractInteractionProps
             <p><strong>Note:</strong> This interface connects to Stellar Soroban smart contracts.</p>
             <p>To fully use this functionality, you need to:</p>
             <ul className="list-disc list-inside space-y-1 ml-4">
-              <li>Deploy a PayStar smart contract to Stellar testnet</li>
+              <li>Deploy the PayStar installment contract to Stellar testnet</li>
               <li>Set the contract ID in your environment variables</li>
               <li>Ensure your wallet has testnet XLM for transaction fees</li>
             </ul>
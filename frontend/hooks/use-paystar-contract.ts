@@ -1,46 +1,70 @@
 import { useState, useCallback } from "react"
-import { createPayStarContract, type PaymentAgreement } from "@/lib/soroban/contracts/paystar"
+import { Networks, contract } from "@stellar/stellar-sdk"
+import { Client, type InstallmentAgreement } from "@/lib/soroban/contracts/installment"
+import { signAndSubmitTransaction } from "@/lib/soroban/wallet-client"
 import { useToast } from "./use-toast"
 
-// You'll need to set this to your deployed contract ID
+// You'll need to set this to your deployed installment contract ID
 const PAYSTAR_CONTRACT_ID = process.env.NEXT_PUBLIC_PAYSTAR_CONTRACT_ID || ""
+const SOROBAN_RPC_URL = process.env.NEXT_PUBLIC_SOROBAN_RPC_URL || "https://soroban-testnet.stellar.org"
+
+export interface NewAgreement {
+  seller: string
+  amount: bigint
+  deadline: bigint // Unix timestamp in seconds
+  arbitrator: string
+  token: string
+  description: string
+}
 
 export interface UsePayStarContractReturn {
-  contract: ReturnType<typeof createPayStarContract> | null
-  createAgreement: (
-    userPublicKey: string,
-    seller: string,
-    amount: string,
-    installments: number,
-    itemDescription: string
-  ) => Promise<string | null>
+  configured: boolean
+  createAgreement: (userPublicKey: string, agreement: NewAgreement) => Promise<boolean | null>
   makePayment: (
     userPublicKey: string,
-    agreementId: string,
-    amount: string
-  ) => Promise<string | null>
-  getAgreement: (agreementId: string) => Promise<PaymentAgreement | null>
-  releaseFunds: (
+    agreementId: bigint,
+    amount: bigint
+  ) => Promise<boolean | null>
+  getAgreement: (agreementId: bigint) => Promise<InstallmentAgreement | null>
+  finalizeAgreement: (
     userPublicKey: string,
-    agreementId: string
-  ) => Promise<string | null>
+    agreementId: bigint
+  ) => Promise<boolean | null>
   isLoading: boolean
   error: string | null
 }
 
+// Read-only calls only need simulation; calls that change state are signed
+// by the connected wallet
+const createClient = (publicKey?: string) =>
+  new Client({
+    contractId: PAYSTAR_CONTRACT_ID,
+    networkPassphrase: Networks.TESTNET,
+    rpcUrl: SOROBAN_RPC_URL,
+    publicKey,
+    signTransaction: publicKey
+      ? (xdr: string) => signAndSubmitTransaction(xdr, publicKey)
+      : undefined,
+  })
+
+const send = async (tx: contract.AssembledTransaction<contract.Result<boolean>>) => {
+  const sent = await tx.signAndSend()
+  return sent.result.unwrap()
+}
+
 export const usePayStarContract = (): UsePayStarContractReturn => {
   const [isLoading, setIsLoading] = useState(false)
   const [error, setError] = useState<string | null>(null)
   const { toast } = useToast()
 
-  const contract = PAYSTAR_CONTRACT_ID ? createPayStarContract(PAYSTAR_CONTRACT_ID) : null
+  const configured = PAYSTAR_CONTRACT_ID !== ""
 
   const handleContractCall = useCallback(async <T>(
     operation: () => Promise<T>,
     successMessage: string,
     errorMessage: string
   ): Promise<T | null> => {
-    if (!contract) {
+    if (!configured) {
       const msg = "Contract not configured. Please set NEXT_PUBLIC_PAYSTAR_CONTRACT_ID"
       setError(msg)
       toast({
@@ -56,82 +80,92 @@ export const usePayStarContract = (): UsePayStarContractReturn => {
 
     try {
       const result = await operation()
-      
+
       toast({
         title: "Success",
         description: successMessage,
       })
-      
+
       return result
     } catch (err) {
       const errorMsg = err instanceof Error ? err.message : errorMessage
       setError(errorMsg)
-      
+
       toast({
         title: "Error",
         description: errorMsg,
         variant: "destructive",
       })
-      
+
       return null
     } finally {
       setIsLoading(false)
     }
-  }, [contract, toast])
+  }, [configured, toast])
 
   const createAgreement = useCallback(async (
     userPublicKey: string,
-    seller: string,
-    amount: string,
-    installments: number,
-    itemDescription: string
-  ): Promise<string | null> => {
+    agreement: NewAgreement
+  ): Promise<boolean | null> => {
     return handleContractCall(
-      () => contract!.createAgreement(userPublicKey, seller, amount, installments, itemDescription),
+      async () => send(await createClient(userPublicKey).create_installment_agreement({
+        ...agreement,
+        buyer: userPublicKey,
+      })),
       "Payment agreement created successfully!",
       "Failed to create payment agreement"
     )
-  }, [contract, handleContractCall])
+  }, [handleContractCall])
 
   const makePayment = useCallback(async (
     userPublicKey: string,
-    agreementId: string,
-    amount: string
-  ): Promise<string | null> => {
+    agreementId: bigint,
+    amount: bigint
+  ): Promise<boolean | null> => {
     return handleContractCall(
-      () => contract!.makePayment(userPublicKey, agreementId, amount),
+      async () => send(await createClient(userPublicKey).pay_on_installment({
+        buyer_address: userPublicKey,
+        installment_amount: amount,
+        agreement_id: agreementId,
+      })),
       "Payment submitted successfully!",
       "Failed to submit payment"
     )
-  }, [contract, handleContractCall])
+  }, [handleContractCall])
 
   const getAgreement = useCallback(async (
-    agreementId: string
-  ): Promise<PaymentAgreement | null> => {
+    agreementId: bigint
+  ): Promise<InstallmentAgreement | null> => {
     return handleContractCall(
-      () => contract!.getAgreement(agreementId),
+      async () => {
+        const tx = await createClient().get_installment_agreement({ agreement_id: agreementId })
+        return tx.result ?? null
+      },
       "Agreement details retrieved",
       "Failed to get agreement details"
     )
-  }, [contract, handleContractCall])
+  }, [handleContractCall])
 
-  const releaseFunds = useCallback(async (
+  const finalizeAgreement = useCallback(async (
     userPublicKey: string,
-    agreementId: string
-  ): Promise<string | null> => {
+    agreementId: bigint
+  ): Promise<boolean | null> => {
     return handleContractCall(
-      () => contract!.releaseFunds(userPublicKey, agreementId),
+      async () => send(await createClient(userPublicKey).finalize_agreement({
+        agreement_id: agreementId,
+        user: userPublicKey,
+      })),
       "Funds released successfully!",
       "Failed to release funds"
     )
-  }, [contract, handleContractCall])
+  }, [handleContractCall])
 
   return {
-    contract,
+    configured,
     createAgreement,
     makePayment,
     getAgreement,
-    releaseFunds,
+    finalizeAgreement,
     isLoading,
     error
   }
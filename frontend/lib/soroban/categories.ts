@@ -0,0 +1,34 @@
+import type { Category } from "@/lib/soroban/contracts/marketplace"
+
+// Shapes the flat list from the marketplace's `get_categories` into the tree the
+// catalogue browses. Listings of a category come a page at a time from
+// `get_items_in_category`, which already includes its subcategories.
+
+export interface CategoryNode extends Category {
+  children: CategoryNode[]
+}
+
+/** The category tree, top-level categories first, each level in id order */
+export const categoryTree = (categories: Category[]): CategoryNode[] => {
+  const nodes = new Map<number, CategoryNode>()
+  for (const category of categories) nodes.set(category.id, { ...category, children: [] })
+
+  const roots: CategoryNode[] = []
+  for (const node of Array.from(nodes.values()).sort((a, b) => a.id - b.id)) {
+    const parent = node.parent === undefined ? undefined : nodes.get(node.parent)
+    if (parent) parent.children.push(node)
+    else roots.push(node)
+  }
+  return roots
+}
+
+/** Names from the top of the tree down to a category, for breadcrumbs */
+export const categoryPath = (categories: Category[], id: number): string[] => {
+  const byId = new Map(categories.map((category) => [category.id, category]))
+  const path: string[] = []
+  for (let category = byId.get(id); category; ) {
+    path.unshift(category.name)
+    category = category.parent === undefined ? undefined : byId.get(category.parent)
+  }
+  return path
+}
@@ -0,0 +1,567 @@
+// Generated from the escrow contract spec by `cargo run -p paystar-cli -- bindings`.
+// Do not edit by hand: regenerate it when the contract interface changes.
+
+import { Buffer } from "buffer"
+import { contract } from "@stellar/stellar-sdk"
+
+export type MethodOptions = {
+  fee?: number
+  timeoutInSeconds?: number
+  simulate?: boolean
+}
+
+export interface Escrow {
+  amount: bigint
+  buyer: string
+  buyer_confirmed: boolean
+  dispute_reason: string | undefined
+  extensions: Array<ReleaseExtension>
+  id: bigint
+  payer: string
+  payment_token: string
+  release_timestamp: bigint
+  seller: string
+  seller_acknowledged: boolean
+  status: EscrowStatus
+}
+
+export type EscrowStatus =
+  | { tag: "Active"; values: void }
+  | { tag: "Released"; values: void }
+  | { tag: "Refunded"; values: void }
+  | { tag: "Disputed"; values: void }
+  | { tag: "Cancelled"; values: void }
+
+/** A pending request to move the release timestamp, awaiting the other party. */
+export interface ExtensionProposal {
+  new_release_timestamp: bigint
+  proposer: string
+}
+
+/** A release-time extension both parties agreed on. */
+export interface ReleaseExtension {
+  accepted_at: bigint
+  new_release_timestamp: bigint
+  previous_release_timestamp: bigint
+  proposer: string
+}
+
+/** Platform fee charged on every settled escrow. */
+export interface FeeConfig {
+  fee_bps: number
+  treasury: string
+}
+
+/** M-of-N signer set that takes over sensitive admin actions once enabled. */
+export interface GovernanceConfig {
+  proposal_ttl: bigint
+  ruling_threshold: bigint
+  signers: Array<string>
+  threshold: number
+}
+
+/** Sensitive actions that run through governance once it is enabled. */
+export type GovernanceAction =
+  | { tag: "SetFee"; values: readonly [number, string] }
+  | { tag: "SetKeeperReward"; values: readonly [number] }
+  | { tag: "Upgrade"; values: readonly [Buffer] }
+  | { tag: "GrantArbiter"; values: readonly [string] }
+  | { tag: "RevokeArbiter"; values: readonly [string] }
+  | { tag: "ResolveDispute"; values: readonly [bigint] }
+  | { tag: "UpdateGovernance"; values: readonly [GovernanceConfig] }
+  | { tag: "DisableGovernance"; values: void }
+
+/** A governance action gathering signer approvals. */
+export interface GovernanceProposal {
+  action: GovernanceAction
+  approvals: Array<string>
+  executed: boolean
+  expires_at: bigint
+  id: bigint
+  proposer: string
+}
+
+/** Error codes of `ContractError`, keyed by code. */
+export const Errors = {
+  1: { message: "AlreadyInitialized" },
+  2: { message: "ReleaseTimeNotPassed" },
+  3: { message: "EscrowNotActive" },
+  4: { message: "EscrowAlreadyDisputed" },
+  5: { message: "EscrowNotDisputed" },
+  6: { message: "NotAdmin" },
+  7: { message: "NotBuyer" },
+  8: { message: "EscrowNotFound" },
+  9: { message: "InvalidAmount" },
+  10: { message: "InvalidReleaseTime" },
+  11: { message: "InvalidFee" },
+  12: { message: "InsufficientFees" },
+  13: { message: "NotSeller" },
+  14: { message: "EscrowAlreadyAcknowledged" },
+  15: { message: "NotParticipant" },
+  16: { message: "NoPendingExtension" },
+  17: { message: "ExtensionLimitReached" },
+  18: { message: "CannotAcceptOwnExtension" },
+  19: { message: "InvalidKeeperReward" },
+  20: { message: "NotPayer" },
+  21: { message: "AlreadyMigrated" },
+  22: { message: "ContractPaused" },
+  23: { message: "MissingRole" },
+  24: { message: "NotPendingAdmin" },
+  25: { message: "InvalidRole" },
+  26: { message: "GovernanceRequired" },
+  27: { message: "GovernanceNotEnabled" },
+  28: { message: "NotSigner" },
+  29: { message: "ProposalNotFound" },
+  30: { message: "ProposalExpired" },
+  31: { message: "ProposalAlreadyApproved" },
+  32: { message: "ProposalAlreadyExecuted" },
+  33: { message: "ThresholdNotMet" },
+  34: { message: "InvalidGovernanceConfig" },
+  35: { message: "NotInitialized" },
+  36: { message: "AmountOverflow" },
+  37: { message: "InsufficientBalance" },
+}
+
+/** Access-control roles. The admin implicitly holds every role. */
+export type Role =
+  | { tag: "Admin"; values: void }
+  | { tag: "Arbiter"; values: void }
+  | { tag: "Pauser"; values: void }
+  | { tag: "FeeManager"; values: void }
+  | { tag: "Attester"; values: void }
+
+export interface Client {
+  /** Initializes the contract with a designated admin address. */
+  initialize: (
+    { admin }: {
+      admin: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /**
+   * Allows the current admin to propose a new admin. Admin rights only move
+   * once the proposed address calls `accept_admin`.
+   */
+  propose_admin: (
+    { admin, new_admin }: {
+      admin: string
+      new_admin: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows the proposed admin to accept admin rights. */
+  accept_admin: (
+    { new_admin }: {
+      new_admin: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows the admin to grant the Arbiter, Pauser or FeeManager role to an address. */
+  grant_role: (
+    { admin, role, account }: {
+      admin: string
+      role: Role
+      account: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows the admin to revoke a previously granted role. */
+  revoke_role: (
+    { admin, role, account }: {
+      admin: string
+      role: Role
+      account: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Creates a new escrow agreement and locks the buyer's funds. */
+  create_escrow: (
+    { buyer, seller, amount, payment_token, release_timestamp }: {
+      buyer: string
+      seller: string
+      amount: bigint
+      payment_token: string
+      release_timestamp: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<bigint>>>
+
+  /**
+   * Creates an escrow funded by a third-party `payer` on behalf of `buyer`,
+   * e.g. a gift or an employer purchase. Refunds go back to the payer.
+   */
+  create_funded_escrow: (
+    { payer, buyer, seller, amount, payment_token, release_timestamp }: {
+      payer: string
+      buyer: string
+      seller: string
+      amount: bigint
+      payment_token: string
+      release_timestamp: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<bigint>>>
+
+  /**
+   * Allows the seller to acknowledge the escrow (order accepted or shipped).
+   * After this the buyer can no longer cancel.
+   */
+  acknowledge_escrow: (
+    { seller, escrow_id }: {
+      seller: string
+      escrow_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows the payer to cancel and get a full refund before the seller acknowledges. */
+  cancel_escrow: (
+    { payer, escrow_id }: {
+      payer: string
+      escrow_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /**
+   * Allows the buyer to confirm they have received the goods/service,
+   * enabling an early release of funds.
+   */
+  confirm_receipt: (
+    { buyer, escrow_id }: {
+      buyer: string
+      escrow_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows the buyer or seller to propose a later release timestamp. */
+  propose_extension: (
+    { proposer, escrow_id, new_release_timestamp }: {
+      proposer: string
+      escrow_id: bigint
+      new_release_timestamp: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows the other party to accept a pending release-time extension. */
+  accept_extension: (
+    { accepter, escrow_id }: {
+      accepter: string
+      escrow_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /**
+   * Releases the funds to the seller if the auto-release time has passed
+   * OR if the buyer has confirmed receipt.
+   */
+  release_funds: (
+    { escrow_id }: {
+      escrow_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /**
+   * Releases matured escrows in release-time order, up to `limit` escrows and
+   * `MAX_RELEASE_DAYS_PER_CALL` days of release buckets. Anyone can call this; the keeper is paid a share of the platform fee if configured.
+   * Returns the number of escrows released.
+   */
+  release_matured: (
+    { keeper, limit }: {
+      keeper: string
+      limit: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<number>>>
+
+  /** Allows the buyer to request a refund if there is a dispute. */
+  dispute_escrow: (
+    { buyer, escrow_id, reason }: {
+      buyer: string
+      escrow_id: bigint
+      reason: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Extends the storage TTL of an escrow and the contract instance. Permissionless. */
+  bump: (
+    { escrow_id }: {
+      escrow_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Extends the storage TTL of a granted role and the contract instance. Permissionless. */
+  bump_role: (
+    { role, account }: {
+      role: Role
+      account: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows an arbiter to resolve a dispute, refunding the payer. */
+  resolve_dispute_and_refund: (
+    { arbiter, escrow_id }: {
+      arbiter: string
+      escrow_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /**
+   * Allows a pauser to pause the contract during an incident.
+   * New escrows are blocked; releases, refunds and cancellations stay open.
+   */
+  pause: (
+    { pauser }: {
+      pauser: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows a pauser to lift a pause. */
+  unpause: (
+    { pauser }: {
+      pauser: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows a fee manager to configure the platform fee (in basis points) and treasury. */
+  set_fee: (
+    { fee_manager, fee_bps, treasury }: {
+      fee_manager: string
+      fee_bps: number
+      treasury: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows a fee manager to withdraw accrued platform fees of a token to the treasury. */
+  withdraw_fees: (
+    { fee_manager, token, amount }: {
+      fee_manager: string
+      token: string
+      amount: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows a fee manager to set the keeper reward as a share (in bps) of the platform fee. */
+  set_keeper_reward: (
+    { fee_manager, reward_bps }: {
+      fee_manager: string
+      reward_bps: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows the admin to replace the contract WASM, keeping all stored data. */
+  upgrade: (
+    { admin, new_wasm_hash }: {
+      admin: string
+      new_wasm_hash: Buffer
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /**
+   * Allows the admin to hand fee changes, upgrades, arbiter registry edits and
+   * large dispute rulings over to an M-of-N signer set.
+   */
+  enable_governance: (
+    { admin, config }: {
+      admin: string
+      config: GovernanceConfig
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Allows a signer to propose a governance action, counting their approval. */
+  propose_action: (
+    { signer, action }: {
+      signer: string
+      action: GovernanceAction
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<bigint>>>
+
+  /** Allows a signer to approve an open governance proposal. */
+  approve_action: (
+    { signer, proposal_id }: {
+      signer: string
+      proposal_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /** Runs a governance proposal once it has enough approvals and has not expired. */
+  execute_action: (
+    { proposal_id }: {
+      proposal_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<void>>>
+
+  /**
+   * Allows the admin to rewrite up to `limit` stored escrows into the current
+   * layout after an upgrade. Call repeatedly until `get_schema_version` is current.
+   */
+  migrate: (
+    { admin, limit }: {
+      admin: string
+      limit: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<number>>>
+
+  /** Retrieves the current admin. */
+  get_admin: (options?: MethodOptions) => Promise<contract.AssembledTransaction<contract.Result<string>>>
+
+  /** Retrieves the admin proposed through `propose_admin`, if any. */
+  get_pending_admin: (options?: MethodOptions) => Promise<contract.AssembledTransaction<string | undefined>>
+
+  /** Retrieves the multisig governance configuration, if governance is enabled. */
+  get_governance: (options?: MethodOptions) => Promise<contract.AssembledTransaction<GovernanceConfig | undefined>>
+
+  /** Retrieves a governance proposal. */
+  get_governance_proposal: (
+    { proposal_id }: {
+      proposal_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<GovernanceProposal>>>
+
+  /** Returns whether an address holds a role (the admin holds every role). */
+  has_role: (
+    { role, account }: {
+      role: Role
+      account: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<boolean>>
+
+  /** Retrieves the details of a specific escrow. */
+  get_escrow: (
+    { escrow_id }: {
+      escrow_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<Escrow>>>
+
+  /** Retrieves the pending release-time extension proposal of an escrow, if any. */
+  get_extension_proposal: (
+    { escrow_id }: {
+      escrow_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<ExtensionProposal | undefined>>
+
+  /**
+   * Retrieves the active escrows releasing on `day` (the release timestamp divided by
+   * `RELEASE_BUCKET_SECONDS`) as `(release_timestamp, escrow_id)`, ordered by release time.
+   * Keepers use this to decide when to call `release_matured`.
+   */
+  get_release_bucket: (
+    { day }: {
+      day: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Array<readonly [bigint, bigint]>>>
+
+  /**
+   * Retrieves the first day whose release bucket may still hold escrows, if any escrow
+   * was ever queued.
+   */
+  get_release_cursor: (options?: MethodOptions) => Promise<contract.AssembledTransaction<bigint | undefined>>
+
+  /** Returns whether the contract is currently paused. */
+  is_paused: (options?: MethodOptions) => Promise<contract.AssembledTransaction<boolean>>
+
+  /** Retrieves the layout version of the stored records. */
+  get_schema_version: (options?: MethodOptions) => Promise<contract.AssembledTransaction<number>>
+
+  /** Retrieves the current platform fee configuration, if any. */
+  get_fee_config: (options?: MethodOptions) => Promise<contract.AssembledTransaction<FeeConfig | undefined>>
+
+  /** Retrieves the platform fees accrued (and not yet withdrawn) for a token. */
+  get_collected_fees: (
+    { token }: {
+      token: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+}
+
+export class Client extends contract.Client {
+  constructor(public readonly options: contract.ClientOptions) {
+    super(
+      new contract.Spec([
+        "AAAAAAAAADlJbml0aWFsaXplcyB0aGUgY29udHJhY3Qgd2l0aCBhIGRlc2lnbmF0ZWQgYWRtaW4gYWRkcmVzcy4AAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAHdBbGxvd3MgdGhlIGN1cnJlbnQgYWRtaW4gdG8gcHJvcG9zZSBhIG5ldyBhZG1pbi4gQWRtaW4gcmlnaHRzIG9ubHkgbW92ZQpvbmNlIHRoZSBwcm9wb3NlZCBhZGRyZXNzIGNhbGxzIGBhY2NlcHRfYWRtaW5gLgAAAAANcHJvcG9zZV9hZG1pbgAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAADFBbGxvd3MgdGhlIHByb3Bvc2VkIGFkbWluIHRvIGFjY2VwdCBhZG1pbiByaWdodHMuAAAAAAAADGFjY2VwdF9hZG1pbgAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAE9BbGxvd3MgdGhlIGFkbWluIHRvIGdyYW50IHRoZSBBcmJpdGVyLCBQYXVzZXIgb3IgRmVlTWFuYWdlciByb2xlIHRvIGFuIGFkZHJlc3MuAAAAAApncmFudF9yb2xlAAAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAADVBbGxvd3MgdGhlIGFkbWluIHRvIHJldm9rZSBhIHByZXZpb3VzbHkgZ3JhbnRlZCByb2xlLgAAAAAAAAtyZXZva2Vfcm9sZQAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAADtDcmVhdGVzIGEgbmV3IGVzY3JvdyBhZ3JlZW1lbnQgYW5kIGxvY2tzIHRoZSBidXllcidzIGZ1bmRzLgAAAAANY3JlYXRlX2VzY3JvdwAAAAAAAAUAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAABFyZWxlYXNlX3RpbWVzdGFtcAAAAAAAAAYAAAABAAAD6QAAAAYAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAIpDcmVhdGVzIGFuIGVzY3JvdyBmdW5kZWQgYnkgYSB0aGlyZC1wYXJ0eSBgcGF5ZXJgIG9uIGJlaGFsZiBvZiBgYnV5ZXJgLAplLmcuIGEgZ2lmdCBvciBhbiBlbXBsb3llciBwdXJjaGFzZS4gUmVmdW5kcyBnbyBiYWNrIHRvIHRoZSBwYXllci4AAAAAABRjcmVhdGVfZnVuZGVkX2VzY3JvdwAAAAYAAAAAAAAABXBheWVyAAAAAAAAEwAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAEXJlbGVhc2VfdGltZXN0YW1wAAAAAAAABgAAAAEAAAPpAAAABgAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAHNBbGxvd3MgdGhlIHNlbGxlciB0byBhY2tub3dsZWRnZSB0aGUgZXNjcm93IChvcmRlciBhY2NlcHRlZCBvciBzaGlwcGVkKS4KQWZ0ZXIgdGhpcyB0aGUgYnV5ZXIgY2FuIG5vIGxvbmdlciBjYW5jZWwuAAAAABJhY2tub3dsZWRnZV9lc2Nyb3cAAAAAAAIAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAJZXNjcm93X2lkAAAAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAFBBbGxvd3MgdGhlIHBheWVyIHRvIGNhbmNlbCBhbmQgZ2V0IGEgZnVsbCByZWZ1bmQgYmVmb3JlIHRoZSBzZWxsZXIgYWNrbm93bGVkZ2VzLgAAAA1jYW5jZWxfZXNjcm93AAAAAAAAAgAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAAAAAAllc2Nyb3dfaWQAAAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAGVBbGxvd3MgdGhlIGJ1eWVyIHRvIGNvbmZpcm0gdGhleSBoYXZlIHJlY2VpdmVkIHRoZSBnb29kcy9zZXJ2aWNlLAplbmFibGluZyBhbiBlYXJseSByZWxlYXNlIG9mIGZ1bmRzLgAAAAAAAA9jb25maXJtX3JlY2VpcHQAAAAAAgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAllc2Nyb3dfaWQAAAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAEBBbGxvd3MgdGhlIGJ1eWVyIG9yIHNlbGxlciB0byBwcm9wb3NlIGEgbGF0ZXIgcmVsZWFzZSB0aW1lc3RhbXAuAAAAEXByb3Bvc2VfZXh0ZW5zaW9uAAAAAAAAAwAAAAAAAAAIcHJvcG9zZXIAAAATAAAAAAAAAAllc2Nyb3dfaWQAAAAAAAAGAAAAAAAAABVuZXdfcmVsZWFzZV90aW1lc3RhbXAAAAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAEJBbGxvd3MgdGhlIG90aGVyIHBhcnR5IHRvIGFjY2VwdCBhIHBlbmRpbmcgcmVsZWFzZS10aW1lIGV4dGVuc2lvbi4AAAAAABBhY2NlcHRfZXh0ZW5zaW9uAAAAAgAAAAAAAAAIYWNjZXB0ZXIAAAATAAAAAAAAAAllc2Nyb3dfaWQAAAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAGtSZWxlYXNlcyB0aGUgZnVuZHMgdG8gdGhlIHNlbGxlciBpZiB0aGUgYXV0by1yZWxlYXNlIHRpbWUgaGFzIHBhc3NlZApPUiBpZiB0aGUgYnV5ZXIgaGFzIGNvbmZpcm1lZCByZWNlaXB0LgAAAAANcmVsZWFzZV9mdW5kcwAAAAAAAAEAAAAAAAAACWVzY3Jvd19pZAAAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAPpSZWxlYXNlcyBtYXR1cmVkIGVzY3Jvd3MgaW4gcmVsZWFzZS10aW1lIG9yZGVyLCB1cCB0byBgbGltaXRgIGVzY3Jvd3MgYW5kCmBNQVhfUkVMRUFTRV9EQVlTX1BFUl9DQUxMYCBkYXlzIG9mIHJlbGVhc2UgYnVja2V0cy4gQW55b25lIGNhbiBjYWxsIHRoaXM7IHRoZSBrZWVwZXIgaXMgcGFpZCBhIHNoYXJlIG9mIHRoZSBwbGF0Zm9ybSBmZWUgaWYgY29uZmlndXJlZC4KUmV0dXJucyB0aGUgbnVtYmVyIG9mIGVzY3Jvd3MgcmVsZWFzZWQuAAAAAAAPcmVsZWFzZV9tYXR1cmVkAAAAAAIAAAAAAAAABmtlZXBlcgAAAAAAEwAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+kAAAAEAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAADtBbGxvd3MgdGhlIGJ1eWVyIHRvIHJlcXVlc3QgYSByZWZ1bmQgaWYgdGhlcmUgaXMgYSBkaXNwdXRlLgAAAAAOZGlzcHV0ZV9lc2Nyb3cAAAAAAAMAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAJZXNjcm93X2lkAAAAAAAABgAAAAAAAAAGcmVhc29uAAAAAAAQAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAE9FeHRlbmRzIHRoZSBzdG9yYWdlIFRUTCBvZiBhbiBlc2Nyb3cgYW5kIHRoZSBjb250cmFjdCBpbnN0YW5jZS4gUGVybWlzc2lvbmxlc3MuAAAAAARidW1wAAAAAQAAAAAAAAAJZXNjcm93X2lkAAAAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAFRFeHRlbmRzIHRoZSBzdG9yYWdlIFRUTCBvZiBhIGdyYW50ZWQgcm9sZSBhbmQgdGhlIGNvbnRyYWN0IGluc3RhbmNlLiBQZXJtaXNzaW9ubGVzcy4AAAAJYnVtcF9yb2xlAAAAAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAADxBbGxvd3MgYW4gYXJiaXRlciB0byByZXNvbHZlIGEgZGlzcHV0ZSwgcmVmdW5kaW5nIHRoZSBwYXllci4AAAAacmVzb2x2ZV9kaXNwdXRlX2FuZF9yZWZ1bmQAAAAAAAIAAAAAAAAAB2FyYml0ZXIAAAAAEwAAAAAAAAAJZXNjcm93X2lkAAAAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAIFBbGxvd3MgYSBwYXVzZXIgdG8gcGF1c2UgdGhlIGNvbnRyYWN0IGR1cmluZyBhbiBpbmNpZGVudC4KTmV3IGVzY3Jvd3MgYXJlIGJsb2NrZWQ7IHJlbGVhc2VzLCByZWZ1bmRzIGFuZCBjYW5jZWxsYXRpb25zIHN0YXkgb3Blbi4AAAAAAAAFcGF1c2UAAAAAAAABAAAAAAAAAAZwYXVzZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAACBBbGxvd3MgYSBwYXVzZXIgdG8gbGlmdCBhIHBhdXNlLgAAAAd1bnBhdXNlAAAAAAEAAAAAAAAABnBhdXNlcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAFJBbGxvd3MgYSBmZWUgbWFuYWdlciB0byBjb25maWd1cmUgdGhlIHBsYXRmb3JtIGZlZSAoaW4gYmFzaXMgcG9pbnRzKSBhbmQgdHJlYXN1cnkuAAAAAAAHc2V0X2ZlZQAAAAADAAAAAAAAAAtmZWVfbWFuYWdlcgAAAAATAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAAAAAAACHRyZWFzdXJ5AAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAFJBbGxvd3MgYSBmZWUgbWFuYWdlciB0byB3aXRoZHJhdyBhY2NydWVkIHBsYXRmb3JtIGZlZXMgb2YgYSB0b2tlbiB0byB0aGUgdHJlYXN1cnkuAAAAAAANd2l0aGRyYXdfZmVlcwAAAAAAAAMAAAAAAAAAC2ZlZV9tYW5hZ2VyAAAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAFZBbGxvd3MgYSBmZWUgbWFuYWdlciB0byBzZXQgdGhlIGtlZXBlciByZXdhcmQgYXMgYSBzaGFyZSAoaW4gYnBzKSBvZiB0aGUgcGxhdGZvcm0gZmVlLgAAAAAAEXNldF9rZWVwZXJfcmV3YXJkAAAAAAAAAgAAAAAAAAALZmVlX21hbmFnZXIAAAAAEwAAAAAAAAAKcmV3YXJkX2JwcwAAAAAABAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAEdBbGxvd3MgdGhlIGFkbWluIHRvIHJlcGxhY2UgdGhlIGNvbnRyYWN0IFdBU00sIGtlZXBpbmcgYWxsIHN0b3JlZCBkYXRhLgAAAAAHdXBncmFkZQAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAH5BbGxvd3MgdGhlIGFkbWluIHRvIGhhbmQgZmVlIGNoYW5nZXMsIHVwZ3JhZGVzLCBhcmJpdGVyIHJlZ2lzdHJ5IGVkaXRzIGFuZApsYXJnZSBkaXNwdXRlIHJ1bGluZ3Mgb3ZlciB0byBhbiBNLW9mLU4gc2lnbmVyIHNldC4AAAAAABFlbmFibGVfZ292ZXJuYW5jZQAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAfQAAAAEEdvdmVybmFuY2VDb25maWcAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAEhBbGxvd3MgYSBzaWduZXIgdG8gcHJvcG9zZSBhIGdvdmVybmFuY2UgYWN0aW9uLCBjb3VudGluZyB0aGVpciBhcHByb3ZhbC4AAAAOcHJvcG9zZV9hY3Rpb24AAAAAAAIAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAAEEdvdmVybmFuY2VBY3Rpb24AAAABAAAD6QAAAAYAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAADdBbGxvd3MgYSBzaWduZXIgdG8gYXBwcm92ZSBhbiBvcGVuIGdvdmVybmFuY2UgcHJvcG9zYWwuAAAAAA5hcHByb3ZlX2FjdGlvbgAAAAAAAgAAAAAAAAAGc2lnbmVyAAAAAAATAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAExSdW5zIGEgZ292ZXJuYW5jZSBwcm9wb3NhbCBvbmNlIGl0IGhhcyBlbm91Z2ggYXBwcm92YWxzIGFuZCBoYXMgbm90IGV4cGlyZWQuAAAADmV4ZWN1dGVfYWN0aW9uAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAJlBbGxvd3MgdGhlIGFkbWluIHRvIHJld3JpdGUgdXAgdG8gYGxpbWl0YCBzdG9yZWQgZXNjcm93cyBpbnRvIHRoZSBjdXJyZW50CmxheW91dCBhZnRlciBhbiB1cGdyYWRlLiBDYWxsIHJlcGVhdGVkbHkgdW50aWwgYGdldF9zY2hlbWFfdmVyc2lvbmAgaXMgY3VycmVudC4AAAAAAAAHbWlncmF0ZQAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPpAAAABAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAABxSZXRyaWV2ZXMgdGhlIGN1cnJlbnQgYWRtaW4uAAAACWdldF9hZG1pbgAAAAAAAAAAAAABAAAD6QAAABMAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAD1SZXRyaWV2ZXMgdGhlIGFkbWluIHByb3Bvc2VkIHRocm91Z2ggYHByb3Bvc2VfYWRtaW5gLCBpZiBhbnkuAAAAAAAAEWdldF9wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAEpSZXRyaWV2ZXMgdGhlIG11bHRpc2lnIGdvdmVybmFuY2UgY29uZmlndXJhdGlvbiwgaWYgZ292ZXJuYW5jZSBpcyBlbmFibGVkLgAAAAAADmdldF9nb3Zlcm5hbmNlAAAAAAAAAAAAAQAAA+gAAAfQAAAAEEdvdmVybmFuY2VDb25maWc=",
+        "AAAAAAAAACBSZXRyaWV2ZXMgYSBnb3Zlcm5hbmNlIHByb3Bvc2FsLgAAABdnZXRfZ292ZXJuYW5jZV9wcm9wb3NhbAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAA+kAAAfQAAAAEkdvdmVybmFuY2VQcm9wb3NhbAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAEVSZXR1cm5zIHdoZXRoZXIgYW4gYWRkcmVzcyBob2xkcyBhIHJvbGUgKHRoZSBhZG1pbiBob2xkcyBldmVyeSByb2xlKS4AAAAAAAAIaGFzX3JvbGUAAAACAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAACtSZXRyaWV2ZXMgdGhlIGRldGFpbHMgb2YgYSBzcGVjaWZpYyBlc2Nyb3cuAAAAAApnZXRfZXNjcm93AAAAAAABAAAAAAAAAAllc2Nyb3dfaWQAAAAAAAAGAAAAAQAAA+kAAAfQAAAABkVzY3JvdwAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAEtSZXRyaWV2ZXMgdGhlIHBlbmRpbmcgcmVsZWFzZS10aW1lIGV4dGVuc2lvbiBwcm9wb3NhbCBvZiBhbiBlc2Nyb3csIGlmIGFueS4AAAAAFmdldF9leHRlbnNpb25fcHJvcG9zYWwAAAAAAAEAAAAAAAAACWVzY3Jvd19pZAAAAAAAAAYAAAABAAAD6AAAB9AAAAARRXh0ZW5zaW9uUHJvcG9zYWwAAAA=",
+        "AAAAAAAAAORSZXRyaWV2ZXMgdGhlIGFjdGl2ZSBlc2Nyb3dzIHJlbGVhc2luZyBvbiBgZGF5YCAodGhlIHJlbGVhc2UgdGltZXN0YW1wIGRpdmlkZWQgYnkKYFJFTEVBU0VfQlVDS0VUX1NFQ09ORFNgKSBhcyBgKHJlbGVhc2VfdGltZXN0YW1wLCBlc2Nyb3dfaWQpYCwgb3JkZXJlZCBieSByZWxlYXNlIHRpbWUuCktlZXBlcnMgdXNlIHRoaXMgdG8gZGVjaWRlIHdoZW4gdG8gY2FsbCBgcmVsZWFzZV9tYXR1cmVkYC4AAAASZ2V0X3JlbGVhc2VfYnVja2V0AAAAAAABAAAAAAAAAANkYXkAAAAABgAAAAEAAAPqAAAD7QAAAAIAAAAGAAAABg==",
+        "AAAAAAAAAGNSZXRyaWV2ZXMgdGhlIGZpcnN0IGRheSB3aG9zZSByZWxlYXNlIGJ1Y2tldCBtYXkgc3RpbGwgaG9sZCBlc2Nyb3dzLCBpZiBhbnkgZXNjcm93CndhcyBldmVyIHF1ZXVlZC4AAAAAEmdldF9yZWxlYXNlX2N1cnNvcgAAAAAAAAAAAAEAAAPoAAAABg==",
+        "AAAAAAAAADFSZXR1cm5zIHdoZXRoZXIgdGhlIGNvbnRyYWN0IGlzIGN1cnJlbnRseSBwYXVzZWQuAAAAAAAACWlzX3BhdXNlZAAAAAAAAAAAAAABAAAAAQ==",
+        "AAAAAAAAADNSZXRyaWV2ZXMgdGhlIGxheW91dCB2ZXJzaW9uIG9mIHRoZSBzdG9yZWQgcmVjb3Jkcy4AAAAAEmdldF9zY2hlbWFfdmVyc2lvbgAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAADlSZXRyaWV2ZXMgdGhlIGN1cnJlbnQgcGxhdGZvcm0gZmVlIGNvbmZpZ3VyYXRpb24sIGlmIGFueS4AAAAAAAAOZ2V0X2ZlZV9jb25maWcAAAAAAAAAAAABAAAD6AAAB9AAAAAJRmVlQ29uZmlnAAAA",
+        "AAAAAAAAAEhSZXRyaWV2ZXMgdGhlIHBsYXRmb3JtIGZlZXMgYWNjcnVlZCAoYW5kIG5vdCB5ZXQgd2l0aGRyYXduKSBmb3IgYSB0b2tlbi4AAAASZ2V0X2NvbGxlY3RlZF9mZWVzAAAAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAACw==",
+        "AAAAAQAAAAAAAAAAAAAABkVzY3JvdwAAAAAADAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAAD2J1eWVyX2NvbmZpcm1lZAAAAAABAAAAAAAAAA5kaXNwdXRlX3JlYXNvbgAAAAAD6AAAABAAAAAAAAAACmV4dGVuc2lvbnMAAAAAA+oAAAfQAAAAEFJlbGVhc2VFeHRlbnNpb24AAAAAAAAAAmlkAAAAAAAGAAAAAAAAAAVwYXllcgAAAAAAABMAAAAAAAAADXBheW1lbnRfdG9rZW4AAAAAAAATAAAAAAAAABFyZWxlYXNlX3RpbWVzdGFtcAAAAAAAAAYAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAATc2VsbGVyX2Fja25vd2xlZGdlZAAAAAABAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAMRXNjcm93U3RhdHVz",
+        "AAAAAgAAAAAAAAAAAAAADEVzY3Jvd1N0YXR1cwAAAAUAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAAAAAAAAAAACFJlbGVhc2VkAAAAAAAAAAAAAAAIUmVmdW5kZWQAAAAAAAAAAAAAAAhEaXNwdXRlZAAAAAAAAAAAAAAACUNhbmNlbGxlZAAAAA==",
+        "AAAAAQAAAEpBIHBlbmRpbmcgcmVxdWVzdCB0byBtb3ZlIHRoZSByZWxlYXNlIHRpbWVzdGFtcCwgYXdhaXRpbmcgdGhlIG90aGVyIHBhcnR5LgAAAAAAAAAAABFFeHRlbnNpb25Qcm9wb3NhbAAAAAAAAAIAAAAAAAAAFW5ld19yZWxlYXNlX3RpbWVzdGFtcAAAAAAAAAYAAAAAAAAACHByb3Bvc2VyAAAAEw==",
+        "AAAAAQAAADBBIHJlbGVhc2UtdGltZSBleHRlbnNpb24gYm90aCBwYXJ0aWVzIGFncmVlZCBvbi4AAAAAAAAAEFJlbGVhc2VFeHRlbnNpb24AAAAEAAAAAAAAAAthY2NlcHRlZF9hdAAAAAAGAAAAAAAAABVuZXdfcmVsZWFzZV90aW1lc3RhbXAAAAAAAAAGAAAAAAAAABpwcmV2aW91c19yZWxlYXNlX3RpbWVzdGFtcAAAAAAABgAAAAAAAAAIcHJvcG9zZXIAAAAT",
+        "AAAAAQAAAC1QbGF0Zm9ybSBmZWUgY2hhcmdlZCBvbiBldmVyeSBzZXR0bGVkIGVzY3Jvdy4AAAAAAAAAAAAACUZlZUNvbmZpZwAAAAAAAAIAAAAAAAAAB2ZlZV9icHMAAAAABAAAAAAAAAAIdHJlYXN1cnkAAAAT",
+        "AAAAAQAAAEdNLW9mLU4gc2lnbmVyIHNldCB0aGF0IHRha2VzIG92ZXIgc2Vuc2l0aXZlIGFkbWluIGFjdGlvbnMgb25jZSBlbmFibGVkLgAAAAAAAAAAEEdvdmVybmFuY2VDb25maWcAAAAEAAAAAAAAAAxwcm9wb3NhbF90dGwAAAAGAAAAAAAAABBydWxpbmdfdGhyZXNob2xkAAAACwAAAAAAAAAHc2lnbmVycwAAAAPqAAAAEwAAAAAAAAAJdGhyZXNob2xkAAAAAAAABA==",
+        "AAAAAgAAAEFTZW5zaXRpdmUgYWN0aW9ucyB0aGF0IHJ1biB0aHJvdWdoIGdvdmVybmFuY2Ugb25jZSBpdCBpcyBlbmFibGVkLgAAAAAAAAAAAAAQR292ZXJuYW5jZUFjdGlvbgAAAAgAAAABAAAAAAAAAAZTZXRGZWUAAAAAAAIAAAAEAAAAEwAAAAEAAAAAAAAAD1NldEtlZXBlclJld2FyZAAAAAABAAAABAAAAAEAAAAAAAAAB1VwZ3JhZGUAAAAAAQAAA+4AAAAgAAAAAQAAAAAAAAAMR3JhbnRBcmJpdGVyAAAAAQAAABMAAAABAAAAAAAAAA1SZXZva2VBcmJpdGVyAAAAAAAAAQAAABMAAAABAAAAAAAAAA5SZXNvbHZlRGlzcHV0ZQAAAAAAAQAAAAYAAAABAAAAAAAAABBVcGRhdGVHb3Zlcm5hbmNlAAAAAQAAB9AAAAAQR292ZXJuYW5jZUNvbmZpZwAAAAAAAAAAAAAAEURpc2FibGVHb3Zlcm5hbmNlAAAA",
+        "AAAAAQAAAC9BIGdvdmVybmFuY2UgYWN0aW9uIGdhdGhlcmluZyBzaWduZXIgYXBwcm92YWxzLgAAAAAAAAAAEkdvdmVybmFuY2VQcm9wb3NhbAAAAAAABgAAAAAAAAAGYWN0aW9uAAAAAAfQAAAAEEdvdmVybmFuY2VBY3Rpb24AAAAAAAAACWFwcHJvdmFscwAAAAAAA+oAAAATAAAAAAAAAAhleGVjdXRlZAAAAAEAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAAAmlkAAAAAAAGAAAAAAAAAAhwcm9wb3NlcgAAABM=",
+        "AAAABAAAAAAAAAAAAAAADUNvbnRyYWN0RXJyb3IAAAAAAAAlAAAAAAAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAAEAAAAAAAAADk5vdEluaXRpYWxpemVkAAAAAAAjAAAAAAAAAA5Db250cmFjdFBhdXNlZAAAAAAAFgAAAAAAAAAUUmVsZWFzZVRpbWVOb3RQYXNzZWQAAAACAAAAAAAAAA9Fc2Nyb3dOb3RBY3RpdmUAAAAAAwAAAAAAAAAVRXNjcm93QWxyZWFkeURpc3B1dGVkAAAAAAAABAAAAAAAAAARRXNjcm93Tm90RGlzcHV0ZWQAAAAAAAAFAAAAAAAAAAhOb3RBZG1pbgAAAAYAAAAAAAAACE5vdEJ1eWVyAAAABwAAAAAAAAALTWlzc2luZ1JvbGUAAAAAFwAAAAAAAAAPTm90UGVuZGluZ0FkbWluAAAAABgAAAAAAAAACU5vdFNlbGxlcgAAAAAAAA0AAAAAAAAADk5vdFBhcnRpY2lwYW50AAAAAAAPAAAAAAAAAAhOb3RQYXllcgAAABQAAAAAAAAAD0FscmVhZHlNaWdyYXRlZAAAAAAVAAAAAAAAAAtJbnZhbGlkUm9sZQAAAAAZAAAAAAAAAA5Fc2Nyb3dOb3RGb3VuZAAAAAAACAAAAAAAAAANSW52YWxpZEFtb3VudAAAAAAAAAkAAAAAAAAAEkludmFsaWRSZWxlYXNlVGltZQAAAAAACgAAAAAAAAAKSW52YWxpZEZlZQAAAAAACwAAAAAAAAAQSW5zdWZmaWNpZW50RmVlcwAAAAwAAAAAAAAAGUVzY3Jvd0FscmVhZHlBY2tub3dsZWRnZWQAAAAAAAAOAAAAAAAAABJOb1BlbmRpbmdFeHRlbnNpb24AAAAAABAAAAAAAAAAFUV4dGVuc2lvbkxpbWl0UmVhY2hlZAAAAAAAABEAAAAAAAAAGENhbm5vdEFjY2VwdE93bkV4dGVuc2lvbgAAABIAAAAAAAAAE0ludmFsaWRLZWVwZXJSZXdhcmQAAAAAEwAAAAAAAAAOQW1vdW50T3ZlcmZsb3cAAAAAACQAAAAAAAAAE0luc3VmZmljaWVudEJhbGFuY2UAAAAAJQAAAAAAAAASR292ZXJuYW5jZVJlcXVpcmVkAAAAAAAaAAAAAAAAABRHb3Zlcm5hbmNlTm90RW5hYmxlZAAAABsAAAAAAAAACU5vdFNpZ25lcgAAAAAAABwAAAAAAAAAEFByb3Bvc2FsTm90Rm91bmQAAAAdAAAAAAAAAA9Qcm9wb3NhbEV4cGlyZWQAAAAAHgAAAAAAAAAXUHJvcG9zYWxBbHJlYWR5QXBwcm92ZWQAAAAAHwAAAAAAAAAXUHJvcG9zYWxBbHJlYWR5RXhlY3V0ZWQAAAAAIAAAAAAAAAAPVGhyZXNob2xkTm90TWV0AAAAACEAAAAAAAAAF0ludmFsaWRHb3Zlcm5hbmNlQ29uZmlnAAAAACI=",
+        "AAAAAgAAADxBY2Nlc3MtY29udHJvbCByb2xlcy4gVGhlIGFkbWluIGltcGxpY2l0bHkgaG9sZHMgZXZlcnkgcm9sZS4AAAAAAAAABFJvbGUAAAAFAAAAAAAAAAAAAAAFQWRtaW4AAAAAAAAAAAAAAAAAAAdBcmJpdGVyAAAAAAAAAAAAAAAABlBhdXNlcgAAAAAAAAAAAAAAAAAKRmVlTWFuYWdlcgAAAAAAAAAAAAAAAAAIQXR0ZXN0ZXI=",
+      ]),
+      options
+    )
+  }
+}
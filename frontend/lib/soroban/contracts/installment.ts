@@ -0,0 +1,364 @@
+// Generated from the installment contract spec by `cargo run -p paystar-cli -- bindings`.
+// Do not edit by hand: regenerate it when the contract interface changes.
+
+import { Buffer } from "buffer"
+import { contract } from "@stellar/stellar-sdk"
+
+export type MethodOptions = {
+  fee?: number
+  timeoutInSeconds?: number
+  simulate?: boolean
+}
+
+export interface InstallmentAgreement {
+  amount_paid: bigint
+  arbitrator: string
+  buyer: string
+  deadline: bigint
+  description: string
+  fee_bps: number
+  id: bigint
+  is_accepted: boolean
+  is_canceled: boolean
+  is_finalized: boolean
+  paid_history: Array<PaidHistory>
+  seller: string
+  token: string
+  total_amount: bigint
+}
+
+export interface PaidHistory {
+  amount: bigint
+  timeline: bigint
+}
+
+export interface FeeConfig {
+  fee_bps: number
+  treasury: string
+}
+
+export interface GovernanceConfig {
+  proposal_ttl: bigint
+  signers: Array<string>
+  threshold: number
+}
+
+export type GovernanceAction =
+  | { tag: "SetFee"; values: readonly [number, string] }
+  | { tag: "Upgrade"; values: readonly [Buffer] }
+  | { tag: "GrantArbiter"; values: readonly [string] }
+  | { tag: "RevokeArbiter"; values: readonly [string] }
+  | { tag: "UpdateGovernance"; values: readonly [GovernanceConfig] }
+  | { tag: "DisableGovernance"; values: void }
+
+export interface GovernanceProposal {
+  action: GovernanceAction
+  approvals: Array<string>
+  executed: boolean
+  expires_at: bigint
+  id: bigint
+  proposer: string
+}
+
+/** Error codes of `ContractError`, keyed by code. */
+export const Errors = {
+  1: { message: "AlreadyInstantiated" },
+  2: { message: "InvalidAmount" },
+  3: { message: "DuplicateUsers" },
+  4: { message: "ArbitratorNotAllowed" },
+  5: { message: "InvalidTimestamp" },
+  6: { message: "InvalidAgreementId" },
+  7: { message: "NotAuthorized" },
+  8: { message: "AgreementNotFOund" },
+  9: { message: "InvalidFee" },
+  10: { message: "InsufficientFees" },
+  11: { message: "NotInitialized" },
+  12: { message: "AlreadyMigrated" },
+  13: { message: "ContractPaused" },
+  14: { message: "NotPendingAdmin" },
+  15: { message: "InvalidRole" },
+  16: { message: "GovernanceRequired" },
+  17: { message: "GovernanceNotEnabled" },
+  18: { message: "NotSigner" },
+  19: { message: "ProposalNotFound" },
+  20: { message: "ProposalExpired" },
+  21: { message: "ProposalAlreadyApproved" },
+  22: { message: "ProposalAlreadyExecuted" },
+  23: { message: "ThresholdNotMet" },
+  24: { message: "InvalidGovernanceConfig" },
+  25: { message: "AmountOverflow" },
+  26: { message: "InsufficientBalance" },
+}
+
+/** Access-control roles. The admin implicitly holds every role. */
+export type Role =
+  | { tag: "Admin"; values: void }
+  | { tag: "Arbiter"; values: void }
+  | { tag: "Pauser"; values: void }
+  | { tag: "FeeManager"; values: void }
+  | { tag: "Attester"; values: void }
+
+export interface Client {
+  initialize: (
+    { admin }: {
+      admin: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<string>>>
+
+  create_installment_agreement: (
+    { seller, buyer, amount, deadline, arbitrator, token, description }: {
+      seller: string
+      buyer: string
+      amount: bigint
+      deadline: bigint
+      arbitrator: string
+      token: string
+      description: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  pay_on_installment: (
+    { buyer_address, installment_amount, agreement_id }: {
+      buyer_address: string
+      installment_amount: bigint
+      agreement_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  finalize_agreement: (
+    { agreement_id, user }: {
+      agreement_id: bigint
+      user: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  accept_installment_agreement: (
+    { seller, accept_agreement, agreement_id }: {
+      seller: string
+      accept_agreement: boolean
+      agreement_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  cancel_and_refund_agreement: (
+    { address, agreement_id }: {
+      address: string
+      agreement_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  propose_admin: (
+    { admin, new_admin }: {
+      admin: string
+      new_admin: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  accept_admin: (
+    { new_admin }: {
+      new_admin: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  grant_role: (
+    { admin, role, address }: {
+      admin: string
+      role: Role
+      address: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  revoke_role: (
+    { admin, role, address }: {
+      admin: string
+      role: Role
+      address: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  get_admin: (options?: MethodOptions) => Promise<contract.AssembledTransaction<contract.Result<string>>>
+
+  get_pending_admin: (options?: MethodOptions) => Promise<contract.AssembledTransaction<string | undefined>>
+
+  has_role: (
+    { role, address }: {
+      role: Role
+      address: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<boolean>>
+
+  bump: (
+    { agreement_id }: {
+      agreement_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  pause: (
+    { pauser }: {
+      pauser: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  unpause: (
+    { pauser }: {
+      pauser: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  is_paused: (options?: MethodOptions) => Promise<contract.AssembledTransaction<boolean>>
+
+  set_fee: (
+    { fee_manager, fee_bps, treasury }: {
+      fee_manager: string
+      fee_bps: number
+      treasury: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  withdraw_fees: (
+    { fee_manager, token, amount }: {
+      fee_manager: string
+      token: string
+      amount: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  upgrade: (
+    { admin, new_wasm_hash }: {
+      admin: string
+      new_wasm_hash: Buffer
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  enable_governance: (
+    { admin, config }: {
+      admin: string
+      config: GovernanceConfig
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  propose_action: (
+    { signer, action }: {
+      signer: string
+      action: GovernanceAction
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<bigint>>>
+
+  approve_action: (
+    { signer, proposal_id }: {
+      signer: string
+      proposal_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  execute_action: (
+    { proposal_id }: {
+      proposal_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<boolean>>>
+
+  get_governance: (options?: MethodOptions) => Promise<contract.AssembledTransaction<GovernanceConfig | undefined>>
+
+  get_governance_proposal: (
+    { proposal_id }: {
+      proposal_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<GovernanceProposal | undefined>>
+
+  migrate: (
+    { admin, limit }: {
+      admin: string
+      limit: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<contract.Result<number>>>
+
+  get_schema_version: (options?: MethodOptions) => Promise<contract.AssembledTransaction<number>>
+
+  get_installment_agreement: (
+    { agreement_id }: {
+      agreement_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<InstallmentAgreement | undefined>>
+
+  get_fee_config: (options?: MethodOptions) => Promise<contract.AssembledTransaction<FeeConfig | undefined>>
+
+  get_collected_fees: (
+    { token }: {
+      token: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+}
+
+export class Client extends contract.Client {
+  constructor(public readonly options: contract.ClientOptions) {
+    super(
+      new contract.Spec([
+        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAA+kAAAATAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAAAAAAAcY3JlYXRlX2luc3RhbGxtZW50X2FncmVlbWVudAAAAAcAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAoAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAKYXJiaXRyYXRvcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAQAAA+kAAAABAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAAAAAAAScGF5X29uX2luc3RhbGxtZW50AAAAAAADAAAAAAAAAA1idXllcl9hZGRyZXNzAAAAAAAAEwAAAAAAAAASaW5zdGFsbG1lbnRfYW1vdW50AAAAAAAKAAAAAAAAAAxhZ3JlZW1lbnRfaWQAAAAKAAAAAQAAA+kAAAABAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAAAAAAASZmluYWxpemVfYWdyZWVtZW50AAAAAAACAAAAAAAAAAxhZ3JlZW1lbnRfaWQAAAAKAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAPpAAAAAQAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAAAAAAAcYWNjZXB0X2luc3RhbGxtZW50X2FncmVlbWVudAAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAQYWNjZXB0X2FncmVlbWVudAAAAAEAAAAAAAAADGFncmVlbWVudF9pZAAAAAoAAAABAAAD6QAAAAEAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAAAAAAAbY2FuY2VsX2FuZF9yZWZ1bmRfYWdyZWVtZW50AAAAAAIAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAAAAAAMYWdyZWVtZW50X2lkAAAACgAAAAEAAAPpAAAAAQAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAAAAAAANcHJvcG9zZV9hZG1pbgAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAEAAAPpAAAAAQAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAAAAAAAMYWNjZXB0X2FkbWluAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAEAAAPpAAAAAQAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAAAAAAAKZ3JhbnRfcm9sZQAAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhZGRyZXNzAAAAABMAAAABAAAD6QAAAAEAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAAAAAAALcmV2b2tlX3JvbGUAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhZGRyZXNzAAAAABMAAAABAAAD6QAAAAEAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAPpAAAAEwAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAAAAAAARZ2V0X3BlbmRpbmdfYWRtaW4AAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAAIaGFzX3JvbGUAAAACAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhZGRyZXNzAAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAAAAAAAEYnVtcAAAAAEAAAAAAAAADGFncmVlbWVudF9pZAAAAAoAAAABAAAD6QAAAAEAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAAAAAAAFcGF1c2UAAAAAAAABAAAAAAAAAAZwYXVzZXIAAAAAABMAAAABAAAD6QAAAAEAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAAAAAAAHdW5wYXVzZQAAAAABAAAAAAAAAAZwYXVzZXIAAAAAABMAAAABAAAD6QAAAAEAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAAAAAAAHc2V0X2ZlZQAAAAADAAAAAAAAAAtmZWVfbWFuYWdlcgAAAAATAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAAAAAAACHRyZWFzdXJ5AAAAEwAAAAEAAAPpAAAAAQAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAAAAAAANd2l0aGRyYXdfZmVlcwAAAAAAAAMAAAAAAAAAC2ZlZV9tYW5hZ2VyAAAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAAKAAAAAQAAA+kAAAABAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAPpAAAAAQAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAAAAAAARZW5hYmxlX2dvdmVybmFuY2UAAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABmNvbmZpZwAAAAAH0AAAABBHb3Zlcm5hbmNlQ29uZmlnAAAAAQAAA+kAAAABAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAAAAAAAOcHJvcG9zZV9hY3Rpb24AAAAAAAIAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAAEEdvdmVybmFuY2VBY3Rpb24AAAABAAAD6QAAAAoAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAAAAAAAOYXBwcm92ZV9hY3Rpb24AAAAAAAIAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAACgAAAAEAAAPpAAAAAQAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAAAAAAAOZXhlY3V0ZV9hY3Rpb24AAAAAAAEAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAoAAAABAAAD6QAAAAEAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAAAAAAAOZ2V0X2dvdmVybmFuY2UAAAAAAAAAAAABAAAD6AAAB9AAAAAQR292ZXJuYW5jZUNvbmZpZw==",
+        "AAAAAAAAAAAAAAAXZ2V0X2dvdmVybmFuY2VfcHJvcG9zYWwAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAACgAAAAEAAAPoAAAH0AAAABJHb3Zlcm5hbmNlUHJvcG9zYWwAAA==",
+        "AAAAAAAAAAAAAAAHbWlncmF0ZQAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPpAAAABAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAAAAAAASZ2V0X3NjaGVtYV92ZXJzaW9uAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAZZ2V0X2luc3RhbGxtZW50X2FncmVlbWVudAAAAAAAAAEAAAAAAAAADGFncmVlbWVudF9pZAAAAAoAAAABAAAD6AAAB9AAAAAUSW5zdGFsbG1lbnRBZ3JlZW1lbnQ=",
+        "AAAAAAAAAAAAAAAOZ2V0X2ZlZV9jb25maWcAAAAAAAAAAAABAAAD6AAAB9AAAAAJRmVlQ29uZmlnAAAA",
+        "AAAAAAAAAAAAAAASZ2V0X2NvbGxlY3RlZF9mZWVzAAAAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAACg==",
+        "AAAAAQAAAAAAAAAAAAAAFEluc3RhbGxtZW50QWdyZWVtZW50AAAADgAAAAAAAAALYW1vdW50X3BhaWQAAAAACgAAAAAAAAAKYXJiaXRyYXRvcgAAAAAAEwAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAB2ZlZV9icHMAAAAABAAAAAAAAAACaWQAAAAAAAoAAAAAAAAAC2lzX2FjY2VwdGVkAAAAAAEAAAAAAAAAC2lzX2NhbmNlbGVkAAAAAAEAAAAAAAAADGlzX2ZpbmFsaXplZAAAAAEAAAAAAAAADHBhaWRfaGlzdG9yeQAAA+oAAAfQAAAAC1BhaWRIaXN0b3J5AAAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAADHRvdGFsX2Ftb3VudAAAAAo=",
+        "AAAAAQAAAAAAAAAAAAAAC1BhaWRIaXN0b3J5AAAAAAIAAAAAAAAABmFtb3VudAAAAAAACgAAAAAAAAAIdGltZWxpbmUAAAAG",
+        "AAAAAQAAAAAAAAAAAAAACUZlZUNvbmZpZwAAAAAAAAIAAAAAAAAAB2ZlZV9icHMAAAAABAAAAAAAAAAIdHJlYXN1cnkAAAAT",
+        "AAAAAQAAAAAAAAAAAAAAEEdvdmVybmFuY2VDb25maWcAAAADAAAAAAAAAAxwcm9wb3NhbF90dGwAAAAGAAAAAAAAAAdzaWduZXJzAAAAA+oAAAATAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAAE",
+        "AAAAAgAAAAAAAAAAAAAAEEdvdmVybmFuY2VBY3Rpb24AAAAGAAAAAQAAAAAAAAAGU2V0RmVlAAAAAAACAAAABAAAABMAAAABAAAAAAAAAAdVcGdyYWRlAAAAAAEAAAPuAAAAIAAAAAEAAAAAAAAADEdyYW50QXJiaXRlcgAAAAEAAAATAAAAAQAAAAAAAAANUmV2b2tlQXJiaXRlcgAAAAAAAAEAAAATAAAAAQAAAAAAAAAQVXBkYXRlR292ZXJuYW5jZQAAAAEAAAfQAAAAEEdvdmVybmFuY2VDb25maWcAAAAAAAAAAAAAABFEaXNhYmxlR292ZXJuYW5jZQAAAA==",
+        "AAAAAQAAAAAAAAAAAAAAEkdvdmVybmFuY2VQcm9wb3NhbAAAAAAABgAAAAAAAAAGYWN0aW9uAAAAAAfQAAAAEEdvdmVybmFuY2VBY3Rpb24AAAAAAAAACWFwcHJvdmFscwAAAAAAA+oAAAATAAAAAAAAAAhleGVjdXRlZAAAAAEAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAAAmlkAAAAAAAKAAAAAAAAAAhwcm9wb3NlcgAAABM=",
+        "AAAABAAAAAAAAAAAAAAADUNvbnRyYWN0RXJyb3IAAAAAAAAaAAAAAAAAABNBbHJlYWR5SW5zdGFudGlhdGVkAAAAAAEAAAAAAAAADUludmFsaWRBbW91bnQAAAAAAAACAAAAAAAAAA5EdXBsaWNhdGVVc2VycwAAAAAAAwAAAAAAAAAUQXJiaXRyYXRvck5vdEFsbG93ZWQAAAAEAAAAAAAAABBJbnZhbGlkVGltZXN0YW1wAAAABQAAAAAAAAASSW52YWxpZEFncmVlbWVudElkAAAAAAAGAAAAAAAAAA1Ob3RBdXRob3JpemVkAAAAAAAABwAAAAAAAAARQWdyZWVtZW50Tm90Rk91bmQAAAAAAAAIAAAAAAAAAApJbnZhbGlkRmVlAAAAAAAJAAAAAAAAABBJbnN1ZmZpY2llbnRGZWVzAAAACgAAAAAAAAAOTm90SW5pdGlhbGl6ZWQAAAAAAAsAAAAAAAAAD0FscmVhZHlNaWdyYXRlZAAAAAAMAAAAAAAAAA5Db250cmFjdFBhdXNlZAAAAAAADQAAAAAAAAAPTm90UGVuZGluZ0FkbWluAAAAAA4AAAAAAAAAC0ludmFsaWRSb2xlAAAAAA8AAAAAAAAAEkdvdmVybmFuY2VSZXF1aXJlZAAAAAAAEAAAAAAAAAAUR292ZXJuYW5jZU5vdEVuYWJsZWQAAAARAAAAAAAAAAlOb3RTaWduZXIAAAAAAAASAAAAAAAAABBQcm9wb3NhbE5vdEZvdW5kAAAAEwAAAAAAAAAPUHJvcG9zYWxFeHBpcmVkAAAAABQAAAAAAAAAF1Byb3Bvc2FsQWxyZWFkeUFwcHJvdmVkAAAAABUAAAAAAAAAF1Byb3Bvc2FsQWxyZWFkeUV4ZWN1dGVkAAAAABYAAAAAAAAAD1RocmVzaG9sZE5vdE1ldAAAAAAXAAAAAAAAABdJbnZhbGlkR292ZXJuYW5jZUNvbmZpZwAAAAAYAAAAAAAAAA5BbW91bnRPdmVyZmxvdwAAAAAAGQAAAAAAAAATSW5zdWZmaWNpZW50QmFsYW5jZQAAAAAa",
+        "AAAAAgAAADxBY2Nlc3MtY29udHJvbCByb2xlcy4gVGhlIGFkbWluIGltcGxpY2l0bHkgaG9sZHMgZXZlcnkgcm9sZS4AAAAAAAAABFJvbGUAAAAFAAAAAAAAAAAAAAAFQWRtaW4AAAAAAAAAAAAAAAAAAAdBcmJpdGVyAAAAAAAAAAAAAAAABlBhdXNlcgAAAAAAAAAAAAAAAAAKRmVlTWFuYWdlcgAAAAAAAAAAAAAAAAAIQXR0ZXN0ZXI=",
+      ]),
+      options
+    )
+  }
+}
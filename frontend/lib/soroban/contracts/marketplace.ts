@@ -0,0 +1,1139 @@
+// Generated from the marketplace contract spec by `cargo run -p paystar-cli -- bindings`.
+// Do not edit by hand: regenerate it when the contract interface changes.
+
+import { Buffer } from "buffer"
+import { contract } from "@stellar/stellar-sdk"
+
+export type MethodOptions = {
+  fee?: number
+  timeoutInSeconds?: number
+  simulate?: boolean
+}
+
+export interface Item {
+  category: number | undefined
+  id: number
+  image_url: string
+  name: string
+  price: bigint
+  quantity: number
+  seller: string
+  tags: Array<string>
+  token: string
+}
+
+/** A node of the category tree */
+export interface Category {
+  id: number
+  name: string
+  parent: number | undefined
+}
+
+/** A seller's public profile */
+export interface SellerProfile {
+  contact_hash: Buffer
+  display_name: string
+  metadata_uri: string
+  payout_address: string
+  registered_at: bigint
+  seller: string
+  verified_at: bigint | undefined
+  verified_by: string | undefined
+}
+
+/** Listings only verified sellers can offer */
+export interface VerificationPolicy {
+  categories: Array<number>
+  min_prices: Map<string, bigint>
+  min_reference_price: bigint | undefined
+}
+
+/** A purchase of one listing, priced as it was when the buyer paid */
+export interface Order {
+  buyer: string
+  created_at: bigint
+  deliver_by: bigint | undefined
+  fee: bigint
+  id: bigint
+  item_id: number
+  payment_token: string
+  qty: number
+  respond_by: bigint | undefined
+  return_by: bigint | undefined
+  return_reason: string | undefined
+  return_window: bigint
+  seller: string
+  seller_paid: boolean
+  ship_by: bigint
+  status: OrderStatus
+  total: bigint
+  tracking_hash: Buffer | undefined
+  unit_price: bigint
+}
+
+/** Where an order is in fulfillment */
+export type OrderStatus =
+  | { tag: "Paid"; values: void }
+  | { tag: "Shipped"; values: void }
+  | { tag: "Delivered"; values: void }
+  | { tag: "Refunded"; values: void }
+  | { tag: "ReturnRequested"; values: void }
+  | { tag: "ReturnAccepted"; values: void }
+  | { tag: "Disputed"; values: void }
+
+/** How long sellers have to ship an order and buyers to confirm its delivery, in seconds */
+export interface FulfillmentConfig {
+  delivery_window: bigint
+  ship_window: bigint
+}
+
+/** Descending price schedule of a listing */
+export interface DutchAuction {
+  curve: PriceCurve
+  end_time: bigint
+  floor_price: bigint
+  item_id: number
+  start_price: bigint
+  start_time: bigint
+}
+
+/** How the price moves from the start price to the floor price */
+export type PriceCurve =
+  | { tag: "Linear"; values: void }
+  | { tag: "Stepwise"; values: readonly [number] }
+
+/** Oracle used to convert reference prices, and the bounds its quotes must meet */
+export interface OracleConfig {
+  base: Asset
+  max_age: bigint
+  max_deviation_bps: number
+  oracle: string
+}
+
+/** A buyer's offer for units of an item */
+export interface Offer {
+  buyer: string
+  counter_price: bigint | undefined
+  escrowed: bigint
+  expires_at: bigint
+  id: bigint
+  item_id: number
+  order_id: bigint | undefined
+  payment_token: string
+  qty: number
+  seller: string
+  status: OfferStatus
+  unit_price: bigint
+}
+
+/** Where an offer is in the negotiation */
+export type OfferStatus =
+  | { tag: "Open"; values: void }
+  | { tag: "Countered"; values: void }
+  | { tag: "Accepted"; values: void }
+  | { tag: "Rejected"; values: void }
+  | { tag: "Withdrawn"; values: void }
+
+/** A single lot sold to the highest bidder */
+export interface Auction {
+  bid_count: number
+  end_time: bigint
+  highest_bid: bigint
+  highest_bidder: string | undefined
+  id: number
+  image_url: string
+  min_increment: bigint
+  name: string
+  payment_token: string
+  reserve_price: bigint
+  seller: string
+  status: AuctionStatus
+}
+
+/** Where an auction is in its lifecycle */
+export type AuctionStatus =
+  | { tag: "Open"; values: void }
+  | { tag: "Sold"; values: void }
+  | { tag: "Unsold"; values: void }
+
+/** Platform fee taken on every purchase */
+export interface FeeConfig {
+  fee_bps: number
+  treasury: string
+}
+
+/** Access-control roles. The admin implicitly holds every role. */
+export type Role =
+  | { tag: "Admin"; values: void }
+  | { tag: "Arbiter"; values: void }
+  | { tag: "Pauser"; values: void }
+  | { tag: "FeeManager"; values: void }
+  | { tag: "Attester"; values: void }
+
+export interface Client {
+  /** Sets the admin and the token purchases are paid in (one-time) */
+  initialize: (
+    { admin, payment_token }: {
+      admin: string
+      payment_token: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Seller lists a new item, priced in the marketplace's payment token */
+  list_item: (
+    { seller, name, price, quantity, image_url }: {
+      seller: string
+      name: string
+      price: bigint
+      quantity: number
+      image_url: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<number>>
+
+  /** Seller lists a new item priced in an accepted token, which becomes its primary token */
+  list_item_with_token: (
+    { seller, name, price, quantity, image_url, token }: {
+      seller: string
+      name: string
+      price: bigint
+      quantity: number
+      image_url: string
+      token: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<number>>
+
+  /** Retrieve all items */
+  get_items: (options?: MethodOptions) => Promise<contract.AssembledTransaction<Array<Item>>>
+
+  /**
+   * Buy item (qty units) in the listing's primary token. The payment is held until the order is delivered and its
+   * return window has closed, when the seller is paid the total price minus the
+   * platform fee. Returns the id of the order.
+   */
+  buy_item: (
+    { buyer, item_id, qty }: {
+      buyer: string
+      item_id: number
+      qty: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+
+  /**
+   * Buy item (qty units) paying in one of the accepted tokens the listing is priced in.
+   * Returns the id of the order.
+   */
+  buy_item_with_token: (
+    { buyer, item_id, qty, payment_token }: {
+      buyer: string
+      item_id: number
+      qty: number
+      payment_token: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+
+  /**
+   * Buy item (qty units) paying in an accepted token, spending at most `max_spend`.
+   * Required for listings priced in the oracle's base asset, whose price in the token
+   * can move before the purchase executes. Returns the id of the order.
+   */
+  buy_item_with_limit: (
+    { buyer, item_id, qty, payment_token, max_spend }: {
+      buyer: string
+      item_id: number
+      qty: number
+      payment_token: string
+      max_spend: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+
+  /**
+   * Seller prices a listing in an accepted token, alongside its primary token or, for
+   * the primary token itself, instead of its earlier price
+   */
+  set_item_price: (
+    { seller, item_id, token, price }: {
+      seller: string
+      item_id: number
+      token: string
+      price: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Seller stops accepting a token for a listing. Removing the primary token's price
+   * makes another of its prices primary; a listing keeps at least one.
+   */
+  remove_item_price: (
+    { seller, item_id, token }: {
+      seller: string
+      item_id: number
+      token: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Price of a listing in every token it accepts */
+  get_item_prices: (
+    { item_id }: {
+      item_id: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Map<string, bigint>>>
+
+  /** Admin adds a Stellar Asset Contract token to the ones purchases can be paid in */
+  allow_token: (
+    { admin, token }: {
+      admin: string
+      token: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Admin stops accepting a token for purchases */
+  disallow_token: (
+    { admin, token }: {
+      admin: string
+      token: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Tokens purchases can be paid in */
+  get_accepted_tokens: (options?: MethodOptions) => Promise<contract.AssembledTransaction<Array<string>>>
+
+  /**
+   * Admin sets the SEP-40 oracle reference prices are converted with, and how old and
+   * how far from the previous quote its prices may be. The oracle must quote in the
+   * configured base asset.
+   */
+  set_oracle: (
+    { admin, config }: {
+      admin: string
+      config: OracleConfig
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve the oracle configuration, if one is set */
+  get_oracle_config: (options?: MethodOptions) => Promise<contract.AssembledTransaction<OracleConfig | undefined>>
+
+  /**
+   * Seller prices a listing in the oracle's base asset, with 7 decimals, or clears that
+   * price with `None`. Buyers pay the converted amount in any accepted token.
+   */
+  set_reference_price: (
+    { seller, item_id, price }: {
+      seller: string
+      item_id: number
+      price: bigint | undefined
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve the reference price of a listing, if it has one */
+  get_reference_price: (
+    { item_id }: {
+      item_id: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint | undefined>>
+
+  /**
+   * Seller creates or updates their public profile. Sales are paid out to `payout_address`.
+   * Changing the display name or payout address of a verified seller drops the badge.
+   */
+  register_seller: (
+    { seller, display_name, metadata_uri, contact_hash, payout_address }: {
+      seller: string
+      display_name: string
+      metadata_uri: string
+      contact_hash: Buffer
+      payout_address: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve a seller's profile, if they registered one */
+  get_seller_profile: (
+    { seller }: {
+      seller: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<SellerProfile | undefined>>
+
+  /** Attester vouches for a registered seller */
+  verify_seller: (
+    { attester, seller }: {
+      attester: string
+      seller: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Attester takes a seller's badge away */
+  revoke_seller_verification: (
+    { attester, seller }: {
+      attester: string
+      seller: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Whether a seller holds the verified badge */
+  is_verified_seller: (
+    { seller }: {
+      seller: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<boolean>>
+
+  /**
+   * Admin reserves categories, subcategories included, and prices for verified sellers:
+   * from `min_prices` up in each token listed there, and from `min_reference_price` up
+   * in the oracle's base asset. Applies to existing listings from their next sale.
+   */
+  set_verification_policy: (
+    { admin, policy }: {
+      admin: string
+      policy: VerificationPolicy
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve the listings reserved for verified sellers */
+  get_verification_policy: (options?: MethodOptions) => Promise<contract.AssembledTransaction<VerificationPolicy>>
+
+  /** Admin adds a category under `parent`, or at the top level. Returns its id. */
+  add_category: (
+    { admin, name, parent }: {
+      admin: string
+      name: string
+      parent: number | undefined
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<number>>
+
+  /** Admin renames a category */
+  rename_category: (
+    { admin, category_id, name }: {
+      admin: string
+      category_id: number
+      name: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Admin removes a category that has no subcategories and no listings */
+  remove_category: (
+    { admin, category_id }: {
+      admin: string
+      category_id: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve a category */
+  get_category: (
+    { category_id }: {
+      category_id: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Category | undefined>>
+
+  /** Retrieve every category; `parent` links them into a tree */
+  get_categories: (options?: MethodOptions) => Promise<contract.AssembledTransaction<Array<Category>>>
+
+  /** Retrieve the direct subcategories of `parent`, or the top-level categories for `None` */
+  get_subcategories: (
+    { parent }: {
+      parent: number | undefined
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Array<Category>>>
+
+  /** Seller moves a listing into a category, or out of every category with `None` */
+  set_item_category: (
+    { seller, item_id, category_id }: {
+      seller: string
+      item_id: number
+      category_id: number | undefined
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Seller replaces the tags of a listing */
+  set_item_tags: (
+    { seller, item_id, tags }: {
+      seller: string
+      item_id: number
+      tags: Array<string>
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Up to `limit` listings in a category and its subcategories from the `start`-th one,
+   * ordered by category and then by when they joined it. A page holds at most
+   * `MAX_PAGE_SIZE` listings.
+   */
+  get_items_in_category: (
+    { category_id, start, limit }: {
+      category_id: number
+      start: number
+      limit: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Array<Item>>>
+
+  /** Number of listings in a category and its subcategories */
+  get_category_item_count: (
+    { category_id }: {
+      category_id: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<number>>
+
+  /**
+   * Seller puts a listing on a Dutch auction: its price falls from `start_price` to
+   * `floor_price` between `start_time` and `end_time`, continuously or in equal steps.
+   * Buyers pay the price at the time of purchase. Replaces any earlier schedule.
+   */
+  set_dutch_auction: (
+    { seller, auction }: {
+      seller: string
+      auction: DutchAuction
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve the price schedule of a listing on a Dutch auction */
+  get_dutch_auction: (
+    { item_id }: {
+      item_id: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<DutchAuction | undefined>>
+
+  /**
+   * Unit price of a listing in its primary token at `timestamp`, following its Dutch
+   * auction if it has one
+   */
+  get_price_at: (
+    { item_id, timestamp }: {
+      item_id: number
+      timestamp: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+
+  /**
+   * Seller opens an English auction for a single lot, ending at `end_time`. Bids are
+   * locked in the contract; a winning bid must meet the reserve price. Returns the id of
+   * the auction.
+   */
+  create_auction: (
+    { seller, name, image_url, reserve_price, min_increment, end_time }: {
+      seller: string
+      name: string
+      image_url: string
+      reserve_price: bigint
+      min_increment: bigint
+      end_time: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<number>>
+
+  /**
+   * Bidder outbids the highest bid by at least the auction's increment, locking the bid
+   * and refunding the previous bidder. Bids near the end extend the auction.
+   */
+  place_bid: (
+    { bidder, auction_id, amount }: {
+      bidder: string
+      auction_id: number
+      amount: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Once the auction has ended, sells the lot to the highest bidder as an order that is
+   * shipped, delivered and paid out like any purchase. Refunds a highest bid under the
+   * reserve, or one the seller may no longer accept under the seller policy. Permissionless.
+   */
+  settle_auction: (
+    { auction_id }: {
+      auction_id: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve an auction */
+  get_auction: (
+    { auction_id }: {
+      auction_id: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Auction | undefined>>
+
+  /**
+   * Up to `limit` auctions from the `start`-th one, oldest first. A page holds at most
+   * `MAX_PAGE_SIZE` auctions.
+   */
+  get_auctions: (
+    { start, limit }: {
+      start: number
+      limit: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Array<Auction>>>
+
+  /** Number of auctions ever created */
+  get_auction_count: (options?: MethodOptions) => Promise<contract.AssembledTransaction<number>>
+
+  /**
+   * Buyer offers `unit_price` per unit for `qty` units, below the current price. The
+   * total is escrowed until the offer is accepted, rejected or withdrawn; nothing can be
+   * accepted after `expires_at`. Returns the id of the offer.
+   */
+  make_offer: (
+    { buyer, item_id, qty, unit_price, expires_at }: {
+      buyer: string
+      item_id: number
+      qty: number
+      unit_price: bigint
+      expires_at: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+
+  /**
+   * Seller accepts an open offer; the units are bought at the offered price.
+   * Returns the id of the order.
+   */
+  accept_offer: (
+    { seller, offer_id }: {
+      seller: string
+      offer_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+
+  /** Seller counters an open offer with a higher price per unit */
+  counter_offer: (
+    { seller, offer_id, counter_price }: {
+      seller: string
+      offer_id: bigint
+      counter_price: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Buyer accepts a counter-offer, paying the difference; the units are bought at the
+   * countered price. Returns the id of the order.
+   */
+  accept_counter_offer: (
+    { buyer, offer_id }: {
+      buyer: string
+      offer_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+
+  /** Seller rejects an offer or retracts a counter-offer; the buyer is refunded */
+  reject_offer: (
+    { seller, offer_id }: {
+      seller: string
+      offer_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Buyer withdraws an offer that was not accepted and is refunded */
+  withdraw_offer: (
+    { buyer, offer_id }: {
+      buyer: string
+      offer_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve an offer */
+  get_offer: (
+    { offer_id }: {
+      offer_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Offer | undefined>>
+
+  /**
+   * Up to `limit` offers made on an item from the `start`-th one, oldest first. A page
+   * holds at most `MAX_PAGE_SIZE` offers.
+   */
+  get_item_offers: (
+    { item_id, start, limit }: {
+      item_id: number
+      start: number
+      limit: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Array<Offer>>>
+
+  /** Number of offers made on an item */
+  get_item_offer_count: (
+    { item_id }: {
+      item_id: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<number>>
+
+  /** Retrieve an order */
+  get_order: (
+    { order_id }: {
+      order_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Order | undefined>>
+
+  /**
+   * Up to `limit` orders placed by a buyer from the `start`-th one, oldest first. A page
+   * holds at most `MAX_PAGE_SIZE` orders.
+   */
+  get_buyer_orders: (
+    { buyer, start, limit }: {
+      buyer: string
+      start: number
+      limit: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Array<Order>>>
+
+  /** Number of orders placed by a buyer */
+  get_buyer_order_count: (
+    { buyer }: {
+      buyer: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<number>>
+
+  /**
+   * Up to `limit` orders received by a seller from the `start`-th one, oldest first. A
+   * page holds at most `MAX_PAGE_SIZE` orders.
+   */
+  get_seller_orders: (
+    { seller, start, limit }: {
+      seller: string
+      start: number
+      limit: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<Array<Order>>>
+
+  /** Number of orders received by a seller */
+  get_seller_order_count: (
+    { seller }: {
+      seller: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<number>>
+
+  /**
+   * Seller marks a paid order as shipped before its shipping deadline, with a hash of
+   * the tracking details. Starts the buyer's delivery window.
+   */
+  mark_shipped: (
+    { seller, order_id, tracking_hash }: {
+      seller: string
+      order_id: bigint
+      tracking_hash: Buffer
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Buyer confirms a shipped order arrived. The seller is paid, or, if the listing accepts
+   * returns, the buyer's return window starts.
+   */
+  confirm_delivery: (
+    { buyer, order_id }: {
+      buyer: string
+      order_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Moves a held payment along once its deadline has passed: a shipped order counts as
+   * delivered after its delivery window, and a delivered order pays the seller after its
+   * return window. Permissionless.
+   */
+  release_order: (
+    { order_id }: {
+      order_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Buyer reclaims the payment of an order that was not shipped by its deadline;
+   * the units go back into stock
+   */
+  reclaim_order: (
+    { buyer, order_id }: {
+      buyer: string
+      order_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Seller sets how long buyers of a listing can request a return after delivery, in
+   * seconds; zero disables returns. Applies to orders placed afterwards.
+   */
+  set_return_window: (
+    { seller, item_id, return_window }: {
+      seller: string
+      item_id: number
+      return_window: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve the return window of a listing */
+  get_return_window: (
+    { item_id }: {
+      item_id: number
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+
+  /** Buyer requests a return of a delivered order within its return window */
+  request_return: (
+    { buyer, order_id, reason }: {
+      buyer: string
+      order_id: bigint
+      reason: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Seller accepts a requested return; the buyer sends the item back */
+  accept_return: (
+    { seller, order_id }: {
+      seller: string
+      order_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Seller confirms the returned item arrived; the buyer is refunded and the units go
+   * back into stock
+   */
+  confirm_return_received: (
+    { seller, order_id }: {
+      seller: string
+      order_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Seller rejects a requested return, escalating it to a dispute */
+  reject_return: (
+    { seller, order_id }: {
+      seller: string
+      order_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Buyer escalates a return to a dispute once the seller has missed the deadline to
+   * answer it (`RESPONSE_WINDOW`) or to confirm the item came back (`RECEIPT_WINDOW`)
+   */
+  escalate_return: (
+    { buyer, order_id }: {
+      buyer: string
+      order_id: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Arbiter resolves a disputed return by refunding the buyer or paying the seller */
+  resolve_return: (
+    { arbiter, order_id, refund_buyer }: {
+      arbiter: string
+      order_id: bigint
+      refund_buyer: boolean
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Admin sets how long sellers have to ship and buyers to confirm delivery, in seconds.
+   * Applies to orders placed or shipped afterwards.
+   */
+  set_fulfillment_windows: (
+    { admin, ship_window, delivery_window }: {
+      admin: string
+      ship_window: bigint
+      delivery_window: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve the shipping and delivery windows */
+  get_fulfillment_config: (options?: MethodOptions) => Promise<contract.AssembledTransaction<FulfillmentConfig>>
+
+  /** Admin proposes a new admin; nothing changes until the new admin accepts */
+  propose_admin: (
+    { admin, new_admin }: {
+      admin: string
+      new_admin: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** The proposed admin accepts the handover */
+  accept_admin: (
+    { new_admin }: {
+      new_admin: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Admin grants the Arbiter, Pauser, FeeManager or Attester role */
+  grant_role: (
+    { admin, role, account }: {
+      admin: string
+      role: Role
+      account: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Admin revokes a previously granted role */
+  revoke_role: (
+    { admin, role, account }: {
+      admin: string
+      role: Role
+      account: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Retrieve the current admin */
+  get_admin: (options?: MethodOptions) => Promise<contract.AssembledTransaction<string>>
+
+  /** Retrieve the admin awaiting acceptance, if any */
+  get_pending_admin: (options?: MethodOptions) => Promise<contract.AssembledTransaction<string | undefined>>
+
+  /** Whether an account holds a role; the admin holds every role */
+  has_role: (
+    { role, account }: {
+      role: Role
+      account: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<boolean>>
+
+  /** Pauser pauses the marketplace during an incident: no new listings or purchases */
+  pause: (
+    { pauser }: {
+      pauser: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Pauser lifts a pause */
+  unpause: (
+    { pauser }: {
+      pauser: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Whether the marketplace is paused */
+  is_paused: (options?: MethodOptions) => Promise<contract.AssembledTransaction<boolean>>
+
+  /** Fee manager sets the platform fee (in basis points) and the treasury that receives it */
+  set_fee: (
+    { fee_manager, fee_bps, treasury }: {
+      fee_manager: string
+      fee_bps: number
+      treasury: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Fee manager withdraws accrued fees of a token to the treasury */
+  withdraw_fees: (
+    { fee_manager, token, amount }: {
+      fee_manager: string
+      token: string
+      amount: bigint
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /** Admin replaces the contract WASM; stored items are kept */
+  upgrade: (
+    { admin, new_wasm_hash }: {
+      admin: string
+      new_wasm_hash: Buffer
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<void>>
+
+  /**
+   * Admin rewrites stored items into the current layout after an upgrade; items
+   * cannot be read, listed or changed until then.
+   * Version 0 wrote purchases to a separate persistent copy of the item map;
+   * those entries are merged back into the instance map, keeping the lowest
+   * quantity since purchases only ever decrease it. Items stored before version 2
+   * get no category and no tags. Every rewritten item is published as `mig_rec`, so
+   * indexers see its new layout. Returns the number of items merged back.
+   */
+  migrate: (
+    { admin }: {
+      admin: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<number>>
+
+  /** Layout version of the stored records; deployments that predate versioning are on 0 */
+  get_schema_version: (options?: MethodOptions) => Promise<contract.AssembledTransaction<number>>
+
+  /** Retrieve the platform fee configuration */
+  get_fee_config: (options?: MethodOptions) => Promise<contract.AssembledTransaction<FeeConfig | undefined>>
+
+  /** Fees accrued in a token and not yet withdrawn */
+  get_collected_fees: (
+    { token }: {
+      token: string
+    },
+    options?: MethodOptions
+  ) => Promise<contract.AssembledTransaction<bigint>>
+}
+
+export class Client extends contract.Client {
+  constructor(public readonly options: contract.ClientOptions) {
+    super(
+      new contract.Spec([
+        "AAAAAAAAAD1TZXRzIHRoZSBhZG1pbiBhbmQgdGhlIHRva2VuIHB1cmNoYXNlcyBhcmUgcGFpZCBpbiAob25lLXRpbWUpAAAAAAAACmluaXRpYWxpemUAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAA",
+        "AAAAAAAAAEJTZWxsZXIgbGlzdHMgYSBuZXcgaXRlbSwgcHJpY2VkIGluIHRoZSBtYXJrZXRwbGFjZSdzIHBheW1lbnQgdG9rZW4AAAAAAAlsaXN0X2l0ZW0AAAAAAAAFAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAACHF1YW50aXR5AAAABAAAAAAAAAAJaW1hZ2VfdXJsAAAAAAAAEAAAAAEAAAAE",
+        "AAAAAAAAAFRTZWxsZXIgbGlzdHMgYSBuZXcgaXRlbSBwcmljZWQgaW4gYW4gYWNjZXB0ZWQgdG9rZW4sIHdoaWNoIGJlY29tZXMgaXRzIHByaW1hcnkgdG9rZW4AAAAUbGlzdF9pdGVtX3dpdGhfdG9rZW4AAAAGAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAACHF1YW50aXR5AAAABAAAAAAAAAAJaW1hZ2VfdXJsAAAAAAAAEAAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAABJSZXRyaWV2ZSBhbGwgaXRlbXMAAAAAAAlnZXRfaXRlbXMAAAAAAAAAAAAAAQAAA+oAAAfQAAAABEl0ZW0=",
+        "AAAAAAAAAORCdXkgaXRlbSAocXR5IHVuaXRzKSBpbiB0aGUgbGlzdGluZydzIHByaW1hcnkgdG9rZW4uIFRoZSBwYXltZW50IGlzIGhlbGQgdW50aWwgdGhlIG9yZGVyIGlzIGRlbGl2ZXJlZCBhbmQgaXRzCnJldHVybiB3aW5kb3cgaGFzIGNsb3NlZCwgd2hlbiB0aGUgc2VsbGVyIGlzIHBhaWQgdGhlIHRvdGFsIHByaWNlIG1pbnVzIHRoZQpwbGF0Zm9ybSBmZWUuIFJldHVybnMgdGhlIGlkIG9mIHRoZSBvcmRlci4AAAAIYnV5X2l0ZW0AAAADAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAAB2l0ZW1faWQAAAAABAAAAAAAAAADcXR5AAAAAAQAAAABAAAABg==",
+        "AAAAAAAAAHBCdXkgaXRlbSAocXR5IHVuaXRzKSBwYXlpbmcgaW4gb25lIG9mIHRoZSBhY2NlcHRlZCB0b2tlbnMgdGhlIGxpc3RpbmcgaXMgcHJpY2VkIGluLgpSZXR1cm5zIHRoZSBpZCBvZiB0aGUgb3JkZXIuAAAAE2J1eV9pdGVtX3dpdGhfdG9rZW4AAAAABAAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAdpdGVtX2lkAAAAAAQAAAAAAAAAA3F0eQAAAAAEAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAEAAAAG",
+        "AAAAAAAAAOVCdXkgaXRlbSAocXR5IHVuaXRzKSBwYXlpbmcgaW4gYW4gYWNjZXB0ZWQgdG9rZW4sIHNwZW5kaW5nIGF0IG1vc3QgYG1heF9zcGVuZGAuClJlcXVpcmVkIGZvciBsaXN0aW5ncyBwcmljZWQgaW4gdGhlIG9yYWNsZSdzIGJhc2UgYXNzZXQsIHdob3NlIHByaWNlIGluIHRoZSB0b2tlbgpjYW4gbW92ZSBiZWZvcmUgdGhlIHB1cmNoYXNlIGV4ZWN1dGVzLiBSZXR1cm5zIHRoZSBpZCBvZiB0aGUgb3JkZXIuAAAAAAAAE2J1eV9pdGVtX3dpdGhfbGltaXQAAAAABQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAdpdGVtX2lkAAAAAAQAAAAAAAAAA3F0eQAAAAAEAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAAJbWF4X3NwZW5kAAAAAAAACwAAAAEAAAAG",
+        "AAAAAAAAAIhTZWxsZXIgcHJpY2VzIGEgbGlzdGluZyBpbiBhbiBhY2NlcHRlZCB0b2tlbiwgYWxvbmdzaWRlIGl0cyBwcmltYXJ5IHRva2VuIG9yLCBmb3IKdGhlIHByaW1hcnkgdG9rZW4gaXRzZWxmLCBpbnN0ZWFkIG9mIGl0cyBlYXJsaWVyIHByaWNlAAAADnNldF9pdGVtX3ByaWNlAAAAAAAEAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAAB2l0ZW1faWQAAAAABAAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAA",
+        "AAAAAAAAAJNTZWxsZXIgc3RvcHMgYWNjZXB0aW5nIGEgdG9rZW4gZm9yIGEgbGlzdGluZy4gUmVtb3ZpbmcgdGhlIHByaW1hcnkgdG9rZW4ncyBwcmljZQptYWtlcyBhbm90aGVyIG9mIGl0cyBwcmljZXMgcHJpbWFyeTsgYSBsaXN0aW5nIGtlZXBzIGF0IGxlYXN0IG9uZS4AAAAAEXJlbW92ZV9pdGVtX3ByaWNlAAAAAAAAAwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAdpdGVtX2lkAAAAAAQAAAAAAAAABXRva2VuAAAAAAAAEwAAAAA=",
+        "AAAAAAAAACxQcmljZSBvZiBhIGxpc3RpbmcgaW4gZXZlcnkgdG9rZW4gaXQgYWNjZXB0cwAAAA9nZXRfaXRlbV9wcmljZXMAAAAAAQAAAAAAAAAHaXRlbV9pZAAAAAAEAAAAAQAAA+wAAAATAAAACw==",
+        "AAAAAAAAAE5BZG1pbiBhZGRzIGEgU3RlbGxhciBBc3NldCBDb250cmFjdCB0b2tlbiB0byB0aGUgb25lcyBwdXJjaGFzZXMgY2FuIGJlIHBhaWQgaW4AAAAAAAthbGxvd190b2tlbgAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAA=",
+        "AAAAAAAAACtBZG1pbiBzdG9wcyBhY2NlcHRpbmcgYSB0b2tlbiBmb3IgcHVyY2hhc2VzAAAAAA5kaXNhbGxvd190b2tlbgAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAA",
+        "AAAAAAAAAB9Ub2tlbnMgcHVyY2hhc2VzIGNhbiBiZSBwYWlkIGluAAAAABNnZXRfYWNjZXB0ZWRfdG9rZW5zAAAAAAAAAAABAAAD6gAAABM=",
+        "AAAAAAAAALhBZG1pbiBzZXRzIHRoZSBTRVAtNDAgb3JhY2xlIHJlZmVyZW5jZSBwcmljZXMgYXJlIGNvbnZlcnRlZCB3aXRoLCBhbmQgaG93IG9sZCBhbmQKaG93IGZhciBmcm9tIHRoZSBwcmV2aW91cyBxdW90ZSBpdHMgcHJpY2VzIG1heSBiZS4gVGhlIG9yYWNsZSBtdXN0IHF1b3RlIGluIHRoZQpjb25maWd1cmVkIGJhc2UgYXNzZXQuAAAACnNldF9vcmFjbGUAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAfQAAAADE9yYWNsZUNvbmZpZwAAAAA=",
+        "AAAAAAAAADBSZXRyaWV2ZSB0aGUgb3JhY2xlIGNvbmZpZ3VyYXRpb24sIGlmIG9uZSBpcyBzZXQAAAARZ2V0X29yYWNsZV9jb25maWcAAAAAAAAAAAAAAQAAA+gAAAfQAAAADE9yYWNsZUNvbmZpZw==",
+        "AAAAAAAAAJ1TZWxsZXIgcHJpY2VzIGEgbGlzdGluZyBpbiB0aGUgb3JhY2xlJ3MgYmFzZSBhc3NldCwgd2l0aCA3IGRlY2ltYWxzLCBvciBjbGVhcnMgdGhhdApwcmljZSB3aXRoIGBOb25lYC4gQnV5ZXJzIHBheSB0aGUgY29udmVydGVkIGFtb3VudCBpbiBhbnkgYWNjZXB0ZWQgdG9rZW4uAAAAAAAAE3NldF9yZWZlcmVuY2VfcHJpY2UAAAAAAwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAdpdGVtX2lkAAAAAAQAAAAAAAAABXByaWNlAAAAAAAD6AAAAAsAAAAA",
+        "AAAAAAAAADhSZXRyaWV2ZSB0aGUgcmVmZXJlbmNlIHByaWNlIG9mIGEgbGlzdGluZywgaWYgaXQgaGFzIG9uZQAAABNnZXRfcmVmZXJlbmNlX3ByaWNlAAAAAAEAAAAAAAAAB2l0ZW1faWQAAAAABAAAAAEAAAPoAAAACw==",
+        "AAAAAAAAAKlTZWxsZXIgY3JlYXRlcyBvciB1cGRhdGVzIHRoZWlyIHB1YmxpYyBwcm9maWxlLiBTYWxlcyBhcmUgcGFpZCBvdXQgdG8gYHBheW91dF9hZGRyZXNzYC4KQ2hhbmdpbmcgdGhlIGRpc3BsYXkgbmFtZSBvciBwYXlvdXQgYWRkcmVzcyBvZiBhIHZlcmlmaWVkIHNlbGxlciBkcm9wcyB0aGUgYmFkZ2UuAAAAAAAAD3JlZ2lzdGVyX3NlbGxlcgAAAAAFAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAADGRpc3BsYXlfbmFtZQAAABAAAAAAAAAADG1ldGFkYXRhX3VyaQAAABAAAAAAAAAADGNvbnRhY3RfaGFzaAAAA+4AAAAgAAAAAAAAAA5wYXlvdXRfYWRkcmVzcwAAAAAAEwAAAAA=",
+        "AAAAAAAAADNSZXRyaWV2ZSBhIHNlbGxlcidzIHByb2ZpbGUsIGlmIHRoZXkgcmVnaXN0ZXJlZCBvbmUAAAAAEmdldF9zZWxsZXJfcHJvZmlsZQAAAAAAAQAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAQAAA+gAAAfQAAAADVNlbGxlclByb2ZpbGUAAAA=",
+        "AAAAAAAAAChBdHRlc3RlciB2b3VjaGVzIGZvciBhIHJlZ2lzdGVyZWQgc2VsbGVyAAAADXZlcmlmeV9zZWxsZXIAAAAAAAACAAAAAAAAAAhhdHRlc3RlcgAAABMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAA=",
+        "AAAAAAAAACRBdHRlc3RlciB0YWtlcyBhIHNlbGxlcidzIGJhZGdlIGF3YXkAAAAacmV2b2tlX3NlbGxlcl92ZXJpZmljYXRpb24AAAAAAAIAAAAAAAAACGF0dGVzdGVyAAAAEwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAA==",
+        "AAAAAAAAAClXaGV0aGVyIGEgc2VsbGVyIGhvbGRzIHRoZSB2ZXJpZmllZCBiYWRnZQAAAAAAABJpc192ZXJpZmllZF9zZWxsZXIAAAAAAAEAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAEAAAAB",
+        "AAAAAAAAAPVBZG1pbiByZXNlcnZlcyBjYXRlZ29yaWVzLCBzdWJjYXRlZ29yaWVzIGluY2x1ZGVkLCBhbmQgcHJpY2VzIGZvciB2ZXJpZmllZCBzZWxsZXJzOgpmcm9tIGBtaW5fcHJpY2VzYCB1cCBpbiBlYWNoIHRva2VuIGxpc3RlZCB0aGVyZSwgYW5kIGZyb20gYG1pbl9yZWZlcmVuY2VfcHJpY2VgIHVwCmluIHRoZSBvcmFjbGUncyBiYXNlIGFzc2V0LiBBcHBsaWVzIHRvIGV4aXN0aW5nIGxpc3RpbmdzIGZyb20gdGhlaXIgbmV4dCBzYWxlLgAAAAAAABdzZXRfdmVyaWZpY2F0aW9uX3BvbGljeQAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABnBvbGljeQAAAAAH0AAAABJWZXJpZmljYXRpb25Qb2xpY3kAAAAAAAA=",
+        "AAAAAAAAADNSZXRyaWV2ZSB0aGUgbGlzdGluZ3MgcmVzZXJ2ZWQgZm9yIHZlcmlmaWVkIHNlbGxlcnMAAAAAF2dldF92ZXJpZmljYXRpb25fcG9saWN5AAAAAAAAAAABAAAH0AAAABJWZXJpZmljYXRpb25Qb2xpY3kAAA==",
+        "AAAAAAAAAEpBZG1pbiBhZGRzIGEgY2F0ZWdvcnkgdW5kZXIgYHBhcmVudGAsIG9yIGF0IHRoZSB0b3AgbGV2ZWwuIFJldHVybnMgaXRzIGlkLgAAAAAADGFkZF9jYXRlZ29yeQAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnBhcmVudAAAAAAD6AAAAAQAAAABAAAABA==",
+        "AAAAAAAAABhBZG1pbiByZW5hbWVzIGEgY2F0ZWdvcnkAAAAPcmVuYW1lX2NhdGVnb3J5AAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAALY2F0ZWdvcnlfaWQAAAAABAAAAAAAAAAEbmFtZQAAABAAAAAA",
+        "AAAAAAAAAEJBZG1pbiByZW1vdmVzIGEgY2F0ZWdvcnkgdGhhdCBoYXMgbm8gc3ViY2F0ZWdvcmllcyBhbmQgbm8gbGlzdGluZ3MAAAAAAA9yZW1vdmVfY2F0ZWdvcnkAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAtjYXRlZ29yeV9pZAAAAAAEAAAAAA==",
+        "AAAAAAAAABNSZXRyaWV2ZSBhIGNhdGVnb3J5AAAAAAxnZXRfY2F0ZWdvcnkAAAABAAAAAAAAAAtjYXRlZ29yeV9pZAAAAAAEAAAAAQAAA+gAAAfQAAAACENhdGVnb3J5",
+        "AAAAAAAAADhSZXRyaWV2ZSBldmVyeSBjYXRlZ29yeTsgYHBhcmVudGAgbGlua3MgdGhlbSBpbnRvIGEgdHJlZQAAAA5nZXRfY2F0ZWdvcmllcwAAAAAAAAAAAAEAAAPqAAAH0AAAAAhDYXRlZ29yeQ==",
+        "AAAAAAAAAFVSZXRyaWV2ZSB0aGUgZGlyZWN0IHN1YmNhdGVnb3JpZXMgb2YgYHBhcmVudGAsIG9yIHRoZSB0b3AtbGV2ZWwgY2F0ZWdvcmllcyBmb3IgYE5vbmVgAAAAAAAAEWdldF9zdWJjYXRlZ29yaWVzAAAAAAAAAQAAAAAAAAAGcGFyZW50AAAAAAPoAAAABAAAAAEAAAPqAAAH0AAAAAhDYXRlZ29yeQ==",
+        "AAAAAAAAAExTZWxsZXIgbW92ZXMgYSBsaXN0aW5nIGludG8gYSBjYXRlZ29yeSwgb3Igb3V0IG9mIGV2ZXJ5IGNhdGVnb3J5IHdpdGggYE5vbmVgAAAAEXNldF9pdGVtX2NhdGVnb3J5AAAAAAAAAwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAdpdGVtX2lkAAAAAAQAAAAAAAAAC2NhdGVnb3J5X2lkAAAAA+gAAAAEAAAAAA==",
+        "AAAAAAAAACVTZWxsZXIgcmVwbGFjZXMgdGhlIHRhZ3Mgb2YgYSBsaXN0aW5nAAAAAAAADXNldF9pdGVtX3RhZ3MAAAAAAAADAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAAB2l0ZW1faWQAAAAABAAAAAAAAAAEdGFncwAAA+oAAAAQAAAAAA==",
+        "AAAAAAAAALdVcCB0byBgbGltaXRgIGxpc3RpbmdzIGluIGEgY2F0ZWdvcnkgYW5kIGl0cyBzdWJjYXRlZ29yaWVzIGZyb20gdGhlIGBzdGFydGAtdGggb25lLApvcmRlcmVkIGJ5IGNhdGVnb3J5IGFuZCB0aGVuIGJ5IHdoZW4gdGhleSBqb2luZWQgaXQuIEEgcGFnZSBob2xkcyBhdCBtb3N0CmBNQVhfUEFHRV9TSVpFYCBsaXN0aW5ncy4AAAAAFWdldF9pdGVtc19pbl9jYXRlZ29yeQAAAAAAAAMAAAAAAAAAC2NhdGVnb3J5X2lkAAAAAAQAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAABEl0ZW0=",
+        "AAAAAAAAADZOdW1iZXIgb2YgbGlzdGluZ3MgaW4gYSBjYXRlZ29yeSBhbmQgaXRzIHN1YmNhdGVnb3JpZXMAAAAAABdnZXRfY2F0ZWdvcnlfaXRlbV9jb3VudAAAAAABAAAAAAAAAAtjYXRlZ29yeV9pZAAAAAAEAAAAAQAAAAQ=",
+        "AAAAAAAAAO9TZWxsZXIgcHV0cyBhIGxpc3Rpbmcgb24gYSBEdXRjaCBhdWN0aW9uOiBpdHMgcHJpY2UgZmFsbHMgZnJvbSBgc3RhcnRfcHJpY2VgIHRvCmBmbG9vcl9wcmljZWAgYmV0d2VlbiBgc3RhcnRfdGltZWAgYW5kIGBlbmRfdGltZWAsIGNvbnRpbnVvdXNseSBvciBpbiBlcXVhbCBzdGVwcy4KQnV5ZXJzIHBheSB0aGUgcHJpY2UgYXQgdGhlIHRpbWUgb2YgcHVyY2hhc2UuIFJlcGxhY2VzIGFueSBlYXJsaWVyIHNjaGVkdWxlLgAAAAARc2V0X2R1dGNoX2F1Y3Rpb24AAAAAAAACAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAAB2F1Y3Rpb24AAAAH0AAAAAxEdXRjaEF1Y3Rpb24AAAAA",
+        "AAAAAAAAADtSZXRyaWV2ZSB0aGUgcHJpY2Ugc2NoZWR1bGUgb2YgYSBsaXN0aW5nIG9uIGEgRHV0Y2ggYXVjdGlvbgAAAAARZ2V0X2R1dGNoX2F1Y3Rpb24AAAAAAAABAAAAAAAAAAdpdGVtX2lkAAAAAAQAAAABAAAD6AAAB9AAAAAMRHV0Y2hBdWN0aW9u",
+        "AAAAAAAAAGZVbml0IHByaWNlIG9mIGEgbGlzdGluZyBpbiBpdHMgcHJpbWFyeSB0b2tlbiBhdCBgdGltZXN0YW1wYCwgZm9sbG93aW5nIGl0cyBEdXRjaAphdWN0aW9uIGlmIGl0IGhhcyBvbmUAAAAAAAxnZXRfcHJpY2VfYXQAAAACAAAAAAAAAAdpdGVtX2lkAAAAAAQAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAYAAAABAAAACw==",
+        "AAAAAAAAALJTZWxsZXIgb3BlbnMgYW4gRW5nbGlzaCBhdWN0aW9uIGZvciBhIHNpbmdsZSBsb3QsIGVuZGluZyBhdCBgZW5kX3RpbWVgLiBCaWRzIGFyZQpsb2NrZWQgaW4gdGhlIGNvbnRyYWN0OyBhIHdpbm5pbmcgYmlkIG11c3QgbWVldCB0aGUgcmVzZXJ2ZSBwcmljZS4gUmV0dXJucyB0aGUgaWQgb2YKdGhlIGF1Y3Rpb24uAAAAAAAOY3JlYXRlX2F1Y3Rpb24AAAAAAAYAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAACWltYWdlX3VybAAAAAAAABAAAAAAAAAADXJlc2VydmVfcHJpY2UAAAAAAAALAAAAAAAAAA1taW5faW5jcmVtZW50AAAAAAAACwAAAAAAAAAIZW5kX3RpbWUAAAAGAAAAAQAAAAQ=",
+        "AAAAAAAAAJxCaWRkZXIgb3V0YmlkcyB0aGUgaGlnaGVzdCBiaWQgYnkgYXQgbGVhc3QgdGhlIGF1Y3Rpb24ncyBpbmNyZW1lbnQsIGxvY2tpbmcgdGhlIGJpZAphbmQgcmVmdW5kaW5nIHRoZSBwcmV2aW91cyBiaWRkZXIuIEJpZHMgbmVhciB0aGUgZW5kIGV4dGVuZCB0aGUgYXVjdGlvbi4AAAAJcGxhY2VfYmlkAAAAAAAAAwAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAEAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAP9PbmNlIHRoZSBhdWN0aW9uIGhhcyBlbmRlZCwgc2VsbHMgdGhlIGxvdCB0byB0aGUgaGlnaGVzdCBiaWRkZXIgYXMgYW4gb3JkZXIgdGhhdCBpcwpzaGlwcGVkLCBkZWxpdmVyZWQgYW5kIHBhaWQgb3V0IGxpa2UgYW55IHB1cmNoYXNlLiBSZWZ1bmRzIGEgaGlnaGVzdCBiaWQgdW5kZXIgdGhlCnJlc2VydmUsIG9yIG9uZSB0aGUgc2VsbGVyIG1heSBubyBsb25nZXIgYWNjZXB0IHVuZGVyIHRoZSBzZWxsZXIgcG9saWN5LiBQZXJtaXNzaW9ubGVzcy4AAAAADnNldHRsZV9hdWN0aW9uAAAAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAEAAAAAA==",
+        "AAAAAAAAABNSZXRyaWV2ZSBhbiBhdWN0aW9uAAAAAAtnZXRfYXVjdGlvbgAAAAABAAAAAAAAAAphdWN0aW9uX2lkAAAAAAAEAAAAAQAAA+gAAAfQAAAAB0F1Y3Rpb24A",
+        "AAAAAAAAAGxVcCB0byBgbGltaXRgIGF1Y3Rpb25zIGZyb20gdGhlIGBzdGFydGAtdGggb25lLCBvbGRlc3QgZmlyc3QuIEEgcGFnZSBob2xkcyBhdCBtb3N0CmBNQVhfUEFHRV9TSVpFYCBhdWN0aW9ucy4AAAAMZ2V0X2F1Y3Rpb25zAAAAAgAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAHQXVjdGlvbgA=",
+        "AAAAAAAAAB9OdW1iZXIgb2YgYXVjdGlvbnMgZXZlciBjcmVhdGVkAAAAABFnZXRfYXVjdGlvbl9jb3VudAAAAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAN9CdXllciBvZmZlcnMgYHVuaXRfcHJpY2VgIHBlciB1bml0IGZvciBgcXR5YCB1bml0cywgYmVsb3cgdGhlIGN1cnJlbnQgcHJpY2UuIFRoZQp0b3RhbCBpcyBlc2Nyb3dlZCB1bnRpbCB0aGUgb2ZmZXIgaXMgYWNjZXB0ZWQsIHJlamVjdGVkIG9yIHdpdGhkcmF3bjsgbm90aGluZyBjYW4gYmUKYWNjZXB0ZWQgYWZ0ZXIgYGV4cGlyZXNfYXRgLiBSZXR1cm5zIHRoZSBpZCBvZiB0aGUgb2ZmZXIuAAAAAAptYWtlX29mZmVyAAAAAAAFAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAAB2l0ZW1faWQAAAAABAAAAAAAAAADcXR5AAAAAAQAAAAAAAAACnVuaXRfcHJpY2UAAAAAAAsAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAABAAAABg==",
+        "AAAAAAAAAGVTZWxsZXIgYWNjZXB0cyBhbiBvcGVuIG9mZmVyOyB0aGUgdW5pdHMgYXJlIGJvdWdodCBhdCB0aGUgb2ZmZXJlZCBwcmljZS4KUmV0dXJucyB0aGUgaWQgb2YgdGhlIG9yZGVyLgAAAAAAAAxhY2NlcHRfb2ZmZXIAAAACAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAACG9mZmVyX2lkAAAABgAAAAEAAAAG",
+        "AAAAAAAAADpTZWxsZXIgY291bnRlcnMgYW4gb3BlbiBvZmZlciB3aXRoIGEgaGlnaGVyIHByaWNlIHBlciB1bml0AAAAAAANY291bnRlcl9vZmZlcgAAAAAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAIb2ZmZXJfaWQAAAAGAAAAAAAAAA1jb3VudGVyX3ByaWNlAAAAAAAACwAAAAA=",
+        "AAAAAAAAAH9CdXllciBhY2NlcHRzIGEgY291bnRlci1vZmZlciwgcGF5aW5nIHRoZSBkaWZmZXJlbmNlOyB0aGUgdW5pdHMgYXJlIGJvdWdodCBhdCB0aGUKY291bnRlcmVkIHByaWNlLiBSZXR1cm5zIHRoZSBpZCBvZiB0aGUgb3JkZXIuAAAAABRhY2NlcHRfY291bnRlcl9vZmZlcgAAAAIAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAIb2ZmZXJfaWQAAAAGAAAAAQAAAAY=",
+        "AAAAAAAAAEpTZWxsZXIgcmVqZWN0cyBhbiBvZmZlciBvciByZXRyYWN0cyBhIGNvdW50ZXItb2ZmZXI7IHRoZSBidXllciBpcyByZWZ1bmRlZAAAAAAADHJlamVjdF9vZmZlcgAAAAIAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAIb2ZmZXJfaWQAAAAGAAAAAA==",
+        "AAAAAAAAAD5CdXllciB3aXRoZHJhd3MgYW4gb2ZmZXIgdGhhdCB3YXMgbm90IGFjY2VwdGVkIGFuZCBpcyByZWZ1bmRlZAAAAAAADndpdGhkcmF3X29mZmVyAAAAAAACAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAACG9mZmVyX2lkAAAABgAAAAA=",
+        "AAAAAAAAABFSZXRyaWV2ZSBhbiBvZmZlcgAAAAAAAAlnZXRfb2ZmZXIAAAAAAAABAAAAAAAAAAhvZmZlcl9pZAAAAAYAAAABAAAD6AAAB9AAAAAFT2ZmZXIAAAA=",
+        "AAAAAAAAAHhVcCB0byBgbGltaXRgIG9mZmVycyBtYWRlIG9uIGFuIGl0ZW0gZnJvbSB0aGUgYHN0YXJ0YC10aCBvbmUsIG9sZGVzdCBmaXJzdC4gQSBwYWdlCmhvbGRzIGF0IG1vc3QgYE1BWF9QQUdFX1NJWkVgIG9mZmVycy4AAAAPZ2V0X2l0ZW1fb2ZmZXJzAAAAAAMAAAAAAAAAB2l0ZW1faWQAAAAABAAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAFT2ZmZXIAAAA=",
+        "AAAAAAAAACBOdW1iZXIgb2Ygb2ZmZXJzIG1hZGUgb24gYW4gaXRlbQAAABRnZXRfaXRlbV9vZmZlcl9jb3VudAAAAAEAAAAAAAAAB2l0ZW1faWQAAAAABAAAAAEAAAAE",
+        "AAAAAAAAABFSZXRyaWV2ZSBhbiBvcmRlcgAAAAAAAAlnZXRfb3JkZXIAAAAAAAABAAAAAAAAAAhvcmRlcl9pZAAAAAYAAAABAAAD6AAAB9AAAAAFT3JkZXIAAAA=",
+        "AAAAAAAAAHpVcCB0byBgbGltaXRgIG9yZGVycyBwbGFjZWQgYnkgYSBidXllciBmcm9tIHRoZSBgc3RhcnRgLXRoIG9uZSwgb2xkZXN0IGZpcnN0LiBBIHBhZ2UKaG9sZHMgYXQgbW9zdCBgTUFYX1BBR0VfU0laRWAgb3JkZXJzLgAAAAAAEGdldF9idXllcl9vcmRlcnMAAAADAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAABU9yZGVyAAAA",
+        "AAAAAAAAACJOdW1iZXIgb2Ygb3JkZXJzIHBsYWNlZCBieSBhIGJ1eWVyAAAAAAAVZ2V0X2J1eWVyX29yZGVyX2NvdW50AAAAAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAAH1VcCB0byBgbGltaXRgIG9yZGVycyByZWNlaXZlZCBieSBhIHNlbGxlciBmcm9tIHRoZSBgc3RhcnRgLXRoIG9uZSwgb2xkZXN0IGZpcnN0LiBBCnBhZ2UgaG9sZHMgYXQgbW9zdCBgTUFYX1BBR0VfU0laRWAgb3JkZXJzLgAAAAAAABFnZXRfc2VsbGVyX29yZGVycwAAAAAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAFT3JkZXIAAAA=",
+        "AAAAAAAAACVOdW1iZXIgb2Ygb3JkZXJzIHJlY2VpdmVkIGJ5IGEgc2VsbGVyAAAAAAAAFmdldF9zZWxsZXJfb3JkZXJfY291bnQAAAAAAAEAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAAItTZWxsZXIgbWFya3MgYSBwYWlkIG9yZGVyIGFzIHNoaXBwZWQgYmVmb3JlIGl0cyBzaGlwcGluZyBkZWFkbGluZSwgd2l0aCBhIGhhc2ggb2YKdGhlIHRyYWNraW5nIGRldGFpbHMuIFN0YXJ0cyB0aGUgYnV5ZXIncyBkZWxpdmVyeSB3aW5kb3cuAAAAAAxtYXJrX3NoaXBwZWQAAAADAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAACG9yZGVyX2lkAAAABgAAAAAAAAANdHJhY2tpbmdfaGFzaAAAAAAAA+4AAAAgAAAAAA==",
+        "AAAAAAAAAIFCdXllciBjb25maXJtcyBhIHNoaXBwZWQgb3JkZXIgYXJyaXZlZC4gVGhlIHNlbGxlciBpcyBwYWlkLCBvciwgaWYgdGhlIGxpc3RpbmcgYWNjZXB0cwpyZXR1cm5zLCB0aGUgYnV5ZXIncyByZXR1cm4gd2luZG93IHN0YXJ0cy4AAAAAAAAQY29uZmlybV9kZWxpdmVyeQAAAAIAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAIb3JkZXJfaWQAAAAGAAAAAA==",
+        "AAAAAAAAAMZNb3ZlcyBhIGhlbGQgcGF5bWVudCBhbG9uZyBvbmNlIGl0cyBkZWFkbGluZSBoYXMgcGFzc2VkOiBhIHNoaXBwZWQgb3JkZXIgY291bnRzIGFzCmRlbGl2ZXJlZCBhZnRlciBpdHMgZGVsaXZlcnkgd2luZG93LCBhbmQgYSBkZWxpdmVyZWQgb3JkZXIgcGF5cyB0aGUgc2VsbGVyIGFmdGVyIGl0cwpyZXR1cm4gd2luZG93LiBQZXJtaXNzaW9ubGVzcy4AAAAAAA1yZWxlYXNlX29yZGVyAAAAAAAAAQAAAAAAAAAIb3JkZXJfaWQAAAAGAAAAAA==",
+        "AAAAAAAAAGlCdXllciByZWNsYWltcyB0aGUgcGF5bWVudCBvZiBhbiBvcmRlciB0aGF0IHdhcyBub3Qgc2hpcHBlZCBieSBpdHMgZGVhZGxpbmU7CnRoZSB1bml0cyBnbyBiYWNrIGludG8gc3RvY2sAAAAAAAANcmVjbGFpbV9vcmRlcgAAAAAAAAIAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAIb3JkZXJfaWQAAAAGAAAAAA==",
+        "AAAAAAAAAJVTZWxsZXIgc2V0cyBob3cgbG9uZyBidXllcnMgb2YgYSBsaXN0aW5nIGNhbiByZXF1ZXN0IGEgcmV0dXJuIGFmdGVyIGRlbGl2ZXJ5LCBpbgpzZWNvbmRzOyB6ZXJvIGRpc2FibGVzIHJldHVybnMuIEFwcGxpZXMgdG8gb3JkZXJzIHBsYWNlZCBhZnRlcndhcmRzLgAAAAAAABFzZXRfcmV0dXJuX3dpbmRvdwAAAAAAAAMAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAHaXRlbV9pZAAAAAAEAAAAAAAAAA1yZXR1cm5fd2luZG93AAAAAAAABgAAAAA=",
+        "AAAAAAAAACdSZXRyaWV2ZSB0aGUgcmV0dXJuIHdpbmRvdyBvZiBhIGxpc3RpbmcAAAAAEWdldF9yZXR1cm5fd2luZG93AAAAAAAAAQAAAAAAAAAHaXRlbV9pZAAAAAAEAAAAAQAAAAY=",
+        "AAAAAAAAAEVCdXllciByZXF1ZXN0cyBhIHJldHVybiBvZiBhIGRlbGl2ZXJlZCBvcmRlciB3aXRoaW4gaXRzIHJldHVybiB3aW5kb3cAAAAAAAAOcmVxdWVzdF9yZXR1cm4AAAAAAAMAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAIb3JkZXJfaWQAAAAGAAAAAAAAAAZyZWFzb24AAAAAABAAAAAA",
+        "AAAAAAAAAEBTZWxsZXIgYWNjZXB0cyBhIHJlcXVlc3RlZCByZXR1cm47IHRoZSBidXllciBzZW5kcyB0aGUgaXRlbSBiYWNrAAAADWFjY2VwdF9yZXR1cm4AAAAAAAACAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAACG9yZGVyX2lkAAAABgAAAAA=",
+        "AAAAAAAAAGFTZWxsZXIgY29uZmlybXMgdGhlIHJldHVybmVkIGl0ZW0gYXJyaXZlZDsgdGhlIGJ1eWVyIGlzIHJlZnVuZGVkIGFuZCB0aGUgdW5pdHMgZ28KYmFjayBpbnRvIHN0b2NrAAAAAAAAF2NvbmZpcm1fcmV0dXJuX3JlY2VpdmVkAAAAAAIAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAIb3JkZXJfaWQAAAAGAAAAAA==",
+        "AAAAAAAAAD1TZWxsZXIgcmVqZWN0cyBhIHJlcXVlc3RlZCByZXR1cm4sIGVzY2FsYXRpbmcgaXQgdG8gYSBkaXNwdXRlAAAAAAAADXJlamVjdF9yZXR1cm4AAAAAAAACAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAACG9yZGVyX2lkAAAABgAAAAA=",
+        "AAAAAAAAAKJCdXllciBlc2NhbGF0ZXMgYSByZXR1cm4gdG8gYSBkaXNwdXRlIG9uY2UgdGhlIHNlbGxlciBoYXMgbWlzc2VkIHRoZSBkZWFkbGluZSB0bwphbnN3ZXIgaXQgKGBSRVNQT05TRV9XSU5ET1dgKSBvciB0byBjb25maXJtIHRoZSBpdGVtIGNhbWUgYmFjayAoYFJFQ0VJUFRfV0lORE9XYCkAAAAAAA9lc2NhbGF0ZV9yZXR1cm4AAAAAAgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAhvcmRlcl9pZAAAAAYAAAAA",
+        "AAAAAAAAAE5BcmJpdGVyIHJlc29sdmVzIGEgZGlzcHV0ZWQgcmV0dXJuIGJ5IHJlZnVuZGluZyB0aGUgYnV5ZXIgb3IgcGF5aW5nIHRoZSBzZWxsZXIAAAAAAA5yZXNvbHZlX3JldHVybgAAAAAAAwAAAAAAAAAHYXJiaXRlcgAAAAATAAAAAAAAAAhvcmRlcl9pZAAAAAYAAAAAAAAADHJlZnVuZF9idXllcgAAAAEAAAAA",
+        "AAAAAAAAAIRBZG1pbiBzZXRzIGhvdyBsb25nIHNlbGxlcnMgaGF2ZSB0byBzaGlwIGFuZCBidXllcnMgdG8gY29uZmlybSBkZWxpdmVyeSwgaW4gc2Vjb25kcy4KQXBwbGllcyB0byBvcmRlcnMgcGxhY2VkIG9yIHNoaXBwZWQgYWZ0ZXJ3YXJkcy4AAAAXc2V0X2Z1bGZpbGxtZW50X3dpbmRvd3MAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAtzaGlwX3dpbmRvdwAAAAAGAAAAAAAAAA9kZWxpdmVyeV93aW5kb3cAAAAABgAAAAA=",
+        "AAAAAAAAACpSZXRyaWV2ZSB0aGUgc2hpcHBpbmcgYW5kIGRlbGl2ZXJ5IHdpbmRvd3MAAAAAABZnZXRfZnVsZmlsbG1lbnRfY29uZmlnAAAAAAAAAAAAAQAAB9AAAAARRnVsZmlsbG1lbnRDb25maWcAAAA=",
+        "AAAAAAAAAEdBZG1pbiBwcm9wb3NlcyBhIG5ldyBhZG1pbjsgbm90aGluZyBjaGFuZ2VzIHVudGlsIHRoZSBuZXcgYWRtaW4gYWNjZXB0cwAAAAANcHJvcG9zZV9hZG1pbgAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAACdUaGUgcHJvcG9zZWQgYWRtaW4gYWNjZXB0cyB0aGUgaGFuZG92ZXIAAAAADGFjY2VwdF9hZG1pbgAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
+        "AAAAAAAAAD1BZG1pbiBncmFudHMgdGhlIEFyYml0ZXIsIFBhdXNlciwgRmVlTWFuYWdlciBvciBBdHRlc3RlciByb2xlAAAAAAAACmdyYW50X3JvbGUAAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAA==",
+        "AAAAAAAAACdBZG1pbiByZXZva2VzIGEgcHJldmlvdXNseSBncmFudGVkIHJvbGUAAAAAC3Jldm9rZV9yb2xlAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAA==",
+        "AAAAAAAAABpSZXRyaWV2ZSB0aGUgY3VycmVudCBhZG1pbgAAAAAACWdldF9hZG1pbgAAAAAAAAAAAAABAAAAEw==",
+        "AAAAAAAAAC5SZXRyaWV2ZSB0aGUgYWRtaW4gYXdhaXRpbmcgYWNjZXB0YW5jZSwgaWYgYW55AAAAAAARZ2V0X3BlbmRpbmdfYWRtaW4AAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAADtXaGV0aGVyIGFuIGFjY291bnQgaG9sZHMgYSByb2xlOyB0aGUgYWRtaW4gaG9sZHMgZXZlcnkgcm9sZQAAAAAIaGFzX3JvbGUAAAACAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAE5QYXVzZXIgcGF1c2VzIHRoZSBtYXJrZXRwbGFjZSBkdXJpbmcgYW4gaW5jaWRlbnQ6IG5vIG5ldyBsaXN0aW5ncyBvciBwdXJjaGFzZXMAAAAAAAVwYXVzZQAAAAAAAAEAAAAAAAAABnBhdXNlcgAAAAAAEwAAAAA=",
+        "AAAAAAAAABRQYXVzZXIgbGlmdHMgYSBwYXVzZQAAAAd1bnBhdXNlAAAAAAEAAAAAAAAABnBhdXNlcgAAAAAAEwAAAAA=",
+        "AAAAAAAAACFXaGV0aGVyIHRoZSBtYXJrZXRwbGFjZSBpcyBwYXVzZWQAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAFVGZWUgbWFuYWdlciBzZXRzIHRoZSBwbGF0Zm9ybSBmZWUgKGluIGJhc2lzIHBvaW50cykgYW5kIHRoZSB0cmVhc3VyeSB0aGF0IHJlY2VpdmVzIGl0AAAAAAAAB3NldF9mZWUAAAAAAwAAAAAAAAALZmVlX21hbmFnZXIAAAAAEwAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAAAAAAh0cmVhc3VyeQAAABMAAAAA",
+        "AAAAAAAAAD1GZWUgbWFuYWdlciB3aXRoZHJhd3MgYWNjcnVlZCBmZWVzIG9mIGEgdG9rZW4gdG8gdGhlIHRyZWFzdXJ5AAAAAAAADXdpdGhkcmF3X2ZlZXMAAAAAAAADAAAAAAAAAAtmZWVfbWFuYWdlcgAAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAADdBZG1pbiByZXBsYWNlcyB0aGUgY29udHJhY3QgV0FTTTsgc3RvcmVkIGl0ZW1zIGFyZSBrZXB0AAAAAAd1cGdyYWRlAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAA==",
+        "AAAAAAAAAe5BZG1pbiByZXdyaXRlcyBzdG9yZWQgaXRlbXMgaW50byB0aGUgY3VycmVudCBsYXlvdXQgYWZ0ZXIgYW4gdXBncmFkZTsgaXRlbXMKY2Fubm90IGJlIHJlYWQsIGxpc3RlZCBvciBjaGFuZ2VkIHVudGlsIHRoZW4uClZlcnNpb24gMCB3cm90ZSBwdXJjaGFzZXMgdG8gYSBzZXBhcmF0ZSBwZXJzaXN0ZW50IGNvcHkgb2YgdGhlIGl0ZW0gbWFwOwp0aG9zZSBlbnRyaWVzIGFyZSBtZXJnZWQgYmFjayBpbnRvIHRoZSBpbnN0YW5jZSBtYXAsIGtlZXBpbmcgdGhlIGxvd2VzdApxdWFudGl0eSBzaW5jZSBwdXJjaGFzZXMgb25seSBldmVyIGRlY3JlYXNlIGl0LiBJdGVtcyBzdG9yZWQgYmVmb3JlIHZlcnNpb24gMgpnZXQgbm8gY2F0ZWdvcnkgYW5kIG5vIHRhZ3MuIEV2ZXJ5IHJld3JpdHRlbiBpdGVtIGlzIHB1Ymxpc2hlZCBhcyBgbWlnX3JlY2AsIHNvCmluZGV4ZXJzIHNlZSBpdHMgbmV3IGxheW91dC4gUmV0dXJucyB0aGUgbnVtYmVyIG9mIGl0ZW1zIG1lcmdlZCBiYWNrLgAAAAAAB21pZ3JhdGUAAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAAFJMYXlvdXQgdmVyc2lvbiBvZiB0aGUgc3RvcmVkIHJlY29yZHM7IGRlcGxveW1lbnRzIHRoYXQgcHJlZGF0ZSB2ZXJzaW9uaW5nIGFyZSBvbiAwAAAAAAASZ2V0X3NjaGVtYV92ZXJzaW9uAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAACdSZXRyaWV2ZSB0aGUgcGxhdGZvcm0gZmVlIGNvbmZpZ3VyYXRpb24AAAAADmdldF9mZWVfY29uZmlnAAAAAAAAAAAAAQAAA+gAAAfQAAAACUZlZUNvbmZpZwAAAA==",
+        "AAAAAAAAAC1GZWVzIGFjY3J1ZWQgaW4gYSB0b2tlbiBhbmQgbm90IHlldCB3aXRoZHJhd24AAAAAAAASZ2V0X2NvbGxlY3RlZF9mZWVzAAAAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAACw==",
+        "AAAAAQAAAAAAAAAAAAAABEl0ZW0AAAAJAAAAAAAAAAhjYXRlZ29yeQAAA+gAAAAEAAAAAAAAAAJpZAAAAAAABAAAAAAAAAAJaW1hZ2VfdXJsAAAAAAAAEAAAAAAAAAAEbmFtZQAAABAAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAIcXVhbnRpdHkAAAAEAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAABHRhZ3MAAAPqAAAAEAAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAQAAABtBIG5vZGUgb2YgdGhlIGNhdGVnb3J5IHRyZWUAAAAAAAAAAAhDYXRlZ29yeQAAAAMAAAAAAAAAAmlkAAAAAAAEAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAGcGFyZW50AAAAAAPoAAAABA==",
+        "AAAAAQAAABlBIHNlbGxlcidzIHB1YmxpYyBwcm9maWxlAAAAAAAAAAAAAA1TZWxsZXJQcm9maWxlAAAAAAAACAAAAAAAAAAMY29udGFjdF9oYXNoAAAD7gAAACAAAAAAAAAADGRpc3BsYXlfbmFtZQAAABAAAAAAAAAADG1ldGFkYXRhX3VyaQAAABAAAAAAAAAADnBheW91dF9hZGRyZXNzAAAAAAATAAAAAAAAAA1yZWdpc3RlcmVkX2F0AAAAAAAABgAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAt2ZXJpZmllZF9hdAAAAAPoAAAABgAAAAAAAAALdmVyaWZpZWRfYnkAAAAD6AAAABM=",
+        "AAAAAQAAAChMaXN0aW5ncyBvbmx5IHZlcmlmaWVkIHNlbGxlcnMgY2FuIG9mZmVyAAAAAAAAABJWZXJpZmljYXRpb25Qb2xpY3kAAAAAAAMAAAAAAAAACmNhdGVnb3JpZXMAAAAAA+oAAAAEAAAAAAAAAAptaW5fcHJpY2VzAAAAAAPsAAAAEwAAAAsAAAAAAAAAE21pbl9yZWZlcmVuY2VfcHJpY2UAAAAD6AAAAAs=",
+        "AAAAAQAAAD9BIHB1cmNoYXNlIG9mIG9uZSBsaXN0aW5nLCBwcmljZWQgYXMgaXQgd2FzIHdoZW4gdGhlIGJ1eWVyIHBhaWQAAAAAAAAAAAVPcmRlcgAAAAAAABMAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAKY3JlYXRlZF9hdAAAAAAABgAAAAAAAAAKZGVsaXZlcl9ieQAAAAAD6AAAAAYAAAAAAAAAA2ZlZQAAAAALAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAHaXRlbV9pZAAAAAAEAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAADcXR5AAAAAAQAAAAAAAAACnJlc3BvbmRfYnkAAAAAA+gAAAAGAAAAAAAAAAlyZXR1cm5fYnkAAAAAAAPoAAAABgAAAAAAAAANcmV0dXJuX3JlYXNvbgAAAAAAA+gAAAAQAAAAAAAAAA1yZXR1cm5fd2luZG93AAAAAAAABgAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAtzZWxsZXJfcGFpZAAAAAABAAAAAAAAAAdzaGlwX2J5AAAAAAYAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAtPcmRlclN0YXR1cwAAAAAAAAAABXRvdGFsAAAAAAAACwAAAAAAAAANdHJhY2tpbmdfaGFzaAAAAAAAA+gAAAPuAAAAIAAAAAAAAAAKdW5pdF9wcmljZQAAAAAACw==",
+        "AAAAAgAAACBXaGVyZSBhbiBvcmRlciBpcyBpbiBmdWxmaWxsbWVudAAAAAAAAAALT3JkZXJTdGF0dXMAAAAABwAAAAAAAAAAAAAABFBhaWQAAAAAAAAAAAAAAAdTaGlwcGVkAAAAAAAAAAAAAAAACURlbGl2ZXJlZAAAAAAAAAAAAAAAAAAACFJlZnVuZGVkAAAAAAAAAAAAAAAPUmV0dXJuUmVxdWVzdGVkAAAAAAAAAAAAAAAADlJldHVybkFjY2VwdGVkAAAAAAAAAAAAAAAAAAhEaXNwdXRlZA==",
+        "AAAAAQAAAFVIb3cgbG9uZyBzZWxsZXJzIGhhdmUgdG8gc2hpcCBhbiBvcmRlciBhbmQgYnV5ZXJzIHRvIGNvbmZpcm0gaXRzIGRlbGl2ZXJ5LCBpbiBzZWNvbmRzAAAAAAAAAAAAABFGdWxmaWxsbWVudENvbmZpZwAAAAAAAAIAAAAAAAAAD2RlbGl2ZXJ5X3dpbmRvdwAAAAAGAAAAAAAAAAtzaGlwX3dpbmRvdwAAAAAG",
+        "AAAAAQAAACZEZXNjZW5kaW5nIHByaWNlIHNjaGVkdWxlIG9mIGEgbGlzdGluZwAAAAAAAAAAAAxEdXRjaEF1Y3Rpb24AAAAGAAAAAAAAAAVjdXJ2ZQAAAAAAB9AAAAAKUHJpY2VDdXJ2ZQAAAAAAAAAAAAhlbmRfdGltZQAAAAYAAAAAAAAAC2Zsb29yX3ByaWNlAAAAAAsAAAAAAAAAB2l0ZW1faWQAAAAABAAAAAAAAAALc3RhcnRfcHJpY2UAAAAACwAAAAAAAAAKc3RhcnRfdGltZQAAAAAABg==",
+        "AAAAAgAAADtIb3cgdGhlIHByaWNlIG1vdmVzIGZyb20gdGhlIHN0YXJ0IHByaWNlIHRvIHRoZSBmbG9vciBwcmljZQAAAAAAAAAAClByaWNlQ3VydmUAAAAAAAIAAAAAAAAAAAAAAAZMaW5lYXIAAAAAAAEAAAAAAAAACFN0ZXB3aXNlAAAAAQAAAAQ=",
+        "AAAAAQAAAExPcmFjbGUgdXNlZCB0byBjb252ZXJ0IHJlZmVyZW5jZSBwcmljZXMsIGFuZCB0aGUgYm91bmRzIGl0cyBxdW90ZXMgbXVzdCBtZWV0AAAAAAAAAAxPcmFjbGVDb25maWcAAAAEAAAAAAAAAARiYXNlAAAH0AAAAAVBc3NldAAAAAAAAAAAAAAHbWF4X2FnZQAAAAAGAAAAAAAAABFtYXhfZGV2aWF0aW9uX2JwcwAAAAAAAAQAAAAAAAAABm9yYWNsZQAAAAAAEw==",
+        "AAAAAQAAACRBIGJ1eWVyJ3Mgb2ZmZXIgZm9yIHVuaXRzIG9mIGFuIGl0ZW0AAAAAAAAABU9mZmVyAAAAAAAADAAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAA1jb3VudGVyX3ByaWNlAAAAAAAD6AAAAAsAAAAAAAAACGVzY3Jvd2VkAAAACwAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAACaWQAAAAAAAYAAAAAAAAAB2l0ZW1faWQAAAAABAAAAAAAAAAIb3JkZXJfaWQAAAPoAAAABgAAAAAAAAANcGF5bWVudF90b2tlbgAAAAAAABMAAAAAAAAAA3F0eQAAAAAEAAAAAAAAAAZzZWxsZXIAAAAAABMAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAtPZmZlclN0YXR1cwAAAAAAAAAACnVuaXRfcHJpY2UAAAAAAAs=",
+        "AAAAAgAAACRXaGVyZSBhbiBvZmZlciBpcyBpbiB0aGUgbmVnb3RpYXRpb24AAAAAAAAAC09mZmVyU3RhdHVzAAAAAAUAAAAAAAAAAAAAAARPcGVuAAAAAAAAAAAAAAAJQ291bnRlcmVkAAAAAAAAAAAAAAAAAAAIQWNjZXB0ZWQAAAAAAAAAAAAAAAhSZWplY3RlZAAAAAAAAAAAAAAACVdpdGhkcmF3bgAAAA==",
+        "AAAAAQAAACdBIHNpbmdsZSBsb3Qgc29sZCB0byB0aGUgaGlnaGVzdCBiaWRkZXIAAAAAAAAAAAdBdWN0aW9uAAAAAAwAAAAAAAAACWJpZF9jb3VudAAAAAAAAAQAAAAAAAAACGVuZF90aW1lAAAABgAAAAAAAAALaGlnaGVzdF9iaWQAAAAACwAAAAAAAAAOaGlnaGVzdF9iaWRkZXIAAAAAA+gAAAATAAAAAAAAAAJpZAAAAAAABAAAAAAAAAAJaW1hZ2VfdXJsAAAAAAAAEAAAAAAAAAANbWluX2luY3JlbWVudAAAAAAAAAsAAAAAAAAABG5hbWUAAAAQAAAAAAAAAA1wYXltZW50X3Rva2VuAAAAAAAAEwAAAAAAAAANcmVzZXJ2ZV9wcmljZQAAAAAAAAsAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAGc3RhdHVzAAAAAAfQAAAADUF1Y3Rpb25TdGF0dXMAAAA=",
+        "AAAAAgAAACRXaGVyZSBhbiBhdWN0aW9uIGlzIGluIGl0cyBsaWZlY3ljbGUAAAAAAAAADUF1Y3Rpb25TdGF0dXMAAAAAAAADAAAAAAAAAAAAAAAET3BlbgAAAAAAAAAAAAAABFNvbGQAAAAAAAAAAAAAAAZVbnNvbGQAAA==",
+        "AAAAAQAAACRQbGF0Zm9ybSBmZWUgdGFrZW4gb24gZXZlcnkgcHVyY2hhc2UAAAAAAAAACUZlZUNvbmZpZwAAAAAAAAIAAAAAAAAAB2ZlZV9icHMAAAAABAAAAAAAAAAIdHJlYXN1cnkAAAAT",
+        "AAAAAgAAADxBY2Nlc3MtY29udHJvbCByb2xlcy4gVGhlIGFkbWluIGltcGxpY2l0bHkgaG9sZHMgZXZlcnkgcm9sZS4AAAAAAAAABFJvbGUAAAAFAAAAAAAAAAAAAAAFQWRtaW4AAAAAAAAAAAAAAAAAAAdBcmJpdGVyAAAAAAAAAAAAAAAABlBhdXNlcgAAAAAAAAAAAAAAAAAKRmVlTWFuYWdlcgAAAAAAAAAAAAAAAAAIQXR0ZXN0ZXI=",
+      ]),
+      options
+    )
+  }
+}
@@ -1,156 +0,0 @@
-export interface PaymentAgreement {
-  id: string
-  buyer: string
-  seller: string
-  amount: string
-  installments: number
-  paidInstallments: number
-  itemDescription: string
-  status: "active" | "completed" | "disputed" | "cancelled"
-  createdAt: number
-  lastPaymentAt?: number
-}
-
-export interface ContractMethods {
-  createAgreement: (
-    seller: string,
-    amount: string,
-    installments: number,
-    itemDescription: string
-  ) => Promise<string>
-  
-  makePayment: (
-    agreementId: string,
-    amount: string
-  ) => Promise<string>
-  
-  getAgreement: (agreementId: string) => Promise<PaymentAgreement | null>
-  
-  releaseFunds: (agreementId: string) => Promise<string>
-  
-  disputeAgreement: (agreementId: string) => Promise<string>
-}
-
-export const createPayStarContract = (contractId: string): ContractMethods => {
-  const createAgreement = async (
-    seller: string,
-    amount: string,
-    installments: number,
-    itemDescription: string
-  ): Promise<string> => {
-    try {
-      // This would invoke the actual Soroban contract
-      // For now, we'll simulate the contract call
-      console.log("Creating agreement:", { seller, amount, installments, itemDescription })
-      
-      // Generate a mock agreement ID
-      const agreementId = `AGR-${Date.now()}`
-      
-      // In a real implementation, this would:
-      // 1. Create a transaction to call the contract
-      // 2. Sign it with the user's wallet
-      // 3. Submit to the Stellar network
-      // 4. Return the transaction hash or agreement ID
-      
-      return agreementId
-    } catch (error) {
-      console.error("Error creating agreement:", error)
-      throw error
-    }
-  }
-
-  const makePayment = async (
-    agreementId: string,
-    amount: string
-  ): Promise<string> => {
-    try {
-      console.log("Making payment:", { agreementId, amount })
-      
-      // Generate a mock transaction hash
-      const txHash = `TX-${Date.now()}`
-      
-      // In a real implementation, this would:
-      // 1. Create a payment transaction
-      // 2. Call the contract's payment method
-      // 3. Update the agreement state
-      // 4. Return the transaction hash
-      
-      return txHash
-    } catch (error) {
-      console.error("Error making payment:", error)
-      throw error
-    }
-  }
-
-  const getAgreement = async (agreementId: string): Promise<PaymentAgreement | null> => {
-    try {
-      console.log("Getting agreement:", agreementId)
-      
-      // In a real implementation, this would query the contract state
-      // For now, return a mock agreement
-      return {
-        id: agreementId,
-        buyer: "BUYER_ADDRESS",
-        seller: "SELLER_ADDRESS",
-        amount: "1000",
-        installments: 4,
-        paidInstallments: 1,
-        itemDescription: "Mock item",
-        status: "active",
-        createdAt: Date.now(),
-        lastPaymentAt: Date.now() - 86400000, // 1 day ago
-      }
-    } catch (error) {
-      console.error("Error getting agreement:", error)
-      return null
-    }
-  }
-
-  const releaseFunds = async (agreementId: string): Promise<string> => {
-    try {
-      console.log("Releasing funds for agreement:", agreementId)
-      
-      // Generate a mock transaction hash
-      const txHash = `TX-RELEASE-${Date.now()}`
-      
-      // In a real implementation, this would:
-      // 1. Verify the caller is authorized (buyer or admin)
-      // 2. Transfer funds from escrow to seller
-      // 3. Update agreement status to completed
-      // 4. Return the transaction hash
-      
-      return txHash
-    } catch (error) {
-      console.error("Error releasing funds:", error)
-      throw error
-    }
-  }
-
-  const disputeAgreement = async (agreementId: string): Promise<string> => {
-    try {
-      console.log("Disputing agreement:", agreementId)
-      
-      // Generate a mock transaction hash
-      const txHash = `TX-DISPUTE-${Date.now()}`
-      
-      // In a real implementation, this would:
-      // 1. Update agreement status to disputed
-      // 2. Lock funds in escrow
-      // 3. Trigger dispute resolution process
-      // 4. Return the transaction hash
-      
-      return txHash
-    } catch (error) {
-      console.error("Error disputing agreement:", error)
-      throw error
-    }
-  }
-
-  return {
-    createAgreement,
-    makePayment,
-    getAgreement,
-    releaseFunds,
-    disputeAgreement,
-  }
-}
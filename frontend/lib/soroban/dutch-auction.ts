@@ -0,0 +1,41 @@
+import type { DutchAuction } from "@/lib/soroban/contracts/marketplace"
+
+// Mirrors the marketplace's price curve so the current price and the countdown to
+// the next drop can be shown without a contract call every second. The contract's
+// `get_price_at` stays the source of truth for what a purchase costs.
+
+/** Unit price of a Dutch auction at a Unix timestamp in seconds */
+export const priceAt = (auction: DutchAuction, timestamp: bigint): bigint => {
+  if (timestamp <= auction.start_time) return auction.start_price
+  if (timestamp >= auction.end_time) return auction.floor_price
+
+  const drop = auction.start_price - auction.floor_price
+  const elapsed = timestamp - auction.start_time
+  const duration = auction.end_time - auction.start_time
+  // BigInt division rounds down like the contract does
+  const dropped =
+    auction.curve.tag === "Linear"
+      ? (drop * elapsed) / duration
+      : stepDrop(drop, elapsed, duration, BigInt(auction.curve.values[0]))
+  return auction.start_price - dropped
+}
+
+/**
+ * When to refresh the price: the next drop of a stepwise curve, every second for a
+ * linear one, or undefined once the price has reached the floor
+ */
+export const nextDropAt = (auction: DutchAuction, timestamp: bigint): bigint | undefined => {
+  if (timestamp >= auction.end_time) return undefined
+  if (timestamp < auction.start_time) return auction.start_time
+  if (auction.curve.tag === "Linear") return timestamp + BigInt(1)
+
+  const steps = BigInt(auction.curve.values[0])
+  const duration = auction.end_time - auction.start_time
+  const taken = ((timestamp - auction.start_time) * steps) / duration
+  // First second at which `taken + 1` steps have elapsed
+  const next = ((taken + BigInt(1)) * duration + steps - BigInt(1)) / steps
+  return auction.start_time + next
+}
+
+const stepDrop = (drop: bigint, elapsed: bigint, duration: bigint, steps: bigint) =>
+  (drop * ((elapsed * steps) / duration)) / steps